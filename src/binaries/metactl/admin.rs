@@ -0,0 +1,90 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use common_meta_client::reply_to_api_result;
+use common_meta_client::MetaGrpcClient;
+use common_meta_types::Endpoint;
+use common_meta_types::MetaAPIError;
+use databend_meta::message::AddLearnerRequest;
+use databend_meta::message::ClusterAdminRequest;
+use databend_meta::message::DemoteVoterRequest;
+use databend_meta::message::PromoteLearnerRequest;
+use databend_meta::message::RemoveNodeRequest;
+use databend_meta::message::TransferLeaderRequest;
+
+use crate::Config;
+
+/// Build a cluster admin request from the `--cmd` and the arguments.
+pub fn build_admin_request(config: &Config) -> anyhow::Result<ClusterAdminRequest> {
+    let node_id = config.node_id;
+    let req = match config.cmd.as_str() {
+        "add-learner" => {
+            let raft_config = &config.raft_config;
+            let grpc_api_advertise_address = if config.grpc_api_advertise_address.is_empty() {
+                None
+            } else {
+                Some(config.grpc_api_advertise_address.clone())
+            };
+            AddLearnerRequest {
+                node_id,
+                endpoint: Endpoint::new(
+                    &raft_config.raft_advertise_host,
+                    raft_config.raft_api_port,
+                ),
+                grpc_api_advertise_address,
+            }
+            .into()
+        }
+        "promote-learner" => PromoteLearnerRequest { node_id }.into(),
+        "demote-voter" => DemoteVoterRequest { node_id }.into(),
+        "remove-node" => RemoveNodeRequest { node_id }.into(),
+        "transfer-leader" => TransferLeaderRequest { to: node_id }.into(),
+        _ => return Err(anyhow!("not a cluster admin cmd: {}", config.cmd)),
+    };
+    Ok(req)
+}
+
+/// Send a cluster admin request to a running meta-service.
+///
+/// The meta-service forwards it to the leader, or to the target node of `TransferLeader`.
+pub async fn cluster_admin(config: &Config, req: ClusterAdminRequest) -> anyhow::Result<()> {
+    if config.grpc_api_address.is_empty() {
+        return Err(anyhow!("cluster admin requires --grpc-api-address"));
+    }
+
+    eprintln!("    To: meta-service: {}", config.grpc_api_address);
+    eprintln!("    Request: {:?}", req);
+
+    let client = MetaGrpcClient::try_create(
+        vec![config.grpc_api_address.clone()],
+        "root",
+        "xxx",
+        None,
+        None,
+        Duration::from_secs(10),
+        None,
+    )?;
+
+    let mut grpc_client = client.make_client().await?;
+
+    let reply = grpc_client.cluster_admin(req).await?.into_inner();
+    let res: Result<(), MetaAPIError> = reply_to_api_result(reply);
+    res?;
+
+    eprintln!("Done");
+    Ok(())
+}
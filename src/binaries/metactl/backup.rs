@@ -0,0 +1,240 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::anyhow;
+use chrono::DateTime;
+use common_meta_client::MetaGrpcClient;
+use common_meta_raft_store::config::RaftConfig;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::log::RaftLog;
+use common_meta_raft_store::state::RaftState;
+use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_sled_store::get_sled_db;
+use common_meta_sled_store::init_sled_db;
+use common_meta_types::protobuf::Empty;
+use common_meta_types::Endpoint;
+use common_meta_types::Node;
+use databend_meta::backup::choose_backup;
+use databend_meta::backup::restore_backup;
+use databend_meta::backup::BackupMeta;
+use databend_meta::backup::RestoreTarget;
+use tokio_stream::StreamExt;
+
+use crate::snapshot::clear;
+use crate::snapshot::get_available_socket_addr;
+use crate::snapshot::init_new_cluster;
+use crate::Config;
+
+/// Take a backup from a running meta-service and save it into the dir `--to`.
+///
+/// The backup contains a snapshot of the state machine and the raft logs after it.
+pub async fn backup_data(config: &Config) -> anyhow::Result<()> {
+    if config.grpc_api_address.is_empty() {
+        return Err(anyhow!("backup requires --grpc-api-address"));
+    }
+
+    let dir = backup_dir(&config.to)?;
+    fs::create_dir_all(&dir)?;
+
+    eprintln!("    From: online meta-service: {}", config.grpc_api_address);
+    eprintln!("    To:   Dir: {}", dir.display());
+
+    let grpc_api_addr = get_available_socket_addr(&config.grpc_api_address).await?;
+
+    let client = MetaGrpcClient::try_create(
+        vec![grpc_api_addr.to_string()],
+        "root",
+        "xxx",
+        None,
+        None,
+        Duration::from_secs(10),
+        None,
+    )?;
+
+    let mut grpc_client = client.make_client().await?;
+
+    let time_ms = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+
+    let backup = grpc_client.backup(tonic::Request::new(Empty {})).await?;
+    let mut stream = backup.into_inner();
+
+    let tmp_path = dir.join(format!(".{}.tmp", time_ms));
+    let mut file = File::create(&tmp_path)?;
+
+    let mut cnt = 0;
+    let mut meta = BackupMeta::new(time_ms);
+
+    while let Some(chunk_res) = stream.next().await {
+        let chunk = chunk_res?;
+
+        for line in &chunk.data {
+            let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+            meta.add_entry(&entry);
+
+            file.write_all(format!("{}\n", line).as_bytes())?;
+            cnt += 1;
+        }
+    }
+
+    file.sync_all()?;
+
+    let path = dir.join(meta.file_name());
+    fs::rename(&tmp_path, &path)?;
+
+    eprintln!(
+        "Backup {} records, snapshot at log index {}, last log index {}: {}",
+        cnt,
+        meta.snapshot_index,
+        meta.last_index,
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Restore a backup into an empty raft dir and initialize a single node cluster with it.
+///
+/// `--from` is a backup file or a backup dir, in which a backup is chosen by `--at`.
+pub async fn restore_data(config: &Config) -> anyhow::Result<()> {
+    let raft_config = &config.raft_config;
+
+    let target = parse_restore_target(&config.at)?;
+    let backup = choose_backup_file(&config.from, target)?;
+
+    eprintln!("    From: {}", backup.display());
+    eprintln!("    Into Meta Dir: '{}'", raft_config.raft_dir);
+    eprintln!("    At:   {}", target);
+
+    init_sled_db(raft_config.raft_dir.clone());
+
+    clear()?;
+
+    let db = get_sled_db();
+    let sto_config = RaftConfig {
+        id: raft_config.id,
+        ..Default::default()
+    };
+    let raft_state = RaftState::open_create(&db, &sto_config, None, Some(())).await?;
+    let (sm_id, _prev_sm_id) = raft_state.read_state_machine_id()?;
+    let sm = StateMachine::open(&sto_config, sm_id).await?;
+
+    let lines = BufReader::new(File::open(&backup)?).lines();
+    let summary = restore_backup(&sm, lines, target).await?;
+
+    let last_applied = summary
+        .last_applied
+        .ok_or_else(|| anyhow!("no state machine data in backup: {}", backup.display()))?;
+
+    // The applied logs are not kept in the raft log, mark them as purged.
+    let log = RaftLog::open(&db, &sto_config).await?;
+    log.set_last_purged(last_applied).await?;
+
+    eprintln!(
+        "Restored snapshot at {:?} and {} logs after it, last applied: {}",
+        summary.snapshot_log_id, summary.applied_logs, last_applied
+    );
+
+    if target != RestoreTarget::Latest && !summary.target_reached {
+        eprintln!(
+            "WARN: the backup ends before {}, restored to the last log in it",
+            target
+        );
+    }
+
+    let endpoint = Endpoint {
+        addr: raft_config.raft_advertise_host.clone(),
+        port: raft_config.raft_api_port,
+    };
+    let nodes = BTreeMap::from([(raft_config.id, Node::new(raft_config.id, endpoint))]);
+
+    init_new_cluster(nodes, Some(last_applied), raft_config.id).await?;
+    Ok(())
+}
+
+/// Parse `--at`: empty for the latest, a log index, or a time in RFC 3339, e.g. `2023-05-01T08:00:00Z`.
+fn parse_restore_target(at: &str) -> anyhow::Result<RestoreTarget> {
+    if at.is_empty() {
+        return Ok(RestoreTarget::Latest);
+    }
+
+    if let Ok(index) = at.parse::<u64>() {
+        return Ok(RestoreTarget::LogIndex(index));
+    }
+
+    let t = DateTime::parse_from_rfc3339(at)
+        .map_err(|e| anyhow!("invalid --at: {}, expect a log index or a time: {}", at, e))?;
+    Ok(RestoreTarget::TimeMs(t.timestamp_millis() as u64))
+}
+
+/// Choose the backup to restore from a backup file or a backup dir.
+fn choose_backup_file(from: &str, target: RestoreTarget) -> anyhow::Result<PathBuf> {
+    let from = backup_dir(from)?;
+    if from.is_file() {
+        // The time of the snapshot is only known by the file name.
+        let meta = from
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(BackupMeta::parse_file_name);
+        return match meta {
+            Some(meta) if !meta.snapshot_not_after(target) => Err(anyhow!(
+                "the snapshot in backup {} is after the target {}",
+                from.display(),
+                target
+            )),
+            None if matches!(target, RestoreTarget::TimeMs(_)) => Err(anyhow!(
+                "can not tell the time of the snapshot in backup {}, it is not a backup file name",
+                from.display()
+            )),
+            _ => Ok(from),
+        };
+    }
+
+    let mut backups = vec![];
+    for entry in fs::read_dir(&from)? {
+        let name = entry?.file_name();
+        if let Some(meta) = name.to_str().and_then(BackupMeta::parse_file_name) {
+            backups.push(meta);
+        }
+    }
+
+    let chosen = choose_backup(&backups, target).ok_or_else(|| {
+        anyhow!(
+            "no backup in {} can be restored to {}",
+            from.display(),
+            target
+        )
+    })?;
+    Ok(from.join(chosen.file_name()))
+}
+
+/// A local path or a `fs://` storage location.
+fn backup_dir(location: &str) -> anyhow::Result<PathBuf> {
+    if location.is_empty() {
+        return Err(anyhow!("backup location is not specified"));
+    }
+    let path = location.strip_prefix("fs://").unwrap_or(location);
+    Ok(Path::new(path).to_path_buf())
+}
@@ -17,6 +17,8 @@
 mod grpc;
 use grpc::export_meta;
 
+mod admin;
+mod backup;
 mod snapshot;
 
 use std::time::Duration;
@@ -53,9 +55,26 @@ pub struct Config {
     #[clap(long)]
     pub export: bool,
 
+    /// Backup a running meta-service into the dir `--to`.
+    #[clap(long)]
+    pub backup: bool,
+
+    /// Restore a backup from `--from` into `--raft-dir`, as a new single node cluster.
+    #[clap(long)]
+    pub restore: bool,
+
     #[clap(long, env = "METASRV_GRPC_API_ADDRESS", default_value = "")]
     pub grpc_api_address: String,
 
+    /// The node to add, promote, demote, remove or transfer the leadership to,
+    /// with cluster admin commands.
+    #[clap(long, default_value = "0")]
+    pub node_id: u64,
+
+    /// The grpc api address of the node to add, with `--cmd add-learner`.
+    #[clap(long, default_value = "")]
+    pub grpc_api_advertise_address: String,
+
     /// When export raft data, this is the name of the save db file.
     /// If `db` is empty, output the exported data as json to stdout instead.
     /// When import raft data, this is the name of the restored db file.
@@ -63,6 +82,20 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub db: String,
 
+    /// When backup, the dir to save the backup into, a local path or `fs://<path>`.
+    #[clap(long, default_value = "")]
+    pub to: String,
+
+    /// When restore, the backup file, or the dir in which the backup is chosen by `--at`.
+    #[clap(long, default_value = "")]
+    pub from: String,
+
+    /// When restore, the point to restore to:
+    /// a log index, or a time in RFC 3339, e.g. `2023-05-01T08:00:00Z`.
+    /// If `at` is empty, restore all the logs in the backup.
+    #[clap(long, default_value = "")]
+    pub at: String,
+
     /// initial_cluster format: node_id=endpoint,grpc_api_addr
     #[clap(long, multiple_occurrences = true, multiple_values = true)]
     pub initial_cluster: Vec<String>,
@@ -241,11 +274,31 @@ async fn main() -> anyhow::Result<()> {
                 Ok(())
             }
 
+            "add-learner" | "promote-learner" | "demote-voter" | "remove-node"
+            | "transfer-leader" => {
+                let req = admin::build_admin_request(&config)?;
+                admin::cluster_admin(&config, req).await
+            }
+
             _ => {
                 eprintln!("valid commands are");
                 eprintln!("  --cmd bench-client-conn-num");
                 eprintln!("    Keep create new connections to metasrv.");
                 eprintln!("    Requires --grpc-api-address.");
+                eprintln!("  --cmd add-learner");
+                eprintln!(
+                    "    Add node --node-id as a learner, which listens on --raft-advertise-host and --raft-api-port."
+                );
+                eprintln!("    Its --grpc-api-advertise-address is optional.");
+                eprintln!("  --cmd promote-learner");
+                eprintln!("    Promote learner --node-id to a voter.");
+                eprintln!("  --cmd demote-voter");
+                eprintln!("    Demote voter --node-id to a learner.");
+                eprintln!("  --cmd remove-node");
+                eprintln!("    Remove voter or learner --node-id from the cluster.");
+                eprintln!("  --cmd transfer-leader");
+                eprintln!("    Make voter --node-id the leader.");
+                eprintln!("  The cluster admin commands require --grpc-api-address.");
 
                 Err(anyhow::anyhow!("unknown cmd: {}", config.cmd))
             }
@@ -264,6 +317,18 @@ async fn main() -> anyhow::Result<()> {
         return snapshot::import_data(&config).await;
     }
 
+    if config.backup {
+        eprintln!();
+        eprintln!("Backup:");
+        return backup::backup_data(&config).await;
+    }
+
+    if config.restore {
+        eprintln!();
+        eprintln!("Restore:");
+        return backup::restore_data(&config).await;
+    }
+
     Err(anyhow::anyhow!("Nothing to do"))
 }
 
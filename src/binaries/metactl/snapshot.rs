@@ -253,7 +253,7 @@ fn build_nodes(initial_cluster: Vec<String>, id: u64) -> anyhow::Result<BTreeMap
 }
 
 // initial_cluster format: node_id=endpoint,grpc_api_addr;
-async fn init_new_cluster(
+pub(crate) async fn init_new_cluster(
     nodes: BTreeMap<NodeId, Node>,
     max_log_id: Option<LogId>,
     id: u64,
@@ -329,7 +329,7 @@ async fn init_new_cluster(
     Ok(())
 }
 
-fn clear() -> anyhow::Result<()> {
+pub(crate) fn clear() -> anyhow::Result<()> {
     let db = get_sled_db();
 
     let tree_names = db.tree_names();
@@ -437,7 +437,7 @@ async fn is_service_running(addr: SocketAddr) -> Result<bool, io::Error> {
 }
 
 // try to get available grpc api socket address
-async fn get_available_socket_addr(endpoint: &str) -> Result<SocketAddr, anyhow::Error> {
+pub(crate) async fn get_available_socket_addr(endpoint: &str) -> Result<SocketAddr, anyhow::Error> {
     let addrs_iter = endpoint.to_socket_addrs()?;
     for addr in addrs_iter {
         if is_service_running(addr).await? {
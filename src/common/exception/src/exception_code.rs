@@ -140,11 +140,17 @@ build_exceptions! {
     TableWithInternalColumnName(1110),
     EmptyShareEndpointConfig(1111),
     LicenceDenied(1112),
+    // alter table modify column to a data type which the old data can't be cast to
+    ModifyColumnTypeError(1113),
+    // alter table drop or modify a column which a computed column depends on
+    ColumnReferencedByComputedColumn(1114),
 
     // Data Related Errors
 
     /// ParquetFileInvalid is used when given parquet file is invalid.
     ParquetFileInvalid(1201),
+    /// OrcFileInvalid is used when given orc file is invalid.
+    OrcFileInvalid(1202),
 
     // Table related errors starts here.
 
@@ -172,7 +178,14 @@ build_exceptions! {
     /// LicenseKeyInvalid is used when license key verification error occurs
     ///
     /// For example: license key is expired
-    LicenseKeyInvalid(1402)
+    LicenseKeyInvalid(1402),
+
+    // Transaction related errors starts here
+
+    /// CurrentTransactionIsAborted is used when a statement of the transaction failed
+    ///
+    /// For example: the statements are rejected until the transaction is rolled back
+    CurrentTransactionIsAborted(1501)
 }
 
 // Meta service errors [2001, 3000].
@@ -278,6 +291,19 @@ build_exceptions! {
     UnknownIndex(2722),
     DropIndexWithDropTime(2723),
 
+    // Sequence error codes.
+    SequenceAlreadyExists(2724),
+    UnknownSequence(2725),
+    SequenceOutOfRange(2726),
+
+    // Task error codes.
+    TaskAlreadyExists(2727),
+    UnknownTask(2728),
+
+    // Masking policy error codes.
+    MaskingPolicyAlreadyExists(2729),
+    UnknownMaskingPolicy(2730),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -117,6 +117,12 @@ impl From<common_arrow::parquet::error::Error> for ErrorCode {
     }
 }
 
+impl From<common_arrow::arrow::io::orc::format::error::Error> for ErrorCode {
+    fn from(error: common_arrow::arrow::io::orc::format::error::Error) -> Self {
+        ErrorCode::OrcFileInvalid(format!("{:?}", error))
+    }
+}
+
 impl From<bincode::error::EncodeError> for ErrorCode {
     fn from(error: bincode::error::EncodeError) -> Self {
         ErrorCode::from_std_error(error)
@@ -32,6 +32,9 @@ use crate::cursor_ext::cursor_read_bytes_ext::ReadBytesExt;
 pub trait BufferReadDateTimeExt {
     fn read_date_text(&mut self, tz: &Tz) -> Result<NaiveDate>;
     fn read_timestamp_text(&mut self, tz: &Tz) -> Result<DateTime<Tz>>;
+    /// Like `read_timestamp_text`, but keeps the UTC offset written in the text.
+    /// The offset of `tz` at that time is used if the text has none.
+    fn read_timestamp_tz_text(&mut self, tz: &Tz) -> Result<DateTime<FixedOffset>>;
     fn parse_time_offset(
         &mut self,
         tz: &Tz,
@@ -56,6 +59,29 @@ fn parse_time_part(buf: &[u8], size: usize) -> Result<u32> {
     }
 }
 
+// Find the UTC offset at the end of a timestamp text, like `Z`, `+08:00` or `-0800`.
+fn parse_offset_suffix(text: &[u8]) -> Option<i32> {
+    let time = text.get(DATE_LEN..)?;
+    if matches!(time.last(), Some(b'z' | b'Z')) {
+        return Some(0);
+    }
+    let pos = time.iter().rposition(|b| *b == b'+' || *b == b'-')?;
+    let digits = time[pos + 1..]
+        .iter()
+        .filter(|b| b.is_ascii_digit())
+        .map(|b| (b - b'0') as i32)
+        .collect::<Vec<_>>();
+    if digits.len() != 4 {
+        return None;
+    }
+    let secs = (digits[0] * 10 + digits[1]) * 3600 + (digits[2] * 10 + digits[3]) * 60;
+    if time[pos] == b'-' {
+        Some(-secs)
+    } else {
+        Some(secs)
+    }
+}
+
 // fn calc_offset(current_tz_sec: i64, val_tz_sec: i64, dt: &DateTime<Tz>, tz: &Tz) -> () {
 //     let offset = (current_tz_sec - val_tz_sec) * 1000 * 1000;
 //     let mut ts = dt.timestamp_micros();
@@ -218,6 +244,17 @@ where T: AsRef<[u8]>
         }
     }
 
+    fn read_timestamp_tz_text(&mut self, tz: &Tz) -> Result<DateTime<FixedOffset>> {
+        let start = self.position() as usize;
+        let dt = self.read_timestamp_text(tz)?;
+        let end = self.position() as usize;
+        let offset = match parse_offset_suffix(&self.get_ref().as_ref()[start..end]) {
+            Some(offset) => offset,
+            None => dt.offset().fix().local_minus_utc(),
+        };
+        Ok(dt.with_timezone(&FixedOffset::east_opt(offset).unwrap()))
+    }
+
     // Only support HH:mm format
     fn parse_time_offset(
         &mut self,
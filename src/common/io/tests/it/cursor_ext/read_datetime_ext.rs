@@ -58,6 +58,29 @@ fn test_read_timestamp_text() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_read_timestamp_tz_text() -> Result<()> {
+    let mut reader = Cursor::new(
+        "2022-03-04T00:01:02+08:00,2022-03-04T00:01:02-0830,2020-01-01T11:11:11.123Z,2022-02-02 12:03,2022-07-01 08:00:00".as_bytes(),
+    );
+    let tz: Tz = "America/New_York".parse().unwrap();
+    let expected = vec![
+        "2022-03-04T00:01:02+08:00",
+        "2022-03-04T00:01:02-08:30",
+        "2020-01-01T11:11:11.123+00:00",
+        "2022-02-02T12:03:00-05:00",
+        "2022-07-01T08:00:00-04:00",
+    ];
+    let mut res = vec![];
+    for _ in 0..expected.len() {
+        let time = reader.read_timestamp_tz_text(&tz)?;
+        res.push(format!("{:?}", time));
+        reader.ignore_byte(b',');
+    }
+    assert_eq!(res, expected);
+    Ok(())
+}
+
 #[test]
 fn test_read_date_text() -> Result<()> {
     let mut reader = Cursor::new("2009-01-01,1000-01-01,2023-03-03,2022-02-02,2009-01-01 3:2:1.123,2009-01-01 0:00:00,2009-01-01 00:00:00.123,2009-01-01 00:00:00.123456,0002-03-03T00:01:02,2022-03-04T00:01:02+08:00,2022-03-04T00:01:02-08:00,0000-00-00,0000-00-00 00:00:00,0001-01-01 00:00:00,2020-01-01T11:11:11Z,2009-01-03 00:00:00,2020-01-01T11:11:11.123Z,2055-02-03 10:00:20.234+08:00,2055-02-03 10:00:20.234-08:00,1022-05-16T03:25:02.000000+08:00,2055-01-01".as_bytes());
@@ -22,11 +22,23 @@ pub type ProfSpanSetRef<K = u32> = Arc<Mutex<ProfSpanSet<K>>>;
 pub struct ProfSpan {
     /// The time spent to process in nanoseconds
     pub process_time: u64,
+    /// The number of blocks spilled to storage
+    pub spill_write_count: u64,
+    /// The size of data spilled to storage in bytes
+    pub spill_write_bytes: u64,
+    /// The number of spilled blocks read back from storage
+    pub spill_read_count: u64,
+    /// The size of spilled data read back from storage in bytes
+    pub spill_read_bytes: u64,
 }
 
 impl ProfSpan {
     pub fn add(&mut self, other: &Self) {
         self.process_time += other.process_time;
+        self.spill_write_count += other.spill_write_count;
+        self.spill_write_bytes += other.spill_write_bytes;
+        self.spill_read_count += other.spill_read_count;
+        self.spill_read_bytes += other.spill_read_bytes;
     }
 }
 
@@ -65,6 +77,7 @@ impl ProfSpanBuilder {
     pub fn finish(self) -> ProfSpan {
         ProfSpan {
             process_time: self.process_time,
+            ..Default::default()
         }
     }
 }
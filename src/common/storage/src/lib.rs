@@ -54,6 +54,20 @@ mod parquet;
 pub use parquet::read_parquet_metas_in_parallel;
 pub use parquet::read_parquet_schema_async;
 
+mod orc;
+pub use orc::deserialize_orc_stripe;
+pub use orc::infer_orc_schema;
+pub use orc::orc_column_ids;
+pub use orc::orc_stripe_column_statistics;
+pub use orc::orc_stripe_num_rows;
+pub use orc::orc_stripe_range;
+pub use orc::read_orc_metadata;
+pub use orc::read_orc_metadata_async;
+pub use orc::read_orc_stripe_async;
+pub use orc::slice_orc_stripe;
+pub use orc::OrcFileMeta;
+pub use orc::OrcStripe;
+
 mod stage;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
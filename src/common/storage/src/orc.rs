@@ -0,0 +1,248 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::orc::format::proto::ColumnStatistics;
+use common_arrow::arrow::io::orc::format::read as orc_format_read;
+use common_arrow::arrow::io::orc::read as orc_read;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+/// The metadata (postscript, footer and stripe statistics) of an orc file.
+pub type OrcFileMeta = orc_format_read::FileMetadata;
+
+// The bytes read for the file tail at first, large enough for the footer of most files.
+const ORC_TAIL_READ_SIZE: u64 = 256 * 1024;
+
+/// The bytes of a stripe, which is the unit of splitting for orc files.
+pub struct OrcStripe {
+    pub index: usize,
+    pub offset: u64,
+    pub data: Vec<u8>,
+}
+
+#[async_backtrace::framed]
+pub async fn read_orc_metadata_async(
+    operator: &Operator,
+    path: &str,
+    file_size: Option<u64>,
+) -> Result<OrcFileMeta> {
+    let file_size = match file_size {
+        Some(size) => size,
+        None => operator.stat(path).await?.content_length(),
+    };
+    let tail_size = std::cmp::min(file_size, ORC_TAIL_READ_SIZE);
+    let start = file_size - tail_size;
+    let tail = operator.range_read(path, start..file_size).await?;
+    match orc_format_read::read_metadata(&mut OffsetCursor::new(start, tail.as_slice())) {
+        Ok(meta) => Ok(meta),
+        // the footer is larger than the tail read, read the whole file instead
+        Err(_) if start > 0 => {
+            let data = operator.range_read(path, 0..file_size).await?;
+            read_orc_metadata(data.as_slice()).map_err(|e| {
+                ErrorCode::OrcFileInvalid(format!(
+                    "Read orc file '{}''s meta error: {}",
+                    path,
+                    e.message()
+                ))
+            })
+        }
+        Err(e) => Err(ErrorCode::OrcFileInvalid(format!(
+            "Read orc file '{}''s meta error: {:?}",
+            path, e
+        ))),
+    }
+}
+
+/// Read the metadata of an orc file which is totally in memory.
+pub fn read_orc_metadata(data: &[u8]) -> Result<OrcFileMeta> {
+    Ok(orc_format_read::read_metadata(&mut OffsetCursor::new(
+        0, data,
+    ))?)
+}
+
+pub fn infer_orc_schema(meta: &OrcFileMeta) -> Result<ArrowSchema> {
+    Ok(orc_read::infer_schema(&meta.footer)?)
+}
+
+/// The byte range of the stripe in the file, including the indexes and the stripe footer.
+pub fn orc_stripe_range(meta: &OrcFileMeta, stripe: usize) -> (u64, u64) {
+    let info = &meta.footer.stripes[stripe];
+    let len = info.index_length() + info.data_length() + info.footer_length();
+    (info.offset(), len)
+}
+
+pub fn orc_stripe_num_rows(meta: &OrcFileMeta, stripe: usize) -> usize {
+    meta.footer.stripes[stripe].number_of_rows() as usize
+}
+
+#[async_backtrace::framed]
+pub async fn read_orc_stripe_async(
+    operator: &Operator,
+    path: &str,
+    meta: &OrcFileMeta,
+    stripe: usize,
+) -> Result<OrcStripe> {
+    let (offset, len) = orc_stripe_range(meta, stripe);
+    let data = operator.range_read(path, offset..offset + len).await?;
+    Ok(OrcStripe {
+        index: stripe,
+        offset,
+        data,
+    })
+}
+
+/// Slice the stripe from an orc file which is totally in memory.
+pub fn slice_orc_stripe(data: &[u8], meta: &OrcFileMeta, stripe: usize) -> OrcStripe {
+    let (offset, len) = orc_stripe_range(meta, stripe);
+    OrcStripe {
+        index: stripe,
+        offset,
+        data: data[offset as usize..(offset + len) as usize].to_vec(),
+    }
+}
+
+/// The column ids of the top level fields, matched by name case-insensitively.
+///
+/// The column id 0 is the root struct, and the ids of the fields are its subtypes.
+pub fn orc_column_ids(meta: &OrcFileMeta, names: &[&str]) -> Result<Vec<u32>> {
+    let root = meta
+        .footer
+        .types
+        .first()
+        .ok_or_else(|| ErrorCode::OrcFileInvalid("orc file without types"))?;
+    names
+        .iter()
+        .map(|name| {
+            root.field_names
+                .iter()
+                .position(|f| f.eq_ignore_ascii_case(name))
+                .and_then(|pos| root.subtypes.get(pos).cloned())
+                .ok_or_else(|| {
+                    ErrorCode::TableSchemaMismatch(format!(
+                        "schema field size mismatch, expected to find column: {}",
+                        name
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Deserialize the given columns of the stripe.
+pub fn deserialize_orc_stripe(
+    meta: &OrcFileMeta,
+    stripe: &OrcStripe,
+    fields: &[Field],
+    column_ids: &[u32],
+) -> Result<ArrowChunk<Box<dyn Array>>> {
+    let mut reader = OffsetCursor::new(stripe.offset, stripe.data.as_slice());
+    let mut scratch = vec![];
+    let footer =
+        orc_format_read::read_stripe_footer(&mut reader, meta, stripe.index, &mut scratch)?;
+
+    let mut arrays = Vec::with_capacity(fields.len());
+    for (field, column_id) in fields.iter().zip(column_ids.iter()) {
+        let column = orc_format_read::read_stripe_column(
+            &mut reader,
+            meta,
+            stripe.index,
+            footer.clone(),
+            *column_id,
+            vec![],
+        )?;
+        arrays.push(orc_read::deserialize(field.data_type().clone(), &column)?);
+    }
+    Ok(ArrowChunk::try_new(arrays)?)
+}
+
+/// The statistics of the column in the stripe, if the file has them.
+pub fn orc_stripe_column_statistics(
+    meta: &OrcFileMeta,
+    stripe: usize,
+    column_id: u32,
+) -> Option<&ColumnStatistics> {
+    meta.metadata
+        .stripe_stats
+        .get(stripe)?
+        .col_stats
+        .get(column_id as usize)
+}
+
+// A cursor over a range of the file which starts at `offset`,
+// the positions used to seek are the positions in the whole file.
+struct OffsetCursor<T> {
+    offset: u64,
+    inner: Cursor<T>,
+}
+
+impl<T: AsRef<[u8]>> OffsetCursor<T> {
+    fn new(offset: u64, data: T) -> Self {
+        Self {
+            offset,
+            inner: Cursor::new(data),
+        }
+    }
+}
+
+impl<T: AsRef<[u8]>> Read for OffsetCursor<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<T: AsRef<[u8]>> Seek for OffsetCursor<T> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                if pos < self.offset {
+                    return Err(std::io::Error::other(format!(
+                        "seek to {} which is before the start {} of the range",
+                        pos, self.offset
+                    )));
+                }
+                self.inner.seek(SeekFrom::Start(pos - self.offset))?
+            }
+            SeekFrom::End(n) => {
+                let len = self.inner.get_ref().as_ref().len() as i64;
+                if len + n < 0 {
+                    return Err(std::io::Error::other(format!(
+                        "seek to {} from the end which is before the start {} of the range",
+                        n, self.offset
+                    )));
+                }
+                self.inner.seek(SeekFrom::End(n))?
+            }
+            SeekFrom::Current(n) => {
+                let current = self.inner.position() as i64;
+                if current + n < 0 {
+                    return Err(std::io::Error::other(format!(
+                        "seek by {} which is before the start {} of the range",
+                        n, self.offset
+                    )));
+                }
+                self.inner.seek(SeekFrom::Current(n))?
+            }
+        };
+        Ok(pos + self.offset)
+    }
+}
@@ -18,6 +18,7 @@ use crate::schema_api_keys::ID_GEN_DATABASE;
 use crate::schema_api_keys::ID_GEN_INDEX;
 use crate::schema_api_keys::ID_GEN_TABLE;
 use crate::schema_api_keys::ID_GEN_TABLE_LOCK;
+use crate::schema_api_keys::ID_GEN_TASK_RUN;
 use crate::share_api_keys::ID_GEN_SHARE;
 use crate::share_api_keys::ID_GEN_SHARE_ENDPOINT;
 
@@ -72,6 +73,12 @@ impl IdGenerator {
             resource: ID_GEN_TABLE_LOCK.to_string(),
         }
     }
+
+    pub fn task_run_id() -> Self {
+        Self {
+            resource: ID_GEN_TASK_RUN.to_string(),
+        }
+    }
 }
 
 impl kvapi::Key for IdGenerator {
@@ -162,6 +169,15 @@ mod t {
             assert_eq!(g1, g2);
         }
 
+        {
+            let g1 = IdGenerator::task_run_id();
+            let k = g1.to_string_key();
+            assert_eq!("__fd_id_gen/task_run_id", k);
+
+            let g2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g1, g2);
+        }
+
         Ok(())
     }
 
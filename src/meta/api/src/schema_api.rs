@@ -14,50 +14,80 @@
 
 use std::sync::Arc;
 
+use common_meta_app::schema::AcquireTaskLeaseReply;
+use common_meta_app::schema::AcquireTaskLeaseReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReply;
 use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateTaskReply;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::CreateTaskRunReply;
+use common_meta_app::schema::CreateTaskRunReq;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropTaskReply;
+use common_meta_app::schema::DropTaskReq;
 use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
+use common_meta_app::schema::GetTaskReply;
+use common_meta_app::schema::GetTaskReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListSequencesReq;
 use common_meta_app::schema::ListTableLockRevReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::ListTasksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRun;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateTaskRunReply;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_meta_app::schema::UpdateTaskStateReply;
+use common_meta_app::schema::UpdateTaskStateReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::GCDroppedDataReply;
@@ -85,6 +115,9 @@ pub trait SchemaApi: Send + Sync {
 
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, KVAppError>;
 
+    /// List databases of a tenant in the order of names.
+    ///
+    /// With `req.start_after` and `req.limit` the databases are returned page by page.
     async fn list_databases(
         &self,
         req: ListDatabaseReq,
@@ -111,6 +144,66 @@ pub trait SchemaApi: Send + Sync {
         req: ListIndexesReq,
     ) -> Result<Vec<(u64, String, IndexMeta)>, KVAppError>;
 
+    // sequence
+
+    async fn create_sequence(
+        &self,
+        req: CreateSequenceReq,
+    ) -> Result<CreateSequenceReply, KVAppError>;
+
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError>;
+
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply, KVAppError>;
+
+    async fn list_sequences(
+        &self,
+        req: ListSequencesReq,
+    ) -> Result<Vec<(String, SequenceMeta)>, KVAppError>;
+
+    /// Reserve `count` values of a sequence at once.
+    ///
+    /// The reserved values will never be returned again, even if they are not used.
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply, KVAppError>;
+
+    // task
+
+    async fn create_task(&self, req: CreateTaskReq) -> Result<CreateTaskReply, KVAppError>;
+
+    async fn drop_task(&self, req: DropTaskReq) -> Result<DropTaskReply, KVAppError>;
+
+    async fn get_task(&self, req: GetTaskReq) -> Result<GetTaskReply, KVAppError>;
+
+    async fn list_tasks(&self, req: ListTasksReq) -> Result<Vec<(String, TaskMeta)>, KVAppError>;
+
+    async fn update_task_state(
+        &self,
+        req: UpdateTaskStateReq,
+    ) -> Result<UpdateTaskStateReply, KVAppError>;
+
+    async fn create_task_run(
+        &self,
+        req: CreateTaskRunReq,
+    ) -> Result<CreateTaskRunReply, KVAppError>;
+
+    async fn update_task_run(
+        &self,
+        req: UpdateTaskRunReq,
+    ) -> Result<UpdateTaskRunReply, KVAppError>;
+
+    /// List the run history of all tasks of a tenant.
+    async fn list_task_runs(&self, req: ListTaskRunsReq) -> Result<Vec<TaskRun>, KVAppError>;
+
+    /// Acquire or extend the lease of running the scheduled tasks of a tenant.
+    ///
+    /// A lease is held by only one query node until it expires.
+    async fn acquire_task_lease(
+        &self,
+        req: AcquireTaskLeaseReq,
+    ) -> Result<AcquireTaskLeaseReply, KVAppError>;
+
     // table
 
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply, KVAppError>;
@@ -134,6 +227,9 @@ pub trait SchemaApi: Send + Sync {
     async fn get_table_history(&self, req: ListTableReq)
     -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
+    /// List tables of a database in the order of names.
+    ///
+    /// With `req.start_after` and `req.limit` the tables are returned page by page.
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
     async fn get_table_by_id(
@@ -159,6 +255,11 @@ pub trait SchemaApi: Send + Sync {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply, KVAppError>;
 
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError>;
+
     // gc dropped {table|db} which out of retention time.
     async fn gc_dropped_data(
         &self,
@@ -28,9 +28,12 @@ use common_meta_app::app_error::DropIndexWithDropTime;
 use common_meta_app::app_error::DropTableWithDropTime;
 use common_meta_app::app_error::DuplicatedUpsertFiles;
 use common_meta_app::app_error::IndexAlreadyExists;
+use common_meta_app::app_error::SequenceAlreadyExists;
+use common_meta_app::app_error::SequenceOutOfRange;
 use common_meta_app::app_error::ShareHasNoGrantedPrivilege;
 use common_meta_app::app_error::TableAlreadyExists;
 use common_meta_app::app_error::TableVersionMismatched;
+use common_meta_app::app_error::TaskAlreadyExists;
 use common_meta_app::app_error::TxnRetryMaxTimes;
 use common_meta_app::app_error::UndropDbHasNoHistory;
 use common_meta_app::app_error::UndropDbWithNoDropTime;
@@ -39,10 +42,14 @@ use common_meta_app::app_error::UndropTableHasNoHistory;
 use common_meta_app::app_error::UndropTableWithNoDropTime;
 use common_meta_app::app_error::UnknownDatabaseId;
 use common_meta_app::app_error::UnknownIndex;
+use common_meta_app::app_error::UnknownSequence;
 use common_meta_app::app_error::UnknownTable;
 use common_meta_app::app_error::UnknownTableId;
+use common_meta_app::app_error::UnknownTask;
 use common_meta_app::app_error::WrongShare;
 use common_meta_app::app_error::WrongShareObject;
+use common_meta_app::schema::AcquireTaskLeaseReply;
+use common_meta_app::schema::AcquireTaskLeaseReq;
 use common_meta_app::schema::CountTablesKey;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
@@ -50,10 +57,16 @@ use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReply;
 use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateTaskReply;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::CreateTaskRunReply;
+use common_meta_app::schema::CreateTaskRunReq;
 use common_meta_app::schema::DBIdTableName;
 use common_meta_app::schema::DatabaseId;
 use common_meta_app::schema::DatabaseIdToName;
@@ -69,26 +82,41 @@ use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropTaskReply;
+use common_meta_app::schema::DropTaskReq;
 use common_meta_app::schema::EmptyProto;
 use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
+use common_meta_app::schema::GetTaskReply;
+use common_meta_app::schema::GetTaskReq;
 use common_meta_app::schema::IndexId;
 use common_meta_app::schema::IndexIdToName;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::IndexNameIdent;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListSequencesReq;
 use common_meta_app::schema::ListTableLockRevReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::ListTasksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
+use common_meta_app::schema::SequenceNameIdent;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -100,14 +128,25 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableLockKey;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::TaskLeaseKey;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskNameIdent;
+use common_meta_app::schema::TaskRun;
+use common_meta_app::schema::TaskRunKey;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateTaskRunReply;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_meta_app::schema::UpdateTaskStateReply;
+use common_meta_app::schema::UpdateTaskStateReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
@@ -123,6 +162,7 @@ use common_meta_types::ConditionResult;
 use common_meta_types::GCDroppedDataReply;
 use common_meta_types::GCDroppedDataReq;
 use common_meta_types::InvalidReply;
+use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
@@ -151,6 +191,7 @@ use crate::is_db_need_to_be_remove;
 use crate::kv_app_error::KVAppError;
 use crate::list_keys;
 use crate::list_u64_value;
+use crate::list_u64_value_page;
 use crate::remove_db_from_share;
 use crate::send_txn;
 use crate::serialize_struct;
@@ -825,9 +866,14 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
             // Using a empty db to to list all
             db_name: "".to_string(),
         };
+        let start_after = req.start_after.map(|db_name| DatabaseNameIdent {
+            tenant: name_key.tenant.clone(),
+            db_name,
+        });
 
         // Pairs of db-name and db_id with seq
-        let (tenant_dbnames, db_ids) = list_u64_value(self, &name_key).await?;
+        let (tenant_dbnames, db_ids) =
+            list_u64_value_page(self, &name_key, start_after.as_ref(), req.limit).await?;
 
         // Keys for fetching serialized DatabaseMeta from kvapi::KVApi
         let mut kv_keys = Vec::with_capacity(db_ids.len());
@@ -1071,6 +1117,467 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         Ok(index_metas)
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn create_sequence(
+        &self,
+        req: CreateSequenceReq,
+    ) -> Result<CreateSequenceReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, _meta): (_, Option<SequenceMeta>) = get_pb_value(self, name_key).await?;
+            debug!(seq, ?name_key, "get_sequence");
+
+            if seq > 0 {
+                return if req.if_not_exists {
+                    Ok(CreateSequenceReply {})
+                } else {
+                    Err(KVAppError::AppError(AppError::SequenceAlreadyExists(
+                        SequenceAlreadyExists::new(
+                            &name_key.sequence_name,
+                            format!("create sequence with tenant: {}", name_key.tenant),
+                        ),
+                    )))
+                };
+            }
+
+            // (tenant, sequence_name) -> sequence_meta
+            let condition = vec![txn_cond_seq(name_key, Eq, 0)];
+            let if_then = vec![txn_op_put(name_key, serialize_struct(&req.meta)?)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&name_key),
+                succ = display(succ),
+                "create_sequence"
+            );
+
+            if succ {
+                return Ok(CreateSequenceReply {});
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, _meta): (_, Option<SequenceMeta>) = get_pb_value(self, name_key).await?;
+
+            if seq == 0 {
+                return if req.if_exists {
+                    Ok(DropSequenceReply {})
+                } else {
+                    Err(KVAppError::AppError(AppError::UnknownSequence(
+                        UnknownSequence::new(&name_key.sequence_name, "drop_sequence"),
+                    )))
+                };
+            }
+
+            let condition = vec![txn_cond_seq(name_key, Eq, seq)];
+            let if_then = vec![txn_op_del(name_key)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&name_key),
+                succ = display(succ),
+                "drop_sequence"
+            );
+
+            if succ {
+                return Ok(DropSequenceReply {});
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let (_seq, meta) = get_sequence_or_err(self, &req.name_ident, "get_sequence").await?;
+        Ok(GetSequenceReply { meta })
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn list_sequences(
+        &self,
+        req: ListSequencesReq,
+    ) -> Result<Vec<(String, SequenceMeta)>, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        // List sequences by `prefix_list` "<prefix>/<tenant>"
+        let prefix_key = kvapi::KeyBuilder::new_prefixed(SequenceNameIdent::PREFIX)
+            .push_str(&req.tenant)
+            .done();
+
+        let kvs = self.prefix_list_kv(&prefix_key).await?;
+        let mut sequences = Vec::with_capacity(kvs.len());
+        for (key, seqv) in kvs.iter() {
+            let name_ident = SequenceNameIdent::from_str_key(key).map_err(|e| {
+                KVAppError::MetaError(MetaError::from(InvalidReply::new("list_sequences", &e)))
+            })?;
+            let meta: SequenceMeta = deserialize_struct(&seqv.data)?;
+            sequences.push((name_ident.sequence_name, meta));
+        }
+
+        Ok(sequences)
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, mut meta) = get_sequence_or_err(self, name_key, ctx).await?;
+
+            let start = meta.current;
+            let end = req
+                .count
+                .checked_mul(meta.increment)
+                .and_then(|n| start.checked_add(n))
+                .ok_or_else(|| {
+                    KVAppError::AppError(AppError::SequenceOutOfRange(SequenceOutOfRange::new(
+                        &name_key.sequence_name,
+                        req.count,
+                        ctx.to_string(),
+                    )))
+                })?;
+
+            meta.current = end;
+            meta.updated_on = Utc::now();
+
+            let condition = vec![txn_cond_seq(name_key, Eq, seq)];
+            let if_then = vec![txn_op_put(name_key, serialize_struct(&meta)?)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&name_key),
+                start,
+                end,
+                succ = display(succ),
+                "get_sequence_next_value"
+            );
+
+            if succ {
+                return Ok(GetSequenceNextValueReply {
+                    start,
+                    end,
+                    increment: meta.increment,
+                    created_on: meta.created_on,
+                });
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn create_task(&self, req: CreateTaskReq) -> Result<CreateTaskReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, _meta): (_, Option<TaskMeta>) = get_pb_value(self, name_key).await?;
+            debug!(seq, ?name_key, "get_task");
+
+            if seq > 0 {
+                return if req.if_not_exists {
+                    Ok(CreateTaskReply {})
+                } else {
+                    Err(KVAppError::AppError(AppError::TaskAlreadyExists(
+                        TaskAlreadyExists::new(
+                            &name_key.task_name,
+                            format!("create task with tenant: {}", name_key.tenant),
+                        ),
+                    )))
+                };
+            }
+
+            // (tenant, task_name) -> task_meta
+            let condition = vec![txn_cond_seq(name_key, Eq, 0)];
+            let if_then = vec![txn_op_put(name_key, serialize_struct(&req.meta)?)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(name = debug(&name_key), succ = display(succ), "create_task");
+
+            if succ {
+                return Ok(CreateTaskReply {});
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn drop_task(&self, req: DropTaskReq) -> Result<DropTaskReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, _meta): (_, Option<TaskMeta>) = get_pb_value(self, name_key).await?;
+
+            if seq == 0 {
+                return if req.if_exists {
+                    Ok(DropTaskReply {})
+                } else {
+                    Err(KVAppError::AppError(AppError::UnknownTask(
+                        UnknownTask::new(&name_key.task_name, "drop_task"),
+                    )))
+                };
+            }
+
+            // The run history of the task is kept until it expires.
+            let condition = vec![txn_cond_seq(name_key, Eq, seq)];
+            let if_then = vec![txn_op_del(name_key)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(name = debug(&name_key), succ = display(succ), "drop_task");
+
+            if succ {
+                return Ok(DropTaskReply {});
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn get_task(&self, req: GetTaskReq) -> Result<GetTaskReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let (_seq, meta) = get_task_or_err(self, &req.name_ident, "get_task").await?;
+        Ok(GetTaskReply { meta })
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn list_tasks(&self, req: ListTasksReq) -> Result<Vec<(String, TaskMeta)>, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        // List tasks by `prefix_list` "<prefix>/<tenant>"
+        let prefix_key = kvapi::KeyBuilder::new_prefixed(TaskNameIdent::PREFIX)
+            .push_str(&req.tenant)
+            .done();
+
+        let kvs = self.prefix_list_kv(&prefix_key).await?;
+        let mut tasks = Vec::with_capacity(kvs.len());
+        for (key, seqv) in kvs.iter() {
+            let name_ident = TaskNameIdent::from_str_key(key).map_err(|e| {
+                KVAppError::MetaError(MetaError::from(InvalidReply::new("list_tasks", &e)))
+            })?;
+            let meta: TaskMeta = deserialize_struct(&seqv.data)?;
+            tasks.push((name_ident.task_name, meta));
+        }
+
+        Ok(tasks)
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn update_task_state(
+        &self,
+        req: UpdateTaskStateReq,
+    ) -> Result<UpdateTaskStateReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, mut meta) = get_task_or_err(self, name_key, ctx).await?;
+            if meta.state == req.state {
+                return Ok(UpdateTaskStateReply {});
+            }
+
+            meta.state = req.state;
+            meta.updated_on = Utc::now();
+
+            let condition = vec![txn_cond_seq(name_key, Eq, seq)];
+            let if_then = vec![txn_op_put(name_key, serialize_struct(&meta)?)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&name_key),
+                succ = display(succ),
+                "update_task_state"
+            );
+
+            if succ {
+                return Ok(UpdateTaskStateReply {});
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn create_task_run(
+        &self,
+        req: CreateTaskRunReq,
+    ) -> Result<CreateTaskRunReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let run_id = fetch_id(self, IdGenerator::task_run_id()).await?;
+
+        let mut run = req.run;
+        run.run_id = run_id;
+        put_task_run(self, &req.tenant, &run, req.expire_at).await?;
+
+        Ok(CreateTaskRunReply { run_id })
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn update_task_run(
+        &self,
+        req: UpdateTaskRunReq,
+    ) -> Result<UpdateTaskRunReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        put_task_run(self, &req.tenant, &req.run, req.expire_at).await?;
+
+        Ok(UpdateTaskRunReply {})
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn list_task_runs(&self, req: ListTaskRunsReq) -> Result<Vec<TaskRun>, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        // List task runs by `prefix_list` "<prefix>/<tenant>"
+        let prefix_key = kvapi::KeyBuilder::new_prefixed(TaskRunKey::PREFIX)
+            .push_str(&req.tenant)
+            .done();
+
+        let kvs = self.prefix_list_kv(&prefix_key).await?;
+        let mut runs = Vec::with_capacity(kvs.len());
+        for (_key, seqv) in kvs.iter() {
+            let run: TaskRun = deserialize_struct(&seqv.data)?;
+            runs.push(run);
+        }
+        runs.sort_by_key(|run| run.run_id);
+
+        Ok(runs)
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn acquire_task_lease(
+        &self,
+        req: AcquireTaskLeaseReq,
+    ) -> Result<AcquireTaskLeaseReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let lease_key = TaskLeaseKey {
+            tenant: req.tenant.clone(),
+        };
+
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (lease_seq, _): (_, Option<EmptyProto>) = get_pb_value(self, &lease_key).await?;
+
+            // The lease is held by another node.
+            if lease_seq != 0 && lease_seq != req.seq {
+                return Ok(AcquireTaskLeaseReply { seq: None });
+            }
+
+            let condition = vec![txn_cond_seq(&lease_key, Eq, lease_seq)];
+            let if_then = vec![txn_op_put_with_expire(
+                &lease_key,
+                serialize_struct(&EmptyProto {})?,
+                req.expire_at,
+            )];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                tenant = display(&req.tenant),
+                succ = display(succ),
+                "acquire_task_lease"
+            );
+
+            if succ {
+                // No other node can update the lease before it expires.
+                let (seq, _): (_, Option<EmptyProto>) = get_pb_value(self, &lease_key).await?;
+                return Ok(AcquireTaskLeaseReply { seq: Some(seq) });
+            }
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
@@ -1846,9 +2353,16 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
             }
         };
 
+        let start_after = req.start_after.as_deref();
         let tb_infos = match db_meta.from_share {
-            None => list_tables_from_unshare_db(self, db_id, tenant_dbname).await?,
-            Some(share) => list_tables_from_share_db(self, share, tenant_dbname).await?,
+            None => {
+                list_tables_from_unshare_db(self, db_id, tenant_dbname, start_after, req.limit)
+                    .await?
+            }
+            Some(share) => {
+                list_tables_from_share_db(self, share, tenant_dbname, start_after, req.limit)
+                    .await?
+            }
         };
 
         Ok(tb_infos)
@@ -2434,6 +2948,123 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let fail_if_duplicated = req.update_table_metas.iter().any(|update| {
+            update
+                .copied_files
+                .as_ref()
+                .map(|v| v.fail_if_duplicated)
+                .unwrap_or(false)
+        });
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for update in req.update_table_metas.iter() {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                // tb_id -> tb_meta
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&update.new_table_meta)?));
+
+                if let Some(copied_files) = &update.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            copied_files,
+                            tb_meta_seq,
+                            copied_files.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                table_metas.push((tbid, tb_meta_seq, table_meta.unwrap()));
+            }
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                let mut share_table_info = vec![];
+                for (_, _, table_meta) in table_metas.iter() {
+                    if let Some(info) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.extend(info);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply {
+                    share_table_info: if share_table_info.is_empty() {
+                        None
+                    } else {
+                        Some(share_table_info)
+                    },
+                });
+            }
+
+            // The txn failed, check if any of the tables has been changed.
+            for (tbid, tb_meta_seq, _) in table_metas.iter() {
+                let (seq, _): (_, Option<TableMeta>) = get_pb_value(self, tbid).await?;
+                if seq != *tb_meta_seq {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            tbid.table_id,
+                            MatchSeq::Exact(*tb_meta_seq),
+                            seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+            }
+
+            if fail_if_duplicated {
+                // the table versions do match, but tx failed, report file duplication error
+                let table_id = table_metas.first().map(|(tbid, _, _)| tbid.table_id);
+                return Err(KVAppError::AppError(AppError::from(
+                    DuplicatedUpsertFiles::new(
+                        table_id.unwrap_or_default(),
+                        "update_multi_table_meta",
+                    ),
+                )));
+            }
+            // continue and try update the "table copied files"
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
@@ -3086,9 +3717,7 @@ async fn count_tables(
     // If the table count of a tenant is not found in kv space,,
     // we should compute the count by listing all tables of the tenant.
     let databases = kv_api
-        .list_databases(ListDatabaseReq {
-            tenant: key.tenant.clone(),
-        })
+        .list_databases(ListDatabaseReq::new(key.tenant.clone()))
         .await?;
     let mut count = 0;
     for db in databases.into_iter() {
@@ -3262,3 +3891,63 @@ pub(crate) async fn get_index_or_err(
 
     Ok((index_id_seq, index_id, index_meta_seq, index_meta))
 }
+
+/// Returns (sequence_meta_seq, sequence_meta)
+async fn get_sequence_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &SequenceNameIdent,
+    ctx: impl Display,
+) -> Result<(u64, SequenceMeta), KVAppError> {
+    let (seq, meta) = get_pb_value(kv_api, name_key).await?;
+
+    match meta {
+        Some(meta) => Ok((seq, meta)),
+        None => Err(KVAppError::AppError(AppError::UnknownSequence(
+            UnknownSequence::new(&name_key.sequence_name, ctx.to_string()),
+        ))),
+    }
+}
+
+async fn get_task_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &TaskNameIdent,
+    ctx: impl Display,
+) -> Result<(u64, TaskMeta), KVAppError> {
+    let (seq, meta) = get_pb_value(kv_api, name_key).await?;
+
+    match meta {
+        Some(meta) => Ok((seq, meta)),
+        None => Err(KVAppError::AppError(AppError::UnknownTask(
+            UnknownTask::new(&name_key.task_name, ctx.to_string()),
+        ))),
+    }
+}
+
+/// Put a task run into the run history, it is removed at `expire_at`.
+async fn put_task_run(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    run: &TaskRun,
+    expire_at: u64,
+) -> Result<(), KVAppError> {
+    let run_key = TaskRunKey {
+        tenant: tenant.to_string(),
+        task_name: run.task_name.clone(),
+        run_id: run.run_id,
+    };
+
+    let txn_req = TxnRequest {
+        condition: vec![],
+        if_then: vec![txn_op_put_with_expire(
+            &run_key,
+            serialize_struct(run)?,
+            expire_at,
+        )],
+        else_then: vec![],
+    };
+
+    let (succ, _responses) = send_txn(kv_api, txn_req).await?;
+    debug!(key = debug(&run_key), succ = display(succ), "put_task_run");
+
+    Ok(())
+}
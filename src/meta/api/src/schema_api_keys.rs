@@ -18,3 +18,4 @@ pub(crate) const ID_GEN_TABLE: &str = "table_id";
 pub(crate) const ID_GEN_DATABASE: &str = "database_id";
 pub(crate) const ID_GEN_TABLE_LOCK: &str = "table_lock_id";
 pub(crate) const ID_GEN_INDEX: &str = "index_id";
+pub(crate) const ID_GEN_TASK_RUN: &str = "task_run_id";
@@ -23,12 +23,17 @@ use common_expression::types::NumberDataType;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::schema::AcquireTaskLeaseReq;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::CreateTaskRunReq;
 use common_meta_app::schema::DBIdTableName;
 use common_meta_app::schema::DatabaseId;
 use common_meta_app::schema::DatabaseIdToName;
@@ -41,20 +46,30 @@ use common_meta_app::schema::DbIdListKey;
 use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::DropTaskReq;
 use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
+use common_meta_app::schema::GetTaskReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::IndexNameIdent;
 use common_meta_app::schema::IndexType;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListSequencesReq;
 use common_meta_app::schema::ListTableLockRevReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::ListTasksReq;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
+use common_meta_app::schema::SequenceNameIdent;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -66,10 +81,18 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskNameIdent;
+use common_meta_app::schema::TaskRun;
+use common_meta_app::schema::TaskRunState;
+use common_meta_app::schema::TaskState;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_meta_app::schema::UpdateTaskStateReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_app::share::AddShareAccountsReq;
@@ -247,6 +270,7 @@ impl SchemaApiTestSuite {
         suite.table_create_get_drop(&b.build().await).await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
@@ -270,6 +294,10 @@ impl SchemaApiTestSuite {
             .update_table_with_copied_files(&b.build().await)
             .await?;
         suite.index_create_list_drop(&b.build().await).await?;
+        suite
+            .sequence_create_next_value_drop(&b.build().await)
+            .await?;
+        suite.task_create_alter_run_drop(&b.build().await).await?;
         suite.table_lock_revision(&b.build().await).await?;
         Ok(())
     }
@@ -878,9 +906,7 @@ impl SchemaApiTestSuite {
         info!("--- list_databases");
         {
             let dbs = mt
-                .list_databases(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .list_databases(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
 
             let got = dbs.iter().map(|x| x.ident.db_id).collect::<Vec<_>>();
@@ -894,6 +920,38 @@ impl SchemaApiTestSuite {
             }
         }
 
+        info!("--- list_databases page by page");
+        {
+            let dbs = mt
+                .list_databases(ListDatabaseReq::new(tenant).with_page(None, Some(1)))
+                .await?;
+            assert_eq!(
+                vec!["db1"],
+                dbs.iter()
+                    .map(|x| &x.name_ident.db_name)
+                    .collect::<Vec<_>>()
+            );
+
+            let dbs = mt
+                .list_databases(
+                    ListDatabaseReq::new(tenant).with_page(Some("db1".to_string()), Some(1)),
+                )
+                .await?;
+            assert_eq!(
+                vec!["db2"],
+                dbs.iter()
+                    .map(|x| &x.name_ident.db_name)
+                    .collect::<Vec<_>>()
+            );
+
+            let dbs = mt
+                .list_databases(
+                    ListDatabaseReq::new(tenant).with_page(Some("db2".to_string()), Some(1)),
+                )
+                .await?;
+            assert!(dbs.is_empty());
+        }
+
         Ok(())
     }
 
@@ -922,9 +980,7 @@ impl SchemaApiTestSuite {
         info!("--- get_databases by tenant1");
         {
             let dbs = mt
-                .list_databases(ListDatabaseReq {
-                    tenant: tenant1.to_string(),
-                })
+                .list_databases(ListDatabaseReq::new(tenant1.to_string()))
                 .await?;
             let got = dbs.iter().map(|x| x.ident.db_id).collect::<Vec<_>>();
             assert_eq!(db_ids, got)
@@ -933,9 +989,7 @@ impl SchemaApiTestSuite {
         info!("--- get_databases by tenant2");
         {
             let dbs = mt
-                .list_databases(ListDatabaseReq {
-                    tenant: tenant2.to_string(),
-                })
+                .list_databases(ListDatabaseReq::new(tenant2.to_string()))
                 .await?;
             let want: Vec<u64> = vec![db_id_3];
             let got = dbs.iter().map(|x| x.ident.db_id).collect::<Vec<_>>();
@@ -1095,9 +1149,7 @@ impl SchemaApiTestSuite {
             assert_eq!(1, res.db_id, "first database id is 1");
 
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
                 name: db_name_ident.to_string_key(),
@@ -1113,9 +1165,7 @@ impl SchemaApiTestSuite {
             })
             .await?;
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
                 name: db_name_ident.to_string_key(),
@@ -1130,9 +1180,7 @@ impl SchemaApiTestSuite {
             })
             .await?;
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
                 name: db_name_ident.to_string_key(),
@@ -1151,9 +1199,7 @@ impl SchemaApiTestSuite {
             })
             .await?;
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
                 name: db_name_ident.to_string_key(),
@@ -1175,9 +1221,7 @@ impl SchemaApiTestSuite {
             let _res = mt.create_database(req).await?;
 
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![DroponInfo {
                 name: db_name_ident.to_string_key(),
@@ -1202,9 +1246,7 @@ impl SchemaApiTestSuite {
             let _res = mt.create_database(req).await?;
 
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![
                 DroponInfo {
@@ -1228,9 +1270,7 @@ impl SchemaApiTestSuite {
             })
             .await?;
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![
                 DroponInfo {
@@ -1258,9 +1298,7 @@ impl SchemaApiTestSuite {
             })
             .await?;
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
             calc_and_compare_drop_on_db_result(res, vec![
                 DroponInfo {
@@ -2062,6 +2100,125 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        let table_meta = |created_on| TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on,
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(Utc::now()),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        info!("--- update multi table meta, normal case");
+        {
+            let mut update_table_metas = vec![];
+            let mut new_table_metas = vec![];
+            for (i, tbl_name) in tbl_names.iter().enumerate() {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics = TableStatistics {
+                    data_bytes: i as u64 + 1,
+                    ..Default::default()
+                };
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(table.ident.seq),
+                    new_table_meta: new_table_meta.clone(),
+                    copied_files: None,
+                });
+                new_table_metas.push(new_table_meta);
+            }
+
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await?;
+
+            for (tbl_name, new_table_meta) in tbl_names.iter().zip(new_table_metas) {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta, new_table_meta);
+            }
+        }
+
+        info!("--- update multi table meta, version mismatch, nothing is updated");
+        {
+            let mut update_table_metas = vec![];
+            let mut old_table_metas = vec![];
+            for (i, tbl_name) in tbl_names.iter().enumerate() {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics = TableStatistics {
+                    data_bytes: 10,
+                    ..Default::default()
+                };
+                // the version of the last table is mismatched
+                let seq = if i == tbl_names.len() - 1 {
+                    table.ident.seq + 1
+                } else {
+                    table.ident.seq
+                };
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(seq),
+                    new_table_meta,
+                    copied_files: None,
+                });
+                old_table_metas.push(table.meta.clone());
+            }
+
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            for (tbl_name, old_table_meta) in tbl_names.iter().zip(old_table_metas) {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta, old_table_meta);
+            }
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -2245,9 +2402,7 @@ impl SchemaApiTestSuite {
             info!("create database res: {:?}", res);
 
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
 
             // assert not return out of retention time data
@@ -2263,9 +2418,7 @@ impl SchemaApiTestSuite {
             upsert_test_data(mt.as_kv_api(), &id_key, data).await?;
 
             let res = mt
-                .get_database_history(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .get_database_history(ListDatabaseReq::new(tenant.to_string()))
                 .await?;
 
             // assert not return out of retention time data
@@ -3591,6 +3744,25 @@ impl SchemaApiTestSuite {
                 assert_eq!(tb_ids[0], res[0].ident.table_id);
                 assert_eq!(tb_ids[1], res[1].ident.table_id);
             }
+
+            info!("--- get_tables page by page");
+            {
+                let req = ListTableReq::new(tenant, db_name).with_page(None, Some(1));
+                let res = mt.list_tables(req).await?;
+                assert_eq!(1, res.len());
+                assert_eq!(tb_ids[0], res[0].ident.table_id);
+
+                let req = ListTableReq::new(tenant, db_name)
+                    .with_page(Some(res[0].name.clone()), Some(1));
+                let res = mt.list_tables(req).await?;
+                assert_eq!(1, res.len());
+                assert_eq!(tb_ids[1], res[0].ident.table_id);
+
+                let req =
+                    ListTableReq::new(tenant, db_name).with_page(Some("tb2".to_string()), None);
+                let res = mt.list_tables(req).await?;
+                assert!(res.is_empty());
+            }
         }
 
         Ok(())
@@ -3874,6 +4046,328 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn sequence_create_next_value_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let name_ident = SequenceNameIdent::new(tenant, "seq1");
+
+        let created_on = Utc::now();
+        let seq_meta = SequenceMeta {
+            created_on,
+            updated_on: created_on,
+            comment: Some("seq1".to_string()),
+            start: 10,
+            increment: 5,
+            current: 10,
+        };
+
+        {
+            info!("--- next value of an unknown sequence");
+            let req = GetSequenceNextValueReq {
+                name_ident: name_ident.clone(),
+                count: 1,
+            };
+
+            let res = mt.get_sequence_next_value(req).await;
+            let err_code = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::UnknownSequence("").code(), err_code.code());
+        }
+
+        {
+            info!("--- create sequence");
+            let req = CreateSequenceReq {
+                if_not_exists: false,
+                name_ident: name_ident.clone(),
+                meta: seq_meta.clone(),
+            };
+            mt.create_sequence(req).await?;
+
+            let req = CreateSequenceReq {
+                if_not_exists: false,
+                name_ident: name_ident.clone(),
+                meta: seq_meta.clone(),
+            };
+            let res = mt.create_sequence(req).await;
+            let err_code = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::SequenceAlreadyExists("").code(), err_code.code());
+
+            let req = CreateSequenceReq {
+                if_not_exists: true,
+                name_ident: name_ident.clone(),
+                meta: seq_meta.clone(),
+            };
+            mt.create_sequence(req).await?;
+        }
+
+        {
+            info!("--- reserve values");
+            let req = GetSequenceNextValueReq {
+                name_ident: name_ident.clone(),
+                count: 3,
+            };
+            let res = mt.get_sequence_next_value(req).await?;
+            assert_eq!((10, 25, 5), (res.start, res.end, res.increment));
+            assert_eq!(seq_meta.created_on, res.created_on);
+
+            let req = GetSequenceNextValueReq {
+                name_ident: name_ident.clone(),
+                count: 1,
+            };
+            let res = mt.get_sequence_next_value(req).await?;
+            assert_eq!((25, 30, 5), (res.start, res.end, res.increment));
+
+            let req = GetSequenceReq {
+                name_ident: name_ident.clone(),
+            };
+            let res = mt.get_sequence(req).await?;
+            assert_eq!(30, res.meta.current);
+            assert_eq!(seq_meta.comment, res.meta.comment);
+        }
+
+        {
+            info!("--- reserve too many values");
+            let req = GetSequenceNextValueReq {
+                name_ident: name_ident.clone(),
+                count: u64::MAX,
+            };
+            let res = mt.get_sequence_next_value(req).await;
+            let err_code = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::SequenceOutOfRange("").code(), err_code.code());
+        }
+
+        {
+            info!("--- list sequences");
+            let req = CreateSequenceReq {
+                if_not_exists: false,
+                name_ident: SequenceNameIdent::new(tenant, "seq2"),
+                meta: SequenceMeta::default(),
+            };
+            mt.create_sequence(req).await?;
+
+            let res = mt.list_sequences(ListSequencesReq::new(tenant)).await?;
+            assert_eq!(
+                vec!["seq1", "seq2"],
+                res.iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+            );
+
+            let res = mt.list_sequences(ListSequencesReq::new("tenant2")).await?;
+            assert!(res.is_empty());
+        }
+
+        {
+            info!("--- drop sequence");
+            let req = DropSequenceReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+            };
+            mt.drop_sequence(req).await?;
+
+            let req = DropSequenceReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+            };
+            let res = mt.drop_sequence(req).await;
+            let err_code = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::UnknownSequence("").code(), err_code.code());
+
+            let req = DropSequenceReq {
+                if_exists: true,
+                name_ident: name_ident.clone(),
+            };
+            mt.drop_sequence(req).await?;
+
+            let res = mt.list_sequences(ListSequencesReq::new(tenant)).await?;
+            assert_eq!(1, res.len());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn task_create_alter_run_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let name_ident = TaskNameIdent::new(tenant, "task1");
+
+        let task_meta = TaskMeta {
+            query: "SELECT 1".to_string(),
+            schedule: Some("*/5 * * * *".to_string()),
+            owner: UserIdentity::new("root", "%"),
+            ..Default::default()
+        };
+
+        {
+            info!("--- create task");
+            let req = CreateTaskReq {
+                if_not_exists: false,
+                name_ident: name_ident.clone(),
+                meta: task_meta.clone(),
+            };
+            mt.create_task(req).await?;
+
+            let req = CreateTaskReq {
+                if_not_exists: false,
+                name_ident: name_ident.clone(),
+                meta: task_meta.clone(),
+            };
+            let res = mt.create_task(req).await;
+            let err_code = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TaskAlreadyExists("").code(), err_code.code());
+
+            let req = CreateTaskReq {
+                if_not_exists: true,
+                name_ident: name_ident.clone(),
+                meta: task_meta.clone(),
+            };
+            mt.create_task(req).await?;
+
+            let req = CreateTaskReq {
+                if_not_exists: false,
+                name_ident: TaskNameIdent::new(tenant, "task2"),
+                meta: TaskMeta {
+                    schedule: None,
+                    after: vec!["task1".to_string()],
+                    ..task_meta.clone()
+                },
+            };
+            mt.create_task(req).await?;
+
+            let res = mt.list_tasks(ListTasksReq::new(tenant)).await?;
+            assert_eq!(
+                vec!["task1", "task2"],
+                res.iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(vec!["task1".to_string()], res[1].1.after);
+        }
+
+        {
+            info!("--- suspend task");
+            let req = UpdateTaskStateReq {
+                name_ident: name_ident.clone(),
+                state: TaskState::Suspended,
+            };
+            mt.update_task_state(req).await?;
+
+            let req = GetTaskReq {
+                name_ident: name_ident.clone(),
+            };
+            let res = mt.get_task(req).await?;
+            assert_eq!(TaskState::Suspended, res.meta.state);
+            assert_eq!(task_meta.query, res.meta.query);
+        }
+
+        {
+            info!("--- add and update task runs");
+            let expire_at = Utc::now().timestamp() as u64 + 3600;
+            let run = TaskRun {
+                task_name: "task1".to_string(),
+                query: task_meta.query.clone(),
+                trigger: "EXECUTE".to_string(),
+                ..Default::default()
+            };
+
+            let mut run_ids = vec![];
+            for _ in 0..2 {
+                let req = CreateTaskRunReq {
+                    tenant: tenant.to_string(),
+                    run: run.clone(),
+                    expire_at,
+                };
+                let res = mt.create_task_run(req).await?;
+                run_ids.push(res.run_id);
+            }
+            assert!(run_ids[0] < run_ids[1]);
+
+            let req = UpdateTaskRunReq {
+                tenant: tenant.to_string(),
+                run: TaskRun {
+                    run_id: run_ids[1],
+                    state: TaskRunState::Succeeded,
+                    completed_on: Some(Utc::now()),
+                    ..run.clone()
+                },
+                expire_at,
+            };
+            mt.update_task_run(req).await?;
+
+            let res = mt.list_task_runs(ListTaskRunsReq::new(tenant)).await?;
+            assert_eq!(
+                vec![
+                    (run_ids[0], TaskRunState::Executing),
+                    (run_ids[1], TaskRunState::Succeeded)
+                ],
+                res.iter().map(|r| (r.run_id, r.state)).collect::<Vec<_>>()
+            );
+
+            let res = mt.list_task_runs(ListTaskRunsReq::new("tenant2")).await?;
+            assert!(res.is_empty());
+        }
+
+        {
+            info!("--- acquire task lease");
+            let expire_at = Utc::now().timestamp() as u64 + 3600;
+            let req = AcquireTaskLeaseReq {
+                tenant: tenant.to_string(),
+                seq: 0,
+                expire_at,
+            };
+            let res = mt.acquire_task_lease(req).await?;
+            let seq = res.seq.unwrap();
+
+            // Held by the first one.
+            let req = AcquireTaskLeaseReq {
+                tenant: tenant.to_string(),
+                seq: 0,
+                expire_at,
+            };
+            let res = mt.acquire_task_lease(req).await?;
+            assert_eq!(None, res.seq);
+
+            // Extended by the holder.
+            let req = AcquireTaskLeaseReq {
+                tenant: tenant.to_string(),
+                seq,
+                expire_at,
+            };
+            let res = mt.acquire_task_lease(req).await?;
+            assert!(res.seq.unwrap() > seq);
+        }
+
+        {
+            info!("--- drop task");
+            let req = DropTaskReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+            };
+            mt.drop_task(req).await?;
+
+            let req = DropTaskReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+            };
+            let res = mt.drop_task(req).await;
+            let err_code = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::UnknownTask("").code(), err_code.code());
+
+            let req = DropTaskReq {
+                if_exists: true,
+                name_ident: name_ident.clone(),
+            };
+            mt.drop_task(req).await?;
+
+            let res = mt.list_tasks(ListTasksReq::new(tenant)).await?;
+            assert_eq!(1, res.len());
+
+            let res = mt.list_task_runs(ListTaskRunsReq::new(tenant)).await?;
+            assert_eq!(2, res.len(), "run history is kept");
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn table_lock_revision<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let mut util = Util::new(mt, "tenant1", "db1", "tb1", "eng1");
@@ -4213,9 +4707,7 @@ impl SchemaApiTestSuite {
         info!("--- list databases from node_b");
         {
             let res = node_b
-                .list_databases(ListDatabaseReq {
-                    tenant: tenant.to_string(),
-                })
+                .list_databases(ListDatabaseReq::new(tenant.to_string()))
                 .await;
             debug!("get database list: {:?}", res);
             let res = res?;
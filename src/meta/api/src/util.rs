@@ -47,6 +47,8 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::share::*;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
+use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::txn_condition::Target;
 use common_meta_types::txn_op::Request;
@@ -177,6 +179,42 @@ pub async fn list_u64_value<K: kvapi::Key>(
 ) -> Result<(Vec<K>, Vec<u64>), MetaError> {
     let res = kv_api.prefix_list_kv(&key.to_string_key()).await?;
 
+    decode_u64_values(&res, "list_u64_value")
+}
+
+/// List a page of kvs whose value's type is `u64`, in the order of keys.
+///
+/// Only the kvs whose key is greater than `start_after` are returned, at most `limit` of them.
+/// The next page starts after the last key of the returned page.
+///
+/// It is the same as `list_u64_value` if neither `start_after` nor `limit` is specified.
+pub async fn list_u64_value_page<K: kvapi::Key>(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    key: &K,
+    start_after: Option<&K>,
+    limit: Option<usize>,
+) -> Result<(Vec<K>, Vec<u64>), MetaError> {
+    if start_after.is_none() && limit.is_none() {
+        return list_u64_value(kv_api, key).await;
+    }
+
+    let (start, end) = kvapi::prefix_to_range(&key.to_string_key()).map_err(|e| {
+        let inv = InvalidArgument::new(e, "list_u64_value_page");
+        MetaNetworkError::InvalidArgument(inv)
+    })?;
+    // The smallest key that is greater than `start_after`.
+    let start = start_after.map_or(start, |k| format!("{}\0", k.to_string_key()));
+
+    let req = ListKVRangeReq::new(start, Some(end), limit.map(|x| x as u64));
+    let res = kv_api.list_kv(req).await?;
+
+    decode_u64_values(&res, "list_u64_value_page")
+}
+
+fn decode_u64_values<K: kvapi::Key>(
+    res: &ListKVReply,
+    ctx: &'static str,
+) -> Result<(Vec<K>, Vec<u64>), MetaError> {
     let n = res.len();
 
     let mut structured_keys = Vec::with_capacity(n);
@@ -188,7 +226,7 @@ pub async fn list_u64_value<K: kvapi::Key>(
 
         // Parse key
         let struct_key = K::from_str_key(str_key).map_err(|e| {
-            let inv = InvalidReply::new("list_u64_value", &e);
+            let inv = InvalidReply::new(ctx, &e);
             MetaNetworkError::InvalidReply(inv)
         })?;
         structured_keys.push(struct_key);
@@ -842,6 +880,8 @@ pub async fn list_tables_from_unshare_db(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     db_id: u64,
     tenant_dbname: &DatabaseNameIdent,
+    start_after: Option<&str>,
+    limit: Option<usize>,
 ) -> Result<Vec<Arc<TableInfo>>, KVAppError> {
     // List tables by tenant, db_id, table_name.
 
@@ -850,8 +890,13 @@ pub async fn list_tables_from_unshare_db(
         // Use empty name to scan all tables
         table_name: "".to_string(),
     };
+    let start_after = start_after.map(|table_name| DBIdTableName {
+        db_id,
+        table_name: table_name.to_string(),
+    });
 
-    let (dbid_tbnames, ids) = list_u64_value(kv_api, &dbid_tbname).await?;
+    let (dbid_tbnames, ids) =
+        list_u64_value_page(kv_api, &dbid_tbname, start_after.as_ref(), limit).await?;
 
     get_tableinfos_by_ids(
         kv_api,
@@ -867,6 +912,8 @@ pub async fn list_tables_from_share_db(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     share: ShareNameIdent,
     tenant_dbname: &DatabaseNameIdent,
+    start_after: Option<&str>,
+    limit: Option<usize>,
 ) -> Result<Vec<Arc<TableInfo>>, KVAppError> {
     let res = get_share_or_err(
         kv_api,
@@ -893,14 +940,24 @@ pub async fn list_tables_from_share_db(
             ids.push(table_id);
         }
     }
-    get_tableinfos_by_ids(
+    let mut tb_infos = get_tableinfos_by_ids(
         kv_api,
         &ids,
         tenant_dbname,
         None,
         DatabaseType::ShareDB(share),
     )
-    .await
+    .await?;
+
+    // The tables granted to a share are not stored in the order of names,
+    // paginate them in memory.
+    if start_after.is_some() || limit.is_some() {
+        tb_infos.sort_by(|a, b| a.name.cmp(&b.name));
+        tb_infos.retain(|tb_info| start_after.map_or(true, |name| tb_info.name.as_str() > name));
+        tb_infos.truncate(limit.unwrap_or(usize::MAX));
+    }
+
+    Ok(tb_infos)
 }
 
 pub async fn convert_share_meta_to_spec(
@@ -1088,7 +1145,8 @@ pub async fn remove_table_from_share(
                     unreachable!();
                 }
             }
-            let all_tables = list_tables_from_unshare_db(kv_api, shared_db_id, &db_name).await?;
+            let all_tables =
+                list_tables_from_unshare_db(kv_api, shared_db_id, &db_name, None, None).await?;
             let table_infos = BTreeMap::from_iter(
                 all_tables
                     .iter()
@@ -1146,7 +1204,8 @@ pub async fn get_share_table_info(
                     unreachable!();
                 }
             }
-            let all_tables = list_tables_from_unshare_db(kv_api, shared_db_id, &db_name).await?;
+            let all_tables =
+                list_tables_from_unshare_db(kv_api, shared_db_id, &db_name, None, None).await?;
             let table_infos = BTreeMap::from_iter(
                 all_tables
                     .iter()
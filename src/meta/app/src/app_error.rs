@@ -596,6 +596,88 @@ impl DropIndexWithDropTime {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("SequenceAlreadyExists: `{sequence_name}` while `{context}`")]
+pub struct SequenceAlreadyExists {
+    sequence_name: String,
+    context: String,
+}
+
+impl SequenceAlreadyExists {
+    pub fn new(sequence_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            sequence_name: sequence_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownSequence: `{sequence_name}` while `{context}`")]
+pub struct UnknownSequence {
+    sequence_name: String,
+    context: String,
+}
+
+impl UnknownSequence {
+    pub fn new(sequence_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            sequence_name: sequence_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("SequenceOutOfRange: `{sequence_name}` can not allocate {count} values while `{context}`")]
+pub struct SequenceOutOfRange {
+    sequence_name: String,
+    count: u64,
+    context: String,
+}
+
+impl SequenceOutOfRange {
+    pub fn new(sequence_name: impl Into<String>, count: u64, context: impl Into<String>) -> Self {
+        Self {
+            sequence_name: sequence_name.into(),
+            count,
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TaskAlreadyExists: `{task_name}` while `{context}`")]
+pub struct TaskAlreadyExists {
+    task_name: String,
+    context: String,
+}
+
+impl TaskAlreadyExists {
+    pub fn new(task_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            task_name: task_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownTask: `{task_name}` while `{context}`")]
+pub struct UnknownTask {
+    task_name: String,
+    context: String,
+}
+
+impl UnknownTask {
+    pub fn new(task_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            task_name: task_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 /// Application error.
 ///
 /// The application does not get expected result but there is nothing wrong with meta-service.
@@ -709,6 +791,21 @@ pub enum AppError {
 
     #[error(transparent)]
     DropIndexWithDropTime(#[from] DropIndexWithDropTime),
+
+    #[error(transparent)]
+    SequenceAlreadyExists(#[from] SequenceAlreadyExists),
+
+    #[error(transparent)]
+    UnknownSequence(#[from] UnknownSequence),
+
+    #[error(transparent)]
+    SequenceOutOfRange(#[from] SequenceOutOfRange),
+
+    #[error(transparent)]
+    TaskAlreadyExists(#[from] TaskAlreadyExists),
+
+    #[error(transparent)]
+    UnknownTask(#[from] UnknownTask),
 }
 
 impl AppErrorMessage for UnknownDatabase {
@@ -935,6 +1032,39 @@ impl AppErrorMessage for DropIndexWithDropTime {
     }
 }
 
+impl AppErrorMessage for SequenceAlreadyExists {
+    fn message(&self) -> String {
+        format!("Sequence '{}' already exists", self.sequence_name)
+    }
+}
+
+impl AppErrorMessage for UnknownSequence {
+    fn message(&self) -> String {
+        format!("Unknown sequence '{}'", self.sequence_name)
+    }
+}
+
+impl AppErrorMessage for SequenceOutOfRange {
+    fn message(&self) -> String {
+        format!(
+            "Sequence '{}' can not allocate {} more values",
+            self.sequence_name, self.count
+        )
+    }
+}
+
+impl AppErrorMessage for TaskAlreadyExists {
+    fn message(&self) -> String {
+        format!("Task '{}' already exists", self.task_name)
+    }
+}
+
+impl AppErrorMessage for UnknownTask {
+    fn message(&self) -> String {
+        format!("Unknown task '{}'", self.task_name)
+    }
+}
+
 impl From<AppError> for ErrorCode {
     fn from(app_err: AppError) -> Self {
         match app_err {
@@ -1002,6 +1132,11 @@ impl From<AppError> for ErrorCode {
             AppError::IndexAlreadyExists(err) => ErrorCode::IndexAlreadyExists(err.message()),
             AppError::UnknownIndex(err) => ErrorCode::UnknownIndex(err.message()),
             AppError::DropIndexWithDropTime(err) => ErrorCode::DropIndexWithDropTime(err.message()),
+            AppError::SequenceAlreadyExists(err) => ErrorCode::SequenceAlreadyExists(err.message()),
+            AppError::UnknownSequence(err) => ErrorCode::UnknownSequence(err.message()),
+            AppError::SequenceOutOfRange(err) => ErrorCode::SequenceOutOfRange(err.message()),
+            AppError::TaskAlreadyExists(err) => ErrorCode::TaskAlreadyExists(err.message()),
+            AppError::UnknownTask(err) => ErrorCode::UnknownTask(err.message()),
         }
     }
 }
@@ -15,6 +15,7 @@
 //! Principal is a user or role that accesses an entity.
 
 mod file_format;
+mod masking_policy;
 mod principal_identity;
 mod role_info;
 mod user_auth;
@@ -29,12 +30,14 @@ mod user_setting;
 mod user_stage;
 
 pub use file_format::*;
+pub use masking_policy::MaskingPolicy;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
+pub use user_auth::ScramSha256Secret;
 pub use user_defined_file_format::UserDefinedFileFormat;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
@@ -16,12 +16,16 @@ use std::str::FromStr;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use hmac::Hmac;
+use hmac::Mac;
+use rand::Rng;
 use sha2::Digest;
 use sha2::Sha256;
 
 const NO_PASSWORD_STR: &str = "no_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
+const SCRAM_SHA256_PASSWORD_STR: &str = "scram_sha256_password";
 const JWT_AUTH_STR: &str = "jwt";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -29,6 +33,7 @@ pub enum AuthType {
     NoPassword,
     Sha256Password,
     DoubleSha1Password,
+    ScramSha256Password,
     JWT,
 }
 
@@ -38,6 +43,7 @@ impl std::str::FromStr for AuthType {
         match s {
             SHA256_PASSWORD_STR => Ok(AuthType::Sha256Password),
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
+            SCRAM_SHA256_PASSWORD_STR => Ok(AuthType::ScramSha256Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
             _ => Err(ErrorCode::InvalidAuthInfo(AuthType::bad_auth_types(s))),
@@ -51,6 +57,7 @@ impl AuthType {
             AuthType::NoPassword => NO_PASSWORD_STR,
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
+            AuthType::ScramSha256Password => SCRAM_SHA256_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
         }
     }
@@ -60,6 +67,7 @@ impl AuthType {
             NO_PASSWORD_STR,
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
+            SCRAM_SHA256_PASSWORD_STR,
             JWT_AUTH_STR,
         ];
         let all = all
@@ -74,6 +82,7 @@ impl AuthType {
         match self {
             AuthType::Sha256Password => Some(PasswordHashMethod::Sha256),
             AuthType::DoubleSha1Password => Some(PasswordHashMethod::DoubleSha1),
+            AuthType::ScramSha256Password => Some(PasswordHashMethod::ScramSha256),
             _ => None,
         }
     }
@@ -107,7 +116,9 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
-            AuthType::Sha256Password | AuthType::DoubleSha1Password => match auth_string {
+            AuthType::Sha256Password
+            | AuthType::DoubleSha1Password
+            | AuthType::ScramSha256Password => match auth_string {
                 Some(p) => {
                     let method = auth_type.get_password_type().unwrap();
                     Ok(AuthInfo::Password {
@@ -171,6 +182,7 @@ impl AuthInfo {
             } => match t {
                 PasswordHashMethod::Sha256 => AuthType::Sha256Password,
                 PasswordHashMethod::DoubleSha1 => AuthType::DoubleSha1Password,
+                PasswordHashMethod::ScramSha256 => AuthType::ScramSha256Password,
             },
         }
     }
@@ -236,6 +248,9 @@ impl AuthInfo {
                 PasswordHashMethod::Sha256 => Err(ErrorCode::AuthenticateFailure(
                     "login with sha256_password user for mysql protocol not supported yet.",
                 )),
+                PasswordHashMethod::ScramSha256 => Err(ErrorCode::AuthenticateFailure(
+                    "login with scram_sha256_password user for mysql protocol not supported.",
+                )),
             },
             _ => Err(ErrorCode::AuthenticateFailure(format!(
                 "user require auth type {}",
@@ -262,13 +277,28 @@ pub enum PasswordHashMethod {
     DoubleSha1 = 1,
     #[default]
     Sha256 = 2,
+    ScramSha256 = 3,
 }
 
 impl PasswordHashMethod {
+    /// Hash the user input. For `ScramSha256` a fresh random salt is generated,
+    /// so use [`PasswordHashMethod::verify`] to check a password against a stored hash.
     pub fn hash(self, user_input: &[u8]) -> Vec<u8> {
         match self {
             PasswordHashMethod::DoubleSha1 => double_sha1(user_input).to_vec(),
             PasswordHashMethod::Sha256 => Sha256::digest(user_input).to_vec(),
+            PasswordHashMethod::ScramSha256 => ScramSha256Secret::new(user_input).to_hash_value(),
+        }
+    }
+
+    pub fn verify(self, hash_value: &[u8], user_input: &[u8]) -> bool {
+        match self {
+            PasswordHashMethod::DoubleSha1 | PasswordHashMethod::Sha256 => {
+                hash_value == self.hash(user_input)
+            }
+            PasswordHashMethod::ScramSha256 => ScramSha256Secret::from_hash_value(hash_value)
+                .map(|secret| secret.verify_password(user_input))
+                .unwrap_or(false),
         }
     }
 
@@ -276,3 +306,104 @@ impl PasswordHashMethod {
         hex::encode(hash_value)
     }
 }
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SCRAM_SALT_LEN: usize = 16;
+const SCRAM_KEY_LEN: usize = 32;
+
+/// The stored secret of a SCRAM-SHA-256 (RFC 5802/7677) password.
+///
+/// It is persisted as the `hash_value` of `AuthInfo::Password` in the layout
+/// `salt || stored_key || server_key`, the iteration count is always [`ScramSha256Secret::ITERATIONS`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScramSha256Secret {
+    pub salt: Vec<u8>,
+    pub stored_key: [u8; SCRAM_KEY_LEN],
+    pub server_key: [u8; SCRAM_KEY_LEN],
+}
+
+impl ScramSha256Secret {
+    pub const ITERATIONS: u32 = 4096;
+
+    pub fn new(password: &[u8]) -> Self {
+        let salt = rand::thread_rng().gen::<[u8; SCRAM_SALT_LEN]>();
+        Self::with_salt(password, &salt)
+    }
+
+    pub fn with_salt(password: &[u8], salt: &[u8]) -> Self {
+        let salted_password = Self::salted_password(password, salt);
+        let client_key = hmac_sha256(&salted_password, b"Client Key");
+        Self {
+            salt: salt.to_vec(),
+            stored_key: Sha256::digest(client_key).into(),
+            server_key: hmac_sha256(&salted_password, b"Server Key"),
+        }
+    }
+
+    pub fn from_hash_value(hash_value: &[u8]) -> Result<Self> {
+        if hash_value.len() != SCRAM_SALT_LEN + 2 * SCRAM_KEY_LEN {
+            return Err(ErrorCode::InvalidAuthInfo(
+                "invalid scram_sha256_password hash value",
+            ));
+        }
+        let (salt, keys) = hash_value.split_at(SCRAM_SALT_LEN);
+        let (stored_key, server_key) = keys.split_at(SCRAM_KEY_LEN);
+        Ok(Self {
+            salt: salt.to_vec(),
+            stored_key: stored_key.try_into().unwrap(),
+            server_key: server_key.try_into().unwrap(),
+        })
+    }
+
+    pub fn to_hash_value(&self) -> Vec<u8> {
+        let mut v = Vec::with_capacity(SCRAM_SALT_LEN + 2 * SCRAM_KEY_LEN);
+        v.extend_from_slice(&self.salt);
+        v.extend_from_slice(&self.stored_key);
+        v.extend_from_slice(&self.server_key);
+        v
+    }
+
+    pub fn verify_password(&self, password: &[u8]) -> bool {
+        *self == Self::with_salt(password, &self.salt)
+    }
+
+    /// Check the `ClientProof` sent by the client:
+    /// `H(ClientProof XOR HMAC(StoredKey, AuthMessage)) == StoredKey`.
+    pub fn verify_client_proof(&self, auth_message: &[u8], client_proof: &[u8]) -> bool {
+        if client_proof.len() != SCRAM_KEY_LEN {
+            return false;
+        }
+        let client_signature = hmac_sha256(&self.stored_key, auth_message);
+        let client_key = client_proof
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(a, b)| a ^ b)
+            .collect::<Vec<_>>();
+        Sha256::digest(client_key).as_slice() == self.stored_key
+    }
+
+    pub fn server_signature(&self, auth_message: &[u8]) -> [u8; SCRAM_KEY_LEN] {
+        hmac_sha256(&self.server_key, auth_message)
+    }
+
+    // Hi(password, salt, i) from RFC 5802, which is PBKDF2 with HMAC-SHA-256.
+    fn salted_password(password: &[u8], salt: &[u8]) -> [u8; SCRAM_KEY_LEN] {
+        let mut mac = HmacSha256::new_from_slice(password).expect("HMAC accepts keys of any size");
+        mac.update(salt);
+        mac.update(&1u32.to_be_bytes());
+        let mut u: [u8; SCRAM_KEY_LEN] = mac.finalize().into_bytes().into();
+        let mut result = u;
+        for _ in 1..Self::ITERATIONS {
+            u = hmac_sha256(password, &u);
+            result.iter_mut().zip(u.iter()).for_each(|(r, u)| *r ^= u);
+        }
+        result
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; SCRAM_KEY_LEN] {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
@@ -109,7 +109,10 @@ pub enum StageFileCompression {
 
 impl StageFileFormatType {
     pub fn has_inner_schema(&self) -> bool {
-        matches!(self, StageFileFormatType::Parquet)
+        matches!(
+            self,
+            StageFileFormatType::Parquet | StageFileFormatType::Orc
+        )
     }
 }
 
@@ -187,11 +190,10 @@ impl FromStr for StageFileFormatType {
             "PARQUET" => Ok(StageFileFormatType::Parquet),
             "XML" => Ok(StageFileFormatType::Xml),
             "JSON" => Ok(StageFileFormatType::Json),
-            "ORC" | "AVRO" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
-            )),
+            "AVRO" => Ok(StageFileFormatType::Avro),
+            "ORC" => Ok(StageFileFormatType::Orc),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | AVRO | ORC)"
             )),
         }
     }
@@ -291,6 +291,27 @@ impl GetDatabaseReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ListDatabaseReq {
     pub tenant: String,
+    /// List only the databases whose name is after this one.
+    pub start_after: Option<String>,
+    /// The max number of databases to return.
+    pub limit: Option<usize>,
+}
+
+impl ListDatabaseReq {
+    pub fn new(tenant: impl Into<String>) -> ListDatabaseReq {
+        ListDatabaseReq {
+            tenant: tenant.into(),
+            start_after: None,
+            limit: None,
+        }
+    }
+
+    /// List a page of databases, the next page starts after the name of the last database returned.
+    pub fn with_page(mut self, start_after: Option<String>, limit: Option<usize>) -> Self {
+        self.start_after = start_after;
+        self.limit = limit;
+        self
+    }
 }
 
 mod kvapi_key_impl {
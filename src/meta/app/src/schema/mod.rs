@@ -17,7 +17,9 @@
 mod catalog;
 mod database;
 mod index;
+mod sequence;
 mod table;
+mod task;
 
 pub use catalog::CatalogMeta;
 pub use catalog::CatalogNameIdent;
@@ -45,6 +47,7 @@ pub use database::RenameDatabaseReq;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
 pub use index::*;
+pub use sequence::*;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -83,12 +86,15 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
 pub use table::UpsertTableCopiedFileReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
+pub use task::*;
 
 const PREFIX_DB_ID_LIST: &str = "__fd_db_id_list";
 const PREFIX_DATABASE: &str = "__fd_database";
@@ -106,3 +112,7 @@ const PREFIX_INDEX: &str = "__fd_index";
 const PREFIX_INDEX_ID_TO_NAME: &str = "__fd_index_id_to_name";
 const PREFIX_INDEX_BY_ID: &str = "__fd_index_by_id";
 const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
+const PREFIX_SEQUENCE: &str = "__fd_sequence";
+const PREFIX_TASK: &str = "__fd_task";
+const PREFIX_TASK_RUN: &str = "__fd_task_run";
+const PREFIX_TASK_LEASE: &str = "__fd_task_lease";
@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct SequenceNameIdent {
+    pub tenant: String,
+    pub sequence_name: String,
+}
+
+impl SequenceNameIdent {
+    pub fn new(tenant: impl Into<String>, sequence_name: impl Into<String>) -> SequenceNameIdent {
+        SequenceNameIdent {
+            tenant: tenant.into(),
+            sequence_name: sequence_name.into(),
+        }
+    }
+}
+
+impl Display for SequenceNameIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'.'{}'", self.tenant, self.sequence_name)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceMeta {
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+    pub comment: Option<String>,
+
+    /// The first value of the sequence.
+    pub start: u64,
+    /// The step between two values, it is always positive.
+    pub increment: u64,
+    /// The next value to allocate.
+    pub current: u64,
+}
+
+impl Default for SequenceMeta {
+    fn default() -> Self {
+        SequenceMeta {
+            created_on: Utc::now(),
+            updated_on: Utc::now(),
+            comment: None,
+            start: 1,
+            increment: 1,
+            current: 1,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReq {
+    pub if_not_exists: bool,
+    pub name_ident: SequenceNameIdent,
+    pub meta: SequenceMeta,
+}
+
+impl Display for CreateSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_sequence(if_not_exists={}):{}={:?}",
+            self.if_not_exists, self.name_ident, self.meta
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReq {
+    pub if_exists: bool,
+    pub name_ident: SequenceNameIdent,
+}
+
+impl Display for DropSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "drop_sequence(if_exists={}):{}",
+            self.if_exists, self.name_ident
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReq {
+    pub name_ident: SequenceNameIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReply {
+    pub meta: SequenceMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListSequencesReq {
+    pub tenant: String,
+}
+
+impl ListSequencesReq {
+    pub fn new(tenant: impl Into<String>) -> ListSequencesReq {
+        ListSequencesReq {
+            tenant: tenant.into(),
+        }
+    }
+}
+
+/// Reserve `count` values of a sequence.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReq {
+    pub name_ident: SequenceNameIdent,
+    pub count: u64,
+}
+
+impl Display for GetSequenceNextValueReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "get_sequence_next_value(count={}):{}",
+            self.count, self.name_ident
+        )
+    }
+}
+
+/// The reserved values are `start, start + increment, ...`, all of them are less than `end`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReply {
+    pub start: u64,
+    pub end: u64,
+    pub increment: u64,
+    /// The creation time of the sequence, which tells it from a dropped sequence of the same name.
+    pub created_on: DateTime<Utc>,
+}
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use crate::schema::SequenceNameIdent;
+    use crate::schema::PREFIX_SEQUENCE;
+
+    /// <prefix>/<tenant>/<sequence_name> -> SequenceMeta
+    impl kvapi::Key for SequenceNameIdent {
+        const PREFIX: &'static str = PREFIX_SEQUENCE;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.sequence_name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let sequence_name = p.next_str()?;
+            p.done()?;
+
+            Ok(SequenceNameIdent {
+                tenant,
+                sequence_name,
+            })
+        }
+    }
+}
@@ -249,6 +249,24 @@ impl TableMeta {
         self.schema = Arc::new(new_schema);
         Ok(())
     }
+
+    pub fn modify_column(
+        &mut self,
+        field: &TableField,
+        field_comment: Option<String>,
+    ) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.modify_column(field)?;
+        let index = new_schema.index_of(field.name())?;
+        self.schema = Arc::new(new_schema);
+        if let Some(comment) = field_comment {
+            if self.field_comments.len() <= index {
+                self.field_comments.resize(index + 1, "".to_string());
+            }
+            self.field_comments[index] = comment;
+        }
+        Ok(())
+    }
 }
 
 impl TableInfo {
@@ -604,6 +622,17 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+/// Update the meta of several tables atomically, either all of them are updated or none.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -638,6 +667,10 @@ impl GetTableReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ListTableReq {
     pub inner: DatabaseNameIdent,
+    /// List only the tables whose name is after this one.
+    pub start_after: Option<String>,
+    /// The max number of tables to return.
+    pub limit: Option<usize>,
 }
 
 impl Deref for ListTableReq {
@@ -655,8 +688,17 @@ impl ListTableReq {
                 tenant: tenant.into(),
                 db_name: db_name.into(),
             },
+            start_after: None,
+            limit: None,
         }
     }
+
+    /// List a page of tables, the next page starts after the name of the last table returned.
+    pub fn with_page(mut self, start_after: Option<String>, limit: Option<usize>) -> Self {
+        self.start_after = start_after;
+        self.limit = limit;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
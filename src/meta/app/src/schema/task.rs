@@ -0,0 +1,394 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::principal::UserIdentity;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct TaskNameIdent {
+    pub tenant: String,
+    pub task_name: String,
+}
+
+impl TaskNameIdent {
+    pub fn new(tenant: impl Into<String>, task_name: impl Into<String>) -> TaskNameIdent {
+        TaskNameIdent {
+            tenant: tenant.into(),
+            task_name: task_name.into(),
+        }
+    }
+}
+
+impl Display for TaskNameIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'.'{}'", self.tenant, self.task_name)
+    }
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TaskState {
+    #[default]
+    Started = 0,
+    Suspended = 1,
+}
+
+impl Display for TaskState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskState::Started => write!(f, "STARTED"),
+            TaskState::Suspended => write!(f, "SUSPENDED"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TaskMeta {
+    /// The SQL statement the task runs.
+    pub query: String,
+    /// The cron expression of the schedule, `None` if the task only runs after other tasks.
+    pub schedule: Option<String>,
+    /// The task runs after each successful run of these tasks.
+    pub after: Vec<String>,
+    pub state: TaskState,
+    pub comment: Option<String>,
+    /// The task runs as this user.
+    pub owner: UserIdentity,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+}
+
+impl Default for TaskMeta {
+    fn default() -> Self {
+        TaskMeta {
+            query: "".to_string(),
+            schedule: None,
+            after: vec![],
+            state: TaskState::default(),
+            comment: None,
+            owner: UserIdentity::default(),
+            created_on: Utc::now(),
+            updated_on: Utc::now(),
+        }
+    }
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TaskRunState {
+    #[default]
+    Executing = 0,
+    Succeeded = 1,
+    Failed = 2,
+}
+
+impl Display for TaskRunState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskRunState::Executing => write!(f, "EXECUTING"),
+            TaskRunState::Succeeded => write!(f, "SUCCEEDED"),
+            TaskRunState::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// A run of a task, it is kept in the run history of the task for a while.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TaskRun {
+    pub run_id: u64,
+    pub task_name: String,
+    pub query: String,
+    pub state: TaskRunState,
+    /// What triggers the run: `SCHEDULE`, `EXECUTE` or `AFTER <task_name>`.
+    pub trigger: String,
+    pub query_id: String,
+    /// The query node the task runs on.
+    pub node_id: String,
+    pub scheduled_on: DateTime<Utc>,
+    pub started_on: DateTime<Utc>,
+    pub completed_on: Option<DateTime<Utc>>,
+    pub error_code: u32,
+    pub error_message: Option<String>,
+}
+
+impl Default for TaskRun {
+    fn default() -> Self {
+        TaskRun {
+            run_id: 0,
+            task_name: "".to_string(),
+            query: "".to_string(),
+            state: TaskRunState::default(),
+            trigger: "".to_string(),
+            query_id: "".to_string(),
+            node_id: "".to_string(),
+            scheduled_on: Utc::now(),
+            started_on: Utc::now(),
+            completed_on: None,
+            error_code: 0,
+            error_message: None,
+        }
+    }
+}
+
+/// The key of a task run: `<prefix>/<tenant>/<task_name>/<run_id>`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TaskRunKey {
+    pub tenant: String,
+    pub task_name: String,
+    pub run_id: u64,
+}
+
+/// The lease of running scheduled tasks of a tenant, only the query node holding it runs them.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TaskLeaseKey {
+    pub tenant: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTaskReq {
+    pub if_not_exists: bool,
+    pub name_ident: TaskNameIdent,
+    pub meta: TaskMeta,
+}
+
+impl Display for CreateTaskReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_task(if_not_exists={}):{}={:?}",
+            self.if_not_exists, self.name_ident, self.meta
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTaskReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropTaskReq {
+    pub if_exists: bool,
+    pub name_ident: TaskNameIdent,
+}
+
+impl Display for DropTaskReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "drop_task(if_exists={}):{}",
+            self.if_exists, self.name_ident
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropTaskReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetTaskReq {
+    pub name_ident: TaskNameIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetTaskReply {
+    pub meta: TaskMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListTasksReq {
+    pub tenant: String,
+}
+
+impl ListTasksReq {
+    pub fn new(tenant: impl Into<String>) -> ListTasksReq {
+        ListTasksReq {
+            tenant: tenant.into(),
+        }
+    }
+}
+
+/// Suspend or resume a task.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateTaskStateReq {
+    pub name_ident: TaskNameIdent,
+    pub state: TaskState,
+}
+
+impl Display for UpdateTaskStateReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "update_task_state({}):{}", self.state, self.name_ident)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateTaskStateReply {}
+
+/// Add a run to the history of a task, a new `run_id` is assigned to it.
+///
+/// The run is removed from the history at `expire_at`, in seconds.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTaskRunReq {
+    pub tenant: String,
+    pub run: TaskRun,
+    pub expire_at: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTaskRunReply {
+    pub run_id: u64,
+}
+
+/// Update a run in the history of a task, e.g., when it completes.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateTaskRunReq {
+    pub tenant: String,
+    pub run: TaskRun,
+    pub expire_at: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateTaskRunReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListTaskRunsReq {
+    pub tenant: String,
+}
+
+impl ListTaskRunsReq {
+    pub fn new(tenant: impl Into<String>) -> ListTaskRunsReq {
+        ListTaskRunsReq {
+            tenant: tenant.into(),
+        }
+    }
+}
+
+/// Acquire or extend the task lease of a tenant, until `expire_at`, in seconds.
+///
+/// `seq` is the seq of the lease the caller holds, or 0 to acquire a new one.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AcquireTaskLeaseReq {
+    pub tenant: String,
+    pub seq: u64,
+    pub expire_at: u64,
+}
+
+/// `seq` is the seq of the lease held by the caller, or `None` if it is held by another node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AcquireTaskLeaseReply {
+    pub seq: Option<u64>,
+}
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use crate::schema::TaskLeaseKey;
+    use crate::schema::TaskNameIdent;
+    use crate::schema::TaskRunKey;
+    use crate::schema::PREFIX_TASK;
+    use crate::schema::PREFIX_TASK_LEASE;
+    use crate::schema::PREFIX_TASK_RUN;
+
+    /// <prefix>/<tenant>/<task_name> -> TaskMeta
+    impl kvapi::Key for TaskNameIdent {
+        const PREFIX: &'static str = PREFIX_TASK;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.task_name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let task_name = p.next_str()?;
+            p.done()?;
+
+            Ok(TaskNameIdent { tenant, task_name })
+        }
+    }
+
+    /// <prefix>/<tenant>/<task_name>/<run_id> -> TaskRun
+    impl kvapi::Key for TaskRunKey {
+        const PREFIX: &'static str = PREFIX_TASK_RUN;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.task_name)
+                .push_u64(self.run_id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let task_name = p.next_str()?;
+            let run_id = p.next_u64()?;
+            p.done()?;
+
+            Ok(TaskRunKey {
+                tenant,
+                task_name,
+                run_id,
+            })
+        }
+    }
+
+    /// <prefix>/<tenant> -> EmptyProto
+    impl kvapi::Key for TaskLeaseKey {
+        const PREFIX: &'static str = PREFIX_TASK_LEASE;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            p.done()?;
+
+            Ok(TaskLeaseKey { tenant })
+        }
+    }
+}
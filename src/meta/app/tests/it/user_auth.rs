@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::AuthType;
+use common_meta_app::principal::PasswordHashMethod;
+use common_meta_app::principal::ScramSha256Secret;
+
+#[test]
+fn test_scram_sha256_secret() -> Result<()> {
+    // The example of RFC 7677, user "user" with password "pencil".
+    let salt = hex::decode("5b6d99689d12358eeca04b141236fa81").unwrap();
+    let secret = ScramSha256Secret::with_salt(b"pencil", &salt);
+
+    let auth_message = "n=user,r=rOprNGfwEbeRWgbNEkqO,\
+        r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096,\
+        c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0";
+    let client_proof =
+        hex::decode("747cdb65aa56224e2352137e52d7bdcad6a0f738df30782caa69a2cfb0277554").unwrap();
+    assert!(secret.verify_client_proof(auth_message.as_bytes(), &client_proof));
+    assert!(!secret.verify_client_proof(auth_message.as_bytes(), &client_proof[1..]));
+    assert_eq!(
+        hex::encode(secret.server_signature(auth_message.as_bytes())),
+        "eabae24d1062db75a9451ff0b6ea7e98c8546549ff741e672d3251b2397de46e"
+    );
+
+    assert!(secret.verify_password(b"pencil"));
+    assert!(!secret.verify_password(b"pencil2"));
+
+    let restored = ScramSha256Secret::from_hash_value(&secret.to_hash_value())?;
+    assert_eq!(restored, secret);
+    assert!(ScramSha256Secret::from_hash_value(b"pencil").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_scram_sha256_password() -> Result<()> {
+    let auth_info = AuthInfo::create(
+        &Some("scram_sha256_password".to_string()),
+        &Some("pencil".to_string()),
+    )?;
+    assert_eq!(auth_info.get_type(), AuthType::ScramSha256Password);
+
+    // Salted randomly, verify rather than compare the hash.
+    let hash_value = auth_info.get_password().unwrap();
+    let method = auth_info.get_password_type().unwrap();
+    assert_eq!(method, PasswordHashMethod::ScramSha256);
+    assert_ne!(hash_value, method.hash(b"pencil"));
+    assert!(method.verify(&hash_value, b"pencil"));
+    assert!(!method.verify(&hash_value, b"pencil2"));
+
+    assert!(auth_info.auth_mysql(b"pencil", b"salt").is_err());
+    Ok(())
+}
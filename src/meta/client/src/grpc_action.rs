@@ -17,6 +17,7 @@ use std::fmt::Debug;
 
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -52,6 +53,7 @@ pub enum MetaGrpcReq {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+    ListKVRange(ListKVRangeReq),
 }
 
 impl TryInto<MetaGrpcReq> for Request<RaftRequest> {
@@ -92,6 +94,10 @@ impl RequestFor for ListKVReq {
     type Reply = ListKVReply;
 }
 
+impl RequestFor for ListKVRangeReq {
+    type Reply = ListKVReply;
+}
+
 impl RequestFor for UpsertKVReq {
     type Reply = UpsertKVReply;
 }
@@ -415,6 +415,13 @@ impl MetaGrpcClient {
                         .await;
                     message::Response::PrefixList(resp)
                 }
+                message::Request::ListRange(r) => {
+                    let resp = self
+                        .kv_api(r)
+                        .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                        .await;
+                    message::Response::PrefixList(resp)
+                }
                 message::Request::Upsert(r) => {
                     let resp = self
                         .kv_api(r)
@@ -15,6 +15,7 @@
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -60,6 +61,11 @@ impl kvapi::KVApi for ClientHandle {
         Ok(reply)
     }
 
+    async fn list_kv(&self, req: ListKVRangeReq) -> Result<ListKVReply, Self::Error> {
+        let reply = self.request(req).await?;
+        Ok(reply)
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         let reply = self.request(txn).await?;
         Ok(reply)
@@ -15,6 +15,7 @@
 use common_base::base::tokio::sync::oneshot::Sender;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -57,6 +58,9 @@ pub enum Request {
     /// List KVs by key prefix
     PrefixList(ListKVReq),
 
+    /// List KVs in a key range, at most `limit` KVs
+    ListRange(ListKVRangeReq),
+
     /// Update or insert KV
     Upsert(UpsertKVReq),
 
@@ -85,6 +89,7 @@ impl Request {
             Request::Get(_) => "Get",
             Request::MGet(_) => "MGet",
             Request::PrefixList(_) => "PrefixList",
+            Request::ListRange(_) => "ListRange",
             Request::Upsert(_) => "Upsert",
             Request::Txn(_) => "Txn",
             Request::Watch(_) => "Watch",
@@ -101,6 +106,7 @@ impl Request {
 pub enum Response {
     Get(Result<GetKVReply, MetaError>),
     MGet(Result<MGetKVReply, MetaError>),
+    /// Reply of both `PrefixList` and `ListRange`
     PrefixList(Result<ListKVReply, MetaError>),
     Upsert(Result<UpsertKVReply, MetaError>),
     Txn(Result<TxnReply, MetaError>),
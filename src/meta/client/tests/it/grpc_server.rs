@@ -78,6 +78,16 @@ impl MetaService for GrpcServiceForTestImpl {
         todo!()
     }
 
+    type BackupStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + Sync + 'static>>;
+
+    async fn backup(
+        &self,
+        _request: Request<common_meta_types::protobuf::Empty>,
+    ) -> Result<Response<Self::BackupStream>, Status> {
+        todo!()
+    }
+
     type WatchStream =
         Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + Sync + 'static>>;
 
@@ -102,6 +112,13 @@ impl MetaService for GrpcServiceForTestImpl {
         todo!()
     }
 
+    async fn cluster_admin(
+        &self,
+        _request: Request<RaftRequest>,
+    ) -> Result<Response<RaftReply>, Status> {
+        todo!()
+    }
+
     async fn get_client_info(
         &self,
         _request: Request<Empty>,
@@ -15,6 +15,7 @@
 use async_trait::async_trait;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
@@ -49,6 +50,11 @@ impl kvapi::KVApi for MetaEmbedded {
         sm.prefix_list_kv(prefix).await
     }
 
+    async fn list_kv(&self, req: ListKVRangeReq) -> Result<ListKVReply, Self::Error> {
+        let sm = self.inner.lock().await;
+        sm.list_kv(req).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         let sm = self.inner.lock().await;
         sm.transaction(txn).await
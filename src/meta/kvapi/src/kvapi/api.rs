@@ -20,6 +20,7 @@ use common_meta_types::TxnRequest;
 
 use crate::kvapi;
 use crate::kvapi::GetKVReply;
+use crate::kvapi::ListKVRangeReq;
 use crate::kvapi::ListKVReply;
 use crate::kvapi::MGetKVReply;
 use crate::kvapi::UpsertKVReply;
@@ -57,6 +58,13 @@ pub trait KVApi: Send + Sync {
     /// List key-value records that are starts with the specified prefix.
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, Self::Error>;
 
+    /// List key-value records in the range `[req.start, req.end)` in key order,
+    /// returns at most `req.limit` records.
+    ///
+    /// Unlike `prefix_list_kv`, the size of the reply is bounded by the limit,
+    /// a large key space can be listed page by page with `ListKVPager`.
+    async fn list_kv(&self, req: ListKVRangeReq) -> Result<ListKVReply, Self::Error>;
+
     /// Run transaction: update one or more records if specified conditions are met.
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error>;
 }
@@ -81,6 +89,10 @@ impl<U: kvapi::KVApi, T: Deref<Target = U> + Send + Sync> kvapi::KVApi for T {
         self.deref().prefix_list_kv(prefix).await
     }
 
+    async fn list_kv(&self, req: ListKVRangeReq) -> Result<ListKVReply, Self::Error> {
+        self.deref().list_kv(req).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         self.deref().transaction(txn).await
     }
@@ -33,6 +33,28 @@ pub struct ListKVReq {
     pub prefix: String,
 }
 
+/// List key-value records in the range `[start, end)`, at most `limit` records are returned.
+///
+/// `end` is unbounded if it is `None`, and so is `limit`.
+/// To list the next page, send the request again with `start` set to the last returned key
+/// followed by a `\0`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListKVRangeReq {
+    pub start: String,
+    pub end: Option<String>,
+    pub limit: Option<u64>,
+}
+
+impl ListKVRangeReq {
+    pub fn new(start: impl ToString, end: Option<String>, limit: Option<u64>) -> Self {
+        Self {
+            start: start.to_string(),
+            end,
+            limit,
+        }
+    }
+}
+
 pub type UpsertKVReply = Change<Vec<u8>>;
 pub type GetKVReply = Option<SeqV<Vec<u8>>>;
 pub type MGetKVReply = Vec<Option<SeqV<Vec<u8>>>>;
@@ -18,6 +18,7 @@ mod key;
 mod key_builder;
 mod key_parser;
 mod message;
+mod pager;
 mod prefix;
 mod test_suite;
 
@@ -30,11 +31,13 @@ pub use key_builder::KeyBuilder;
 pub use key_parser::KeyParser;
 pub use message::GetKVReply;
 pub use message::GetKVReq;
+pub use message::ListKVRangeReq;
 pub use message::ListKVReply;
 pub use message::ListKVReq;
 pub use message::MGetKVReply;
 pub use message::MGetKVReq;
 pub use message::UpsertKVReply;
 pub use message::UpsertKVReq;
+pub use pager::ListKVPager;
 pub use prefix::prefix_to_range;
 pub use test_suite::TestSuite;
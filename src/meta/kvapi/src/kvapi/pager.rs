@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! List a range of key-value records page by page.
+
+use crate::kvapi::KVApi;
+use crate::kvapi::ListKVRangeReq;
+use crate::kvapi::ListKVReply;
+
+/// Lists the records in a range with a sequence of bounded `list_kv` requests,
+/// so that neither the meta-service nor the client holds the entire range in memory.
+///
+/// ```ignore
+/// let mut pager = ListKVPager::new(kv_api, start, Some(end), 1000);
+/// while let Some(page) = pager.next_page().await? {
+///     // process page
+/// }
+/// ```
+pub struct ListKVPager<'a, KV: KVApi + ?Sized> {
+    kv_api: &'a KV,
+    req: ListKVRangeReq,
+    done: bool,
+}
+
+impl<'a, KV: KVApi + ?Sized> ListKVPager<'a, KV> {
+    pub fn new(kv_api: &'a KV, start: impl ToString, end: Option<String>, page_size: u64) -> Self {
+        assert!(page_size > 0, "page_size must be greater than 0");
+
+        Self {
+            kv_api,
+            req: ListKVRangeReq::new(start, end, Some(page_size)),
+            done: false,
+        }
+    }
+
+    /// Returns the next non-empty page, or `None` if all the records in the range are returned.
+    pub async fn next_page(&mut self) -> Result<Option<ListKVReply>, KV::Error> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let page = self.kv_api.list_kv(self.req.clone()).await?;
+
+        match page.last() {
+            Some((last_key, _)) if (page.len() as u64) >= self.req.limit.unwrap_or(u64::MAX) => {
+                // The smallest key greater than `last_key`.
+                self.req.start = format!("{}\0", last_key);
+            }
+            _ => self.done = true,
+        }
+
+        if page.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(page))
+        }
+    }
+}
@@ -59,6 +59,7 @@ impl kvapi::TestSuite {
         self.kv_timeout(&builder.build().await).await?;
         self.kv_meta(&builder.build().await).await?;
         self.kv_list(&builder.build().await).await?;
+        self.kv_list_range(&builder.build().await).await?;
         self.kv_mget(&builder.build().await).await?;
         self.kv_txn_absent_seq_0(&builder.build().await).await?;
         self.kv_transaction(&builder.build().await).await?;
@@ -430,6 +431,68 @@ impl kvapi::TestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "info", skip(self, kv))]
+    pub async fn kv_list_range<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        info!("--- kvapi::KVApiTestSuite::kv_list_range() start");
+
+        kv.upsert_kv(UpsertKVReq::update("t", b"")).await?;
+        for i in 0..9 {
+            let key = format!("__tables/{}", i);
+            kv.upsert_kv(UpsertKVReq::update(&key, key.as_bytes()))
+                .await?;
+        }
+        kv.upsert_kv(UpsertKVReq::update("v", b"")).await?;
+
+        let keys = |res: kvapi::ListKVReply| res.into_iter().map(|(k, _)| k).collect::<Vec<_>>();
+
+        info!("--- list with start, end and limit");
+        {
+            let req =
+                kvapi::ListKVRangeReq::new("__tables/2", Some("__tables/7".to_string()), None);
+            let res = kv.list_kv(req).await?;
+            assert_eq!(
+                keys(res),
+                (2..7)
+                    .map(|i| format!("__tables/{}", i))
+                    .collect::<Vec<_>>()
+            );
+
+            let req = kvapi::ListKVRangeReq::new("__tables/2", None, Some(3));
+            let res = kv.list_kv(req).await?;
+            assert_eq!(
+                keys(res),
+                (2..5)
+                    .map(|i| format!("__tables/{}", i))
+                    .collect::<Vec<_>>()
+            );
+
+            let req = kvapi::ListKVRangeReq::new("u", None, None);
+            let res = kv.list_kv(req).await?;
+            assert_eq!(keys(res), vec!["v".to_string()]);
+        }
+
+        info!("--- list page by page");
+        {
+            let (start, end) = kvapi::prefix_to_range("__tables/")?;
+            let mut pager = kvapi::ListKVPager::new(kv, start, Some(end), 4);
+            let mut pages = vec![];
+            while let Some(page) = pager.next_page().await? {
+                pages.push(keys(page));
+            }
+            assert_eq!(pages.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![
+                4, 4, 1
+            ]);
+            assert_eq!(
+                pages.concat(),
+                (0..9)
+                    .map(|i| format!("__tables/{}", i))
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "info", skip(self, kv))]
     pub async fn kv_mget<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- kvapi::KVApiTestSuite::kv_mget() start");
@@ -67,9 +67,11 @@ mod file_format_from_to_protobuf_impl;
 mod from_to_protobuf;
 mod index_from_to_protobuf_impl;
 mod schema_from_to_protobuf_impl;
+mod sequence_from_to_protobuf_impl;
 mod share_from_to_protobuf_impl;
 mod stage_from_to_protobuf_impl;
 mod table_from_to_protobuf_impl;
+mod task_from_to_protobuf_impl;
 mod user_from_to_protobuf_impl;
 mod util;
 
@@ -79,7 +79,21 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr);
-        Ok(v)
+
+        let mut history = Vec::with_capacity(p.history.len());
+        for h in p.history {
+            let data_type =
+                ex::TableDataType::from_pb(h.data_type.ok_or_else(|| Incompatible {
+                    reason: "ColumnHistory.data_type can not be None".to_string(),
+                })?)?;
+            history.push((h.column_id, data_type));
+        }
+
+        let computed_expr = match p.computed_expr {
+            Some(computed_expr) => Some(ex::ComputedExpr::from_pb(computed_expr)?),
+            None => None,
+        };
+        Ok(v.with_history(history).with_computed_expr(computed_expr))
     }
 
     fn to_pb(&self) -> Result<pb::DataField, Incompatible> {
@@ -90,11 +104,61 @@ impl FromToProto for ex::TableField {
             default_expr: self.default_expr().cloned(),
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
+            history: self
+                .history()
+                .iter()
+                .map(|(column_id, data_type)| {
+                    Ok(pb::ColumnHistory {
+                        column_id: *column_id,
+                        data_type: Some(data_type.to_pb()?),
+                    })
+                })
+                .collect::<Result<Vec<_>, Incompatible>>()?,
+            computed_expr: match self.computed_expr() {
+                Some(computed_expr) => Some(computed_expr.to_pb()?),
+                None => None,
+            },
         };
         Ok(p)
     }
 }
 
+impl FromToProto for ex::ComputedExpr {
+    type PB = pb::ComputedExpr;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::ComputedExpr) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        match p.computed_expr {
+            Some(pb::computed_expr::ComputedExpr::Virtual(expr)) => {
+                Ok(ex::ComputedExpr::Virtual(expr))
+            }
+            Some(pb::computed_expr::ComputedExpr::Stored(expr)) => {
+                Ok(ex::ComputedExpr::Stored(expr))
+            }
+            None => Err(Incompatible {
+                reason: "ComputedExpr.computed_expr can not be None".to_string(),
+            }),
+        }
+    }
+
+    fn to_pb(&self) -> Result<pb::ComputedExpr, Incompatible> {
+        let computed_expr = match self {
+            ex::ComputedExpr::Virtual(expr) => {
+                pb::computed_expr::ComputedExpr::Virtual(expr.clone())
+            }
+            ex::ComputedExpr::Stored(expr) => pb::computed_expr::ComputedExpr::Stored(expr.clone()),
+        };
+        Ok(pb::ComputedExpr {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            computed_expr: Some(computed_expr),
+        })
+    }
+}
+
 impl FromToProto for ex::TableDataType {
     type PB = pb::DataType;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -182,6 +246,10 @@ impl FromToProto for ex::TableDataType {
                     }
                     Dt24::TimestampT(_) => ex::TableDataType::Timestamp,
                     Dt24::DateT(_) => ex::TableDataType::Date,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::TimestampTzT(_) => ex::TableDataType::TimestampTz,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                     Dt24::NullableT(x) => ex::TableDataType::Nullable(Box::new(
                         ex::TableDataType::from_pb(Box::into_inner(x))?,
                     )),
@@ -238,6 +306,8 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::TimestampTz => new_pb_dt24(Dt24::TimestampTzT(pb::Empty {})),
             TableDataType::Nullable(v) => {
                 let x = v.to_pb()?;
                 new_pb_dt24(Dt24::NullableT(Box::new(x)))
@@ -250,7 +320,9 @@ impl FromToProto for ex::TableDataType {
                 let x = v.to_pb()?;
                 new_pb_dt24(Dt24::MapT(Box::new(x)))
             }
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
             TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Tuple {
                 fields_name,
                 fields_type,
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_meta_app as mt;
+use common_protos::pb;
+use num::FromPrimitive;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::schema::TaskMeta {
+    type PB = pb::TaskMeta;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            query: p.query,
+            schedule: p.schedule,
+            after: p.after,
+            state: FromPrimitive::from_i32(p.state).ok_or_else(|| Incompatible {
+                reason: format!("invalid TaskState: {}", p.state),
+            })?,
+            comment: p.comment,
+            owner: mt::principal::UserIdentity::from_pb(p.owner.ok_or_else(|| Incompatible {
+                reason: "TaskMeta.owner cannot be None".to_string(),
+            })?)?,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+            updated_on: DateTime::<Utc>::from_pb(p.updated_on)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let p = pb::TaskMeta {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            query: self.query.clone(),
+            schedule: self.schedule.clone(),
+            after: self.after.clone(),
+            state: self.state as i32,
+            comment: self.comment.clone(),
+            owner: Some(self.owner.to_pb()?),
+            created_on: self.created_on.to_pb()?,
+            updated_on: self.updated_on.to_pb()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::schema::TaskRun {
+    type PB = pb::TaskRun;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            run_id: p.run_id,
+            task_name: p.task_name,
+            query: p.query,
+            state: FromPrimitive::from_i32(p.state).ok_or_else(|| Incompatible {
+                reason: format!("invalid TaskRunState: {}", p.state),
+            })?,
+            trigger: p.trigger,
+            query_id: p.query_id,
+            node_id: p.node_id,
+            scheduled_on: DateTime::<Utc>::from_pb(p.scheduled_on)?,
+            started_on: DateTime::<Utc>::from_pb(p.started_on)?,
+            completed_on: match p.completed_on {
+                Some(completed_on) => Some(DateTime::<Utc>::from_pb(completed_on)?),
+                None => None,
+            },
+            error_code: p.error_code,
+            error_message: p.error_message,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let p = pb::TaskRun {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            run_id: self.run_id,
+            task_name: self.task_name.clone(),
+            query: self.query.clone(),
+            state: self.state as i32,
+            trigger: self.trigger.clone(),
+            query_id: self.query_id.clone(),
+            node_id: self.node_id.clone(),
+            scheduled_on: self.scheduled_on.to_pb()?,
+            started_on: self.started_on.to_pb()?,
+            completed_on: match self.completed_on {
+                Some(completed_on) => Some(completed_on.to_pb()?),
+                None => None,
+            },
+            error_code: self.error_code,
+            error_message: self.error_message.clone(),
+        };
+        Ok(p)
+    }
+}
@@ -68,6 +68,17 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (36, "2023-05-12: Add: metadata.proto/ComputedExpr (reverted)", ),
     (37, "2023-05-05: Add: index.proto", ),
     (38, "2023-05-19: Rename: table.proto/TableCopiedFileLock to EmptyProto", ),
+    (39, "2023-05-22: Add: file_format.proto/AvroFileFormatParams", ),
+    (40, "2023-05-23: Add: file_format.proto/OrcFileFormatParams", ),
+    (41, "2023-05-24: Add: metadata.proto/DataField::history", ),
+    (42, "2023-05-26: Add: metadata.proto/ComputedExpr", ),
+    (43, "2023-05-29: Add: user.proto/AuthInfo::Password::PasswordHashMethod::ScramSha256", ),
+    (44, "2023-06-01: Add: sequence.proto/SequenceMeta", ),
+    (45, "2023-06-05: Add: task.proto/TaskMeta, TaskRun", ),
+    (46, "2023-06-07: Add: datatype.proto/DataType::interval_t", ),
+    (47, "2023-06-09: Add: datatype.proto/DataType::timestamp_tz_t", ),
+    (48, "2023-06-12: Add: datatype.proto/DataType::binary_t", ),
+    (49, "2023-06-14: Add: datatype.proto/DataType::geometry_t", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -43,3 +43,14 @@ mod v034_schema;
 mod v035_user_stage;
 mod v037_index_meta;
 mod v038_empty_proto;
+mod v039_avro_file_format_params;
+mod v040_orc_file_format_params;
+mod v041_column_history;
+mod v042_computed_expr;
+mod v043_auth_info_scram;
+mod v044_sequence_meta;
+mod v045_task;
+mod v046_interval;
+mod v047_timestamp_tz;
+mod v048_binary;
+mod v049_geometry;
@@ -0,0 +1,41 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordHashMethod;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v43_auth_info_scram() -> anyhow::Result<()> {
+    let auth_info_v43 = vec![18, 7, 10, 3, 1, 2, 3, 16, 3, 160, 6, 43, 168, 6, 24];
+
+    let want = || AuthInfo::Password {
+        hash_value: vec![1, 2, 3],
+        hash_method: PasswordHashMethod::ScramSha256,
+    };
+    common::test_load_old(func_name!(), auth_info_v43.as_slice(), 43, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
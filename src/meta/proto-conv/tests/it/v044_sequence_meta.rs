@@ -0,0 +1,52 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::schema::SequenceMeta;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v44_sequence_meta() -> anyhow::Result<()> {
+    let sequence_meta_v44 = vec![
+        10, 23, 50, 48, 50, 51, 45, 48, 54, 45, 48, 49, 32, 48, 56, 58, 48, 48, 58, 48, 48, 32, 85,
+        84, 67, 18, 23, 50, 48, 50, 51, 45, 48, 54, 45, 48, 50, 32, 48, 57, 58, 51, 48, 58, 48, 48,
+        32, 85, 84, 67, 26, 3, 115, 101, 113, 32, 1, 40, 2, 48, 11, 160, 6, 44, 168, 6, 24,
+    ];
+
+    let want = || SequenceMeta {
+        created_on: Utc.with_ymd_and_hms(2023, 6, 1, 8, 0, 0).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2023, 6, 2, 9, 30, 0).unwrap(),
+        comment: Some("seq".to_string()),
+        start: 1,
+        increment: 2,
+        current: 11,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), sequence_meta_v44.as_slice(), 44, want())?;
+
+    Ok(())
+}
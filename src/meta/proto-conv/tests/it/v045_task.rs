@@ -0,0 +1,92 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRun;
+use common_meta_app::schema::TaskRunState;
+use common_meta_app::schema::TaskState;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v45_task_meta() -> anyhow::Result<()> {
+    let task_meta_v45 = vec![
+        10, 8, 83, 69, 76, 69, 67, 84, 32, 49, 18, 9, 48, 32, 42, 32, 42, 32, 42, 32, 42, 26, 2,
+        116, 49, 32, 1, 42, 4, 116, 97, 115, 107, 50, 12, 10, 1, 117, 18, 1, 37, 160, 6, 45, 168,
+        6, 24, 58, 23, 50, 48, 50, 51, 45, 48, 54, 45, 48, 53, 32, 48, 56, 58, 48, 48, 58, 48, 48,
+        32, 85, 84, 67, 66, 23, 50, 48, 50, 51, 45, 48, 54, 45, 48, 53, 32, 48, 57, 58, 51, 48, 58,
+        48, 48, 32, 85, 84, 67, 160, 6, 45, 168, 6, 24,
+    ];
+
+    let want = || TaskMeta {
+        query: "SELECT 1".to_string(),
+        schedule: Some("0 * * * *".to_string()),
+        after: vec!["t1".to_string()],
+        state: TaskState::Suspended,
+        comment: Some("task".to_string()),
+        owner: UserIdentity::new("u", "%"),
+        created_on: Utc.with_ymd_and_hms(2023, 6, 5, 8, 0, 0).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2023, 6, 5, 9, 30, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), task_meta_v45.as_slice(), 45, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v45_task_run() -> anyhow::Result<()> {
+    let task_run_v45 = vec![
+        8, 3, 18, 2, 116, 50, 26, 8, 83, 69, 76, 69, 67, 84, 32, 49, 32, 2, 42, 8, 65, 70, 84, 69,
+        82, 32, 116, 49, 50, 3, 113, 105, 100, 58, 4, 110, 111, 100, 101, 66, 23, 50, 48, 50, 51,
+        45, 48, 54, 45, 48, 53, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 74, 23, 50, 48,
+        50, 51, 45, 48, 54, 45, 48, 53, 32, 49, 48, 58, 48, 48, 58, 48, 49, 32, 85, 84, 67, 82, 23,
+        50, 48, 50, 51, 45, 48, 54, 45, 48, 53, 32, 49, 48, 58, 48, 48, 58, 48, 53, 32, 85, 84, 67,
+        88, 238, 7, 98, 3, 101, 114, 114, 160, 6, 45, 168, 6, 24,
+    ];
+
+    let want = || TaskRun {
+        run_id: 3,
+        task_name: "t2".to_string(),
+        query: "SELECT 1".to_string(),
+        state: TaskRunState::Failed,
+        trigger: "AFTER t1".to_string(),
+        query_id: "qid".to_string(),
+        node_id: "node".to_string(),
+        scheduled_on: Utc.with_ymd_and_hms(2023, 6, 5, 10, 0, 0).unwrap(),
+        started_on: Utc.with_ymd_and_hms(2023, 6, 5, 10, 0, 1).unwrap(),
+        completed_on: Some(Utc.with_ymd_and_hms(2023, 6, 5, 10, 0, 5).unwrap()),
+        error_code: 1006,
+        error_message: Some("err".to_string()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), task_run_v45.as_slice(), 45, want())?;
+
+    Ok(())
+}
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ops::Bound;
+
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
+use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_stoerr::MetaStorageError;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::MetaError;
@@ -119,4 +124,31 @@ impl kvapi::KVApi for StateMachine {
 
         Ok(x.collect())
     }
+
+    async fn list_kv(&self, req: ListKVRangeReq) -> Result<ListKVReply, Self::Error> {
+        let kvs = self.kvs();
+        let end = match req.end {
+            Some(end) => Bound::Excluded(end),
+            None => Bound::Unbounded,
+        };
+        let it = kvs.range((Bound::Included(req.start), end))?;
+        let limit = req.limit.unwrap_or(u64::MAX) as usize;
+
+        let local_now_ms = SeqV::<()>::now_ms();
+        let mut res = vec![];
+
+        // Iterate lazily and stop at the limit, the range may contain a huge number of records.
+        for item in it {
+            if res.len() >= limit {
+                break;
+            }
+
+            let (k, v) = item?.kv().map_err(MetaStorageError::from)?;
+            if let (_, Some(v)) = Self::expire_seq_v(Some(v), local_now_ms) {
+                res.push((k, v));
+            }
+        }
+
+        Ok(res)
+    }
 }
@@ -51,6 +51,7 @@ use tonic::Streaming;
 use tracing::debug;
 use tracing::info;
 
+use crate::message::ClusterAdminRequest;
 use crate::meta_service::meta_service_impl::GrpcStream;
 use crate::meta_service::MetaNode;
 use crate::metrics::network_metrics;
@@ -183,6 +184,10 @@ impl MetaService for MetaServiceImpl {
                 let res = m.prefix_list_kv(&a.prefix).await;
                 RaftReply::from(res)
             }
+            MetaGrpcReq::ListKVRange(a) => {
+                let res = m.list_kv(a).await;
+                RaftReply::from(res)
+            }
         };
 
         network_metrics::incr_request_result(reply.error.is_empty());
@@ -248,6 +253,27 @@ impl MetaService for MetaServiceImpl {
         Ok(Response::new(Box::pin(s)))
     }
 
+    type BackupStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + Sync + 'static>>;
+
+    // Backup meta data online.
+    //
+    // Including the data header, a state machine snapshot and the raft logs applied after it.
+    async fn backup(
+        &self,
+        _request: Request<common_meta_types::protobuf::Empty>,
+    ) -> Result<Response<Self::BackupStream>, Status> {
+        let _guard = RequestInFlight::guard();
+
+        let meta_node = &self.meta_node;
+        let res = meta_node.sto.backup().await?;
+
+        let stream = ExportStream { data: res };
+        let s = stream.map(|strings| Ok(ExportedChunk { data: strings }));
+
+        Ok(Response::new(Box::pin(s)))
+    }
+
     type WatchStream =
         Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + Sync + 'static>>;
 
@@ -293,6 +319,30 @@ impl MetaService for MetaServiceImpl {
         Ok(Response::new(resp))
     }
 
+    async fn cluster_admin(
+        &self,
+        request: Request<RaftRequest>,
+    ) -> Result<Response<RaftReply>, Status> {
+        self.check_token(request.metadata())?;
+
+        let _guard = RequestInFlight::guard();
+
+        common_tracing::extract_remote_span_as_parent(&request);
+
+        let req: ClusterAdminRequest = serde_json::from_str(&request.get_ref().data)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        info!("Receive cluster_admin request: {:?}", req);
+
+        let res = self.meta_node.cluster_admin(req).await;
+        network_metrics::incr_request_result(res.is_ok());
+
+        let reply = RaftReply::from(res);
+        network_metrics::incr_sent_bytes(reply.encoded_len() as u64);
+
+        Ok(Response::new(reply))
+    }
+
     async fn get_client_info(
         &self,
         request: Request<Empty>,
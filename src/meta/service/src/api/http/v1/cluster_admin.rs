@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_meta_types::Endpoint;
+use common_meta_types::NodeId;
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::IntoResponse;
+use poem::web::Json;
+use poem::web::Query;
+
+use crate::message::AddLearnerRequest;
+use crate::message::ClusterAdminRequest;
+use crate::message::DemoteVoterRequest;
+use crate::message::PromoteLearnerRequest;
+use crate::message::RemoveNodeRequest;
+use crate::message::TransferLeaderRequest;
+use crate::meta_service::MetaNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct AddLearnerQuery {
+    node_id: NodeId,
+    raft_advertise_host: String,
+    raft_api_port: u32,
+    grpc_api_advertise_address: Option<String>,
+}
+
+/// Add a node to the cluster as a learner.
+///
+/// request: `?node_id=3&raft_advertise_host=localhost&raft_api_port=28304[&grpc_api_advertise_address=127.0.0.1:39191]`
+#[poem::handler]
+pub async fn add_learner(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<AddLearnerQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let query = query.0;
+    let req = AddLearnerRequest {
+        node_id: query.node_id,
+        endpoint: Endpoint::new(query.raft_advertise_host, query.raft_api_port),
+        grpc_api_advertise_address: query.grpc_api_advertise_address,
+    };
+    cluster_admin(&meta_node, req.into()).await
+}
+
+/// Promote a learner to a voter.
+///
+/// request: `?node_id=3`
+#[poem::handler]
+pub async fn promote_learner(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<PromoteLearnerRequest>,
+) -> poem::Result<impl IntoResponse> {
+    cluster_admin(&meta_node, query.0.into()).await
+}
+
+/// Demote a voter to a learner.
+///
+/// request: `?node_id=3`
+#[poem::handler]
+pub async fn demote_voter(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<DemoteVoterRequest>,
+) -> poem::Result<impl IntoResponse> {
+    cluster_admin(&meta_node, query.0.into()).await
+}
+
+/// Remove a voter or a learner from the cluster.
+///
+/// request: `?node_id=3`
+#[poem::handler]
+pub async fn remove_node(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<RemoveNodeRequest>,
+) -> poem::Result<impl IntoResponse> {
+    cluster_admin(&meta_node, query.0.into()).await
+}
+
+/// Make a voter the leader of the cluster.
+///
+/// request: `?to=3`
+#[poem::handler]
+pub async fn transfer_leader(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<TransferLeaderRequest>,
+) -> poem::Result<impl IntoResponse> {
+    cluster_admin(&meta_node, query.0.into()).await
+}
+
+async fn cluster_admin(
+    meta_node: &Arc<MetaNode>,
+    req: ClusterAdminRequest,
+) -> poem::Result<Json<()>> {
+    meta_node.cluster_admin(req.clone()).await.map_err(|e| {
+        poem::Error::from_string(
+            format!("failed to {:?}: {}", req, e),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })?;
+    Ok(Json(()))
+}
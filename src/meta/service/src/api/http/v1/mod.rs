@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod cluster_admin;
 pub mod cluster_state;
 pub mod config;
 pub mod ctrl;
@@ -78,6 +78,26 @@ impl HttpService {
                 "/v1/cluster/status",
                 get(super::http::v1::cluster_state::status_handler),
             )
+            .at(
+                "/v1/cluster/add_learner",
+                get(super::http::v1::cluster_admin::add_learner),
+            )
+            .at(
+                "/v1/cluster/promote_learner",
+                get(super::http::v1::cluster_admin::promote_learner),
+            )
+            .at(
+                "/v1/cluster/demote_voter",
+                get(super::http::v1::cluster_admin::demote_voter),
+            )
+            .at(
+                "/v1/cluster/remove_node",
+                get(super::http::v1::cluster_admin::remove_node),
+            )
+            .at(
+                "/v1/cluster/transfer_leader",
+                get(super::http::v1::cluster_admin::transfer_leader),
+            )
             .at(
                 "/v1/metrics",
                 get(super::http::v1::metrics::metrics_handler),
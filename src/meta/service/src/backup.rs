@@ -0,0 +1,302 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Backup the meta-service online and restore it to a point in time.
+//!
+//! A backup is a list of json lines in the same form as [`export`](crate::export):
+//! `[tree_name, {key_space: {key, value}}]`. It contains:
+//! - the data header;
+//! - the key values of a state machine snapshot;
+//! - the raft logs that are applied after the snapshot, in the order of log index.
+//!
+//! The state of any log between the snapshot and the last log can be rebuilt from a backup,
+//! by loading the snapshot and applying the logs up to it.
+//!
+//! A backup file is named after its [`BackupMeta`], so that a backup to restore
+//! can be chosen by [`choose_backup`] without reading the backups.
+
+use std::fmt;
+
+use anyhow::anyhow;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::ondisk::DATA_VERSION;
+use common_meta_raft_store::ondisk::TREE_HEADER;
+use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_raft_store::state_machine::StateMachineMetaKey;
+use common_meta_raft_store::state_machine::StateMachineMetaValue;
+use common_meta_sled_store::get_sled_db;
+use common_meta_types::Entry;
+use common_meta_types::EntryPayload;
+use common_meta_types::LogId;
+use tracing::info;
+
+/// The point in time to restore a backup to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestoreTarget {
+    /// Apply all the logs in the backup.
+    Latest,
+
+    /// Apply the logs up to and including this log index.
+    LogIndex(u64),
+
+    /// Apply the logs proposed no later than this time, in milliseconds since the epoch.
+    TimeMs(u64),
+}
+
+impl RestoreTarget {
+    /// Whether the log entry happens no later than the target.
+    fn includes(&self, entry: &Entry) -> bool {
+        match self {
+            RestoreTarget::Latest => true,
+            RestoreTarget::LogIndex(index) => entry.log_id.index <= *index,
+            RestoreTarget::TimeMs(time_ms) => match &entry.payload {
+                EntryPayload::Normal(log_entry) => log_entry
+                    .time_ms
+                    .map_or(true, |proposed| proposed <= *time_ms),
+                // Blank and membership logs do not carry a time.
+                _ => true,
+            },
+        }
+    }
+}
+
+impl fmt::Display for RestoreTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RestoreTarget::Latest => write!(f, "latest"),
+            RestoreTarget::LogIndex(index) => write!(f, "log-index: {}", index),
+            RestoreTarget::TimeMs(time_ms) => write!(f, "time-ms: {}", time_ms),
+        }
+    }
+}
+
+const BACKUP_FILE_PREFIX: &str = "meta-backup-";
+
+/// What a backup contains, which is encoded in the file name of the backup:
+/// `meta-backup-<time_ms>-<snapshot_time_ms>-<snapshot_index>-<last_index>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BackupMeta {
+    /// The time the backup is taken, in milliseconds since the epoch.
+    pub time_ms: u64,
+
+    /// The latest time the snapshot can be at, in milliseconds since the epoch.
+    ///
+    /// The time of a snapshot is not recorded, but it is no later than any log after it,
+    /// or the time the backup is taken if there is no log after it.
+    pub snapshot_time_ms: u64,
+
+    /// The last log index in the snapshot.
+    pub snapshot_index: u64,
+
+    /// The last log index in the backup.
+    pub last_index: u64,
+}
+
+impl BackupMeta {
+    pub fn new(time_ms: u64) -> Self {
+        BackupMeta {
+            time_ms,
+            snapshot_time_ms: time_ms,
+            snapshot_index: 0,
+            last_index: 0,
+        }
+    }
+
+    /// Update the meta with an entry in the backup.
+    pub fn add_entry(&mut self, entry: &RaftStoreEntry) {
+        match entry {
+            RaftStoreEntry::StateMachineMeta {
+                key: StateMachineMetaKey::LastApplied,
+                value: StateMachineMetaValue::LogId(log_id),
+            } => {
+                self.snapshot_index = log_id.index;
+                self.last_index = self.last_index.max(log_id.index);
+            }
+            RaftStoreEntry::Logs { key, value } => {
+                self.last_index = self.last_index.max(*key);
+                if let EntryPayload::Normal(log_entry) = &value.payload {
+                    if let Some(time_ms) = log_entry.time_ms {
+                        self.snapshot_time_ms = self.snapshot_time_ms.min(time_ms);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn file_name(&self) -> String {
+        format!(
+            "{}{}-{}-{}-{}",
+            BACKUP_FILE_PREFIX,
+            self.time_ms,
+            self.snapshot_time_ms,
+            self.snapshot_index,
+            self.last_index
+        )
+    }
+
+    pub fn parse_file_name(name: &str) -> Option<Self> {
+        let parts = name
+            .strip_prefix(BACKUP_FILE_PREFIX)?
+            .split('-')
+            .map(|x| x.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        if let [time_ms, snapshot_time_ms, snapshot_index, last_index] = parts[..] {
+            Some(BackupMeta {
+                time_ms,
+                snapshot_time_ms,
+                snapshot_index,
+                last_index,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Whether the snapshot is at or before the target, i.e., the backup can be restored to it.
+    pub fn snapshot_not_after(&self, target: RestoreTarget) -> bool {
+        match target {
+            RestoreTarget::Latest => true,
+            RestoreTarget::LogIndex(index) => self.snapshot_index <= index,
+            RestoreTarget::TimeMs(time_ms) => self.snapshot_time_ms <= time_ms,
+        }
+    }
+
+    /// Whether all the logs up to the target are in the backup.
+    pub fn covers(&self, target: RestoreTarget) -> bool {
+        match target {
+            RestoreTarget::Latest => true,
+            RestoreTarget::LogIndex(index) => self.last_index >= index,
+            RestoreTarget::TimeMs(time_ms) => self.time_ms >= time_ms,
+        }
+    }
+}
+
+/// Choose a backup to restore to the target.
+///
+/// It is the newest backup whose snapshot is at or before the target and whose logs cover it.
+/// If no backup covers the target, the newest one whose snapshot is at or before it is chosen,
+/// which can only be restored to its last log.
+pub fn choose_backup(backups: &[BackupMeta], target: RestoreTarget) -> Option<&BackupMeta> {
+    let restorable = backups.iter().filter(|b| b.snapshot_not_after(target));
+    restorable
+        .clone()
+        .filter(|b| b.covers(target))
+        .max_by_key(|b| b.time_ms)
+        .or_else(|| restorable.max_by_key(|b| b.time_ms))
+}
+
+/// What a backup is restored to.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RestoreSummary {
+    /// The last log included in the snapshot of the backup.
+    pub snapshot_log_id: Option<LogId>,
+
+    /// The last log applied to the restored state machine.
+    pub last_applied: Option<LogId>,
+
+    /// Number of logs applied after the snapshot.
+    pub applied_logs: u64,
+
+    /// Whether there are logs after the target that are not applied.
+    pub target_reached: bool,
+}
+
+/// Restore the lines of a backup into an empty state machine.
+///
+/// The data header is written to the sled db, the snapshot is loaded into `sm`,
+/// then the logs are applied in order until a log after `target`.
+/// It fails if the snapshot is already after the target.
+pub async fn restore_backup<I>(
+    sm: &StateMachine,
+    lines: I,
+    target: RestoreTarget,
+) -> anyhow::Result<RestoreSummary>
+where
+    I: IntoIterator<Item = std::io::Result<String>>,
+{
+    let mut summary = RestoreSummary::default();
+    let mut first_log = true;
+
+    for line in lines {
+        let line = line?;
+        let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+
+        match entry {
+            RaftStoreEntry::DataHeader { ref value, .. } => {
+                if !DATA_VERSION.is_compatible(value.version) {
+                    return Err(anyhow!(
+                        "invalid data version of backup: {:?}, this program version is {:?}",
+                        value.version,
+                        DATA_VERSION,
+                    ));
+                }
+
+                let header_tree = get_sled_db().open_tree(TREE_HEADER)?;
+                let (k, v) = RaftStoreEntry::serialize(&entry)?;
+                header_tree.insert(k, v)?;
+                header_tree.flush_async().await?;
+            }
+            RaftStoreEntry::Logs { value, .. } => {
+                if !target.includes(&value) {
+                    if first_log {
+                        return Err(anyhow!(
+                            "the backup starts after the target {}: the first log {} is not included",
+                            target,
+                            value.log_id
+                        ));
+                    }
+                    summary.target_reached = true;
+                    break;
+                }
+                first_log = false;
+
+                sm.apply(&value).await?;
+                summary.last_applied = Some(value.log_id);
+                summary.applied_logs += 1;
+            }
+            RaftStoreEntry::RaftStateKV { .. } | RaftStoreEntry::LogMeta { .. } => {
+                // Not part of a state machine.
+            }
+            sm_entry => {
+                if let RaftStoreEntry::StateMachineMeta {
+                    key: StateMachineMetaKey::LastApplied,
+                    value: StateMachineMetaValue::LogId(log_id),
+                } = &sm_entry
+                {
+                    if let RestoreTarget::LogIndex(index) = target {
+                        if log_id.index > index {
+                            return Err(anyhow!(
+                                "the backup starts after the target {}: the snapshot is at log {}",
+                                target,
+                                log_id
+                            ));
+                        }
+                    }
+                    summary.snapshot_log_id = Some(*log_id);
+                    summary.last_applied = Some(*log_id);
+                }
+
+                let (k, v) = RaftStoreEntry::serialize(&sm_entry)?;
+                sm.sm_tree.tree.insert(k, v)?;
+            }
+        }
+    }
+
+    sm.sm_tree.tree.flush_async().await?;
+
+    info!("restored backup to {}: {:?}", target, summary);
+    Ok(summary)
+}
@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 pub mod api;
+pub mod backup;
 pub mod configs;
 pub mod export;
 pub mod logging;
@@ -14,6 +14,7 @@
 
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -56,6 +57,62 @@ pub struct LeaveRequest {
     pub node_id: NodeId,
 }
 
+/// Add a node to the cluster as a learner.
+///
+/// A learner receives logs from the leader but does not vote.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AddLearnerRequest {
+    pub node_id: NodeId,
+    pub endpoint: Endpoint,
+    pub grpc_api_advertise_address: Option<String>,
+}
+
+/// Promote a learner to a voter.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PromoteLearnerRequest {
+    pub node_id: NodeId,
+}
+
+/// Demote a voter to a learner.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DemoteVoterRequest {
+    pub node_id: NodeId,
+}
+
+/// Remove a voter or a learner from the cluster, e.g., a node that is dead.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RemoveNodeRequest {
+    pub node_id: NodeId,
+}
+
+/// Make a voter the leader of the cluster.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TransferLeaderRequest {
+    pub to: NodeId,
+}
+
+/// Cluster admin operations that change the membership or the leadership.
+///
+/// Except `TransferLeader`, which is handled by the node to transfer to,
+/// they are handled by the leader.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, derive_more::From)]
+pub enum ClusterAdminRequest {
+    AddLearner(AddLearnerRequest),
+    PromoteLearner(PromoteLearnerRequest),
+    DemoteVoter(DemoteVoterRequest),
+    RemoveNode(RemoveNodeRequest),
+    TransferLeader(TransferLeaderRequest),
+}
+
+impl tonic::IntoRequest<RaftRequest> for ClusterAdminRequest {
+    fn into_request(self) -> tonic::Request<RaftRequest> {
+        let mes = RaftRequest {
+            data: serde_json::to_string(&self).expect("fail to serialize"),
+        };
+        tonic::Request::new(mes)
+    }
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -71,12 +128,14 @@ pub enum ForwardRequestBody {
 
     Join(JoinRequest),
     Leave(LeaveRequest),
+    ClusterAdmin(ClusterAdminRequest),
 
     Write(LogEntry),
 
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+    ListKVRange(ListKVRangeReq),
 }
 
 /// A request that is forwarded from one raft node to another
@@ -104,10 +163,12 @@ pub enum ForwardResponse {
 
     Join(()),
     Leave(()),
+    ClusterAdmin(()),
     AppliedState(AppliedState),
 
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
+    /// Reply of both `ListKV` and `ListKVRange`.
     ListKV(ListKVReply),
 }
 
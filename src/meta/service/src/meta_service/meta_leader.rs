@@ -37,11 +37,14 @@ use maplit::btreeset;
 use tracing::debug;
 use tracing::info;
 
+use crate::message::AddLearnerRequest;
+use crate::message::ClusterAdminRequest;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::RemoveNodeRequest;
 use crate::meta_service::raftmeta::MetaRaft;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
@@ -83,6 +86,10 @@ impl<'a> MetaLeader<'a> {
                 self.leave(leave_req).await?;
                 Ok(ForwardResponse::Leave(()))
             }
+            ForwardRequestBody::ClusterAdmin(admin_req) => {
+                self.cluster_admin(admin_req).await?;
+                Ok(ForwardResponse::ClusterAdmin(()))
+            }
             ForwardRequestBody::Write(entry) => {
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
@@ -112,6 +119,14 @@ impl<'a> MetaLeader<'a> {
                     .map_err(|meta_err| MetaDataReadError::new("list_kv", "", &meta_err))?;
                 Ok(ForwardResponse::ListKV(res))
             }
+            ForwardRequestBody::ListKVRange(req) => {
+                let sm = self.get_state_machine().await;
+                let res = sm
+                    .list_kv(req)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("list_kv_range", "", &meta_err))?;
+                Ok(ForwardResponse::ListKV(res))
+            }
         }
     }
 
@@ -192,6 +207,100 @@ impl<'a> MetaLeader<'a> {
         Ok(())
     }
 
+    /// Change the membership of the cluster.
+    ///
+    /// `TransferLeader` is handled by the target node thus it never reaches here.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn cluster_admin(&self, req: ClusterAdminRequest) -> Result<(), MetaOperationError> {
+        match req {
+            ClusterAdminRequest::AddLearner(r) => self.add_learner(r).await,
+            ClusterAdminRequest::PromoteLearner(r) => {
+                self.raft
+                    .change_membership(ChangeMembers::AddVoterIds(btreeset! {r.node_id}), false)
+                    .await?;
+                Ok(())
+            }
+            ClusterAdminRequest::DemoteVoter(r) => {
+                // Retain the removed voter as a learner.
+                self.raft
+                    .change_membership(ChangeMembers::RemoveVoters(btreeset! {r.node_id}), true)
+                    .await?;
+                Ok(())
+            }
+            ClusterAdminRequest::RemoveNode(r) => self.remove_node(r).await,
+            ClusterAdminRequest::TransferLeader(_) => {
+                unreachable!("TransferLeader is handled by MetaNode::transfer_leader()")
+            }
+        }
+    }
+
+    /// Add a node to the cluster as a learner and start replicating logs to it.
+    ///
+    /// If the node is already in the cluster, its node info is left untouched.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn add_learner(&self, req: AddLearnerRequest) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+
+        let ent = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::AddNode {
+                node_id,
+                node: Node::new(node_id, req.endpoint)
+                    .with_grpc_advertise_address(req.grpc_api_advertise_address),
+                overriding: false,
+            },
+        };
+        self.write(ent).await?;
+
+        self.raft
+            .change_membership(
+                ChangeMembers::AddNodes(btreemap! {node_id=>MembershipNode{}}),
+                false,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a voter or a learner from the cluster, no matter it is alive or not.
+    ///
+    /// - Remove the node from membership.
+    /// - Remove the node info.
+    ///
+    /// Removing the last voter is refused by raft.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn remove_node(&self, req: RemoveNodeRequest) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+
+        let (is_voter, is_learner) = {
+            let metrics = self.raft.metrics().borrow().clone();
+            let membership = metrics.membership_config.membership();
+            (
+                membership.voter_ids().any(|id| id == node_id),
+                membership.learner_ids().any(|id| id == node_id),
+            )
+        };
+
+        if is_voter {
+            self.raft
+                .change_membership(ChangeMembers::RemoveVoters(btreeset! {node_id}), false)
+                .await?;
+        } else if is_learner {
+            self.raft
+                .change_membership(ChangeMembers::RemoveNodes(btreeset! {node_id}), false)
+                .await?;
+        }
+
+        let ent = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::RemoveNode { node_id },
+        };
+        self.write(ent).await?;
+
+        Ok(())
+    }
+
     /// Write a log through local raft node and return the states before and after applying the log.
     ///
     /// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
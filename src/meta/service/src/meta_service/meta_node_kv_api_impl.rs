@@ -16,6 +16,7 @@ use async_trait::async_trait;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
@@ -92,6 +93,13 @@ impl kvapi::KVApi for MetaNode {
         Ok(res)
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn list_kv(&self, req: ListKVRangeReq) -> Result<ListKVReply, Self::Error> {
+        let res = self.consistent_read(req).await?;
+
+        Ok(res)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, txn))]
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         info!("MetaNode::transaction(): {}", txn);
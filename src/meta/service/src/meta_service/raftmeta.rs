@@ -54,6 +54,8 @@ use common_meta_types::LogEntry;
 use common_meta_types::LogId;
 use common_meta_types::MembershipNode;
 use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
+use common_meta_types::MetaDataReadError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaManagementError;
 use common_meta_types::MetaNetworkError;
@@ -77,11 +79,13 @@ use tracing::warn;
 use tracing::Instrument;
 
 use crate::configs::Config as MetaConfig;
+use crate::message::ClusterAdminRequest;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::TransferLeaderRequest;
 use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::RaftServiceImpl;
@@ -995,6 +999,13 @@ impl MetaNode {
     ) -> Result<ForwardResponse, MetaAPIError> {
         debug!("handle_forwardable_request: {:?}", req);
 
+        // Transferring leader is handled by the target node instead of the leader.
+        if let ForwardRequestBody::ClusterAdmin(ClusterAdminRequest::TransferLeader(r)) = &req.body
+        {
+            self.transfer_leader(r.clone()).await?;
+            return Ok(ForwardResponse::ClusterAdmin(()));
+        }
+
         let forward = req.forward_to_leader;
 
         let assume_leader_res = self.assume_leader().await;
@@ -1089,6 +1100,73 @@ impl MetaNode {
         Ok(resp)
     }
 
+    /// Change the membership or the leadership of the cluster.
+    ///
+    /// Membership changes are forwarded to the leader,
+    /// while `TransferLeader` is sent to the node to transfer to.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn cluster_admin(&self, req: ClusterAdminRequest) -> Result<(), MetaAPIError> {
+        self.handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body: ForwardRequestBody::ClusterAdmin(req),
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Let node `req.to` become the leader.
+    ///
+    /// If `self` is not the target, the request is sent to the target.
+    /// The target starts an election with a higher term and waits until it becomes the leader.
+    /// The target must be a voter, and it won't be elected if it has not yet caught up the logs.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn transfer_leader(&self, req: TransferLeaderRequest) -> Result<(), MetaAPIError> {
+        if req.to != self.sto.id {
+            let to = req.to;
+            self.forward_to(&to, ForwardRequest {
+                forward_to_leader: 0,
+                body: ForwardRequestBody::ClusterAdmin(req.into()),
+            })
+            .await?;
+            return Ok(());
+        }
+
+        let metrics = self.raft.metrics().borrow().clone();
+        if metrics.current_leader == Some(self.sto.id) {
+            return Ok(());
+        }
+
+        let is_voter = metrics
+            .membership_config
+            .membership()
+            .voter_ids()
+            .any(|id| id == self.sto.id);
+
+        if !is_voter {
+            let e = AnyError::error(format!("node {} is not a voter", self.sto.id));
+            let read_err = MetaDataReadError::new("transfer_leader", "", &e);
+            return Err(MetaDataError::ReadError(read_err).into());
+        }
+
+        info!("trigger election to transfer leader to {}", self.sto.id);
+        self.raft
+            .trigger_elect()
+            .await
+            .map_err(MetaDataError::WriteError)?;
+
+        self.raft
+            .wait(Some(Duration::from_millis(10_000)))
+            .current_leader(self.sto.id, "transfer leader")
+            .await
+            .map_err(|e| {
+                let read_err = MetaDataReadError::new("transfer_leader", "wait to be leader", &e);
+                MetaDataError::ReadError(read_err)
+            })?;
+
+        Ok(())
+    }
+
     /// Submit a write request to the known leader. Returns the response after applying the request.
     #[tracing::instrument(level = "debug", skip(self, req))]
     pub async fn write(&self, req: LogEntry) -> Result<AppliedState, MetaAPIError> {
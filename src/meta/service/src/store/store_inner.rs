@@ -22,6 +22,7 @@ use common_base::base::tokio;
 use common_base::base::tokio::sync::RwLock;
 use common_base::base::tokio::sync::RwLockWriteGuard;
 use common_meta_raft_store::config::RaftConfig;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
 use common_meta_raft_store::log::RaftLog;
 use common_meta_raft_store::ondisk::TREE_HEADER;
 use common_meta_raft_store::state::RaftState;
@@ -308,23 +309,8 @@ impl StoreInner {
 
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn export(&self) -> Result<Vec<String>, std::io::Error> {
-        let mut res = vec![];
-
         // Export data header first
-        {
-            let header_tree = SledTree::open(&self.db, TREE_HEADER, false)
-                .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
-
-            let header_kvs = header_tree
-                .export()
-                .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
-
-            for kv in header_kvs.iter() {
-                let line = vec_kv_to_json(TREE_HEADER, kv)
-                    .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
-                res.push(line);
-            }
-        }
+        let mut res = self.export_header()?;
 
         // TODO(1): raft_state and log should be exported in the same transaction.
         //          The IO of saving vote and log operation must be sequentially done.
@@ -372,6 +358,108 @@ impl StoreInner {
         Ok(res)
     }
 
+    fn export_header(&self) -> Result<Vec<String>, std::io::Error> {
+        let mut res = vec![];
+
+        let header_tree = SledTree::open(&self.db, TREE_HEADER, false)
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+
+        let header_kvs = header_tree
+            .export()
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+
+        for kv in header_kvs.iter() {
+            let line = vec_kv_to_json(TREE_HEADER, kv)
+                .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+            res.push(line);
+        }
+
+        Ok(res)
+    }
+
+    /// Export a consistent backup: a state machine snapshot and the logs applied after it.
+    ///
+    /// Unlike `export()`, the state machine and the logs are consistent with each other,
+    /// the state at any log between them can be rebuilt with [`restore_backup`].
+    ///
+    /// The current snapshot is used if there is one, so that the backup covers as many logs as possible.
+    /// Only the applied logs are included, because an applied log is committed and never changes.
+    ///
+    /// [`restore_backup`]: crate::backup::restore_backup
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn backup(&self) -> Result<Vec<String>, std::io::Error> {
+        let invalid_data = |e| std::io::Error::new(ErrorKind::InvalidData, e);
+
+        let mut res = self.export_header()?;
+
+        let sm_tree_name = self.state_machine.read().await.sm_tree.name.clone();
+
+        let current = self.current_snapshot.read().await.clone();
+        let (snap, snapshot_log_id) = match current {
+            Some(snapshot) => {
+                let snap: SerializableSnapshot = serde_json::from_slice(&snapshot.data)
+                    .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+                (snap, snapshot.meta.last_log_id)
+            }
+            None => {
+                // An exclusive lock is required, because sled does not provide snapshot isolation.
+                let sm = self.state_machine.write().await;
+                let (snap, last_applied, _, _) =
+                    tokio::task::block_in_place(|| sm.build_snapshot()).map_err(invalid_data)?;
+                (snap, last_applied)
+            }
+        };
+
+        for kv in snap.kvs.iter() {
+            let line = vec_kv_to_json(&sm_tree_name, kv).map_err(invalid_data)?;
+            res.push(line);
+        }
+
+        let last_applied = self
+            .state_machine
+            .read()
+            .await
+            .get_last_applied()
+            .map_err(invalid_data)?;
+
+        let start = snapshot_log_id.map_or(0, |log_id| log_id.index + 1);
+        let end = last_applied.map_or(0, |log_id| log_id.index + 1);
+
+        let logs = self.log.range_values(start..end).map_err(invalid_data)?;
+
+        // Logs after the snapshot may be purged if a newer snapshot is built during the backup.
+        let contiguous = logs
+            .iter()
+            .enumerate()
+            .all(|(i, entry)| entry.log_id.index == start + i as u64);
+        if !contiguous || logs.len() as u64 != end.saturating_sub(start) {
+            return Err(std::io::Error::new(
+                ErrorKind::Interrupted,
+                format!(
+                    "logs in [{}, {}) are purged during backup, retry it",
+                    start, end
+                ),
+            ));
+        }
+
+        for entry in logs {
+            let kv_entry = RaftStoreEntry::Logs {
+                key: entry.log_id.index,
+                value: entry,
+            };
+            let line = serde_json::to_string(&(&self.log.inner.name, kv_entry))
+                .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+            res.push(line);
+        }
+
+        info!(
+            "backup: snapshot at {:?}, logs in [{}, {})",
+            snapshot_log_id, start, end
+        );
+
+        Ok(res)
+    }
+
     pub async fn get_node(&self, node_id: &NodeId) -> Result<Option<Node>, MetaError> {
         let sm = self.state_machine.read().await;
 
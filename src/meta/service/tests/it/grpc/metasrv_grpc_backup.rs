@@ -0,0 +1,384 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_meta_client::ClientHandle;
+use common_meta_client::MetaGrpcClient;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_types::protobuf::Empty;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use databend_meta::backup::choose_backup;
+use databend_meta::backup::restore_backup;
+use databend_meta::backup::BackupMeta;
+use databend_meta::backup::RestoreTarget;
+use databend_meta::init_meta_ut;
+use pretty_assertions::assert_eq;
+use tokio_stream::StreamExt;
+use tracing::info;
+
+use crate::tests::service::MetaSrvTestContext;
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_backup_with_writes_in_flight() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Keep writing keys one by one while taking a backup.
+    // - The logs in the backup are contiguous after the snapshot.
+    // - Restore the backup: the restored keys are the first n written keys.
+    // - Restore the backup to a log index: the keys written after it are not restored.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = MetaGrpcClient::try_create(
+        vec![addr],
+        "root",
+        "xxx",
+        None,
+        Some(Duration::from_secs(10)),
+        Duration::from_secs(10),
+        None,
+    )?;
+
+    info!("--- upsert initial kv");
+    {
+        for k in ["foo", "bar", "wow"] {
+            client
+                .upsert_kv(UpsertKVReq::new(
+                    k,
+                    MatchSeq::GE(0),
+                    Operation::Update(k.as_bytes().to_vec()),
+                    None,
+                ))
+                .await?;
+        }
+    }
+
+    info!("--- write keys in background");
+    let stop = Arc::new(AtomicBool::new(false));
+    let writer = {
+        let client = client.clone();
+        let stop = stop.clone();
+        tokio::spawn(async move {
+            let mut i = 0;
+            while !stop.load(Ordering::Relaxed) {
+                let k = format!("k-{:05}", i);
+                client
+                    .upsert_kv(UpsertKVReq::new(
+                        &k,
+                        MatchSeq::GE(0),
+                        Operation::Update(k.as_bytes().to_vec()),
+                        None,
+                    ))
+                    .await?;
+                i += 1;
+            }
+            Ok::<u64, anyhow::Error>(i)
+        })
+    };
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    info!("--- backup");
+    let lines = {
+        let mut grpc_client = client.make_client().await?;
+        let backup = grpc_client.backup(tonic::Request::new(Empty {})).await?;
+        let mut stream = backup.into_inner();
+
+        let mut lines = vec![];
+        while let Some(chunk_res) = stream.next().await {
+            let chunk = chunk_res?;
+            lines.extend_from_slice(&chunk.data);
+        }
+        lines
+    };
+
+    stop.store(true, Ordering::Relaxed);
+    let written = writer.await??;
+    info!("written {} keys", written);
+
+    info!("--- check logs in backup are contiguous");
+    let log_indexes = {
+        let mut indexes = vec![];
+        for line in lines.iter() {
+            let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+            if let RaftStoreEntry::Logs { key, .. } = entry {
+                indexes.push(key);
+            }
+        }
+        indexes
+    };
+    for w in log_indexes.windows(2) {
+        assert_eq!(w[0] + 1, w[1]);
+    }
+
+    info!("--- restore all the backup");
+    let n = {
+        let tc = MetaSrvTestContext::new(1);
+        let sm = StateMachine::open(&tc.config.raft_config, 0).await?;
+
+        let summary = restore_backup(
+            &sm,
+            lines.iter().map(|x| Ok(x.clone())),
+            RestoreTarget::Latest,
+        )
+        .await?;
+
+        assert!(!summary.target_reached);
+        assert_eq!(
+            summary.applied_logs,
+            log_indexes.len() as u64,
+            "all logs are applied"
+        );
+        if let Some(last) = log_indexes.last() {
+            assert_eq!(Some(*last), summary.last_applied.map(|x| x.index));
+        }
+
+        for k in ["foo", "bar", "wow"] {
+            let got = sm.get_kv(k).await?;
+            assert_eq!(Some(k.as_bytes().to_vec()), got.map(|x| x.data));
+        }
+
+        assert_restored_prefix(&sm, written).await?
+    };
+    info!("restored {} keys", n);
+    assert!(n > 0, "some keys are written before backup");
+
+    info!("--- restore to a log index");
+    if log_indexes.len() > 1 {
+        let tc = MetaSrvTestContext::new(2);
+        let sm = StateMachine::open(&tc.config.raft_config, 0).await?;
+
+        let target_index = log_indexes[log_indexes.len() - 2];
+        let summary = restore_backup(
+            &sm,
+            lines.iter().map(|x| Ok(x.clone())),
+            RestoreTarget::LogIndex(target_index),
+        )
+        .await?;
+
+        assert!(summary.target_reached);
+        assert_eq!(Some(target_index), summary.last_applied.map(|x| x.index));
+
+        let m = assert_restored_prefix(&sm, written).await?;
+        assert_eq!(n - 1, m, "the last written key is not restored");
+    }
+
+    Ok(())
+}
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_restore_backup_to_time() -> anyhow::Result<()> {
+    // - Write keys `a-*` and build a snapshot.
+    // - Write keys `b-*` and `c-*`, take a time between them.
+    // - Restore the backup to the time: `a-*` and `b-*` are restored, `c-*` are not.
+    // - A time before the snapshot can not be restored to.
+
+    let (tc, addr) = crate::tests::start_metasrv().await?;
+    let meta_node = tc.grpc_srv.as_ref().unwrap().get_meta_node();
+
+    let client = MetaGrpcClient::try_create(
+        vec![addr],
+        "root",
+        "xxx",
+        None,
+        Some(Duration::from_secs(10)),
+        Duration::from_secs(10),
+        None,
+    )?;
+
+    info!("--- write a-* and build a snapshot");
+    upsert_keys(&client, &["a-0", "a-1"]).await?;
+    meta_node.raft.trigger_snapshot().await?;
+    meta_node
+        .raft
+        .wait(Some(Duration::from_secs(10)))
+        .metrics(|x| x.snapshot.is_some(), "snapshot is built")
+        .await?;
+
+    info!("--- write b-* and c-* with a time between them");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    upsert_keys(&client, &["b-0", "b-1"]).await?;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let time_ms = SeqV::<()>::now_ms();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    upsert_keys(&client, &["c-0", "c-1"]).await?;
+
+    info!("--- backup");
+    let lines = {
+        let mut grpc_client = client.make_client().await?;
+        let backup = grpc_client.backup(tonic::Request::new(Empty {})).await?;
+        let mut stream = backup.into_inner();
+
+        let mut lines = vec![];
+        while let Some(chunk_res) = stream.next().await {
+            let chunk = chunk_res?;
+            lines.extend_from_slice(&chunk.data);
+        }
+        lines
+    };
+
+    let mut meta = BackupMeta::new(SeqV::<()>::now_ms());
+    for line in lines.iter() {
+        let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+        meta.add_entry(&entry);
+    }
+    info!("backup meta: {:?}", meta);
+
+    let target = RestoreTarget::TimeMs(time_ms);
+    assert!(meta.snapshot_not_after(target));
+    assert!(meta.covers(target));
+    assert_eq!(Some(&meta), choose_backup(&[meta], target));
+
+    info!("--- restore to the time");
+    {
+        let tc = MetaSrvTestContext::new(1);
+        let sm = StateMachine::open(&tc.config.raft_config, 0).await?;
+
+        let summary = restore_backup(&sm, lines.iter().map(|x| Ok(x.clone())), target).await?;
+        assert!(summary.target_reached);
+        assert!(summary.snapshot_log_id.is_some());
+
+        for k in ["a-0", "a-1", "b-0", "b-1"] {
+            let got = sm.get_kv(k).await?;
+            assert_eq!(
+                Some(k.as_bytes().to_vec()),
+                got.map(|x| x.data),
+                "{} is restored",
+                k
+            );
+        }
+        for k in ["c-0", "c-1"] {
+            let got = sm.get_kv(k).await?;
+            assert!(got.is_none(), "{} is written after the time", k);
+        }
+    }
+
+    info!("--- a time before the snapshot is rejected");
+    {
+        let target = RestoreTarget::TimeMs(meta.snapshot_time_ms - 1);
+        assert!(!meta.snapshot_not_after(target));
+        assert_eq!(None, choose_backup(&[meta], target));
+
+        let tc = MetaSrvTestContext::new(2);
+        let sm = StateMachine::open(&tc.config.raft_config, 0).await?;
+        let res = restore_backup(&sm, lines.iter().map(|x| Ok(x.clone())), target).await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_choose_backup() -> anyhow::Result<()> {
+    let b1 = BackupMeta {
+        time_ms: 1000,
+        snapshot_time_ms: 500,
+        snapshot_index: 10,
+        last_index: 20,
+    };
+    let b2 = BackupMeta {
+        time_ms: 2000,
+        snapshot_time_ms: 1500,
+        snapshot_index: 18,
+        last_index: 30,
+    };
+    let backups = [b1, b2];
+
+    assert_eq!(Some(b2), BackupMeta::parse_file_name(&b2.file_name()));
+    assert_eq!(None, BackupMeta::parse_file_name("meta-backup-1000-10-20"));
+
+    assert_eq!(Some(&b2), choose_backup(&backups, RestoreTarget::Latest));
+
+    // The snapshot of b2 is after the time.
+    assert_eq!(
+        Some(&b1),
+        choose_backup(&backups, RestoreTarget::TimeMs(800))
+    );
+    // Both cover the time, the newest is chosen.
+    assert_eq!(
+        Some(&b2),
+        choose_backup(&backups, RestoreTarget::TimeMs(1800))
+    );
+    // No backup covers the time, b1 is restored to its last log.
+    assert_eq!(
+        Some(&b1),
+        choose_backup(&backups, RestoreTarget::TimeMs(1200))
+    );
+    assert_eq!(
+        Some(&b2),
+        choose_backup(&backups, RestoreTarget::TimeMs(3000))
+    );
+    // All the snapshots are after the time.
+    assert_eq!(None, choose_backup(&backups, RestoreTarget::TimeMs(400)));
+
+    assert_eq!(
+        Some(&b1),
+        choose_backup(&backups, RestoreTarget::LogIndex(15))
+    );
+    assert_eq!(
+        Some(&b2),
+        choose_backup(&backups, RestoreTarget::LogIndex(19))
+    );
+    assert_eq!(
+        Some(&b2),
+        choose_backup(&backups, RestoreTarget::LogIndex(25))
+    );
+    assert_eq!(None, choose_backup(&backups, RestoreTarget::LogIndex(5)));
+
+    Ok(())
+}
+
+async fn upsert_keys(client: &Arc<ClientHandle>, keys: &[&str]) -> anyhow::Result<()> {
+    for k in keys {
+        client
+            .upsert_kv(UpsertKVReq::new(
+                k,
+                MatchSeq::GE(0),
+                Operation::Update(k.as_bytes().to_vec()),
+                None,
+            ))
+            .await?;
+    }
+    Ok(())
+}
+
+/// Assert the restored keys `k-*` are the first several keys written, returns the number of them.
+async fn assert_restored_prefix(sm: &StateMachine, written: u64) -> anyhow::Result<u64> {
+    let mut n = 0;
+    for i in 0..written {
+        let k = format!("k-{:05}", i);
+        let got = sm.get_kv(&k).await?;
+        if got.is_none() {
+            break;
+        }
+        n += 1;
+    }
+
+    for i in n..written {
+        let k = format!("k-{:05}", i);
+        let got = sm.get_kv(&k).await?;
+        assert!(got.is_none(), "k-{:05} is not restored, but {} is", n, k);
+    }
+
+    Ok(n)
+}
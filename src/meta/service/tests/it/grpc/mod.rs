@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod metasrv_grpc_api;
+mod metasrv_grpc_backup;
 mod metasrv_grpc_export;
 pub mod metasrv_grpc_get_client_info;
 pub mod metasrv_grpc_handshake;
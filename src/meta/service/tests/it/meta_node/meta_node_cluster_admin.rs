@@ -0,0 +1,204 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_meta_types::NodeId;
+use databend_meta::init_meta_ut;
+use databend_meta::message::AddLearnerRequest;
+use databend_meta::message::DemoteVoterRequest;
+use databend_meta::message::PromoteLearnerRequest;
+use databend_meta::message::RemoveNodeRequest;
+use databend_meta::message::TransferLeaderRequest;
+use databend_meta::meta_service::MetaNode;
+use maplit::btreeset;
+use pretty_assertions::assert_eq;
+use tracing::info;
+
+use crate::tests::meta_node::start_meta_node_cluster;
+use crate::tests::meta_node::timeout;
+use crate::tests::service::MetaSrvTestContext;
+
+#[async_entry::test(worker_threads = 5, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_meta_node_cluster_admin_membership() -> anyhow::Result<()> {
+    // - Bring up a cluster of voter 0,1,2 and learner 3.
+    // - Promote learner 3 by sending the request to a follower.
+    // - Demote voter 3.
+    // - Remove learner 3.
+    // - Stop voter 1 and remove it.
+    // - Add a new learner 4.
+
+    let (_log_index, tcs) = start_meta_node_cluster(btreeset![0, 1, 2], btreeset![3]).await?;
+    let all = test_context_nodes(&tcs);
+
+    let leader = all[0].clone();
+    let follower = all[2].clone();
+
+    info!("--- promote learner 3");
+    {
+        follower
+            .cluster_admin(PromoteLearnerRequest { node_id: 3 }.into())
+            .await?;
+
+        leader
+            .raft
+            .wait(timeout())
+            .members(btreeset! {0,1,2,3}, "node-3 is promoted")
+            .await?;
+        assert_eq!(btreeset! {}, learner_ids(&leader));
+    }
+
+    info!("--- demote voter 3");
+    {
+        follower
+            .cluster_admin(DemoteVoterRequest { node_id: 3 }.into())
+            .await?;
+
+        leader
+            .raft
+            .wait(timeout())
+            .members(btreeset! {0,1,2}, "node-3 is demoted")
+            .await?;
+        assert_eq!(btreeset! {3}, learner_ids(&leader));
+    }
+
+    info!("--- remove learner 3");
+    {
+        follower
+            .cluster_admin(RemoveNodeRequest { node_id: 3 }.into())
+            .await?;
+
+        assert_eq!(btreeset! {}, learner_ids(&leader));
+
+        let nodes = leader.get_nodes().await?;
+        assert_eq!(
+            vec!["0", "1", "2"],
+            nodes.iter().map(|x| x.name.clone()).collect::<Vec<_>>()
+        );
+    }
+
+    info!("--- stop and remove voter 1");
+    {
+        all[1].stop().await?;
+
+        follower
+            .cluster_admin(RemoveNodeRequest { node_id: 1 }.into())
+            .await?;
+
+        leader
+            .raft
+            .wait(timeout())
+            .members(btreeset! {0,2}, "node-1 is removed")
+            .await?;
+
+        let nodes = leader.get_nodes().await?;
+        assert_eq!(
+            vec!["0", "2"],
+            nodes.iter().map(|x| x.name.clone()).collect::<Vec<_>>()
+        );
+    }
+
+    info!("--- add learner 4");
+    {
+        let node_id = 4;
+        let tc4 = MetaSrvTestContext::new(node_id);
+        let mn4 = MetaNode::open_create(&tc4.config.raft_config, None, Some(())).await?;
+
+        follower
+            .cluster_admin(
+                AddLearnerRequest {
+                    node_id,
+                    endpoint: tc4.config.raft_config.raft_api_addr().await?,
+                    grpc_api_advertise_address: tc4.config.grpc_api_advertise_address(),
+                }
+                .into(),
+            )
+            .await?;
+
+        assert_eq!(btreeset! {4}, learner_ids(&leader));
+
+        let leader_log_index = leader.raft.metrics().borrow().last_log_index;
+        mn4.raft
+            .wait(timeout())
+            .log(leader_log_index, "node-4 replicated logs")
+            .await?;
+
+        let nodes = leader.get_nodes().await?;
+        assert_eq!(
+            vec!["0", "2", "4"],
+            nodes.iter().map(|x| x.name.clone()).collect::<Vec<_>>()
+        );
+
+        mn4.stop().await?;
+    }
+
+    Ok(())
+}
+
+#[async_entry::test(worker_threads = 5, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_meta_node_cluster_admin_transfer_leader() -> anyhow::Result<()> {
+    // - Bring up a cluster of voter 0,1,2 and learner 3.
+    // - Transfer leader to 2 by sending the request to node 1.
+    // - Transfer leader to a learner is not allowed.
+
+    let (_log_index, tcs) = start_meta_node_cluster(btreeset![0, 1, 2], btreeset![3]).await?;
+    let all = test_context_nodes(&tcs);
+
+    info!("--- transfer leader to 2");
+    {
+        all[1]
+            .cluster_admin(TransferLeaderRequest { to: 2 }.into())
+            .await?;
+
+        for mn in all.iter() {
+            mn.raft
+                .wait(timeout())
+                .current_leader(2, format!("node-{} sees new leader", mn.sto.id))
+                .await?;
+        }
+    }
+
+    info!("--- transfer leader to the current leader is a no-op");
+    {
+        all[0]
+            .cluster_admin(TransferLeaderRequest { to: 2 }.into())
+            .await?;
+        assert_eq!(Some(2), all[2].get_leader().await?);
+    }
+
+    info!("--- transfer leader to learner 3 is not allowed");
+    {
+        let res = all[0]
+            .cluster_admin(TransferLeaderRequest { to: 3 }.into())
+            .await;
+        assert!(res.is_err());
+        assert_eq!(Some(2), all[2].get_leader().await?);
+    }
+
+    Ok(())
+}
+
+fn learner_ids(mn: &MetaNode) -> BTreeSet<NodeId> {
+    let metrics = mn.raft.metrics().borrow().clone();
+    metrics
+        .membership_config
+        .membership()
+        .learner_ids()
+        .collect()
+}
+
+fn test_context_nodes(tcs: &[MetaSrvTestContext]) -> Vec<Arc<MetaNode>> {
+    tcs.iter().map(|tc| tc.meta_node()).collect::<Vec<_>>()
+}
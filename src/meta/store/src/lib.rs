@@ -25,6 +25,7 @@ use common_meta_client::MetaGrpcClient;
 use common_meta_embedded::MetaEmbedded;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
+use common_meta_kvapi::kvapi::ListKVRangeReq;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReply;
@@ -117,6 +118,13 @@ impl kvapi::KVApi for MetaStore {
         }
     }
 
+    async fn list_kv(&self, req: ListKVRangeReq) -> Result<ListKVReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.list_kv(req).await,
+            MetaStore::R(x) => x.list_kv(req).await,
+        }
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
         match self {
             MetaStore::L(x) => x.transaction(txn).await,
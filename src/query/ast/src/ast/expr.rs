@@ -157,7 +157,8 @@ pub enum Expr {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -213,6 +214,8 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// The `?` placeholder of a prepared statement
+    Placeholder { span: Span },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -423,7 +426,11 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    TimestampTz,
+    Interval,
     String,
+    Binary,
+    Geometry,
     Array(Box<TypeName>),
     Map {
         key_type: Box<TypeName>,
@@ -455,6 +462,23 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The `OVER` clause of a window function call,
+/// with the optional `IGNORE NULLS` or `RESPECT NULLS` before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDesc {
+    /// `Some(true)` for `IGNORE NULLS`, `Some(false)` for `RESPECT NULLS`.
+    pub ignore_nulls: Option<bool>,
+    pub window: Window,
+}
+
+/// The lambda argument of a higher-order function call, like `x -> x + 1`
+/// in `array_transform(arr, x -> x + 1)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda {
+    pub params: Vec<Identifier>,
+    pub expr: Box<Expr>,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -635,7 +659,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::Placeholder { span } => *span,
         }
     }
 }
@@ -839,9 +864,21 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMP_TZ")?;
+            }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Array(ty) => {
                 write!(f, "ARRAY({})", ty)?;
             }
@@ -1021,6 +1058,19 @@ impl Display for WindowSpec {
     }
 }
 
+impl Display for Lambda {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.params.len() == 1 {
+            write!(f, "{}", self.params[0])?;
+        } else {
+            write!(f, "(")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
+        write!(f, " -> {}", self.expr)
+    }
+}
+
 impl Display for Expr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1164,6 +1214,9 @@ impl Display for Expr {
             Expr::CountAll { .. } => {
                 write!(f, "COUNT(*)")?;
             }
+            Expr::Placeholder { .. } => {
+                write!(f, "?")?;
+            }
             Expr::Tuple { exprs, .. } => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, exprs)?;
@@ -1178,6 +1231,7 @@ impl Display for Expr {
                 args,
                 params,
                 window,
+                lambda,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -1191,10 +1245,18 @@ impl Display for Expr {
                     write!(f, "DISTINCT ")?;
                 }
                 write_comma_separated_list(f, args)?;
+                if let Some(lambda) = lambda {
+                    write!(f, ", {lambda}")?;
+                }
                 write!(f, ")")?;
 
                 if let Some(window) = window {
-                    write!(f, " OVER ({window})")?;
+                    match window.ignore_nulls {
+                        Some(true) => write!(f, " IGNORE NULLS")?,
+                        Some(false) => write!(f, " RESPECT NULLS")?,
+                        None => {}
+                    }
+                    write!(f, " OVER ({})", window.window)?;
                 }
             }
             Expr::Case {
@@ -430,13 +430,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        _over: &'ast Option<Window>,
+        _over: &'ast Option<WindowDesc>,
+        lambda: &'ast Option<Lambda>,
     ) {
         let mut children = Vec::with_capacity(args.len());
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        if let Some(lambda) = lambda {
+            self.visit_expr(&lambda.expr);
+            let child = self.children.pop().unwrap();
+            let lambda_name = format!("Lambda {}", lambda);
+            let lambda_format_ctx = AstFormatContext::with_children(lambda_name, 1);
+            let lambda_node = FormatTreeNode::with_children(lambda_format_ctx, vec![child]);
+            children.push(lambda_node);
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -634,6 +643,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {
+        let name = "Placeholder".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_query(&mut self, query: &'ast Query) {
         let mut children = Vec::new();
         if let Some(with) = &query.with {
@@ -846,6 +862,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_begin(&mut self) {
+        let name = "Begin".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit(&mut self) {
+        let name = "Commit".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_abort(&mut self) {
+        let name = "Abort".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_show_process_list(&mut self) {
         let name = "ShowProcessList".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -1042,6 +1079,65 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_merge_into(&mut self, merge_into: &'ast MergeIntoStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&merge_into.catalog, &merge_into.database, &merge_into.table);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_reference(&merge_into.source);
+        children.push(self.children.pop().unwrap());
+        self.visit_expr(&merge_into.join_expr);
+        children.push(self.children.pop().unwrap());
+
+        for option in merge_into.merge_options.iter() {
+            let mut option_children = Vec::new();
+            let (selection, option_name) = match option {
+                MergeOption::Match(clause) => {
+                    match &clause.operation {
+                        MatchOperation::Update { update_list } => {
+                            for update_expr in update_list.iter() {
+                                self.visit_identifier(&update_expr.name);
+                                option_children.push(self.children.pop().unwrap());
+                                self.visit_expr(&update_expr.expr);
+                                option_children.push(self.children.pop().unwrap());
+                            }
+                        }
+                        MatchOperation::Delete => {}
+                    }
+                    let name = match &clause.operation {
+                        MatchOperation::Update { .. } => "MatchedUpdate",
+                        MatchOperation::Delete => "MatchedDelete",
+                    };
+                    (&clause.selection, name)
+                }
+                MergeOption::Unmatch(clause) => {
+                    if let Some(columns) = &clause.insert_operation.columns {
+                        for column in columns.iter() {
+                            self.visit_identifier(column);
+                            option_children.push(self.children.pop().unwrap());
+                        }
+                    }
+                    for value in clause.insert_operation.values.iter() {
+                        self.visit_expr(value);
+                        option_children.push(self.children.pop().unwrap());
+                    }
+                    (&clause.selection, "UnmatchedInsert")
+                }
+            };
+            if let Some(selection) = selection {
+                self.visit_expr(selection);
+                option_children.push(self.children.pop().unwrap());
+            }
+            let format_ctx =
+                AstFormatContext::with_children(option_name.to_string(), option_children.len());
+            children.push(FormatTreeNode::with_children(format_ctx, option_children));
+        }
+
+        let name = "MergeInto".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_show_databases(&mut self, stmt: &'ast ShowDatabasesStmt) {
         let mut children = Vec::new();
         if let Some(limit) = &stmt.limit {
@@ -1344,6 +1440,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::ModifyColumn { column } => {
+                let action_name = format!("Action Modify column {}", column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::SetMaskingPolicy { column, policy } => {
+                let action_name =
+                    format!("Action Set masking policy {} of column {}", policy, column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::UnsetMaskingPolicy { column } => {
+                let action_name = format!("Action Unset masking policy of column {}", column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::DropColumn { column } => {
                 let action_name = format!("Action Drop column {}", column);
                 let action_format_ctx = AstFormatContext::new(action_name);
@@ -1496,6 +1608,28 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
         self.visit_index_ref(&stmt.index_name);
         let index_child = self.children.pop().unwrap();
@@ -1518,6 +1652,189 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_sequences(&mut self) {
+        let name = "ShowSequences".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_sequence(&mut self, stmt: &'ast CreateSequenceStmt) {
+        let mut children = Vec::new();
+        let sequence_format_ctx =
+            AstFormatContext::new(format!("SequenceIdentifier {}", stmt.sequence));
+        children.push(FormatTreeNode::new(sequence_format_ctx));
+        if let Some(start) = stmt.start {
+            let start_format_ctx = AstFormatContext::new(format!("Start {}", start));
+            children.push(FormatTreeNode::new(start_format_ctx));
+        }
+        if let Some(increment) = stmt.increment {
+            let increment_format_ctx = AstFormatContext::new(format!("Increment {}", increment));
+            children.push(FormatTreeNode::new(increment_format_ctx));
+        }
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, stmt: &'ast DropSequenceStmt) {
+        let sequence_format_ctx =
+            AstFormatContext::new(format!("SequenceIdentifier {}", stmt.sequence));
+        let child = FormatTreeNode::new(sequence_format_ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_tasks(&mut self) {
+        let name = "ShowTasks".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_task(&mut self, stmt: &'ast CreateTaskStmt) {
+        let mut children = Vec::new();
+        let task_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.task));
+        children.push(FormatTreeNode::new(task_format_ctx));
+        if let Some(schedule) = &stmt.schedule {
+            let schedule_format_ctx = AstFormatContext::new(format!("Schedule {}", schedule));
+            children.push(FormatTreeNode::new(schedule_format_ctx));
+        }
+        for after in stmt.after.iter() {
+            let after_format_ctx = AstFormatContext::new(format!("After {}", after));
+            children.push(FormatTreeNode::new(after_format_ctx));
+        }
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+        self.visit_statement(&stmt.statement);
+        children.push(self.children.pop().unwrap());
+
+        let name = "CreateTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_alter_task(&mut self, stmt: &'ast AlterTaskStmt) {
+        let task_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.task));
+        let task_child = FormatTreeNode::new(task_format_ctx);
+        let action_format_ctx = AstFormatContext::new(format!("Action {:?}", stmt.action));
+        let action_child = FormatTreeNode::new(action_format_ctx);
+
+        let name = "AlterTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![task_child, action_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_task(&mut self, stmt: &'ast DropTaskStmt) {
+        let task_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.task));
+        let child = FormatTreeNode::new(task_format_ctx);
+
+        let name = "DropTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_execute_task(&mut self, stmt: &'ast ExecuteTaskStmt) {
+        let task_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.task));
+        let child = FormatTreeNode::new(task_format_ctx);
+
+        let name = "ExecuteTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_streams(&mut self) {
+        let name = "ShowStreams".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        let mut table_name = String::new();
+        if let Some(database) = &stmt.table_database {
+            table_name.push_str(&database.to_string());
+            table_name.push('.');
+        }
+        table_name.push_str(&stmt.table.to_string());
+        let table_format_ctx = AstFormatContext::new(format!("OnTable {}", table_name));
+        let table_child = FormatTreeNode::new(table_format_ctx);
+        let mut children = vec![stream_child, table_child];
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_masking_policy(&mut self, stmt: &'ast CreateMaskingPolicyStmt) {
+        let mut children = Vec::new();
+        let name_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(name_format_ctx));
+        for (arg, data_type) in stmt.args.iter() {
+            let arg_format_ctx = AstFormatContext::new(format!("Arg {} {}", arg, data_type));
+            children.push(FormatTreeNode::new(arg_format_ctx));
+        }
+        let return_format_ctx = AstFormatContext::new(format!("Returns {}", stmt.return_type));
+        children.push(FormatTreeNode::new(return_format_ctx));
+        self.visit_expr(&stmt.body);
+        let body_child = self.children.pop().unwrap();
+        let body_format_ctx = AstFormatContext::with_children("Body".to_string(), 1);
+        children.push(FormatTreeNode::with_children(body_format_ctx, vec![
+            body_child,
+        ]));
+        if let Some(comment) = &stmt.comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_masking_policy(&mut self, stmt: &'ast DropMaskingPolicyStmt) {
+        let policy_format_ctx = AstFormatContext::new(format!("PolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DropMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
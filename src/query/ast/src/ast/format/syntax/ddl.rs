@@ -157,6 +157,18 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::AddColumn { column } => RcDoc::line()
             .append(RcDoc::text("ADD COLUMN "))
             .append(RcDoc::text(column.to_string())),
+        AlterTableAction::ModifyColumn { column } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string())),
+        AlterTableAction::SetMaskingPolicy { column, policy } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(" SET MASKING POLICY "))
+            .append(RcDoc::text(policy.to_string())),
+        AlterTableAction::UnsetMaskingPolicy { column } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(" UNSET MASKING POLICY")),
         AlterTableAction::DropColumn { column } => RcDoc::line()
             .append(RcDoc::text("DROP COLUMN "))
             .append(RcDoc::text(column.to_string())),
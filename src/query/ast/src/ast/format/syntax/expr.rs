@@ -229,6 +229,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             name,
             args,
             params,
+            lambda,
             ..
         } => RcDoc::text(name.to_string())
             .append(if !params.is_empty() {
@@ -249,6 +250,13 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(inline_comma(args.into_iter().map(pretty_expr)))
+            .append(if let Some(lambda) = lambda {
+                RcDoc::text(",")
+                    .append(RcDoc::space())
+                    .append(RcDoc::text(lambda.to_string()))
+            } else {
+                RcDoc::nil()
+            })
             .append(RcDoc::text(")")),
         Expr::Case {
             operand,
@@ -368,5 +376,6 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::space())
             .append(pretty_expr(*date))
             .append(RcDoc::text(")")),
+        Expr::Placeholder { .. } => RcDoc::text("?"),
     }
 }
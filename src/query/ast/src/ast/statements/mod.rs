@@ -22,13 +22,18 @@ mod hint;
 mod index;
 mod insert;
 mod kill;
+mod masking_policy;
+mod merge_into;
 mod presign;
 mod replace;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
+mod task;
 mod unset;
 mod update;
 mod user;
@@ -44,13 +49,18 @@ pub use hint::*;
 pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use masking_policy::*;
+pub use merge_into::*;
 pub use presign::*;
 pub use replace::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
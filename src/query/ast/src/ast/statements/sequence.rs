@@ -0,0 +1,63 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateSequenceStmt {
+    pub if_not_exists: bool,
+    pub sequence: Identifier,
+    pub start: Option<u64>,
+    pub increment: Option<u64>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateSequenceStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE SEQUENCE")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {}", self.sequence)?;
+        if let Some(start) = self.start {
+            write!(f, " START WITH {start}")?;
+        }
+        if let Some(increment) = self.increment {
+            write!(f, " INCREMENT BY {increment}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropSequenceStmt {
+    pub if_exists: bool,
+    pub sequence: Identifier,
+}
+
+impl Display for DropSequenceStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP SEQUENCE")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {}", self.sequence)
+    }
+}
@@ -76,6 +76,7 @@ pub enum Statement {
 
     Insert(InsertStmt),
     Replace(ReplaceStmt),
+    MergeInto(MergeIntoStmt),
 
     Delete {
         hints: Option<Hint>,
@@ -85,6 +86,11 @@ pub enum Statement {
 
     Update(UpdateStmt),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
     ShowCreateCatalog(ShowCreateCatalogStmt),
@@ -124,11 +130,34 @@ pub enum Statement {
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
 
     // indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
 
+    // Sequences
+    ShowSequences,
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+
+    // Tasks
+    ShowTasks,
+    CreateTask(CreateTaskStmt),
+    AlterTask(AlterTaskStmt),
+    DropTask(DropTaskStmt),
+    ExecuteTask(ExecuteTaskStmt),
+
+    // Streams
+    ShowStreams,
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
+
+    // Masking policies
+    CreateMaskingPolicy(CreateMaskingPolicyStmt),
+    DropMaskingPolicy(DropMaskingPolicyStmt),
+
     // User
     ShowUsers,
     CreateUser(CreateUserStmt),
@@ -276,6 +305,7 @@ impl Display for Statement {
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Delete {
                 table_reference,
                 selection,
@@ -290,6 +320,9 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ROLLBACK")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -376,8 +409,23 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSequences => write!(f, "SHOW SEQUENCES")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowTasks => write!(f, "SHOW TASKS")?,
+            Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTask(stmt) => write!(f, "{stmt}")?,
+            Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowStreams => write!(f, "SHOW STREAMS")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaskingPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
@@ -285,6 +285,16 @@ pub enum AlterTableAction {
     AddColumn {
         column: ColumnDefinition,
     },
+    ModifyColumn {
+        column: ColumnDefinition,
+    },
+    SetMaskingPolicy {
+        column: Identifier,
+        policy: Identifier,
+    },
+    UnsetMaskingPolicy {
+        column: Identifier,
+    },
     DropColumn {
         column: Identifier,
     },
@@ -310,6 +320,15 @@ impl Display for AlterTableAction {
             AlterTableAction::AddColumn { column } => {
                 write!(f, "ADD COLUMN {column}")
             }
+            AlterTableAction::ModifyColumn { column } => {
+                write!(f, "MODIFY COLUMN {column}")
+            }
+            AlterTableAction::SetMaskingPolicy { column, policy } => {
+                write!(f, "MODIFY COLUMN {column} SET MASKING POLICY {policy}")
+            }
+            AlterTableAction::UnsetMaskingPolicy { column } => {
+                write!(f, "MODIFY COLUMN {column} UNSET MASKING POLICY")
+            }
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")
             }
@@ -578,11 +597,28 @@ impl Display for OptimizeTableAction {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnExpr {
+    Default(Box<Expr>),
+    Virtual(Box<Expr>),
+    Stored(Box<Expr>),
+}
+
+impl Display for ColumnExpr {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ColumnExpr::Default(expr) => write!(f, "DEFAULT {expr}"),
+            ColumnExpr::Virtual(expr) => write!(f, "AS ({expr}) VIRTUAL"),
+            ColumnExpr::Stored(expr) => write!(f, "AS ({expr}) STORED"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ColumnDefinition {
     pub name: Identifier,
     pub data_type: TypeName,
-    pub default_expr: Option<Box<Expr>>,
+    pub expr: Option<ColumnExpr>,
     pub comment: Option<String>,
 }
 
@@ -594,8 +630,8 @@ impl Display for ColumnDefinition {
             write!(f, " NOT NULL")?;
         }
 
-        if let Some(default_expr) = &self.default_expr {
-            write!(f, " DEFAULT {default_expr}")?;
+        if let Some(expr) = &self.expr {
+            write!(f, " {expr}")?;
         }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
@@ -284,8 +284,9 @@ pub enum ExprElement {
         distinct: bool,
         name: Identifier,
         args: Vec<Expr>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
         params: Vec<Literal>,
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -336,6 +337,8 @@ pub enum ExprElement {
         unit: IntervalKind,
         date: Expr,
     },
+    /// `?` placeholder of a prepared statement
+    Placeholder,
     Skip,
 }
 
@@ -484,6 +487,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             } => Expr::FunctionCall {
                 span: transform_span(elem.span.0),
                 distinct,
@@ -491,6 +495,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             },
             ExprElement::Case {
                 operand,
@@ -553,6 +558,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 unit,
                 date: Box::new(date),
             },
+            ExprElement::Placeholder => Expr::Placeholder {
+                span: transform_span(elem.span.0),
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -818,6 +826,25 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: None,
+            lambda: None,
+        },
+    );
+
+    let function_call_with_lambda = map(
+        rule! {
+            #function_name
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
+        },
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
+            distinct: false,
+            name,
+            args: vec![arg],
+            params: vec![],
+            window: None,
+            lambda: Some(Lambda {
+                params,
+                expr: Box::new(expr),
+            }),
         },
     );
 
@@ -825,14 +852,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ (( IGNORE | RESPECT ) ~ NULLS)?
             ~ (OVER ~ #window_spec_ident)
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, opt_nulls, window)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
-            window: Some(window.1),
+            window: Some(WindowDesc {
+                ignore_nulls: opt_nulls.map(|(token, _)| token.kind == IGNORE),
+                window: window.1,
+            }),
+            lambda: None,
         },
     );
 
@@ -848,6 +880,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: params.map(|x| x.1).unwrap_or_default(),
             window: None,
+            lambda: None,
         },
     );
 
@@ -902,6 +935,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     // and then will be converted back to a floating point literal if the map access
     // is not following a primary element nor a postfix element.
     let literal = map(literal, |lit| ExprElement::Literal { lit });
+    let placeholder = value(ExprElement::Placeholder, rule! { "?" });
     let array = map(
         // Array that contains a single literal item will be parsed as a bracket map access,
         // and then will be converted back to an array if the map access is not following
@@ -1016,6 +1050,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #trim_from : "`TRIM([(BOTH | LEADEING | TRAILING) ... FROM ...)`"
             | #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all : "COUNT(*)"
+            | #function_call_with_lambda : "<function>"
             | #function_call_with_window : "<function>"
             | #function_call_with_params : "<function>"
             | #function_call : "<function>"
@@ -1027,6 +1062,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #literal : "<literal>"
             | #array : "`[...]`"
             | #map_expr : "`{...}`"
+            | #placeholder : "`?`"
         ),
     )))(i)?;
 
@@ -1300,6 +1336,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         },
     );
     let ty_bitmap = value(TypeName::Bitmap, rule! { BITMAP });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     let ty_nullable = map(
         rule! { NULLABLE ~ ( "(" ~ #type_name ~ ")" ) },
         |(_, item_type)| TypeName::Nullable(Box::new(item_type.1)),
@@ -1327,9 +1364,15 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { (DATETIME | TIMESTAMP) ~ ( "(" ~ #literal_u64 ~ ")" )? },
         |(_, _)| TypeName::Timestamp,
     );
+    let ty_timestamp_tz = value(TypeName::TimestampTz, rule! { TIMESTAMP_TZ });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
     let ty_string = value(
         TypeName::String,
-        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+    );
+    let ty_binary = value(
+        TypeName::Binary,
+        rule! { ( BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     map(
@@ -1350,6 +1393,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_array
             | #ty_map
             | #ty_bitmap
+            | #ty_geometry
             | #ty_tuple : "TUPLE(<type>, ...)"
             | #ty_named_tuple : "TUPLE(<name> <type>, ...)"
             ) ~ NULL? : "type name"
@@ -1357,7 +1401,10 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             rule! {
             ( #ty_date
             | #ty_datetime
+            | #ty_timestamp_tz
+            | #ty_interval
             | #ty_string
+            | #ty_binary
             | #ty_variant
             | #ty_nullable
             ) ~ NULL? : "type name" },
@@ -1471,3 +1518,18 @@ pub fn map_element(i: Input) -> IResult<(Expr, Expr)> {
         |(key, _, value)| (key, value),
     )(i)
 }
+
+pub fn lambda_params(i: Input) -> IResult<Vec<Identifier>> {
+    let single_param = map(rule! { #ident }, |param| vec![param]);
+    let multi_params = map(
+        rule! {
+            "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, params, _)| params,
+    );
+
+    rule!(
+        #single_param
+        | #multi_params
+    )(i)
+}
@@ -147,6 +147,36 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge = map(
+        rule! {
+            MERGE ~ #hint? ~ INTO ~ #period_separated_idents_1_to_3 ~ #table_alias?
+            ~ USING ~ #merge_source ~ ON ~ ^#expr ~ (#merge_option)+
+        },
+        |(
+            _,
+            opt_hints,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            Statement::MergeInto(MergeIntoStmt {
+                hints: opt_hints,
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                merge_options,
+            })
+        },
+    );
+
     let delete = map(
         rule! {
             DELETE ~ #hint? ~ FROM ~ #table_reference_only
@@ -201,6 +231,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let show_indexes = value(Statement::ShowIndexes, rule! { SHOW ~ INDEXES });
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let abort = value(Statement::Abort, rule! { ROLLBACK });
+
     // kill query 199;
     let kill_stmt = map(
         rule! {
@@ -671,6 +705,34 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -719,6 +781,168 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let show_sequences = value(Statement::ShowSequences, rule! { SHOW ~ SEQUENCES });
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( START ~ WITH? ~ #literal_u64 )?
+            ~ ( INCREMENT ~ BY? ~ #literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, opt_if_not_exists, sequence, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                sequence,
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, sequence)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                sequence,
+            })
+        },
+    );
+
+    let show_tasks = value(Statement::ShowTasks, rule! { SHOW ~ TASKS });
+    let create_task = map(
+        rule! {
+            CREATE ~ TASK ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( SCHEDULE ~ "=" ~ #literal_string )?
+            ~ ( AFTER ~ #comma_separated_list1(ident) )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+            ~ AS ~ #statement
+        },
+        |(_, _, opt_if_not_exists, task, opt_schedule, opt_after, opt_comment, _, statement)| {
+            Statement::CreateTask(CreateTaskStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                task,
+                schedule: opt_schedule.map(|(_, _, schedule)| schedule),
+                after: opt_after.map(|(_, after)| after).unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                statement: Box::new(statement.stmt),
+            })
+        },
+    );
+    let alter_task = map(
+        rule! {
+            ALTER ~ TASK ~ #ident ~ #alter_task_action
+        },
+        |(_, _, task, action)| Statement::AlterTask(AlterTaskStmt { task, action }),
+    );
+    let drop_task = map(
+        rule! {
+            DROP ~ TASK ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, task)| {
+            Statement::DropTask(DropTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                task,
+            })
+        },
+    );
+    let execute_task = map(
+        rule! {
+            EXECUTE ~ TASK ~ #ident
+        },
+        |(_, _, task)| Statement::ExecuteTask(ExecuteTaskStmt { task }),
+    );
+
+    let show_streams = value(Statement::ShowStreams, rule! { SHOW ~ STREAMS });
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+
+    let create_masking_policy = map(
+        rule! {
+            CREATE ~ MASKING ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(masking_policy_arg) ~ ")"
+            ~ RETURNS ~ #type_name ~ "->" ~ #expr
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            body,
+            opt_comment,
+        )| {
+            Statement::CreateMaskingPolicy(CreateMaskingPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                args,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_masking_policy = map(
+        rule! {
+            DROP ~ MASKING ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropMaskingPolicy(DropMaskingPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
     let show_users = value(Statement::ShowUsers, rule! { SHOW ~ USERS });
     let create_user = map(
         rule! {
@@ -1200,10 +1424,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge : "`MERGE INTO <target_table> USING <source> ON <join_expr> { matchedClause | notMatchedClause } [ ... ]`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
+            | #abort : "`ROLLBACK`"
         ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
@@ -1228,11 +1456,34 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ... FROM <table> GROUP BY ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
         ),
+        rule!(
+            #show_sequences: "`SHOW SEQUENCES`"
+            | #create_sequence: "`CREATE SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH] <start>] [INCREMENT [BY] <increment>] [COMMENT = '<string_literal>']`"
+            | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <sequence>`"
+        ),
+        rule!(
+            #show_tasks: "`SHOW TASKS`"
+            | #create_task: "`CREATE TASK [IF NOT EXISTS] <task> [SCHEDULE = '<cron_expr>'] [AFTER <task>, ...] [COMMENT = '<string_literal>'] AS <statement>`"
+            | #alter_task: "`ALTER TASK <task> SUSPEND | RESUME`"
+            | #drop_task: "`DROP TASK [IF EXISTS] <task>`"
+            | #execute_task: "`EXECUTE TASK <task>`"
+        ),
+        rule!(
+            #show_streams: "`SHOW STREAMS`"
+            | #create_stream: "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [COMMENT = '<string_literal>']`"
+            | #drop_stream: "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+        ),
+        rule!(
+            #create_masking_policy: "`CREATE MASKING POLICY [IF NOT EXISTS] <policy> AS (<arg> <type>, ...) RETURNS <type> -> <expr> [COMMENT = '<string_literal>']`"
+            | #drop_masking_policy: "`DROP MASKING POLICY [IF EXISTS] <policy>`"
+        ),
         rule!(
             #show_users : "`SHOW USERS`"
             | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
@@ -1419,7 +1670,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint {
         Nullable(bool),
-        DefaultExpr(Box<Expr>),
+        Expr(ColumnExpr),
     }
 
     let nullable = alt((
@@ -1430,7 +1681,23 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             DEFAULT ~ ^#subexpr(NOT_PREC)
         },
-        |(_, default_expr)| ColumnConstraint::DefaultExpr(Box::new(default_expr)),
+        |(_, default_expr)| ColumnConstraint::Expr(ColumnExpr::Default(Box::new(default_expr))),
+    );
+    let virtual_expr = map(
+        rule! {
+            AS ~ ^"(" ~ ^#subexpr(NOT_PREC) ~ ^")" ~ VIRTUAL
+        },
+        |(_, _, virtual_expr, _, _)| {
+            ColumnConstraint::Expr(ColumnExpr::Virtual(Box::new(virtual_expr)))
+        },
+    );
+    let stored_expr = map(
+        rule! {
+            AS ~ ^"(" ~ ^#subexpr(NOT_PREC) ~ ^")" ~ STORED
+        },
+        |(_, _, stored_expr, _, _)| {
+            ColumnConstraint::Expr(ColumnExpr::Stored(Box::new(stored_expr)))
+        },
     );
 
     let comment = map(
@@ -1444,22 +1711,20 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #default_expr )*
+            ~ ( #nullable | #default_expr | #virtual_expr | #stored_expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <default value>] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <default value> | AS (<expr>) VIRTUAL | AS (<expr>) STORED] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let mut def = ColumnDefinition {
                 name,
                 data_type,
-                default_expr: None,
+                expr: None,
                 comment,
             };
             for constraint in constraints {
                 match constraint {
-                    ColumnConstraint::DefaultExpr(default_expr) => {
-                        def.default_expr = Some(default_expr)
-                    }
+                    ColumnConstraint::Expr(expr) => def.expr = Some(expr),
                     ColumnConstraint::Nullable(nullable) => {
                         if nullable {
                             def.data_type = def.data_type.wrap_nullable();
@@ -1676,6 +1941,18 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
     )(i)
 }
 
+pub fn alter_task_action(i: Input) -> IResult<AlterTaskAction> {
+    alt((
+        value(AlterTaskAction::Suspend, rule! { SUSPEND }),
+        value(AlterTaskAction::Resume, rule! { RESUME }),
+    ))(i)
+}
+
+pub fn masking_policy_arg(i: Input) -> IResult<(Identifier, TypeName)> {
+    rule! { #ident ~ #type_name }
+    (i)
+}
+
 pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     let rename_table = map(
         rule! {
@@ -1689,6 +1966,24 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, column)| AlterTableAction::AddColumn { column },
     );
+    let set_masking_policy = map(
+        rule! {
+            MODIFY ~ COLUMN? ~ #ident ~ SET ~ MASKING ~ POLICY ~ #ident
+        },
+        |(_, _, column, _, _, _, policy)| AlterTableAction::SetMaskingPolicy { column, policy },
+    );
+    let unset_masking_policy = map(
+        rule! {
+            MODIFY ~ COLUMN? ~ #ident ~ UNSET ~ MASKING ~ POLICY
+        },
+        |(_, _, column, _, _, _)| AlterTableAction::UnsetMaskingPolicy { column },
+    );
+    let modify_column = map(
+        rule! {
+            MODIFY ~ COLUMN? ~ #column_def
+        },
+        |(_, _, column)| AlterTableAction::ModifyColumn { column },
+    );
     let drop_column = map(
         rule! {
             DROP ~ COLUMN ~ #ident
@@ -1729,6 +2024,9 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     rule!(
         #rename_table
         | #add_column
+        | #set_masking_policy
+        | #unset_masking_policy
+        | #modify_column
         | #drop_column
         | #alter_table_cluster_key
         | #drop_table_cluster_key
@@ -1975,6 +2273,10 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::NoPassword, rule! { NO_PASSWORD }),
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
+        value(
+            AuthType::ScramSha256Password,
+            rule! { SCRAM_SHA256_PASSWORD },
+        ),
         value(AuthType::JWT, rule! { JWT }),
     ))(i)
 }
@@ -2077,6 +2379,86 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
     )(i)
 }
 
+pub fn merge_source(i: Input) -> IResult<TableReference> {
+    let source_table = map(
+        consumed(rule! {
+            #period_separated_idents_1_to_3 ~ #table_alias?
+        }),
+        |(span, ((catalog, database, table), alias))| TableReference::Table {
+            span: transform_span(span.0),
+            catalog,
+            database,
+            table,
+            alias,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        },
+    );
+    let source_query = map(
+        consumed(rule! {
+            "(" ~ #query ~ ")" ~ #table_alias
+        }),
+        |(span, (_, query, _, alias))| TableReference::Subquery {
+            span: transform_span(span.0),
+            subquery: Box::new(query),
+            alias: Some(alias),
+        },
+    );
+
+    rule!(
+        #source_query
+        | #source_table
+    )(i)
+}
+
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    // the column being updated may be qualified by the target table name or alias
+    let merge_update_expr = map(
+        rule! { ( #ident ~ "." )? ~ #ident ~ "=" ~ ^#expr },
+        |(_, name, _, expr)| UpdateExpr { name, expr },
+    );
+    let match_operation = alt((
+        map(
+            rule! { UPDATE ~ SET ~ ^#comma_separated_list1(merge_update_expr) },
+            |(_, _, update_list)| MatchOperation::Update { update_list },
+        ),
+        value(MatchOperation::Delete, rule! { DELETE }),
+    ));
+    let matched_clause = map(
+        rule! {
+            WHEN ~ MATCHED ~ (AND ~ ^#expr)? ~ THEN ~ #match_operation
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched_clause = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ (AND ~ ^#expr)? ~ THEN ~ INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                insert_operation: InsertOperation {
+                    columns: opt_columns.map(|(_, columns, _)| columns),
+                    values,
+                },
+            })
+        },
+    );
+
+    rule!(
+        #matched_clause
+        | #unmatched_clause
+    )(i)
+}
+
 pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
     map(rule! { ( #ident ~ "=" ~ ^#expr ) }, |(name, _, expr)| {
         UpdateExpr { name, expr }
@@ -212,14 +212,22 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
+        lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
             walk_expr(self, arg);
         }
 
         if let Some(over) = over {
-            self.visit_window(over);
+            self.visit_window(&over.window);
+        }
+
+        if let Some(lambda) = lambda {
+            for param in lambda.params.iter() {
+                self.visit_identifier(param);
+            }
+            walk_expr(self, &lambda.expr);
         }
     }
 
@@ -334,6 +342,8 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &'ast Statement) {
         walk_statement(self, statement);
     }
@@ -382,6 +392,7 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_insert(&mut self, _insert: &'ast InsertStmt) {}
     fn visit_replace(&mut self, _replace: &'ast ReplaceStmt) {}
+    fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
 
     fn visit_insert_source(&mut self, _insert_source: &'ast InsertSource) {}
 
@@ -394,6 +405,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_update(&mut self, _update: &'ast UpdateStmt) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &'ast ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &'ast ShowCreateCatalogStmt) {}
@@ -456,9 +473,30 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
 
+    fn visit_show_sequences(&mut self) {}
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+
+    fn visit_show_tasks(&mut self) {}
+    fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
+    fn visit_alter_task(&mut self, _stmt: &'ast AlterTaskStmt) {}
+    fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
+    fn visit_execute_task(&mut self, _stmt: &'ast ExecuteTaskStmt) {}
+
+    fn visit_show_streams(&mut self) {}
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &'ast CreateMaskingPolicyStmt) {}
+    fn visit_drop_masking_policy(&mut self, _stmt: &'ast DropMaskingPolicyStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
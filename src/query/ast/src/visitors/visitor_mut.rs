@@ -208,14 +208,15 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier,
         args: &mut [Expr],
         _params: &mut [Literal],
-        over: &mut Option<Window>,
+        over: &mut Option<WindowDesc>,
+        lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
         }
 
         if let Some(over) = over {
-            match over {
+            match &mut over.window {
                 Window::WindowReference(reference) => {
                     self.visit_identifier(&mut reference.window_name);
                 }
@@ -234,6 +235,13 @@ pub trait VisitorMut: Sized {
                 }
             }
         }
+
+        if let Some(lambda) = lambda {
+            for param in lambda.params.iter_mut() {
+                self.visit_identifier(param);
+            }
+            walk_expr_mut(self, &mut lambda.expr);
+        }
     }
 
     fn visit_frame_bound(&mut self, bound: &mut WindowFrameBound) {
@@ -329,6 +337,8 @@ pub trait VisitorMut: Sized {
         walk_expr_mut(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &mut Statement) {
         walk_statement_mut(self, statement);
     }
@@ -379,6 +389,7 @@ pub trait VisitorMut: Sized {
 
     fn visit_insert(&mut self, _insert: &mut InsertStmt) {}
     fn visit_replace(&mut self, _replace: &mut ReplaceStmt) {}
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
 
     fn visit_insert_source(&mut self, _insert_source: &mut InsertSource) {}
 
@@ -391,6 +402,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_update(&mut self, _update: &mut UpdateStmt) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &mut ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &mut ShowCreateCatalogStmt) {}
@@ -453,9 +470,30 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
 
+    fn visit_show_sequences(&mut self) {}
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+
+    fn visit_show_tasks(&mut self) {}
+    fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
+    fn visit_alter_task(&mut self, _stmt: &mut AlterTaskStmt) {}
+    fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
+    fn visit_execute_task(&mut self, _stmt: &mut ExecuteTaskStmt) {}
+
+    fn visit_show_streams(&mut self) {}
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &mut CreateMaskingPolicyStmt) {}
+    fn visit_drop_masking_policy(&mut self, _stmt: &mut DropMaskingPolicyStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
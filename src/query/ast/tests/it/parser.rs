@@ -78,6 +78,10 @@ fn test_statement() {
         r#"show columns in t from ctl.db"#,
         r#"show full columns from t from db like 'id%'"#,
         r#"show processlist;"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"commit;"#,
+        r#"rollback;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
@@ -86,6 +90,7 @@ fn test_statement() {
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
+        r#"create table t (a int, b int as (a + 1) stored, c int as (a * 2) virtual);"#,
         r#"create table if not exists a.b (c integer default 1 not null, b varchar) as select * from t;"#,
         r#"create table if not exists a.b (c tuple(m integer, n string), d tuple(integer, string));"#,
         r#"create table a.b like c.d;"#,
@@ -112,6 +117,8 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create materialized view mv as select a, sum(b) from t group by a;"#,
+        r#"refresh materialized view db.mv;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -170,12 +177,14 @@ fn test_statement() {
         r#"create user 'test-e'@'localhost' identified by 'password';"#,
         r#"drop user if exists 'test-j'@'localhost';"#,
         r#"alter user 'test-e'@'localhost' identified by 'new-password';"#,
+        r#"create user 'test-p'@'%' identified with scram_sha256_password by 'password';"#,
         r#"create role 'test'"#,
         r#"drop role if exists 'test'"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
+        r#"ALTER TABLE t MODIFY COLUMN a bigint COMMENT 'hello';"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
@@ -398,6 +407,20 @@ fn test_statement() {
         r#"SELECT * FROM t GROUP BY GROUPING SETS ((a, b), (), (d, e))"#,
         r#"SELECT * FROM t GROUP BY CUBE (a, b, c)"#,
         r#"SELECT * FROM t GROUP BY ROLLUP (a, b, c)"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq1 START WITH 10 INCREMENT BY 5 COMMENT = 'seq comment';"#,
+        r#"DROP SEQUENCE seq1;"#,
+        r#"SHOW SEQUENCES;"#,
+        r#"CREATE TASK IF NOT EXISTS t1 SCHEDULE = '0 * * * *' AFTER t0 COMMENT = 'hourly' AS TRUNCATE TABLE a;"#,
+        r#"ALTER TASK t1 SUSPEND;"#,
+        r#"EXECUTE TASK t1;"#,
+        r#"DROP TASK IF EXISTS t1;"#,
+        r#"SHOW TASKS;"#,
+        r#"CREATE STREAM IF NOT EXISTS db.s1 ON TABLE db.t1 COMMENT = 'test stream';"#,
+        r#"DROP STREAM IF EXISTS s1;"#,
+        r#"SHOW STREAMS;"#,
+        r#"CREATE MASKING POLICY IF NOT EXISTS mask_email AS (val STRING) RETURNS STRING -> '***' COMMENT = 'hide email';"#,
+        r#"DROP MASKING POLICY IF EXISTS mask_email;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a SET MASKING POLICY mask_email;"#,
     ];
 
     for case in cases {
@@ -623,6 +646,9 @@ fn test_expr() {
         r#"a is distinct from b"#,
         r#"1 is not distinct from null"#,
         r#"{'k1':1,'k2':2}"#,
+        // lambda expr
+        r#"array_transform(a, x -> x + 1)"#,
+        r#"array_sort(a, (x, y) -> y - x)"#,
         // window expr
         r#"ROW_NUMBER() OVER (ORDER BY salary DESC)"#,
         r#"SUM(salary) OVER ()"#,
@@ -633,6 +659,9 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"FIRST_VALUE(salary) IGNORE NULLS OVER (PARTITION BY department)"#,
+        // prepared statement placeholder
+        r#"a = ?"#,
     ];
 
     for case in cases {
@@ -17,40 +17,70 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::AcquireTaskLeaseReply;
+use common_meta_app::schema::AcquireTaskLeaseReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReply;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateTaskReply;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::CreateTaskRunReply;
+use common_meta_app::schema::CreateTaskRunReq;
 use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropTaskReply;
+use common_meta_app::schema::DropTaskReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::GetTaskReply;
+use common_meta_app::schema::GetTaskReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListSequencesReq;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::ListTasksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRun;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateTaskRunReply;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_meta_app::schema::UpdateTaskStateReply;
+use common_meta_app::schema::UpdateTaskStateReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MetaId;
@@ -91,6 +121,37 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn list_indexes(&self, req: ListIndexesReq) -> Result<Vec<(u64, String, IndexMeta)>>;
 
+    async fn create_sequence(&self, req: CreateSequenceReq) -> Result<CreateSequenceReply>;
+
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply>;
+
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply>;
+
+    async fn list_sequences(&self, req: ListSequencesReq) -> Result<Vec<(String, SequenceMeta)>>;
+
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply>;
+
+    async fn create_task(&self, req: CreateTaskReq) -> Result<CreateTaskReply>;
+
+    async fn drop_task(&self, req: DropTaskReq) -> Result<DropTaskReply>;
+
+    async fn get_task(&self, req: GetTaskReq) -> Result<GetTaskReply>;
+
+    async fn list_tasks(&self, req: ListTasksReq) -> Result<Vec<(String, TaskMeta)>>;
+
+    async fn update_task_state(&self, req: UpdateTaskStateReq) -> Result<UpdateTaskStateReply>;
+
+    async fn create_task_run(&self, req: CreateTaskRunReq) -> Result<CreateTaskRunReply>;
+
+    async fn update_task_run(&self, req: UpdateTaskRunReq) -> Result<UpdateTaskRunReply>;
+
+    async fn list_task_runs(&self, req: ListTaskRunsReq) -> Result<Vec<TaskRun>>;
+
+    async fn acquire_task_lease(&self, req: AcquireTaskLeaseReq) -> Result<AcquireTaskLeaseReply>;
+
     #[async_backtrace::framed]
     async fn exists_database(&self, tenant: &str, db_name: &str) -> Result<bool> {
         match self.get_database(tenant, db_name).await {
@@ -163,6 +224,16 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply>;
 
+    // Update the meta of several tables atomically, used to commit a multi-statement transaction.
+    async fn update_multi_table_meta(
+        &self,
+        _req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Err(ErrorCode::Unimplemented(
+            "'update_multi_table_meta' not implemented",
+        ))
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply>;
 
     async fn get_table_copied_file_info(
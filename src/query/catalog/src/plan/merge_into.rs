@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::RemoteExpr;
+
+/// `WHEN MATCHED [AND condition] THEN UPDATE | DELETE`
+///
+/// All the expressions refer to the columns of the joined input block by offset.
+#[derive(Clone, Debug)]
+pub struct MatchedClauseInfo {
+    pub condition: Option<RemoteExpr>,
+    /// One expression for each field of the target table, which produces the updated row.
+    /// `None` means the matched rows are deleted.
+    pub update: Option<Vec<RemoteExpr>>,
+}
+
+/// `WHEN NOT MATCHED [AND condition] THEN INSERT`
+#[derive(Clone, Debug)]
+pub struct UnmatchedClauseInfo {
+    pub condition: Option<RemoteExpr>,
+    /// One expression for each field of the target table, which produces the inserted row.
+    pub values: Vec<RemoteExpr>,
+}
+
+/// Describes how the output of `target RIGHT JOIN source` is merged into the target table.
+#[derive(Clone, Debug)]
+pub struct MergeIntoInfo {
+    /// Offset of the target table's `_row_id` column in the input block,
+    /// NULL values of it mean the source row is not matched.
+    pub row_id_offset: usize,
+    pub matched_clauses: Vec<MatchedClauseInfo>,
+    pub unmatched_clauses: Vec<UnmatchedClauseInfo>,
+}
@@ -40,6 +40,7 @@ use common_storage::StorageMetrics;
 
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
+use crate::plan::MergeIntoInfo;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
@@ -217,6 +218,22 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        merge_into_info: MergeIntoInfo,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, pipeline, merge_into_info);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "merge_into operation for table {} is not implemented. table engine : {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn commit_insertion(
         &self,
@@ -463,6 +480,7 @@ pub trait ColumnStatisticsProvider {
             (Scalar::Timestamp(min), Scalar::Timestamp(max)) => (max as i128 - min as i128) as u64,
             (Scalar::Date(min), Scalar::Date(max)) => (max as i64 - min as i64) as u64,
             (Scalar::String(mut min), Scalar::String(mut max))
+            | (Scalar::Binary(mut min), Scalar::Binary(mut max))
             | (Scalar::Variant(mut min), Scalar::Variant(mut max)) => {
                 // There are 128 characters in ASCII code and 128^4 = 268435456 < 2^32 < 128^5.
                 if min.is_empty() || max.is_empty() || min.len() > 4 || max.len() > 4 {
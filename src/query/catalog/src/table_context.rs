@@ -30,6 +30,7 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -44,6 +45,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -137,10 +139,16 @@ pub trait TableContext: Send + Sync {
     fn apply_changed_settings(&self, changes: HashMap<String, ChangeValue>) -> Result<()>;
     fn get_changed_settings(&self) -> HashMap<String, ChangeValue>;
 
+    fn txn_mgr(&self) -> TxnManagerRef;
+
     // Get the storage data accessor operator from the session manager.
     fn get_data_operator(&self) -> Result<DataOperator>;
     fn push_precommit_block(&self, block: DataBlock);
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
+    /// Record the offset that a stream read by the query advances to, it is committed
+    /// together with the mutation of the query.
+    fn add_stream_offset(&self, req: UpdateTableMetaReq);
+    fn get_stream_offsets(&self) -> Vec<UpdateTableMetaReq>;
 
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
@@ -0,0 +1,135 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MetaId;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement is committed on its own.
+    AutoCommit,
+    /// In a `BEGIN ... COMMIT` block.
+    Active,
+    /// A statement of the transaction failed, the transaction can only be rolled back.
+    Fail,
+}
+
+/// The state of the multi-statement transaction of a session.
+///
+/// The tables are read at the snapshot taken when they are first accessed in the
+/// transaction, the mutations are buffered and committed to the meta service atomically
+/// at `COMMIT`.
+#[derive(Debug)]
+pub struct TxnManager {
+    state: TxnState,
+    // (catalog, database, table) -> table info seen by the transaction.
+    tables: HashMap<(String, String, String), TableInfo>,
+    // table id -> (catalog, pending update of the table meta).
+    mutated_tables: BTreeMap<MetaId, (String, UpdateTableMetaReq)>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            tables: HashMap::new(),
+            mutated_tables: BTreeMap::new(),
+        }))
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state != TxnState::AutoCommit
+    }
+
+    pub fn get_table_info(&self, catalog: &str, database: &str, table: &str) -> Option<TableInfo> {
+        self.tables
+            .get(&(catalog.to_string(), database.to_string(), table.to_string()))
+            .cloned()
+    }
+
+    pub fn add_table_info(
+        &mut self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        table_info: TableInfo,
+    ) {
+        self.tables.insert(
+            (catalog.to_string(), database.to_string(), table.to_string()),
+            table_info,
+        );
+    }
+
+    /// Buffer the update of a table meta, the later statements of the transaction see the
+    /// new meta, and the table version to check at `COMMIT` is the one of the first update.
+    pub fn update_table_meta(&mut self, catalog: &str, req: UpdateTableMetaReq) {
+        for table_info in self.tables.values_mut() {
+            if table_info.ident.table_id == req.table_id {
+                table_info.meta = req.new_table_meta.clone();
+            }
+        }
+
+        match self.mutated_tables.entry(req.table_id) {
+            Entry::Occupied(mut v) => {
+                let (_, pending) = v.get_mut();
+                pending.new_table_meta = req.new_table_meta;
+                match (&mut pending.copied_files, req.copied_files) {
+                    (Some(pending_files), Some(files)) => {
+                        pending_files.file_info.extend(files.file_info);
+                        pending_files.fail_if_duplicated |= files.fail_if_duplicated;
+                    }
+                    (pending_files @ None, files) => *pending_files = files,
+                    (Some(_), None) => {}
+                }
+            }
+            Entry::Vacant(v) => {
+                v.insert((catalog.to_string(), req));
+            }
+        }
+    }
+
+    /// Take the pending updates and reset the state to auto commit.
+    pub fn finish(&mut self) -> Vec<(String, UpdateTableMetaReq)> {
+        self.state = TxnState::AutoCommit;
+        self.tables.clear();
+        std::mem::take(&mut self.mutated_tables)
+            .into_values()
+            .collect()
+    }
+}
@@ -1118,6 +1118,12 @@ pub struct QueryConfig {
     #[clap(long, default_value = "120")]
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
 
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, default_value = "5433")]
+    pub postgres_handler_port: u16,
+
     #[clap(long, default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -1353,6 +1359,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_host: self.mysql_handler_host,
             mysql_handler_port: self.mysql_handler_port,
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
             max_active_sessions: self.max_active_sessions,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
@@ -1417,6 +1425,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_handler_port: inner.mysql_handler_port,
             mysql_handler_tcp_keepalive_timeout_secs: inner
                 .mysql_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
             max_active_sessions: inner.max_active_sessions,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
@@ -1810,7 +1820,9 @@ impl TryInto<AuthInfo> for UserAuthConfig {
         match auth_type {
             AuthType::NoPassword => check_no_auth_string(self.auth_string, AuthInfo::None),
             AuthType::JWT => check_no_auth_string(self.auth_string, AuthInfo::JWT),
-            AuthType::Sha256Password | AuthType::DoubleSha1Password => {
+            AuthType::Sha256Password
+            | AuthType::DoubleSha1Password
+            | AuthType::ScramSha256Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match self.auth_string {
                     None => Err(ErrorCode::InvalidConfig("must set auth_string")),
@@ -128,6 +128,8 @@ pub struct QueryConfig {
     pub mysql_handler_host: String,
     pub mysql_handler_port: u16,
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
     pub max_active_sessions: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
@@ -196,6 +198,8 @@ impl Default for QueryConfig {
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
             mysql_handler_tcp_keepalive_timeout_secs: 120,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
             max_active_sessions: 256,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
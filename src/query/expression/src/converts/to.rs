@@ -36,6 +36,8 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             crate::types::number::NumberScalar::Float64(x) => DataValue::Float64((*x).into()),
         },
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
+        Scalar::Interval(_) => unimplemented!("interval type is not supported"),
+        Scalar::TimestampTz(_) => unimplemented!("timestamp_tz type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
@@ -50,6 +52,12 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             let values = x.iter().map(scalar_to_datavalue).collect();
             DataValue::Struct(values)
         }
-        Scalar::EmptyMap | Scalar::Map(_) | Scalar::Bitmap(_) => unimplemented!(),
+        Scalar::EmptyMap
+        | Scalar::Map(_)
+        | Scalar::Binary(_)
+        | Scalar::Geometry(_)
+        | Scalar::Bitmap(_) => {
+            unimplemented!()
+        }
     }
 }
@@ -26,6 +26,7 @@ use tracing::error;
 
 use crate::block::DataBlock;
 use crate::expression::Expr;
+use crate::expression::RemoteExpr;
 use crate::function::EvalContext;
 use crate::property::Domain;
 use crate::type_check::check_function;
@@ -37,6 +38,7 @@ use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableDomain;
 use crate::types::BooleanType;
 use crate::types::DataType;
+use crate::types::Int64Type;
 use crate::types::NullableType;
 use crate::types::ValueType;
 use crate::utils::arrow::constant_bitmap;
@@ -197,6 +199,13 @@ impl<'a> Evaluator<'a> {
                 ctx.render_error(*span, id.params(), &args, &function.signature.name)?;
                 Ok(result)
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_expr,
+                return_type,
+                ..
+            } => self.run_lambda(name, args, lambda_expr, return_type, validity),
         };
 
         #[cfg(debug_assertions)]
@@ -879,6 +888,275 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    /// Evaluate a higher-order array function. The lambda is evaluated column-at-a-time over the
+    /// flattened elements of all arrays in the block, rather than row by row.
+    fn run_lambda(
+        &self,
+        func_name: &str,
+        args: &[Expr],
+        lambda_expr: &RemoteExpr,
+        return_type: &DataType,
+        validity: Option<Bitmap>,
+    ) -> Result<Value<AnyType>> {
+        let arg = &args[0];
+        match self.partial_run(arg, validity)? {
+            Value::Scalar(scalar) => {
+                let column = ColumnBuilder::repeat(&scalar.as_ref(), 1, arg.data_type()).build();
+                let result = self.run_lambda_column(func_name, column, lambda_expr, return_type)?;
+                Ok(Value::Scalar(result.index(0).unwrap().to_owned()))
+            }
+            Value::Column(column) => {
+                let result = self.run_lambda_column(func_name, column, lambda_expr, return_type)?;
+                Ok(Value::Column(result))
+            }
+        }
+    }
+
+    fn run_lambda_column(
+        &self,
+        func_name: &str,
+        column: Column,
+        lambda_expr: &RemoteExpr,
+        return_type: &DataType,
+    ) -> Result<Column> {
+        let num_rows = column.len();
+        let (column, validity) = match column {
+            Column::Nullable(box NullableColumn { column, validity }) => (column, Some(validity)),
+            column => (column, None),
+        };
+        let array = match column {
+            Column::Array(box array) => array,
+            _ => unreachable!("the first argument of {func_name} must be an array"),
+        };
+        let lambda_expr = lambda_expr.as_expr(self.fn_registry);
+
+        let result = match func_name {
+            "array_transform" => {
+                let values = self.run_lambda_expr(&lambda_expr, vec![array.values.clone()])?;
+                Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: array.offsets,
+                }))
+            }
+            "array_filter" => {
+                let predicate = self.run_lambda_expr(&lambda_expr, vec![array.values.clone()])?;
+                let bitmap = match predicate {
+                    Column::Boolean(bitmap) => bitmap,
+                    Column::Nullable(box NullableColumn {
+                        column: Column::Boolean(bitmap),
+                        validity,
+                    }) => &bitmap & &validity,
+                    Column::Null { len } => constant_bitmap(false, len).into(),
+                    _ => unreachable!("the lambda of array_filter must return a boolean"),
+                };
+                let mut count = (0..array.offsets[0] as usize)
+                    .filter(|i| bitmap.get_bit(*i))
+                    .count() as u64;
+                let mut offsets = Vec::with_capacity(array.offsets.len());
+                offsets.push(count);
+                for window in array.offsets.windows(2) {
+                    count += (window[0] as usize..window[1] as usize)
+                        .filter(|i| bitmap.get_bit(*i))
+                        .count() as u64;
+                    offsets.push(count);
+                }
+                Column::Array(Box::new(ArrayColumn {
+                    values: array.values.filter(&bitmap),
+                    offsets: offsets.into(),
+                }))
+            }
+            "array_sort" => {
+                let indices = self.sort_by_lambda(&lambda_expr, &array)?;
+                let start = array.offsets[0];
+                Column::Array(Box::new(ArrayColumn {
+                    values: array.values.take(&indices),
+                    offsets: array
+                        .offsets
+                        .iter()
+                        .map(|offset| offset - start)
+                        .collect::<Vec<_>>()
+                        .into(),
+                }))
+            }
+            "array_reduce" => {
+                let lens = (0..num_rows)
+                    .map(|row| {
+                        let is_valid = validity
+                            .as_ref()
+                            .map(|validity| validity.get_bit(row))
+                            .unwrap_or(true);
+                        if is_valid {
+                            (array.offsets[row + 1] - array.offsets[row]) as usize
+                        } else {
+                            0
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                // Order the non-empty arrays by their lengths descendingly, so that the arrays
+                // still being reduced at each step are always a prefix of the accumulators.
+                let mut order = (0..num_rows)
+                    .filter(|row| lens[*row] > 0)
+                    .collect::<Vec<_>>();
+                order.sort_by(|a, b| lens[*b].cmp(&lens[*a]));
+
+                let first = order
+                    .iter()
+                    .map(|row| array.offsets[*row])
+                    .collect::<Vec<_>>();
+                let mut acc = array.values.take(&first);
+                let max_len = order.first().map(|row| lens[*row]).unwrap_or(0);
+                for step in 1..max_len {
+                    let active = order.iter().take_while(|row| lens[**row] > step).count();
+                    let elements = order[..active]
+                        .iter()
+                        .map(|row| array.offsets[*row] + step as u64)
+                        .collect::<Vec<_>>();
+                    let reduced = self.run_lambda_expr(&lambda_expr, vec![
+                        acc.slice(0..active),
+                        array.values.take(&elements),
+                    ])?;
+                    acc = if active == acc.len() {
+                        reduced
+                    } else {
+                        let mut builder = ColumnBuilder::with_capacity(&acc.data_type(), acc.len());
+                        builder.append_column(&reduced);
+                        builder.append_column(&acc.slice(active..acc.len()));
+                        builder.build()
+                    };
+                }
+
+                let mut positions = vec![None; num_rows];
+                for (pos, row) in order.iter().enumerate() {
+                    positions[*row] = Some(pos);
+                }
+                let mut builder = ColumnBuilder::with_capacity(return_type, num_rows);
+                for pos in positions {
+                    match pos {
+                        Some(pos) => builder.push(acc.index(pos).unwrap()),
+                        None => builder.push_default(),
+                    }
+                }
+                return Ok(builder.build());
+            }
+            _ => unreachable!("unknown lambda function {func_name}"),
+        };
+
+        match validity {
+            Some(validity) => Ok(result.wrap_nullable(Some(validity))),
+            None => Ok(result),
+        }
+    }
+
+    /// Evaluate the body of a lambda, whose parameters are bound to the given columns in order.
+    fn run_lambda_expr(&self, lambda_expr: &Expr, columns: Vec<Column>) -> Result<Column> {
+        let num_rows = columns[0].len();
+        let entries = columns
+            .into_iter()
+            .map(|column| BlockEntry {
+                data_type: column.data_type(),
+                value: Value::Column(column),
+            })
+            .collect();
+        let block = DataBlock::new(entries, num_rows);
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(lambda_expr)?;
+        Ok(result.convert_to_full_column(lambda_expr.data_type(), num_rows))
+    }
+
+    /// Sort the elements of every array with the comparator lambda, returns the indices of the
+    /// sorted elements in the values of the arrays.
+    ///
+    /// The arrays are merge sorted bottom-up all together, so that each step of the merges only
+    /// evaluates the lambda once for the next comparisons of all merges. The lambda compares
+    /// `O(n log n)` pairs of elements and is evaluated `O(n)` times for arrays of length `n`.
+    /// A NULL result means the elements are equal, and any results of an inconsistent lambda
+    /// still produce a permutation of the elements.
+    fn sort_by_lambda(&self, lambda_expr: &Expr, array: &ArrayColumn<AnyType>) -> Result<Vec<u64>> {
+        let start = array.offsets[0];
+        let mut indices = (start..array.offsets[array.offsets.len() - 1]).collect::<Vec<_>>();
+        let ranges = array
+            .offsets
+            .windows(2)
+            .map(|window| ((window[0] - start) as usize, (window[1] - start) as usize))
+            .collect::<Vec<_>>();
+        let max_len = ranges.iter().map(|(lo, hi)| hi - lo).max().unwrap_or(0);
+
+        // Merges the sorted runs `left..mid` and `mid..right` into `out`.
+        struct Merge {
+            left: usize,
+            mid: usize,
+            right: usize,
+            out: usize,
+            left_end: usize,
+        }
+
+        let mut width = 1;
+        while width < max_len {
+            let mut merges = vec![];
+            for (lo, hi) in ranges.iter() {
+                for left in (*lo..*hi).step_by(2 * width) {
+                    let mid = (left + width).min(*hi);
+                    let right = (left + 2 * width).min(*hi);
+                    if mid < right {
+                        merges.push(Merge {
+                            left,
+                            mid,
+                            right,
+                            out: left,
+                            left_end: mid,
+                        });
+                    }
+                }
+            }
+
+            // The runs not merged in this pass are already in place.
+            let mut sorted = indices.clone();
+            loop {
+                merges.retain(|merge| merge.left < merge.left_end && merge.mid < merge.right);
+                if merges.is_empty() {
+                    break;
+                }
+                let lhs = merges
+                    .iter()
+                    .map(|merge| indices[merge.left])
+                    .collect::<Vec<_>>();
+                let rhs = merges
+                    .iter()
+                    .map(|merge| indices[merge.mid])
+                    .collect::<Vec<_>>();
+                let ordering = self.run_lambda_expr(lambda_expr, vec![
+                    array.values.take(&lhs),
+                    array.values.take(&rhs),
+                ])?;
+                let greater = lambda_greater(ordering);
+
+                for (merge, greater) in merges.iter_mut().zip(greater) {
+                    // Take the element of the left run first if they are equal, to keep the
+                    // sort stable.
+                    if greater {
+                        sorted[merge.out] = indices[merge.mid];
+                        merge.mid += 1;
+                    } else {
+                        sorted[merge.out] = indices[merge.left];
+                        merge.left += 1;
+                    }
+                    merge.out += 1;
+                    if merge.left == merge.left_end {
+                        sorted[merge.out..merge.right]
+                            .copy_from_slice(&indices[merge.mid..merge.right]);
+                    } else if merge.mid == merge.right {
+                        sorted[merge.out..merge.right]
+                            .copy_from_slice(&indices[merge.left..merge.left_end]);
+                    }
+                }
+            }
+            indices = sorted;
+            width *= 2;
+        }
+
+        Ok(indices)
+    }
+
     /// Evaluate a set-returning-function. Return multiple sets of results
     /// for each input row, along with the number of rows in each set.
     pub fn run_srf(&self, expr: &Expr) -> Result<Vec<(Value<AnyType>, usize)>> {
@@ -917,6 +1195,26 @@ impl<'a> Evaluator<'a> {
     }
 }
 
+/// Whether the first element is greater than the second one of each pair compared by the
+/// comparator lambda of `array_sort`, a NULL result means they are equal.
+fn lambda_greater(ordering: Column) -> Vec<bool> {
+    match ordering {
+        Column::Null { len } => vec![false; len],
+        Column::Nullable(box NullableColumn { column, validity }) => {
+            let column = Int64Type::try_downcast_column(&column).unwrap();
+            column
+                .iter()
+                .zip(validity.iter())
+                .map(|(ordering, is_valid)| is_valid && *ordering > 0)
+                .collect()
+        }
+        column => {
+            let column = Int64Type::try_downcast_column(&column).unwrap();
+            column.iter().map(|ordering| *ordering > 0).collect()
+        }
+    }
+}
+
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
     input_domains: &'a HashMap<Index, Domain>,
     func_ctx: &'a FunctionContext,
@@ -1241,6 +1539,17 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
                     }
                 };
 
+                // Non-deterministic functions like `nextval()` must be evaluated for every row,
+                // except the niladic ones like `now()`, which stay the same within a query.
+                if !args.is_empty()
+                    && self
+                        .fn_registry
+                        .get_property(&function.signature.name)
+                        .map_or(false, |property| property.non_deterministic)
+                {
+                    return (func_expr, None);
+                }
+
                 let func_domain = args_domain.and_then(|domains| match (calc_domain)(&domains) {
                     FunctionDomain::MayThrow => None,
                     FunctionDomain::Full => Some(Domain::full(return_type)),
@@ -1277,6 +1586,48 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: *span,
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        return (
+                            Expr::Constant {
+                                span: *span,
+                                scalar,
+                                data_type: return_type.clone(),
+                            },
+                            None,
+                        );
+                    }
+                }
+
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
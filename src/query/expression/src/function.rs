@@ -95,6 +95,17 @@ pub struct FunctionContext {
     pub openai_api_key: String,
     pub openai_api_embedding_model: String,
     pub openai_api_completion_model: String,
+
+    /// Provides sequence values for `nextval()`, absent if the query node does not support it.
+    pub sequence_values: Option<Arc<dyn SequenceValues>>,
+}
+
+/// Reserves values of a sequence of the current tenant.
+pub trait SequenceValues: Send + Sync {
+    /// Returns the next `count` values of the sequence.
+    ///
+    /// It is called in a synchronous function evaluation and may block on the meta-service.
+    fn next_values(&self, sequence: &str, count: u64) -> Result<Vec<u64>>;
 }
 
 #[derive(Clone)]
@@ -134,6 +134,14 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::Interval(i)
+            }
+            Column::TimestampTz(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::TimestampTz(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -159,6 +167,14 @@ impl Column {
                 let column = ArrayColumn::try_downcast(column).unwrap();
                 Self::filter_scalar_types::<MapType<AnyType, AnyType>>(&column, builder, filter)
             }
+            Column::Binary(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Binary(column)
+            }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
             Column::Bitmap(column) => {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::Bitmap(column)
@@ -32,6 +32,11 @@ use crate::types::DataType;
 use crate::utils::arrow::column_to_arrow_array;
 use crate::Column;
 use crate::DataBlock;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INTERVAL;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
+use crate::ARROW_EXT_TYPE_VARIANT;
 
 pub type Aborting = Arc<Box<dyn Fn() -> bool + Send + Sync + 'static>>;
 
@@ -201,6 +206,47 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_binary(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    // Geometries are compared by their WKB bytes.
+    let left = Column::from_arrow(left, &DataType::Binary)
+        .into_binary()
+        .or_else(Column::into_geometry)
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Binary)
+        .into_binary()
+        .or_else(Column::into_geometry)
+        .unwrap();
+    Ok(Box::new(move |i, j| {
+        let l = unsafe { left.index_unchecked(i) };
+        let r = unsafe { right.index_unchecked(j) };
+        l.cmp(r)
+    }))
+}
+
+fn compare_interval(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Interval)
+        .as_interval()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Interval)
+        .as_interval()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| left[i].cmp(&right[j])))
+}
+
+fn compare_timestamp_tz(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::TimestampTz)
+        .as_timestamp_tz()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::TimestampTz)
+        .as_timestamp_tz()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| left[i].cmp(&right[j])))
+}
+
 fn compare_decimal256(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     let left = left
         .as_any()
@@ -219,8 +265,14 @@ fn compare_decimal256(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCom
 fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     match left.data_type() {
         ArrowType::Extension(name, _, _) => {
-            if name == "Variant" {
+            if name == ARROW_EXT_TYPE_VARIANT {
                 compare_variant(left, right)
+            } else if name == ARROW_EXT_TYPE_BINARY || name == ARROW_EXT_TYPE_GEOMETRY {
+                compare_binary(left, right)
+            } else if name == ARROW_EXT_TYPE_INTERVAL {
+                compare_interval(left, right)
+            } else if name == ARROW_EXT_TYPE_TIMESTAMP_TZ {
+                compare_timestamp_tz(left, right)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
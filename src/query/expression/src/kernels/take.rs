@@ -17,8 +17,10 @@ use itertools::Itertools;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -26,9 +28,11 @@ use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -104,6 +108,10 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => Self::take_arg_types::<IntervalType, _>(column, indices),
+            Column::TimestampTz(column) => {
+                Self::take_arg_types::<TimestampTzType, _>(column, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -129,6 +137,8 @@ impl Column {
                 let column = ArrayColumn::try_downcast(column).unwrap();
                 Self::take_value_types::<MapType<AnyType, AnyType>, _>(&column, builder, indices)
             }
+            Column::Binary(column) => Self::take_arg_types::<BinaryType, _>(column, indices),
+            Column::Geometry(column) => Self::take_arg_types::<GeometryType, _>(column, indices),
             Column::Bitmap(column) => Self::take_arg_types::<BitmapType, _>(column, indices),
             Column::Nullable(c) => {
                 let column = c.column.take(indices);
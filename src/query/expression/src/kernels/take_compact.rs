@@ -19,8 +19,10 @@ use common_exception::Result;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -124,6 +126,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices, row_num);
+                Column::Interval(builder.into())
+            }
+            Column::TimestampTz(column) => {
+                let builder = Self::take_primitive_types(column, indices, row_num);
+                Column::TimestampTz(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -151,6 +161,12 @@ impl Column {
                     &column, builder, indices, row_num,
                 )
             }
+            Column::Binary(column) => {
+                BinaryType::upcast_column(Self::take_string_types(column, indices, row_num))
+            }
+            Column::Geometry(column) => {
+                GeometryType::upcast_column(Self::take_string_types(column, indices, row_num))
+            }
             Column::Bitmap(column) => {
                 BitmapType::upcast_column(Self::take_string_types(column, indices, row_num))
             }
@@ -18,6 +18,7 @@ use crate::types::boolean::BooleanDomain;
 use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
 use crate::types::decimal::DecimalDomain;
+use crate::types::geometry::GeometryDomain;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -103,6 +104,7 @@ pub enum Domain {
     /// `Map(None)` means that the map is empty, thus there is no inner domain information.
     Map(Option<(Box<Domain>, Box<Domain>)>),
     Tuple(Vec<Domain>),
+    Geometry(GeometryDomain),
     /// For certain types, like `Variant`, the domain is useless therefore is not defined.
     Undefined,
 }
@@ -201,7 +203,12 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
-            DataType::Bitmap | DataType::Variant => Domain::Undefined,
+            DataType::Geometry => Domain::Geometry(GeometryDomain::full()),
+            DataType::Interval
+            | DataType::TimestampTz
+            | DataType::Binary
+            | DataType::Bitmap
+            | DataType::Variant => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -328,6 +335,9 @@ impl Domain {
                     .map(|(self_tup, other_tup)| self_tup.merge(other_tup))
                     .collect(),
             ),
+            (Domain::Geometry(this), Domain::Geometry(other)) => {
+                Domain::Geometry(this.merge(other))
+            }
             (Domain::Undefined, Domain::Undefined) => Domain::Undefined,
             (this, other) => unreachable!("unable to merge {this:?} with {other:?}"),
         }
@@ -34,9 +34,13 @@ use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INTERVAL;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -92,6 +96,31 @@ pub struct TableField {
     data_type: TableDataType,
     #[serde(default = "uninit_column_id")]
     column_id: ColumnId,
+    // The column ids and data types of the column before its data type was modified,
+    // from the newest to the oldest. The blocks written before the modification only
+    // have the old columns, they are read by the old data type and cast to the new one.
+    #[serde(default)]
+    history: Vec<(ColumnId, TableDataType)>,
+    #[serde(default)]
+    computed_expr: Option<ComputedExpr>,
+}
+
+/// The expression of a computed column, represented as raw sql.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ComputedExpr {
+    /// Evaluated when the column is read, the value is not stored.
+    Virtual(String),
+    /// Evaluated when the row is written, the value is stored like other columns.
+    Stored(String),
+}
+
+impl ComputedExpr {
+    pub fn expr(&self) -> &String {
+        match self {
+            ComputedExpr::Virtual(expr) => expr,
+            ComputedExpr::Stored(expr) => expr,
+        }
+    }
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -107,10 +136,14 @@ pub enum TableDataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
+    TimestampTz,
     Nullable(Box<TableDataType>),
     Array(Box<TableDataType>),
     Map(Box<TableDataType>),
+    Binary,
     Bitmap,
+    Geometry,
     Tuple {
         fields_name: Vec<String>,
         fields_type: Vec<TableDataType>,
@@ -339,6 +372,11 @@ impl TableSchema {
             if field.contain_column_id(column_id) {
                 return false;
             }
+            for previous in field.previous_fields() {
+                if previous.contain_column_id(column_id) {
+                    return false;
+                }
+            }
         }
 
         true
@@ -373,6 +411,80 @@ impl TableSchema {
         self.fields.retain(|f| !is_internal_column_id(f.column_id));
     }
 
+    /// Returns the schema without the computed fields, which are not written by users.
+    pub fn remove_computed_fields(&self) -> Self {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| f.computed_expr().is_none())
+            .cloned()
+            .collect();
+
+        Self {
+            fields,
+            metadata: self.metadata.clone(),
+            next_column_id: self.next_column_id,
+        }
+    }
+
+    /// Returns the schema without the virtual computed fields, which are evaluated when reading
+    /// and not stored in the blocks.
+    pub fn remove_virtual_computed_fields(&self) -> Self {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+            .cloned()
+            .collect();
+
+        Self {
+            fields,
+            metadata: self.metadata.clone(),
+            next_column_id: self.next_column_id,
+        }
+    }
+
+    /// Modify the data type or the default expr of the column.
+    ///
+    /// The table is not rewritten, if the data type is changed, the column gets new column ids
+    /// and the old ones are kept in the history of the field, the blocks written before are read
+    /// by the old data type and cast to the new one.
+    pub fn modify_column(&mut self, field: &TableField) -> Result<()> {
+        let i = self.index_of(field.name())?;
+        let old_field = self.fields[i].clone();
+        let new_field = if old_field.data_type() == field.data_type() {
+            old_field.with_default_expr(field.default_expr().cloned())
+        } else {
+            if !old_field.data_type().can_evolve_to(field.data_type()) {
+                return Err(ErrorCode::ModifyColumnTypeError(format!(
+                    "cannot modify column {} from type {} to type {}",
+                    field.name(),
+                    old_field.data_type().sql_name(),
+                    field.data_type().sql_name(),
+                )));
+            }
+            let mut new_field = field.build_column_id(&mut self.next_column_id);
+            new_field.history = Some((old_field.column_id, old_field.data_type.clone()))
+                .into_iter()
+                .chain(old_field.history.iter().cloned())
+                .collect();
+            new_field
+        };
+        self.fields[i] = new_field;
+        Ok(())
+    }
+
+    /// The schema to read the blocks written before the data type of the columns was modified,
+    /// the fields are replaced by the previous fields at the same positions.
+    #[must_use]
+    pub fn with_previous_fields(&self, previous_fields: &[(FieldIndex, TableField)]) -> Self {
+        let mut schema = self.clone();
+        for (index, field) in previous_fields {
+            schema.fields[*index] = field.clone();
+        }
+        schema
+    }
+
     pub fn drop_column(&mut self, column: &str) -> Result<()> {
         if self.fields.len() == 1 {
             return Err(ErrorCode::DropColumnEmptyError(
@@ -851,6 +963,8 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id: 0,
+            history: vec![],
+            computed_expr: None,
         }
     }
 
@@ -860,6 +974,8 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id,
+            history: vec![],
+            computed_expr: None,
         }
     }
 
@@ -905,6 +1021,8 @@ impl TableField {
             default_expr: self.default_expr.clone(),
             data_type: self.data_type.clone(),
             column_id,
+            history: self.history.clone(),
+            computed_expr: self.computed_expr.clone(),
         }
     }
 
@@ -937,6 +1055,27 @@ impl TableField {
         self.column_id
     }
 
+    pub fn history(&self) -> &[(ColumnId, TableDataType)] {
+        &self.history
+    }
+
+    #[must_use]
+    pub fn with_history(mut self, history: Vec<(ColumnId, TableDataType)>) -> Self {
+        self.history = history;
+        self
+    }
+
+    // The fields of the column before its data type was modified, from the newest to the oldest.
+    pub fn previous_fields(&self) -> Vec<TableField> {
+        self.history
+            .iter()
+            .map(|(column_id, data_type)| {
+                TableField::new_from_column_id(&self.name, data_type.clone(), *column_id)
+                    .with_default_expr(self.default_expr.clone())
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn with_default_expr(mut self, default_expr: Option<String>) -> Self {
         self.default_expr = default_expr;
@@ -955,6 +1094,16 @@ impl TableField {
         self.default_expr.as_ref()
     }
 
+    #[must_use]
+    pub fn with_computed_expr(mut self, computed_expr: Option<ComputedExpr>) -> Self {
+        self.computed_expr = computed_expr;
+        self
+    }
+
+    pub fn computed_expr(&self) -> Option<&ComputedExpr> {
+        self.computed_expr.as_ref()
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -978,9 +1127,13 @@ impl From<&TableDataType> for DataType {
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Interval => DataType::Interval,
+            TableDataType::TimestampTz => DataType::TimestampTz,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
+            TableDataType::Binary => DataType::Binary,
+            TableDataType::Geometry => DataType::Geometry,
             TableDataType::Bitmap => DataType::Bitmap,
             TableDataType::Tuple { fields_type, .. } => {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
@@ -1017,6 +1170,39 @@ impl TableDataType {
         }
     }
 
+    /// Whether the values of this type can be cast to the new type without loss,
+    /// which is required to modify the data type of a column without rewriting the table.
+    pub fn can_evolve_to(&self, new_type: &TableDataType) -> bool {
+        match (self, new_type) {
+            (from, to) if from == to => true,
+            (TableDataType::Nullable(from), TableDataType::Nullable(to)) => from.can_evolve_to(to),
+            (TableDataType::Nullable(_), _) => false,
+            // the column ids of the inner columns are changed if the nested type is wrapped
+            (TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_), _) => {
+                false
+            }
+            (from, TableDataType::Nullable(to)) => from.can_evolve_to(to),
+            (TableDataType::Number(from), TableDataType::Number(to)) => {
+                match (from.is_float(), to.is_float()) {
+                    (true, true) => to.bit_width() >= from.bit_width(),
+                    // the integers can be represented by the mantissa of the float exactly
+                    (false, true) => to.bit_width() == 64 && from.bit_width() <= 32,
+                    (true, false) => false,
+                    (false, false) => match (from.is_signed(), to.is_signed()) {
+                        (true, true) | (false, false) => to.bit_width() >= from.bit_width(),
+                        (false, true) => to.bit_width() > from.bit_width(),
+                        (true, false) => false,
+                    },
+                }
+            }
+            (TableDataType::Decimal(from), TableDataType::Decimal(to)) => {
+                to.scale() >= from.scale()
+                    && to.precision() - to.scale() >= from.precision() - from.scale()
+            }
+            _ => false,
+        }
+    }
+
     pub fn remove_recursive_nullable(&self) -> Self {
         match self {
             TableDataType::Nullable(ty) => ty.as_ref().remove_recursive_nullable(),
@@ -1176,6 +1362,8 @@ impl From<&ArrowField> for TableField {
             data_type: f.into(),
             default_expr: None,
             column_id: 0,
+            history: vec![],
+            computed_expr: None,
         }
     }
 }
@@ -1215,10 +1403,12 @@ impl From<&ArrowField> for TableDataType {
             | ArrowDataType::FixedSizeList(f, _) =>
                 TableDataType::Array(Box::new(f.as_ref().into())),
 
-            ArrowDataType::Binary
-            | ArrowDataType::LargeBinary
-            | ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8 => TableDataType::String,
+            ArrowDataType::LargeBinary | ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => {
+                TableDataType::String
+            }
+            // Plain BYTE_ARRAY and FIXED_LEN_BYTE_ARRAY columns written by other engines
+            // carry raw bytes, `LargeBinary` is kept as `String` for our own files.
+            ArrowDataType::Binary | ArrowDataType::FixedSizeBinary(_) => TableDataType::Binary,
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
@@ -1238,7 +1428,11 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
+                ARROW_EXT_TYPE_TIMESTAMP_TZ => TableDataType::TimestampTz,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
             },
             // this is safe, because we define the datatype firstly
@@ -1325,6 +1519,16 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
+            DataType::TimestampTz => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1350,6 +1554,16 @@ impl From<&DataType> for ArrowDataType {
                     false,
                 )
             }
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             DataType::Bitmap => ArrowDataType::Extension(
                 ARROW_EXT_TYPE_BITMAP.to_string(),
                 Box::new(ArrowDataType::LargeBinary),
@@ -1405,6 +1619,16 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
+            TableDataType::TimestampTz => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1435,6 +1659,16 @@ impl From<&TableDataType> for ArrowDataType {
                     false,
                 )
             }
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             TableDataType::Bitmap => ArrowDataType::Extension(
                 ARROW_EXT_TYPE_BITMAP.to_string(),
                 Box::new(ArrowDataType::LargeBinary),
@@ -1478,6 +1712,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
+        DataType::TimestampTz => Ok(TableDataType::TimestampTz),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -1487,6 +1723,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Map(inner_type) => {
             Ok(TableDataType::Map(Box::new(infer_schema_type(inner_type)?)))
         }
+        DataType::Binary => Ok(TableDataType::Binary),
+        DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
         DataType::Tuple(fields) => {
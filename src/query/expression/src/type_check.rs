@@ -78,6 +78,27 @@ pub fn check<Index: ColumnIndex>(
                 .try_collect()?;
             check_function(*span, name, params, &args_expr, fn_registry)
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+            return_type,
+        } => {
+            let args: Vec<_> = args
+                .iter()
+                .map(|arg| check(arg, fn_registry))
+                .try_collect()?;
+            Ok(Expr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args,
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            })
+        }
     }
 }
 
@@ -602,6 +623,8 @@ pub fn common_super_type(
 pub fn get_simple_cast_function(is_try: bool, dest_type: &DataType) -> Option<String> {
     let function_name = if dest_type.is_decimal() {
         "to_decimal".to_owned()
+    } else if dest_type == &DataType::TimestampTz {
+        "to_timestamp_tz".to_owned()
     } else {
         format!("to_{}", dest_type.to_string().to_lowercase())
     };
@@ -628,6 +651,10 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float64",
     "to_timestamp",
     "to_date",
+    "to_interval",
+    "to_timestamp_tz",
+    "to_binary",
+    "to_geometry",
     "to_variant",
     "to_boolean",
     "to_decimal",
@@ -14,6 +14,7 @@
 
 pub mod any;
 pub mod array;
+pub mod binary;
 pub mod bitmap;
 pub mod boolean;
 pub mod date;
@@ -21,6 +22,8 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -28,6 +31,7 @@ pub mod number;
 pub mod number_class;
 pub mod string;
 pub mod timestamp;
+pub mod timestamp_tz;
 pub mod variant;
 
 use std::fmt::Debug;
@@ -40,6 +44,7 @@ use serde::Serialize;
 
 pub use self::any::AnyType;
 pub use self::array::ArrayType;
+pub use self::binary::BinaryType;
 pub use self::bitmap::BitmapType;
 pub use self::boolean::BooleanType;
 pub use self::date::DateType;
@@ -48,6 +53,8 @@ use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -55,6 +62,7 @@ pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringType;
 pub use self::timestamp::TimestampType;
+pub use self::timestamp_tz::TimestampTzType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::values::Column;
@@ -71,14 +79,18 @@ pub enum DataType {
     EmptyMap,
     Boolean,
     String,
+    Binary,
     Number(NumberDataType),
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
+    TimestampTz,
     Nullable(Box<DataType>),
     Array(Box<DataType>),
     Map(Box<DataType>),
     Bitmap,
+    Geometry,
     Tuple(Vec<DataType>),
     Variant,
     Generic(usize),
@@ -237,6 +249,7 @@ impl DataType {
                 NumberDataType::Float64 => "DOUBLE".to_string(),
             },
             DataType::String => "VARCHAR".to_string(),
+            DataType::TimestampTz => "TIMESTAMP_TZ".to_string(),
             DataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
             _ => self.to_string().to_uppercase(),
         }
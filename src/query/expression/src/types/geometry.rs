@@ -0,0 +1,296 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use geo::BoundingRect;
+use geo::Geometry;
+use geo::Point;
+use geozero::geojson::GeoJson;
+use geozero::wkb::Wkb;
+use geozero::wkt::WktStr;
+use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToJson;
+use geozero::ToWkb;
+use geozero::ToWkt;
+
+use crate::property::Domain;
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::string::StringIterator;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// Geometries are stored as WKB (Well-Known Binary) in a `StringColumn`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryType;
+
+impl ValueType for GeometryType {
+    type Scalar = Vec<u8>;
+    type ScalarRef<'a> = &'a [u8];
+    type Column = StringColumn;
+    type Domain = GeometryDomain;
+    type ColumnIterator<'a> = StringIterator<'a>;
+    type ColumnBuilder = StringColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_geometry().cloned()
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        col.as_geometry().cloned()
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            crate::ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        domain.as_geometry().cloned()
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Geometry(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Geometry(col)
+    }
+
+    fn upcast_domain(domain: Self::Domain) -> Domain {
+        Domain::Geometry(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.index(index)
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        StringColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.put_slice(item);
+        builder.commit_row();
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.commit_row();
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size<'a>(scalar: &Self::ScalarRef<'a>) -> usize {
+        scalar.len()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.data.len() + col.offsets.len() * 8
+    }
+}
+
+impl ArgType for GeometryType {
+    fn data_type() -> DataType {
+        DataType::Geometry
+    }
+
+    fn full_domain() -> Self::Domain {
+        GeometryDomain::full()
+    }
+
+    fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
+        StringColumnBuilder::with_capacity(capacity, 0)
+    }
+}
+
+/// The bounding box of the geometries in a scalar or a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryDomain {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl GeometryDomain {
+    pub fn full() -> Self {
+        GeometryDomain {
+            min_x: f64::NEG_INFINITY,
+            min_y: f64::NEG_INFINITY,
+            max_x: f64::INFINITY,
+            max_y: f64::INFINITY,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        *self == Self::full()
+    }
+
+    pub fn merge(&self, other: &Self) -> Self {
+        GeometryDomain {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        self.min_x <= other.min_x
+            && self.min_y <= other.min_y
+            && other.max_x <= self.max_x
+            && other.max_y <= self.max_y
+    }
+}
+
+/// Parse a WKT (Well-Known Text) string into WKB.
+pub fn parse_wkt(wkt: &[u8]) -> Result<Vec<u8>, String> {
+    let wkt = std::str::from_utf8(wkt).map_err(|err| err.to_string())?;
+    WktStr(wkt)
+        .to_wkb(CoordDimensions::xy())
+        .map_err(|err| format!("invalid WKT {wkt:?}: {err}"))
+}
+
+/// Parse a GeoJSON geometry into WKB.
+pub fn parse_geojson(json: &[u8]) -> Result<Vec<u8>, String> {
+    let json = std::str::from_utf8(json).map_err(|err| err.to_string())?;
+    GeoJson(json)
+        .to_wkb(CoordDimensions::xy())
+        .map_err(|err| format!("invalid GeoJSON {json:?}: {err}"))
+}
+
+/// Check that the bytes are a valid WKB geometry.
+pub fn check_wkb(wkb: &[u8]) -> Result<(), String> {
+    wkb_to_geo(wkb).map(|_| ())
+}
+
+pub fn wkb_to_wkt(wkb: &[u8]) -> Result<String, String> {
+    Wkb(wkb.to_vec()).to_wkt().map_err(|err| err.to_string())
+}
+
+pub fn wkb_to_geojson(wkb: &[u8]) -> Result<String, String> {
+    Wkb(wkb.to_vec()).to_json().map_err(|err| err.to_string())
+}
+
+pub fn wkb_to_geo(wkb: &[u8]) -> Result<Geometry<f64>, String> {
+    Wkb(wkb.to_vec()).to_geo().map_err(|err| err.to_string())
+}
+
+pub fn geo_to_wkb(geometry: &Geometry<f64>) -> Result<Vec<u8>, String> {
+    geometry
+        .to_wkb(CoordDimensions::xy())
+        .map_err(|err| err.to_string())
+}
+
+/// Return the bounding box of a WKB geometry, or `None` if the geometry
+/// is empty or can not be decoded.
+pub fn geometry_bounding_box(wkb: &[u8]) -> Option<GeometryDomain> {
+    let rect = wkb_to_geo(wkb).ok()?.bounding_rect()?;
+    Some(GeometryDomain {
+        min_x: rect.min().x,
+        min_y: rect.min().y,
+        max_x: rect.max().x,
+        max_y: rect.max().y,
+    })
+}
+
+/// Block statistics of a geometry column keep the bounding box as two points:
+/// `min` is the lower-left corner and `max` is the upper-right corner.
+pub fn bounding_box_to_statistics(domain: &GeometryDomain) -> Option<(Scalar, Scalar)> {
+    let point = |x: f64, y: f64| geo_to_wkb(&Geometry::Point(Point::new(x, y))).ok();
+    Some((
+        Scalar::Geometry(point(domain.min_x, domain.min_y)?),
+        Scalar::Geometry(point(domain.max_x, domain.max_y)?),
+    ))
+}
+
+/// Restore the bounding box from the `min` and `max` block statistics.
+pub fn statistics_to_bounding_box(min: &Scalar, max: &Scalar) -> Option<GeometryDomain> {
+    let point = |scalar: &Scalar| match wkb_to_geo(scalar.as_geometry()?).ok()? {
+        Geometry::Point(point) => Some(point),
+        _ => None,
+    };
+    let (min, max) = (point(min)?, point(max)?);
+    Some(GeometryDomain {
+        min_x: min.x(),
+        min_y: min.y(),
+        max_x: max.x(),
+        max_y: max.y(),
+    })
+}
@@ -0,0 +1,406 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Write;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const DAYS_IN_A_MONTH: i64 = 30;
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+
+/// An interval made of months, days and microseconds, packed into an `i128`
+/// the same way as arrow's `months_days_ns`.
+///
+/// The three parts are kept apart because they can't be converted into each
+/// other without a reference point: a month may have 28 to 31 days, and a day
+/// may have 23 to 25 hours across a daylight saving transition.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        let months = (months as u32 as i128) << 96;
+        let days = (days as u32 as i128) << 64;
+        let micros = micros as u64 as i128;
+        months_days_micros(months | days | micros)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    #[inline]
+    pub fn micros(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// The approximate length of the interval, assuming a month has 30 days
+    /// and a day has 24 hours. It's only used to order intervals.
+    pub fn total_micros(&self) -> i128 {
+        (self.months() as i128 * DAYS_IN_A_MONTH as i128 + self.days() as i128)
+            * MICROS_IN_A_DAY as i128
+            + self.micros() as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(months_days_micros::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.micros().checked_add(other.micros())?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(months_days_micros::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.micros().checked_neg()?,
+        ))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", interval_to_string(*self))
+    }
+}
+
+impl Debug for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Format the interval in ISO-8601 duration format, like `P1Y2M3DT4H5M6.5S`.
+///
+/// Each part carries its own sign, and the microseconds are split into
+/// hours, minutes and seconds with the same sign.
+pub fn interval_to_string(interval: months_days_micros) -> String {
+    let (months, days, micros) = (interval.months(), interval.days(), interval.micros());
+    if months == 0 && days == 0 && micros == 0 {
+        return "PT0S".to_string();
+    }
+
+    let mut buf = String::from("P");
+    let (years, months) = (months / 12, months % 12);
+    if years != 0 {
+        write!(buf, "{years}Y").unwrap();
+    }
+    if months != 0 {
+        write!(buf, "{months}M").unwrap();
+    }
+    if days != 0 {
+        write!(buf, "{days}D").unwrap();
+    }
+    if micros != 0 {
+        buf.push('T');
+        let sign = if micros < 0 { "-" } else { "" };
+        let micros = micros.unsigned_abs();
+        let hours = micros / MICROS_IN_AN_HOUR as u64;
+        let minutes = micros % MICROS_IN_AN_HOUR as u64 / MICROS_IN_A_MINUTE as u64;
+        let seconds = micros % MICROS_IN_A_MINUTE as u64 / MICROS_IN_A_SEC as u64;
+        let fraction = micros % MICROS_IN_A_SEC as u64;
+        if hours != 0 {
+            write!(buf, "{sign}{hours}H").unwrap();
+        }
+        if minutes != 0 {
+            write!(buf, "{sign}{minutes}M").unwrap();
+        }
+        if seconds != 0 || fraction != 0 {
+            write!(buf, "{sign}{seconds}").unwrap();
+            if fraction != 0 {
+                let fraction = format!("{fraction:06}");
+                write!(buf, ".{}", fraction.trim_end_matches('0')).unwrap();
+            }
+            buf.push('S');
+        }
+    }
+    buf
+}
+
+/// Parse an ISO-8601 duration, like `P1Y2M3DT4H5M6.5S`, into an interval.
+///
+/// Every part may be signed, and a leading `-` negates the whole interval.
+/// Only the seconds may have a fraction, which is truncated to microseconds.
+pub fn string_to_interval(s: &[u8]) -> Result<months_days_micros, String> {
+    let err = || {
+        format!(
+            "invalid interval '{}', expected an ISO-8601 duration like 'P1Y2M3DT4H5M6S'",
+            String::from_utf8_lossy(s)
+        )
+    };
+    let overflow = || format!("interval '{}' is out of range", String::from_utf8_lossy(s));
+
+    let text = std::str::from_utf8(s).map_err(|_| err())?.trim();
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let mut rest = text
+        .strip_prefix('P')
+        .or_else(|| text.strip_prefix('p'))
+        .ok_or_else(err)?;
+    if rest.is_empty() {
+        return Err(err());
+    }
+
+    let (mut months, mut days, mut micros) = (0i64, 0i64, 0i64);
+    let mut in_time = false;
+    while !rest.is_empty() {
+        if !in_time && (rest.starts_with('T') || rest.starts_with('t')) {
+            in_time = true;
+            rest = &rest[1..];
+            if rest.is_empty() {
+                return Err(err());
+            }
+            continue;
+        }
+
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '-' || c == '+' || c == '.'))
+            .ok_or_else(err)?;
+        let (number, unit) = (&rest[..end], rest[end..].chars().next().unwrap());
+        rest = &rest[end + unit.len_utf8()..];
+
+        let unit = unit.to_ascii_uppercase();
+        if number.contains('.') {
+            // Only the seconds may have a fraction.
+            if !in_time || unit != 'S' {
+                return Err(err());
+            }
+            let value = number.parse::<f64>().map_err(|_| err())?;
+            let value = (value * MICROS_IN_A_SEC as f64).trunc();
+            if !value.is_finite() || value.abs() > i64::MAX as f64 {
+                return Err(overflow());
+            }
+            micros = micros.checked_add(value as i64).ok_or_else(overflow)?;
+            continue;
+        }
+
+        let value = number.parse::<i64>().map_err(|_| err())?;
+        let (part, factor) = match (in_time, unit) {
+            (false, 'Y') => (&mut months, 12),
+            (false, 'M') => (&mut months, 1),
+            (false, 'W') => (&mut days, 7),
+            (false, 'D') => (&mut days, 1),
+            (true, 'H') => (&mut micros, MICROS_IN_AN_HOUR),
+            (true, 'M') => (&mut micros, MICROS_IN_A_MINUTE),
+            (true, 'S') => (&mut micros, MICROS_IN_A_SEC),
+            _ => return Err(err()),
+        };
+        *part = value
+            .checked_mul(factor)
+            .and_then(|value| part.checked_add(value))
+            .ok_or_else(overflow)?;
+    }
+
+    let months = i32::try_from(months).map_err(|_| overflow())?;
+    let days = i32::try_from(days).map_err(|_| overflow())?;
+    let interval = months_days_micros::new(months, days, micros);
+    if negative {
+        interval.checked_neg().ok_or_else(overflow)
+    } else {
+        Ok(interval)
+    }
+}
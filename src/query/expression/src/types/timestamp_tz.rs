@@ -0,0 +1,288 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::ops::Range;
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_arrow::arrow::buffer::Buffer;
+use common_io::cursor_ext::BufferReadDateTimeExt;
+use common_io::cursor_ext::ReadBytesExt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::check_timestamp;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const TIMESTAMP_TZ_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f%:z";
+
+/// A point in time together with the UTC offset it was written in, packed
+/// into an `i128`: the UTC microseconds in the low 64 bits and the offset in
+/// seconds in the high 64 bits.
+///
+/// Two values are ordered by the point in time, so `2023-01-01 08:00:00+08:00`
+/// and `2023-01-01 00:00:00+00:00` are the same instant, but they are kept
+/// apart when stored and displayed.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct timestamp_tz(pub i128);
+
+impl timestamp_tz {
+    pub fn new(micros: i64, offset: i32) -> Self {
+        let offset = (offset as i128) << 64;
+        let micros = micros as u64 as i128;
+        timestamp_tz(offset | micros)
+    }
+
+    /// The UTC microseconds since the unix epoch.
+    #[inline]
+    pub fn micros(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// The UTC offset in seconds, east of UTC is positive.
+    #[inline]
+    pub fn offset(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    /// The microseconds of the wall clock time in its own offset, as if it
+    /// were in UTC.
+    #[inline]
+    pub fn local_micros(&self) -> i64 {
+        self.micros() + self.offset() as i64 * MICROS_IN_A_SEC
+    }
+
+    pub fn to_datetime(&self) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east_opt(self.offset()).unwrap();
+        let secs = self.micros().div_euclid(MICROS_IN_A_SEC);
+        let nanos = self.micros().rem_euclid(MICROS_IN_A_SEC) as u32 * 1000;
+        offset.timestamp_opt(secs, nanos).unwrap()
+    }
+}
+
+impl PartialOrd for timestamp_tz {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for timestamp_tz {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.micros()
+            .cmp(&other.micros())
+            .then_with(|| self.offset().cmp(&other.offset()))
+    }
+}
+
+impl Display for timestamp_tz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", timestamp_tz_to_string(*self))
+    }
+}
+
+impl Debug for timestamp_tz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = timestamp_tz;
+    type ScalarRef<'a> = timestamp_tz;
+    type Column = Buffer<timestamp_tz>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, timestamp_tz>>;
+    type ColumnBuilder = Vec<timestamp_tz>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: timestamp_tz) -> timestamp_tz {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+#[inline]
+pub fn timestamp_tz_to_string(ts: timestamp_tz) -> impl Display {
+    ts.to_datetime().format(TIMESTAMP_TZ_FORMAT)
+}
+
+/// Parse a timestamp keeping the UTC offset written in it, like
+/// `2023-06-01 08:00:00+08:00`. The offset of `tz` is used if there is none.
+pub fn string_to_timestamp_tz(ts_str: impl AsRef<[u8]>, tz: Tz) -> Option<timestamp_tz> {
+    let mut reader = Cursor::new(ts_str.as_ref());
+    let dt = reader.read_timestamp_tz_text(&tz).ok()?;
+    reader.must_eof().ok()?;
+    let micros = check_timestamp(dt.timestamp_micros()).ok()?;
+    Some(timestamp_tz::new(micros, dt.offset().local_minus_utc()))
+}
+
+/// Attach the offset of `tz` at the given point in time.
+pub fn timestamp_to_timestamp_tz(micros: i64, tz: Tz) -> timestamp_tz {
+    let secs = micros.div_euclid(MICROS_IN_A_SEC);
+    let nanos = micros.rem_euclid(MICROS_IN_A_SEC) as u32 * 1000;
+    let offset = tz.timestamp_opt(secs, nanos).unwrap().offset().fix();
+    timestamp_tz::new(micros, offset.local_minus_utc())
+}
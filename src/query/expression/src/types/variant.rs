@@ -15,8 +15,11 @@
 use std::ops::Range;
 
 use super::date::date_to_string;
+use super::geometry::wkb_to_geojson;
+use super::interval::interval_to_string;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
+use super::timestamp_tz::timestamp_tz_to_string;
 use crate::date_helper::TzLUT;
 use crate::property::Domain;
 use crate::types::map::KvPair;
@@ -192,8 +195,22 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::Decimal(x) => x.to_float64().into(),
         ScalarRef::Boolean(b) => jsonb::Value::Bool(b),
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
+        ScalarRef::Binary(s) => jsonb::Value::String(hex::encode_upper(s).into()),
+        ScalarRef::Geometry(g) => {
+            // Geometries are converted into GeoJSON objects.
+            match wkb_to_geojson(g) {
+                Ok(json) => {
+                    let value = jsonb::parse_value(json.as_bytes()).unwrap();
+                    value.write_to_vec(buf);
+                }
+                Err(_) => jsonb::Value::Null.write_to_vec(buf),
+            }
+            return;
+        }
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => interval_to_string(i).into(),
+        ScalarRef::TimestampTz(ts) => timestamp_tz_to_string(ts).to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -206,11 +223,14 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                 .map(|(k, v)| {
                     let key = match k {
                         ScalarRef::String(v) => unsafe { String::from_utf8_unchecked(v.to_vec()) },
+                        ScalarRef::Binary(v) => hex::encode_upper(v),
                         ScalarRef::Number(v) => v.to_string(),
                         ScalarRef::Decimal(v) => v.to_string(),
                         ScalarRef::Boolean(v) => v.to_string(),
                         ScalarRef::Timestamp(v) => timestamp_to_string(v, inner_tz).to_string(),
                         ScalarRef::Date(v) => date_to_string(v, inner_tz).to_string(),
+                        ScalarRef::Interval(v) => interval_to_string(v),
+                        ScalarRef::TimestampTz(v) => timestamp_tz_to_string(v).to_string(),
                         _ => unreachable!(),
                     };
                     let mut val = vec![];
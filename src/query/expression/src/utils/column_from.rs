@@ -56,7 +56,11 @@ macro_rules! for_common_scalar_values {
             { StringType },
             { DateType },
             { TimestampType },
+            { IntervalType },
+            { TimestampTzType },
             { VariantType },
+            { BinaryType },
+            { GeometryType },
             { BitmapType }
         }
     };
@@ -115,6 +119,15 @@ impl<'a, D: AsRef<[&'a str]>> FromData<D, [Vec<u8>; 2]> for StringType {
     }
 }
 
+impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for BinaryType {
+    fn from_data(d: D) -> Column {
+        BinaryType::upcast_column(BinaryType::column_from_ref_iter(
+            d.as_ref().iter().copied(),
+            &[],
+        ))
+    }
+}
+
 impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for BitmapType {
     fn from_data(d: D) -> Column {
         BitmapType::upcast_column(BitmapType::column_from_ref_iter(
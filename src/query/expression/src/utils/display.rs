@@ -40,6 +40,9 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::wkb_to_wkt;
+use crate::types::geometry::GeometryDomain;
+use crate::types::interval::interval_to_string;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -50,6 +53,7 @@ use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
 use crate::types::timestamp::timestamp_to_string;
+use crate::types::timestamp_tz::timestamp_tz_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::ValueType;
@@ -125,6 +129,8 @@ impl<'a> Debug for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
+            ScalarRef::TimestampTz(t) => write!(f, "{t:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -157,6 +163,11 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Binary(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Geometry(s) => match wkb_to_wkt(s) {
+                Ok(wkt) => write!(f, "{wkt:?}"),
+                Err(_) => write!(f, "0x{}", &hex::encode(s)),
+            },
         }
     }
 }
@@ -173,8 +184,12 @@ impl Debug for Column {
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
+            Column::TimestampTz(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
             Column::Bitmap(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
@@ -204,6 +219,8 @@ impl<'a> Display for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "'{}'", interval_to_string(*i)),
+            ScalarRef::TimestampTz(t) => write!(f, "'{}'", timestamp_tz_to_string(*t)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -239,6 +256,11 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Binary(s) => write!(f, "{}", &hex::encode_upper(s)),
+            ScalarRef::Geometry(s) => match wkb_to_wkt(s) {
+                Ok(wkt) => write!(f, "{wkt}"),
+                Err(_) => write!(f, "{}", &hex::encode_upper(s)),
+            },
         }
     }
 }
@@ -430,6 +452,18 @@ impl<Index: ColumnIndex> Display for RawExpr<Index> {
                 }
                 write!(f, ")")
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                write!(f, "{lambda_display})")
+            }
         }
     }
 }
@@ -443,6 +477,8 @@ impl Display for DataType {
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
+            DataType::TimestampTz => write!(f, "TimestampTz"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -454,6 +490,8 @@ impl Display for DataType {
                 }
                 _ => unreachable!(),
             },
+            DataType::Binary => write!(f, "Binary"),
+            DataType::Geometry => write!(f, "Geometry"),
             DataType::Bitmap => write!(f, "Bitmap"),
             DataType::Tuple(tys) => {
                 write!(f, "Tuple(")?;
@@ -483,6 +521,8 @@ impl Display for TableDataType {
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Interval => write!(f, "Interval"),
+            TableDataType::TimestampTz => write!(f, "TimestampTz"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -497,6 +537,8 @@ impl Display for TableDataType {
                 }
                 _ => unreachable!(),
             },
+            TableDataType::Binary => write!(f, "Binary"),
+            TableDataType::Geometry => write!(f, "Geometry"),
             TableDataType::Bitmap => write!(f, "Bitmap"),
             TableDataType::Tuple {
                 fields_name,
@@ -614,6 +656,18 @@ impl<Index: ColumnIndex> Display for Expr<Index> {
                 }
                 write!(f, ")")
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                write!(f, "{lambda_display})")
+            }
         }
     }
 }
@@ -746,6 +800,23 @@ impl<Index: ColumnIndex> Expr<Index> {
                         s
                     }
                 },
+                Expr::LambdaFunctionCall {
+                    name,
+                    args,
+                    lambda_display,
+                    ..
+                } => {
+                    let mut s = String::new();
+                    s += name;
+                    s += "(";
+                    for arg in args {
+                        s += &arg.sql_display();
+                        s += ", ";
+                    }
+                    s += lambda_display;
+                    s += ")";
+                    s
+                }
             }
         }
 
@@ -853,6 +924,19 @@ impl Display for StringDomain {
     }
 }
 
+impl Display for GeometryDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{({}, {})..=({}, {})}}",
+            display_f64(self.min_x),
+            display_f64(self.min_y),
+            display_f64(self.max_x),
+            display_f64(self.max_y)
+        )
+    }
+}
+
 impl Display for NumberDomain {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         with_integer_mapped_type!(|TYPE| match self {
@@ -919,6 +1003,7 @@ impl Display for Domain {
             Domain::Map(Some((key_domain, val_domain))) => {
                 write!(f, "{{[{key_domain}], [{val_domain}]}}")
             }
+            Domain::Geometry(domain) => write!(f, "{domain}"),
             Domain::Undefined => write!(f, "Undefined"),
         }
     }
@@ -45,6 +45,7 @@ use serde::Serializer;
 use crate::property::Domain;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::DATE_MAX;
@@ -55,6 +56,12 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::geo_to_wkb;
+use crate::types::geometry::geometry_bounding_box;
+use crate::types::geometry::GeometryDomain;
+use crate::types::geometry::GeometryType;
+use crate::types::interval::months_days_micros;
+use crate::types::interval::IntervalType;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -70,6 +77,8 @@ use crate::types::string::StringDomain;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::timestamp_tz::timestamp_tz;
+use crate::types::timestamp_tz::TimestampTzType;
 use crate::types::variant::JSONB_NULL;
 use crate::types::*;
 use crate::utils::arrow::append_bitmap;
@@ -112,6 +121,12 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    // Variants below are appended to keep the serialized form of the column
+    // statistics in existing segments stable, new variants must go last.
+    Interval(months_days_micros),
+    TimestampTz(timestamp_tz),
+    Binary(Vec<u8>),
+    Geometry(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -131,6 +146,10 @@ pub enum ScalarRef<'a> {
     Bitmap(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Interval(months_days_micros),
+    TimestampTz(timestamp_tz),
+    Binary(&'a [u8]),
+    Geometry(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -150,6 +169,10 @@ pub enum Column {
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
+    Interval(Buffer<months_days_micros>),
+    TimestampTz(Buffer<timestamp_tz>),
+    Binary(StringColumn),
+    Geometry(StringColumn),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -169,6 +192,10 @@ pub enum ColumnBuilder {
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
+    Interval(Vec<months_days_micros>),
+    TimestampTz(Vec<timestamp_tz>),
+    Binary(StringColumnBuilder),
+    Geometry(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -308,8 +335,12 @@ impl Scalar {
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
+            Scalar::TimestampTz(t) => ScalarRef::TimestampTz(*t),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
+            Scalar::Binary(b) => ScalarRef::Binary(b.as_slice()),
+            Scalar::Geometry(b) => ScalarRef::Geometry(b.as_slice()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
@@ -338,6 +369,8 @@ impl Scalar {
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Interval => Scalar::Interval(months_days_micros::default()),
+            DataType::TimestampTz => Scalar::TimestampTz(timestamp_tz::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -349,6 +382,8 @@ impl Scalar {
                 let col = builder.build();
                 Scalar::Map(col)
             }
+            DataType::Binary => Scalar::Binary(vec![]),
+            DataType::Geometry => Scalar::Geometry(vec![]),
             DataType::Bitmap => Scalar::Bitmap(vec![]),
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
@@ -380,8 +415,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
+            ScalarRef::TimestampTz(t) => Scalar::TimestampTz(*t),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
+            ScalarRef::Binary(b) => Scalar::Binary(b.to_vec()),
+            ScalarRef::Geometry(b) => Scalar::Geometry(b.to_vec()),
             ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
             ScalarRef::Tuple(fields) => {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
@@ -455,7 +494,14 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Geometry(g) => {
+                Domain::Geometry(geometry_bounding_box(g).unwrap_or_else(GeometryDomain::full))
+            }
+            ScalarRef::Interval(_)
+            | ScalarRef::TimestampTz(_)
+            | ScalarRef::Binary(_)
+            | ScalarRef::Bitmap(_)
+            | ScalarRef::Variant(_) => Domain::Undefined,
         }
     }
 
@@ -478,8 +524,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Interval(_) => 16,
+            ScalarRef::TimestampTz(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
+            ScalarRef::Binary(b) => b.len(),
+            ScalarRef::Geometry(b) => b.len(),
             ScalarRef::Bitmap(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
@@ -505,8 +555,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(_) => DataType::String,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Interval(_) => DataType::Interval,
+            ScalarRef::TimestampTz(_) => DataType::TimestampTz,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
+            ScalarRef::Binary(_) => DataType::Binary,
+            ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Bitmap(_) => DataType::Bitmap,
             ScalarRef::Tuple(fields) => {
                 let inner = fields
@@ -530,8 +584,12 @@ impl PartialOrd for Scalar {
             (Scalar::Decimal(d1), Scalar::Decimal(d2)) => d1.partial_cmp(d2),
             (Scalar::Boolean(b1), Scalar::Boolean(b2)) => b1.partial_cmp(b2),
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
+            (Scalar::Binary(b1), Scalar::Binary(b2)) => b1.partial_cmp(b2),
+            (Scalar::Geometry(g1), Scalar::Geometry(g2)) => g1.partial_cmp(g2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
+            (Scalar::TimestampTz(t1), Scalar::TimestampTz(t2)) => t1.partial_cmp(t2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => {
@@ -570,8 +628,12 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Decimal(d1), ScalarRef::Decimal(d2)) => d1.partial_cmp(d2),
             (ScalarRef::Boolean(b1), ScalarRef::Boolean(b2)) => b1.partial_cmp(b2),
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
+            (ScalarRef::Binary(b1), ScalarRef::Binary(b2)) => b1.partial_cmp(b2),
+            (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => g1.partial_cmp(g2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::TimestampTz(t1), ScalarRef::TimestampTz(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => {
@@ -616,6 +678,8 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::String(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
+            ScalarRef::TimestampTz(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
                 str.hash(state);
@@ -624,6 +688,8 @@ impl Hash for ScalarRef<'_> {
                 let str = serialize_column(v);
                 str.hash(state);
             }
+            ScalarRef::Binary(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
             ScalarRef::Bitmap(v) => v.hash(state),
             ScalarRef::Tuple(v) => {
                 v.hash(state);
@@ -653,10 +719,20 @@ impl PartialOrd for Column {
             (Column::Decimal(col1), Column::Decimal(col2)) => col1.partial_cmp(col2),
             (Column::Boolean(col1), Column::Boolean(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::String(col1), Column::String(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Timestamp(col1), Column::Timestamp(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::TimestampTz(col1), Column::TimestampTz(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1
@@ -689,6 +765,10 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_BINARY: &str = "Binary";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
+pub const ARROW_EXT_TYPE_TIMESTAMP_TZ: &str = "TimestampTz";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -702,8 +782,12 @@ impl Column {
             Column::String(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Interval(col) => col.len(),
+            Column::TimestampTz(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
+            Column::Binary(col) => col.len(),
+            Column::Geometry(col) => col.len(),
             Column::Bitmap(col) => col.len(),
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
@@ -722,8 +806,12 @@ impl Column {
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
+            Column::TimestampTz(col) => Some(ScalarRef::TimestampTz(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
+            Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
             Column::Nullable(col) => Some(col.index(index)?.unwrap_or(ScalarRef::Null)),
             Column::Tuple(fields) => Some(ScalarRef::Tuple(
@@ -750,8 +838,12 @@ impl Column {
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
+            Column::TimestampTz(col) => ScalarRef::TimestampTz(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
+            Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
             Column::Nullable(col) => col.index_unchecked(index).unwrap_or(ScalarRef::Null),
             Column::Tuple(fields) => ScalarRef::Tuple(
@@ -799,8 +891,16 @@ impl Column {
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::TimestampTz(col) => {
+                Column::TimestampTz(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
+            Column::Binary(col) => Column::Binary(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
             Column::Nullable(col) => Column::Nullable(Box::new(col.slice(range))),
             Column::Tuple(fields) => Column::Tuple(
@@ -890,7 +990,17 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Geometry(col) => Domain::Geometry(
+                col.iter()
+                    .filter_map(geometry_bounding_box)
+                    .reduce(|acc, domain| acc.merge(&domain))
+                    .unwrap_or_else(GeometryDomain::full),
+            ),
+            Column::Interval(_)
+            | Column::TimestampTz(_)
+            | Column::Binary(_)
+            | Column::Bitmap(_)
+            | Column::Variant(_) => Domain::Undefined,
         }
     }
 
@@ -910,6 +1020,8 @@ impl Column {
             Column::String(_) => DataType::String,
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Interval(_) => DataType::Interval,
+            Column::TimestampTz(_) => DataType::TimestampTz,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -918,6 +1030,8 @@ impl Column {
                 let inner = col.values.data_type();
                 DataType::Map(Box::new(inner))
             }
+            Column::Binary(_) => DataType::Binary,
+            Column::Geometry(_) => DataType::Geometry,
             Column::Bitmap(_) => DataType::Bitmap,
             Column::Nullable(inner) => {
                 let inner = inner.column.data_type();
@@ -1088,6 +1202,28 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values = col.iter().map(|v| v.0).collect::<Vec<_>>();
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type,
+                        values.into(),
+                        None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::TimestampTz(col) => {
+                let values = col.iter().map(|v| v.0).collect::<Vec<_>>();
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type,
+                        values.into(),
+                        None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -1128,7 +1264,7 @@ impl Column {
                     .unwrap(),
                 )
             }
-            Column::Bitmap(col) => {
+            Column::Binary(col) | Column::Geometry(col) | Column::Bitmap(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1421,6 +1557,33 @@ impl Column {
                     .values()
                     .clone(),
             ),
+            // the extension name is lost when the array round-trips through arrow-rs
+            ArrowDataType::Extension(_, _, None) | ArrowDataType::Decimal(38, 0)
+                if data_type == DataType::Interval =>
+            {
+                let values = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
+                    .expect("fail to read from arrow: array should be `PrimitiveArray<i128>`")
+                    .values()
+                    .iter()
+                    .map(|v| months_days_micros(*v))
+                    .collect::<Vec<_>>();
+                Column::Interval(values.into())
+            }
+            ArrowDataType::Extension(_, _, None) | ArrowDataType::Decimal(38, 0)
+                if data_type == DataType::TimestampTz =>
+            {
+                let values = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
+                    .expect("fail to read from arrow: array should be `PrimitiveArray<i128>`")
+                    .values()
+                    .iter()
+                    .map(|v| timestamp_tz(*v))
+                    .collect::<Vec<_>>();
+                Column::TimestampTz(values.into())
+            }
             ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_VARIANT => {
                 let arrow_col = arrow_col
                     .as_any()
@@ -1556,9 +1719,55 @@ impl Column {
                     offsets,
                 })
             }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_BINARY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Binary(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Geometry(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
+            ArrowDataType::FixedSizeBinary(size) => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::FixedSizeBinaryArray>()
+                    .expect("fail to read from arrow: array should be `FixedSizeBinaryArray`");
+                let offsets = (0..arrow_col.len() + 1)
+                    .map(|i| (i * size) as u64)
+                    .collect::<Vec<_>>();
+                Column::Binary(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets: offsets.into(),
+                })
+            }
             ty => unimplemented!("unsupported arrow type {ty:?}"),
         };
 
+        // Parquet `BYTE_ARRAY` columns without the `UTF8` annotation are read as plain
+        // binary arrays, which share the layout of strings.
+        let column = match column {
+            Column::String(col) if data_type == DataType::Binary => Column::Binary(col),
+            column => column,
+        };
+
         if is_nullable {
             let validity = arrow_col.validity().cloned().unwrap_or_else(|| {
                 let mut validity = MutableBitmap::with_capacity(arrow_col.len());
@@ -1577,6 +1786,7 @@ impl Column {
         use jsonb::Value as JsonbValue;
         use rand::distributions::Alphanumeric;
         use rand::distributions::DistString;
+        use rand::distributions::Standard;
         use rand::rngs::SmallRng;
         use rand::Rng;
         use rand::SeedableRng;
@@ -1624,6 +1834,29 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        months_days_micros::new(
+                            rng.gen_range(-1200..=1200),
+                            rng.gen_range(-366..=366),
+                            rng.gen_range(-86_400_000_000..=86_400_000_000),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::TimestampTz => TimestampTzType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        timestamp_tz::new(
+                            rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX),
+                            rng.gen_range(-14 * 3600..=14 * 3600),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len),
                 validity: Bitmap::from(
@@ -1658,6 +1891,17 @@ impl Column {
                     offsets: offsets.into(),
                 }))
             }
+            DataType::Binary => BinaryType::from_data((0..len).map(|_| {
+                let rng = SmallRng::from_entropy();
+                rng.sample_iter(Standard)
+                    // randomly generate 5 bytes.
+                    .take(5)
+                    .collect::<Vec<u8>>()
+            })),
+            DataType::Geometry => GeometryType::from_data((0..len).map(|_| {
+                let (x, y): (f64, f64) = SmallRng::from_entropy().gen();
+                geo_to_wkb(&geo::Geometry::Point(geo::Point::new(x, y))).unwrap()
+            })),
             DataType::Bitmap => BitmapType::from_data((0..len).map(|_| {
                 let data: [u64; 4] = SmallRng::from_entropy().gen();
                 let rb = RoaringTreemap::from_iter(data.iter());
@@ -1774,8 +2018,12 @@ impl Column {
             Column::String(col) => col.data.len() + col.offsets.len() * 8,
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Interval(col) => col.len() * 16,
+            Column::TimestampTz(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
+            Column::Binary(col) => col.data.len() + col.offsets.len() * 8,
+            Column::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             Column::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
@@ -1849,12 +2097,16 @@ impl ColumnBuilder {
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
+            Column::TimestampTz(col) => ColumnBuilder::TimestampTz(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
             Column::Map(box col) => {
                 ColumnBuilder::Map(Box::new(ArrayColumnBuilder::from_column(col)))
             }
+            Column::Binary(col) => ColumnBuilder::Binary(StringColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
             Column::Bitmap(col) => ColumnBuilder::Bitmap(StringColumnBuilder::from_column(col)),
             Column::Nullable(box col) => {
                 ColumnBuilder::Nullable(Box::new(NullableColumnBuilder::from_column(col)))
@@ -1906,8 +2158,12 @@ impl ColumnBuilder {
             }
             ScalarRef::Boolean(b) => ColumnBuilder::Boolean(constant_bitmap(*b, n)),
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Binary(b) => ColumnBuilder::Binary(StringColumnBuilder::repeat(b, n)),
+            ScalarRef::Geometry(g) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(g, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Interval(d) => ColumnBuilder::Interval(vec![*d; n]),
+            ScalarRef::TimestampTz(d) => ColumnBuilder::TimestampTz(vec![*d; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
@@ -1948,8 +2204,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
+            ColumnBuilder::TimestampTz(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
+            ColumnBuilder::Binary(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
@@ -1982,8 +2242,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Interval(col) => col.len() * 16,
+            ColumnBuilder::TimestampTz(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
+            ColumnBuilder::Binary(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
@@ -2007,6 +2271,8 @@ impl ColumnBuilder {
             ColumnBuilder::String(_) => DataType::String,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Interval(_) => DataType::Interval,
+            ColumnBuilder::TimestampTz(_) => DataType::TimestampTz,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -2015,6 +2281,8 @@ impl ColumnBuilder {
                 let inner = col.builder.data_type();
                 DataType::Map(Box::new(inner))
             }
+            ColumnBuilder::Binary(_) => DataType::Binary,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
             ColumnBuilder::Bitmap(_) => DataType::Bitmap,
             ColumnBuilder::Nullable(col) => DataType::Nullable(Box::new(col.builder.data_type())),
             ColumnBuilder::Tuple(fields) => {
@@ -2052,6 +2320,8 @@ impl ColumnBuilder {
             }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -2083,6 +2353,14 @@ impl ColumnBuilder {
                         .collect(),
                 )
             }
+            DataType::Binary => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Binary(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Bitmap => {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Bitmap(StringColumnBuilder::with_capacity(capacity, data_capacity))
@@ -2105,7 +2383,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Number(builder), ScalarRef::Number(value)) => builder.push(value),
             (ColumnBuilder::Decimal(builder), ScalarRef::Decimal(value)) => builder.push(value),
             (ColumnBuilder::Boolean(builder), ScalarRef::Boolean(value)) => builder.push(value),
-            (ColumnBuilder::String(builder), ScalarRef::String(value)) => {
+            (ColumnBuilder::String(builder), ScalarRef::String(value))
+            | (ColumnBuilder::Binary(builder), ScalarRef::Binary(value))
+            | (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
                 builder.put_slice(value);
                 builder.commit_row();
             }
@@ -2113,6 +2393,10 @@ impl ColumnBuilder {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => builder.push(value),
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                builder.push(value)
+            }
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -2154,8 +2438,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(months_days_micros::default()),
+            ColumnBuilder::TimestampTz(builder) => builder.push(timestamp_tz::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
+            ColumnBuilder::Binary(builder) => builder.commit_row(),
+            ColumnBuilder::Geometry(builder) => builder.commit_row(),
             ColumnBuilder::Bitmap(builder) => builder.commit_row(),
             ColumnBuilder::Nullable(builder) => builder.push_null(),
             ColumnBuilder::Tuple(fields) => {
@@ -2192,6 +2480,8 @@ impl ColumnBuilder {
                 builder.push(v);
             }
             ColumnBuilder::String(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Geometry(builder)
             | ColumnBuilder::Variant(builder)
             | ColumnBuilder::Bitmap(builder) => {
                 let offset: u64 = reader.read_uvarint()?;
@@ -2209,6 +2499,12 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                builder.push(months_days_micros(i128::de_binary(reader)));
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                builder.push(timestamp_tz(i128::de_binary(reader)));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_uvarint()?;
                 for _ in 0..len {
@@ -2278,6 +2574,8 @@ impl ColumnBuilder {
                 }
             }
             ColumnBuilder::String(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Geometry(builder)
             | ColumnBuilder::Variant(builder)
             | ColumnBuilder::Bitmap(builder) => {
                 for row in 0..rows {
@@ -2301,6 +2599,18 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(months_days_micros(i128::de_binary(&mut reader)));
+                }
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(timestamp_tz(i128::de_binary(&mut reader)));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2373,8 +2683,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
+            ColumnBuilder::TimestampTz(builder) => builder.pop().map(Scalar::TimestampTz),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
+            ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
             ColumnBuilder::Nullable(builder) => Some(builder.pop()?.unwrap_or(Scalar::Null)),
             ColumnBuilder::Tuple(fields) => {
@@ -2416,6 +2730,12 @@ impl ColumnBuilder {
             (ColumnBuilder::String(builder), Column::String(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Binary(builder), Column::Binary(other)) => {
+                builder.append_column(other);
+            }
+            (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
                 builder.append_column(other);
             }
@@ -2425,6 +2745,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::TimestampTz(builder), Column::TimestampTz(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2458,8 +2784,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
+            ColumnBuilder::TimestampTz(builder) => Column::TimestampTz(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
+            ColumnBuilder::Binary(builder) => Column::Binary(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
             ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
             ColumnBuilder::Tuple(fields) => {
@@ -2482,8 +2812,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
+            ColumnBuilder::TimestampTz(builder) => Scalar::TimestampTz(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
+            ColumnBuilder::Binary(builder) => Scalar::Binary(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
             ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
             ColumnBuilder::Tuple(fields) => Scalar::Tuple(
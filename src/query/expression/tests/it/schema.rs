@@ -527,6 +527,71 @@ fn test_schema_modify_field() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_schema_modify_column() -> Result<()> {
+    let field1 = TableField::new("a", TableDataType::Number(NumberDataType::Int32));
+    let field2 = TableField::new("b", TableDataType::String);
+    let mut schema = TableSchema::new(vec![field1, field2]);
+
+    // modify the default expr only, the column id is not changed
+    schema.modify_column(
+        &TableField::new("b", TableDataType::String).with_default_expr(Some("'b'".to_string())),
+    )?;
+    assert_eq!(schema.column_id_of("b").unwrap(), 1);
+    assert_eq!(schema.field(1).default_expr(), Some(&"'b'".to_string()));
+    assert_eq!(schema.next_column_id(), 2);
+
+    // widen column a
+    schema.modify_column(&TableField::new(
+        "a",
+        TableDataType::Number(NumberDataType::Int64),
+    ))?;
+    assert_eq!(schema.column_id_of("a").unwrap(), 2);
+    assert_eq!(schema.field(0).history(), &[(
+        0,
+        TableDataType::Number(NumberDataType::Int32)
+    )]);
+    assert_eq!(schema.is_column_deleted(0), false);
+    assert_eq!(schema.to_leaf_column_ids(), vec![2, 1]);
+    assert_eq!(schema.next_column_id(), 3);
+
+    // make column a nullable
+    schema.modify_column(&TableField::new(
+        "a",
+        TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64))),
+    ))?;
+    assert_eq!(schema.column_id_of("a").unwrap(), 3);
+    assert_eq!(schema.field(0).history(), &[
+        (2, TableDataType::Number(NumberDataType::Int64)),
+        (0, TableDataType::Number(NumberDataType::Int32)),
+    ]);
+    assert_eq!(schema.is_column_deleted(0), false);
+    assert_eq!(schema.is_column_deleted(2), false);
+
+    // narrow column a
+    assert!(
+        schema
+            .modify_column(&TableField::new(
+                "a",
+                TableDataType::Number(NumberDataType::Int64),
+            ))
+            .is_err()
+    );
+    assert!(
+        schema
+            .modify_column(&TableField::new("b", TableDataType::Boolean))
+            .is_err()
+    );
+
+    // the previous columns are deleted with the column
+    schema.drop_column("a")?;
+    assert_eq!(schema.is_column_deleted(0), true);
+    assert_eq!(schema.is_column_deleted(2), true);
+    assert_eq!(schema.is_column_deleted(3), true);
+
+    Ok(())
+}
+
 #[test]
 fn test_leaf_columns_of() -> Result<()> {
     let fields = vec![
@@ -26,10 +26,15 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::parse_geojson;
+use common_expression::types::geometry::parse_wkt;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp_tz::timestamp_tz;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
 use common_expression::with_decimal_type;
@@ -82,7 +87,11 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
@@ -186,6 +195,37 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => {
+                let bytes = hex::decode(s).map_err(|_| {
+                    ErrorCode::BadBytes(format!("Invalid hex value for BINARY: {s:?}"))
+                })?;
+                column.put_slice(&bytes);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
+    fn read_geometry(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        // Accept both WKT strings and GeoJSON objects.
+        let wkb = match value {
+            Value::String(s) => parse_wkt(s.as_bytes()),
+            Value::Object(_) => parse_geojson(value.to_string().as_bytes()),
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "Incorrect json value, must be string or object",
+                ));
+            }
+        }
+        .map_err(ErrorCode::BadBytes)?;
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -234,6 +274,32 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let interval = string_to_interval(v.as_bytes()).map_err(ErrorCode::BadBytes)?;
+                column.push(interval);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect interval value")),
+        }
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<timestamp_tz>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let mut reader = Cursor::new(v.as_bytes());
+                let dt = reader.read_timestamp_tz_text(&self.timezone)?;
+
+                let micros = dt.timestamp_micros();
+                check_timestamp(micros)?;
+                column.push(timestamp_tz::new(micros, dt.offset().local_minus_utc()));
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect timestamp_tz value")),
+        }
+    }
+
     fn read_variant(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
         let v = jsonb::Value::from(value);
         v.write_to_vec(&mut column.data);
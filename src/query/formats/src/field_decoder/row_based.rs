@@ -28,10 +28,14 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::parse_wkt;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp_tz::timestamp_tz;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
 use common_expression::with_decimal_type;
@@ -87,9 +91,13 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, raw),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, raw),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader, raw),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader, raw),
             ColumnBuilder::String(c) => self.read_string(c, reader, raw),
             ColumnBuilder::Array(c) => self.read_array(c, reader, raw),
             ColumnBuilder::Map(c) => self.read_map(c, reader, raw),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, raw),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, raw),
             ColumnBuilder::Bitmap(c) => self.read_string(c, reader, raw),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, raw),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, raw),
@@ -258,6 +266,77 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<months_days_micros>,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let interval = string_to_interval(&buf).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<timestamp_tz>,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let mut buffer_readr = Cursor::new(&buf);
+        let dt = buffer_readr.read_timestamp_tz_text(&self.common_settings().timezone)?;
+        if !buffer_readr.eof() {
+            let data = buf.to_str().unwrap_or("not utf8");
+            let msg = format!(
+                "fail to deserialize timestamp_tz, unexpected end at pos {} of {}",
+                buffer_readr.position(),
+                data
+            );
+            return Err(ErrorCode::BadBytes(msg));
+        }
+        let micros = dt.timestamp_micros();
+        check_timestamp(micros)?;
+        column.push(timestamp_tz::new(micros, dt.offset().local_minus_utc()));
+        Ok(())
+    }
+
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let bytes = hex::decode(&buf).map_err(|_| {
+            ErrorCode::BadBytes(format!(
+                "Invalid hex value for BINARY: {:?}",
+                String::from_utf8_lossy(&buf)
+            ))
+        })?;
+        column.put_slice(&bytes);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let wkb = parse_wkt(&buf).map_err(ErrorCode::BadBytes)?;
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
@@ -17,10 +17,15 @@ use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::geometry::wkb_to_wkt;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::NumberColumn;
 use common_expression::types::string::StringColumn;
 use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::timestamp_tz::timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use common_expression::types::ValueType;
 use common_expression::Column;
 use lexical_core::ToLexical;
@@ -59,10 +64,14 @@ pub trait FieldEncoderRowBased {
             Column::Decimal(c) => self.write_decimal(c, row_index, out_buf),
             Column::Date(c) => self.write_date(c, row_index, out_buf, raw),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, raw),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, raw),
+            Column::TimestampTz(c) => self.write_timestamp_tz(c, row_index, out_buf, raw),
             Column::String(c) => self.write_string(c, row_index, out_buf, raw),
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf, raw),
             Column::Array(box c) => self.write_array(c, row_index, out_buf, raw),
             Column::Map(box c) => self.write_map(c, row_index, out_buf, raw),
+            Column::Binary(b) => self.write_binary(b, row_index, out_buf, raw),
+            Column::Geometry(g) => self.write_geometry(g, row_index, out_buf, raw),
             Column::Bitmap(b) => self.write_string(b, row_index, out_buf, raw),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf, raw),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, raw),
@@ -147,6 +156,29 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(unsafe { column.index_unchecked(row_index) }, out_buf, raw);
     }
 
+    fn write_binary(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        self.write_string_inner(hex::encode_upper(v).as_bytes(), out_buf, raw);
+    }
+
+    fn write_geometry(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = wkb_to_wkt(v).unwrap_or_else(|_| hex::encode_upper(v));
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_date(&self, column: &Buffer<i32>, row_index: usize, out_buf: &mut Vec<u8>, raw: bool) {
         let v = unsafe { column.get_unchecked(row_index) };
         let s = date_to_string(*v as i64, self.common_settings().timezone).to_string();
@@ -165,6 +197,30 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<months_days_micros>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = interval_to_string(*v);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    fn write_timestamp_tz(
+        &self,
+        column: &Buffer<timestamp_tz>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = timestamp_tz_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_variant(
         &self,
         column: &StringColumn,
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::geometry::wkb_to_wkt;
+use common_expression::types::interval::interval_to_string;
 use common_expression::types::number::NumberScalar;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
@@ -96,9 +99,15 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => JsonValue::String(interval_to_string(v)),
+        ScalarRef::TimestampTz(v) => JsonValue::String(timestamp_tz_to_string(v).to_string()),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
+        ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
+        ScalarRef::Geometry(x) => {
+            JsonValue::String(wkb_to_wkt(x).unwrap_or_else(|_| hex::encode_upper(x)))
+        }
         ScalarRef::Array(x) => {
             let vals = x
                 .iter()
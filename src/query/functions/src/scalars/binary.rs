@@ -0,0 +1,145 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use common_expression::error_to_null;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::BinaryType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS binary)
+    // to_binary(xx)
+    register_string_to_binary(registry);
+
+    // cast(binary AS string)
+    // to_string(binary)
+    register_binary_to_string(registry);
+
+    // to_hex(binary), to_base64(binary)
+    // from_hex(string)
+    //
+    // `from_base64` still returns a string, use `to_binary(from_base64(xx))`
+    // to get the decoded bytes as binary.
+    register_binary_encoding(registry);
+}
+
+fn register_string_to_binary(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, _| {
+            output.put_slice(val);
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(())),
+            })
+        },
+        vectorize_with_builder_1_arg::<StringType, NullableType<BinaryType>>(|val, output, _| {
+            output.builder.put_slice(val);
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_binary_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        eval_binary_to_string,
+    );
+
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_binary_to_string),
+    );
+
+    fn eval_binary_to_string(
+        val: ValueRef<BinaryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, ctx| {
+            if std::str::from_utf8(val).is_err() {
+                ctx.set_error(output.len(), "binary value is not a valid UTF-8 string");
+            } else {
+                output.put_slice(val);
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_binary_encoding(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_hex",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            let old_len = output.data.len();
+            output.data.resize(old_len + val.len() * 2, 0);
+            hex::encode_to_slice(val, &mut output.data[old_len..]).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_hex",
+        |_| FunctionDomain::MayThrow,
+        eval_from_hex,
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_from_hex",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_from_hex),
+    );
+
+    fn eval_from_hex(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            match hex::decode(val) {
+                Ok(bytes) => output.put_slice(&bytes),
+                Err(err) => ctx.set_error(output.len(), err.to_string()),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
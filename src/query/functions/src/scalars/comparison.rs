@@ -22,15 +22,18 @@ use common_expression::types::string::StringDomain;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
 use common_expression::types::ValueType;
 use common_expression::types::VariantType;
 use common_expression::types::ALL_NUMBER_CLASSES;
@@ -55,8 +58,11 @@ use crate::scalars::string_multi_args::regexp;
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
     register_string_cmp(registry);
+    register_binary_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_interval_cmp(registry);
+    register_timestamp_tz_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -160,6 +166,39 @@ fn register_string_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, StringType);
 }
 
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_date_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, DateType);
 }
@@ -168,6 +207,72 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
+fn register_timestamp_tz_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros() == rhs.micros(),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros() != rhs.micros(),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros() > rhs.micros(),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros() >= rhs.micros(),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros() < rhs.micros(),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros() <= rhs.micros(),
+    );
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
@@ -721,7 +826,7 @@ fn decode_one(data: &[u8]) -> Option<(u8, usize)> {
 
 #[inline]
 /// Borrow from [tikv](https://github.com/tikv/tikv/blob/fe997db4db8a5a096f8a45c0db3eb3c2e5879262/components/tidb_query_expr/src/impl_like.rs)
-fn like(haystack: &[u8], pattern: &[u8]) -> bool {
+pub fn like(haystack: &[u8], pattern: &[u8]) -> bool {
     // current search positions in pattern and target.
     let (mut px, mut tx) = (0, 0);
     // positions for backtrace.
@@ -39,12 +39,16 @@ use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::timestamp::timestamp_to_string;
 use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz;
 use common_expression::types::DateType;
 use common_expression::types::Int32Type;
 use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
 use common_expression::utils::arrow::constant_bitmap;
 use common_expression::utils::date_helper::*;
 use common_expression::vectorize_1_arg;
@@ -72,8 +76,17 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_timestamp_to_date(registry);
     register_number_to_date(registry);
 
-    // cast([date | timestamp] AS string)
-    // to_string([date | timestamp])
+    // cast(xx AS timestamp_tz)
+    // to_timestamp_tz(xx)
+    register_string_to_timestamp_tz(registry);
+    register_timestamp_to_timestamp_tz(registry);
+
+    // cast(timestamp_tz AS [timestamp | date])
+    // to_[timestamp | date](timestamp_tz)
+    register_timestamp_tz_to_timestamp(registry);
+
+    // cast([date | timestamp | timestamp_tz] AS string)
+    // to_string([date | timestamp | timestamp_tz])
     register_to_string(registry);
 
     // cast([date | timestamp] AS [uint8 | int8 | ...])
@@ -97,6 +110,10 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // convert_timezone(string, [timestamp | timestamp_tz]) -> timestamp_tz
+    // to_*(timestamp_tz) and to_*(timestamp, string) evaluated in the given time zone
+    register_timezone_functions(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -383,6 +400,88 @@ fn register_number_to_date(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_string_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            // A value without an explicit offset is read in the session time zone.
+            match string_to_timestamp_tz(val, ctx.func_ctx.tz.tz) {
+                Some(ts) => output.push(ts),
+                None => {
+                    ctx.set_error(output.len(), "cannot parse to type `TIMESTAMP_TZ`");
+                    output.push(timestamp_tz::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_timestamp_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_| FunctionDomain::Full,
+        eval_timestamp_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_timestamp_to_timestamp_tz),
+    );
+
+    fn eval_timestamp_to_timestamp_tz(
+        val: ValueRef<TimestampType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_1_arg::<TimestampType, TimestampTzType>(|val, ctx| {
+            timestamp_to_timestamp_tz(val, ctx.func_ctx.tz.tz)
+        })(val, ctx)
+    }
+}
+
+fn register_timestamp_tz_to_timestamp(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, TimestampType>(|val, _| val.micros()),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, NullableType<TimestampType>>(|val, _| {
+            Some(val.micros())
+        }),
+    );
+
+    // The date is taken from the wall clock time in the value's own offset.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+        "to_date",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, DateType>(|val, _| {
+            microseconds_to_days(val.local_micros())
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+        "try_to_date",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, NullableType<DateType>>(|val, _| {
+            Some(microseconds_to_days(val.local_micros()))
+        }),
+    );
+}
+
 fn register_to_string(registry: &mut FunctionRegistry) {
     registry.register_aliases("to_string", &["date_format"]);
     registry.register_combine_nullable_2_arg::<TimestampType, StringType, StringType, _, _>(
@@ -479,6 +578,35 @@ fn register_to_string(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.data, "{}", val).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "try_to_string",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<StringType>>(
+            |val, output, _| {
+                write!(output.builder.data, "{}", val).unwrap();
+                output.builder.commit_row();
+                output.validity.push(true);
+            },
+        ),
+    );
 }
 
 fn register_to_number(registry: &mut FunctionRegistry) {
@@ -1119,25 +1247,6 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         },
         |a, b, _| a - b,
     );
-
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
-        "minus",
-        |lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
-        },
-        |a, b, _| a - b,
-    );
 }
 
 fn register_rounder_functions(registry: &mut FunctionRegistry) {
@@ -1312,3 +1421,149 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+/// Look up a time zone by its IANA name, e.g. `America/New_York`.
+fn timezone_by_name(tz: &[u8]) -> Result<TzLUT, String> {
+    std::str::from_utf8(tz)
+        .ok()
+        .and_then(|name| TzFactory::instance().get_by_name(name).ok())
+        .ok_or_else(|| format!("invalid timezone `{}`", String::from_utf8_lossy(tz)))
+}
+
+fn register_timezone_functions(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampTzType>(
+                |tz, val, output, ctx| match timezone_by_name(tz) {
+                    Ok(tz) => output.push(timestamp_to_timestamp_tz(val, tz.tz)),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(timestamp_tz::default());
+                    }
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampTzType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampTzType, TimestampTzType>(
+                |tz, val, output, ctx| match timezone_by_name(tz) {
+                    Ok(tz) => output.push(timestamp_to_timestamp_tz(val.micros(), tz.tz)),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(timestamp_tz::default());
+                    }
+                },
+            ),
+        );
+
+    // timestamp_tz: evaluated on the wall clock time in the value's own offset,
+    // which is the same as evaluating the local micros in UTC.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampTzType, _, _>(
+        "to_start_of_day",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, TimestampTzType>(|val, _| {
+            let start = TzLUT::default().round_us(val.local_micros(), Round::Day);
+            timestamp_tz::new(start - val.offset() as i64 * MICROS_IN_A_SEC, val.offset())
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, UInt16Type, _, _>(
+        "to_year",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, UInt16Type>(|val, _| {
+            ToNumberImpl::eval_timestamp::<ToYear, _>(val.local_micros(), TzLUT::default())
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, UInt8Type, _, _>(
+        "to_month",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, UInt8Type>(|val, _| {
+            ToNumberImpl::eval_timestamp::<ToMonth, _>(val.local_micros(), TzLUT::default())
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, UInt8Type, _, _>(
+        "to_day_of_month",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, UInt8Type>(|val, _| {
+            ToNumberImpl::eval_timestamp::<ToDayOfMonth, _>(val.local_micros(), TzLUT::default())
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, UInt8Type, _, _>(
+        "to_hour",
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, UInt8Type>(|val, _| {
+            TzLUT::default().to_hour(val.local_micros())
+        }),
+    );
+
+    // timestamp: evaluated in the time zone given by the second argument
+    // instead of the session time zone.
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, TimestampType, _, _>(
+        "to_start_of_day",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, TimestampType>(
+            |val, tz, output, ctx| match timezone_by_name(tz) {
+                Ok(tz) => output.push(tz.round_us(val, Round::Day)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, UInt16Type, _, _>(
+        "to_year",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, UInt16Type>(
+            |val, tz, output, ctx| match timezone_by_name(tz) {
+                Ok(tz) => output.push(ToNumberImpl::eval_timestamp::<ToYear, _>(val, tz)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, UInt8Type, _, _>(
+        "to_month",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, UInt8Type>(
+            |val, tz, output, ctx| match timezone_by_name(tz) {
+                Ok(tz) => output.push(ToNumberImpl::eval_timestamp::<ToMonth, _>(val, tz)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, UInt8Type, _, _>(
+        "to_day_of_month",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, UInt8Type>(
+            |val, tz, output, ctx| match timezone_by_name(tz) {
+                Ok(tz) => output.push(ToNumberImpl::eval_timestamp::<ToDayOfMonth, _>(val, tz)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, UInt8Type, _, _>(
+        "to_hour",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, UInt8Type>(
+            |val, tz, output, ctx| match timezone_by_name(tz) {
+                Ok(tz) => output.push(tz.to_hour(val)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+}
@@ -0,0 +1,420 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::error_to_null;
+use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::geometry::check_wkb;
+use common_expression::types::geometry::geo_to_wkb;
+use common_expression::types::geometry::parse_geojson;
+use common_expression::types::geometry::parse_wkt;
+use common_expression::types::geometry::wkb_to_geo;
+use common_expression::types::geometry::wkb_to_geojson;
+use common_expression::types::geometry::wkb_to_wkt;
+use common_expression::types::geometry::GeometryDomain;
+use common_expression::types::BinaryType;
+use common_expression::types::BooleanType;
+use common_expression::types::Float64Type;
+use common_expression::types::GeometryType;
+use common_expression::types::StringType;
+use common_expression::types::F64;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+use geo::Area;
+use geo::BooleanOps;
+use geo::ConvexHull;
+use geo::Coord;
+use geo::Geometry;
+use geo::MultiPoint;
+use geo::MultiPolygon;
+use geo::Point;
+use geo::Polygon;
+use geo::Relate;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS geometry)
+    // to_geometry(xx)
+    register_string_to_geometry(registry);
+
+    // cast(geometry AS string)
+    // to_string(geometry)
+    register_geometry_to_string(registry);
+
+    // st_geomfromwkt, st_geomfromwkb, st_geomfromgeojson
+    // st_astext, st_aswkb, st_asgeojson
+    register_geometry_io(registry);
+
+    // st_contains, st_intersects
+    register_geometry_relation(registry);
+
+    // st_area, st_distance, st_buffer
+    register_geometry_measure(registry);
+}
+
+fn register_string_to_geometry(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_| FunctionDomain::MayThrow,
+        eval_wkt_to_geometry,
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_wkt_to_geometry),
+    );
+}
+
+fn register_geometry_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        eval_geometry_to_wkt,
+    );
+
+    registry.register_combine_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_geometry_to_wkt),
+    );
+}
+
+fn register_geometry_io(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromwkt",
+        |_| FunctionDomain::MayThrow,
+        eval_wkt_to_geometry,
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, GeometryType>(|val, output, ctx| {
+            match check_wkb(val) {
+                Ok(()) => output.put_slice(val),
+                Err(err) => ctx.set_error(output.len(), err),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromgeojson",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            match parse_geojson(val) {
+                Ok(wkb) => output.put_slice(&wkb),
+                Err(err) => ctx.set_error(output.len(), err),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_astext",
+        |_| FunctionDomain::MayThrow,
+        eval_geometry_to_wkt,
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_aswkb",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, BinaryType>(|val, output, _| {
+            output.put_slice(val);
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asgeojson",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+            match wkb_to_geojson(val) {
+                Ok(json) => output.put_str(&json),
+                Err(err) => ctx.set_error(output.len(), err),
+            }
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_geometry_relation(registry: &mut FunctionRegistry) {
+    // Neither relation can hold if the bounding boxes are disjoint, which
+    // allows the range pruner to skip blocks by their bounding box.
+    fn calc_relation_domain(
+        lhs: &GeometryDomain,
+        rhs: &GeometryDomain,
+    ) -> FunctionDomain<BooleanType> {
+        if lhs.intersects(rhs) {
+            FunctionDomain::MayThrow
+        } else {
+            FunctionDomain::Domain(BooleanDomain {
+                has_false: true,
+                has_true: false,
+            })
+        }
+    }
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        calc_relation_domain,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |lhs, rhs, output, ctx| match (wkb_to_geo(lhs), wkb_to_geo(rhs)) {
+                (Ok(lhs), Ok(rhs)) => output.push(lhs.relate(&rhs).is_contains()),
+                (Err(err), _) | (_, Err(err)) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        calc_relation_domain,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |lhs, rhs, output, ctx| match (wkb_to_geo(lhs), wkb_to_geo(rhs)) {
+                (Ok(lhs), Ok(rhs)) => output.push(lhs.relate(&rhs).is_intersects()),
+                (Err(err), _) | (_, Err(err)) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+}
+
+fn register_geometry_measure(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_area",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|val, output, ctx| {
+            match wkb_to_geo(val) {
+                Ok(geometry) => output.push(F64::from(geometry.unsigned_area())),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, Float64Type, _, _>(
+        "st_distance",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, Float64Type>(
+            |lhs, rhs, output, ctx| {
+                let distance = match (wkb_to_geo(lhs), wkb_to_geo(rhs)) {
+                    (Ok(lhs), Ok(rhs)) => distance(&lhs, &rhs),
+                    (Err(err), _) | (_, Err(err)) => Err(err),
+                };
+                match distance {
+                    Ok(distance) => output.push(F64::from(distance)),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(F64::from(0.0));
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Float64Type, GeometryType, _, _>(
+        "st_buffer",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, Float64Type, GeometryType>(
+            |val, distance, output, ctx| {
+                let buffer = wkb_to_geo(val)
+                    .and_then(|geometry| buffer(&geometry, *distance))
+                    .and_then(|geometry| geo_to_wkb(&geometry));
+                match buffer {
+                    Ok(wkb) => output.put_slice(&wkb),
+                    Err(err) => ctx.set_error(output.len(), err),
+                }
+                output.commit_row();
+            },
+        ),
+    );
+}
+
+fn eval_wkt_to_geometry(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<GeometryType> {
+    vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+        match parse_wkt(val) {
+            Ok(wkb) => output.put_slice(&wkb),
+            Err(err) => ctx.set_error(output.len(), err),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
+
+fn eval_geometry_to_wkt(val: ValueRef<GeometryType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+        match wkb_to_wkt(val) {
+            Ok(wkt) => output.put_str(&wkt),
+            Err(err) => ctx.set_error(output.len(), err),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
+
+/// The minimum euclidean distance between two geometries.
+fn distance(lhs: &Geometry<f64>, rhs: &Geometry<f64>) -> Result<f64, String> {
+    let (lhs_segments, rhs_segments) = (segments(lhs), segments(rhs));
+    if lhs_segments.is_empty() || rhs_segments.is_empty() {
+        return Err("st_distance is undefined for empty geometries".to_string());
+    }
+    if lhs.relate(rhs).is_intersects() {
+        return Ok(0.0);
+    }
+
+    // The geometries don't intersect, so neither do any pair of their
+    // segments, and the distance is reached at an endpoint of a segment.
+    let mut distance = f64::INFINITY;
+    for (a, b) in &lhs_segments {
+        for (c, d) in &rhs_segments {
+            distance = distance
+                .min(point_segment_distance(*a, *c, *d))
+                .min(point_segment_distance(*b, *c, *d))
+                .min(point_segment_distance(*c, *a, *b))
+                .min(point_segment_distance(*d, *a, *b));
+        }
+    }
+    Ok(distance)
+}
+
+/// Split a geometry into segments, points are represented as degenerate
+/// segments with the same start and end.
+fn segments(geometry: &Geometry<f64>) -> Vec<(Coord<f64>, Coord<f64>)> {
+    fn polygon_segments(polygon: &Polygon<f64>) -> Vec<(Coord<f64>, Coord<f64>)> {
+        std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .flat_map(|ring| ring.lines())
+            .map(|line| (line.start, line.end))
+            .collect()
+    }
+
+    match geometry {
+        Geometry::Point(point) => vec![(point.0, point.0)],
+        Geometry::Line(line) => vec![(line.start, line.end)],
+        Geometry::LineString(line_string) => line_string
+            .lines()
+            .map(|line| (line.start, line.end))
+            .collect(),
+        Geometry::Polygon(polygon) => polygon_segments(polygon),
+        Geometry::MultiPoint(points) => points.iter().map(|point| (point.0, point.0)).collect(),
+        Geometry::MultiLineString(line_strings) => line_strings
+            .iter()
+            .flat_map(|line_string| line_string.lines())
+            .map(|line| (line.start, line.end))
+            .collect(),
+        Geometry::MultiPolygon(polygons) => polygons.iter().flat_map(polygon_segments).collect(),
+        Geometry::GeometryCollection(geometries) => geometries.iter().flat_map(segments).collect(),
+        Geometry::Rect(rect) => polygon_segments(&rect.to_polygon()),
+        Geometry::Triangle(triangle) => polygon_segments(&triangle.to_polygon()),
+    }
+}
+
+fn point_segment_distance(p: Coord<f64>, a: Coord<f64>, b: Coord<f64>) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).clamp(0.0, 1.0)
+    };
+    let (x, y) = (a.x + t * dx, a.y + t * dy);
+    ((p.x - x).powi(2) + (p.y - y).powi(2)).sqrt()
+}
+
+/// The number of vertices used to approximate the circle around a point.
+const BUFFER_SEGMENTS: usize = 32;
+
+/// The buffer is the set of points within the distance of the geometry, which is
+/// the union of the areas of the geometry and the capsules around its segments,
+/// the circles around the vertices are approximated by regular polygons.
+fn buffer(geometry: &Geometry<f64>, distance: f64) -> Result<Geometry<f64>, String> {
+    if distance.is_nan() || distance < 0.0 {
+        return Err(format!(
+            "st_buffer distance must be a non-negative number, but got {distance}"
+        ));
+    }
+    if distance == 0.0 {
+        return Ok(geometry.clone());
+    }
+
+    let polygons = areas(geometry)
+        .into_iter()
+        .chain(
+            segments(geometry)
+                .into_iter()
+                .map(|(start, end)| capsule(start, end, distance)),
+        )
+        .map(|polygon| MultiPolygon::new(vec![polygon]))
+        .collect::<Vec<_>>();
+    let mut buffer = union_all(polygons);
+    if buffer.0.len() == 1 {
+        Ok(Geometry::Polygon(buffer.0.pop().unwrap()))
+    } else {
+        Ok(Geometry::MultiPolygon(buffer))
+    }
+}
+
+/// The polygons of the areal parts of a geometry.
+fn areas(geometry: &Geometry<f64>) -> Vec<Polygon<f64>> {
+    match geometry {
+        Geometry::Polygon(polygon) => vec![polygon.clone()],
+        Geometry::MultiPolygon(polygons) => polygons.0.clone(),
+        Geometry::Rect(rect) => vec![rect.to_polygon()],
+        Geometry::Triangle(triangle) => vec![triangle.to_polygon()],
+        Geometry::GeometryCollection(geometries) => geometries.iter().flat_map(areas).collect(),
+        _ => vec![],
+    }
+}
+
+/// The points within the distance of a segment, which is the convex hull of the
+/// circles around both ends.
+fn capsule(start: Coord<f64>, end: Coord<f64>, distance: f64) -> Polygon<f64> {
+    [start, end]
+        .into_iter()
+        .flat_map(|center| {
+            (0..BUFFER_SEGMENTS).map(move |i| {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / BUFFER_SEGMENTS as f64;
+                Point::new(
+                    center.x + distance * angle.cos(),
+                    center.y + distance * angle.sin(),
+                )
+            })
+        })
+        .collect::<MultiPoint<f64>>()
+        .convex_hull()
+}
+
+/// Union the polygons pairwise, so that each polygon only takes part in
+/// `O(log n)` unions.
+fn union_all(mut polygons: Vec<MultiPolygon<f64>>) -> MultiPolygon<f64> {
+    while polygons.len() > 1 {
+        polygons = polygons
+            .chunks(2)
+            .map(|pair| match pair {
+                [lhs, rhs] => lhs.union(rhs),
+                _ => pair[0].clone(),
+            })
+            .collect();
+    }
+    polygons.pop().unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
@@ -357,7 +357,10 @@ impl DFHash for Scalar {
                     DFHash::hash(v, state);
                 }
             }),
-            Scalar::String(vals) | Scalar::Variant(vals) => {
+            Scalar::String(vals)
+            | Scalar::Binary(vals)
+            | Scalar::Geometry(vals)
+            | Scalar::Variant(vals) => {
                 for v in vals {
                     DFHash::hash(v, state);
                 }
@@ -0,0 +1,378 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::error_to_null;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MICROS_IN_AN_HOUR;
+use common_expression::types::interval::MICROS_IN_A_DAY;
+use common_expression::types::interval::MICROS_IN_A_MINUTE;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::Int64Type;
+use common_expression::types::string::StringDomain;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::DateType;
+use common_expression::types::IntervalType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::AddMonthsImpl;
+use common_expression::utils::date_helper::TzLUT;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast(interval AS string)
+    // to_string(interval)
+    register_interval_to_string(registry);
+
+    // to_[years | quarters | months | weeks | days | hours | minutes | seconds](number)
+    // interval number [year | quarter | month | week | day | hour | minute | second]
+    register_number_to_interval(registry);
+
+    // interval [+ | -] interval
+    // -interval
+    register_interval_arithmetic(registry);
+
+    // [date | timestamp] [+ | -] interval
+    // interval + [date | timestamp]
+    // timestamp - timestamp
+    register_timestamp_interval_arithmetic(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", interval_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", interval_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_number_to_interval(registry: &mut FunctionRegistry) {
+    macro_rules! register_to_interval {
+        ($name:expr, $to_interval:expr) => {
+            registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+                $name,
+                |_| FunctionDomain::MayThrow,
+                vectorize_with_builder_1_arg::<Int64Type, IntervalType>(|val, output, ctx| {
+                    let to_interval: fn(i64) -> Option<months_days_micros> = $to_interval;
+                    match to_interval(val) {
+                        Some(interval) => output.push(interval),
+                        None => {
+                            ctx.set_error(
+                                output.len(),
+                                format!("interval {val} of `{}` is out of range", $name),
+                            );
+                            output.push(months_days_micros::default());
+                        }
+                    }
+                }),
+            );
+        };
+    }
+
+    register_to_interval!("to_years", |n| {
+        Some(months_days_micros::new(
+            i32::try_from(n.checked_mul(12)?).ok()?,
+            0,
+            0,
+        ))
+    });
+    register_to_interval!("to_quarters", |n| {
+        Some(months_days_micros::new(
+            i32::try_from(n.checked_mul(3)?).ok()?,
+            0,
+            0,
+        ))
+    });
+    register_to_interval!("to_months", |n| {
+        Some(months_days_micros::new(i32::try_from(n).ok()?, 0, 0))
+    });
+    register_to_interval!("to_weeks", |n| {
+        Some(months_days_micros::new(
+            0,
+            i32::try_from(n.checked_mul(7)?).ok()?,
+            0,
+        ))
+    });
+    register_to_interval!("to_days", |n| {
+        Some(months_days_micros::new(0, i32::try_from(n).ok()?, 0))
+    });
+    register_to_interval!("to_hours", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_AN_HOUR)?,
+        ))
+    });
+    register_to_interval!("to_minutes", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_A_MINUTE)?,
+        ))
+    });
+    register_to_interval!("to_seconds", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_A_SEC)?,
+        ))
+    });
+}
+
+fn register_interval_arithmetic(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| match lhs.checked_add(&rhs) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| match rhs.checked_neg().and_then(|rhs| lhs.checked_add(&rhs)) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            match val.checked_neg() {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+}
+
+/// Add the interval to the timestamp: the months are added on the calendar,
+/// like `add_months`, then the days and microseconds are added as elapsed time.
+fn add_interval(us: i64, tz: TzLUT, interval: months_days_micros) -> Result<i64, String> {
+    let us = if interval.months() != 0 {
+        AddMonthsImpl::eval_timestamp(us, tz, interval.months())?
+    } else {
+        us
+    };
+    (interval.days() as i64)
+        .checked_mul(MICROS_IN_A_DAY)
+        .and_then(|delta| delta.checked_add(interval.micros()))
+        .and_then(|delta| us.checked_add(delta))
+        .ok_or_else(|| "timestamp is out of range".to_string())
+        .and_then(check_timestamp)
+}
+
+fn register_timestamp_interval_arithmetic(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match add_interval(ts, ctx.func_ctx.tz, interval) {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| match add_interval(ts, ctx.func_ctx.tz, interval) {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let res = interval
+                        .checked_neg()
+                        .ok_or_else(|| "interval out of range".to_string())
+                        .and_then(|interval| add_interval(ts, ctx.func_ctx.tz, interval));
+                    match res {
+                        Ok(ts) => output.push(ts),
+                        Err(e) => {
+                            ctx.set_error(output.len(), e);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+
+    // The date is taken as the midnight of the day, and the result is a timestamp,
+    // since the interval may carry hours, minutes or seconds.
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                match add_interval(ts, ctx.func_ctx.tz, interval) {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                match add_interval(ts, ctx.func_ctx.tz, interval) {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                let res = interval
+                    .checked_neg()
+                    .ok_or_else(|| "interval out of range".to_string())
+                    .and_then(|interval| add_interval(ts, ctx.func_ctx.tz, interval));
+                match res {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    // The difference is split into whole days and the remaining microseconds,
+    // so it reads like `P1DT2H` instead of `PT26H`.
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| {
+            let diff = lhs as i128 - rhs as i128;
+            let days = diff / MICROS_IN_A_DAY as i128;
+            let micros = diff % MICROS_IN_A_DAY as i128;
+            months_days_micros::new(0, days as i32, micros as i64)
+        },
+    );
+}
@@ -17,6 +17,7 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
 mod bitmap;
 mod boolean;
 mod comparison;
@@ -24,7 +25,9 @@ mod control;
 mod datetime;
 mod decimal;
 mod geo;
+mod geometry;
 mod hash;
+mod interval;
 mod map;
 mod math;
 mod other;
@@ -36,6 +39,7 @@ mod vector;
 
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
+pub use comparison::like;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
 
@@ -47,12 +51,15 @@ pub fn register(registry: &mut FunctionRegistry) {
     control::register(registry);
     comparison::register(registry);
     datetime::register(registry);
+    interval::register(registry);
     math::register(registry);
     map::register(registry);
     string::register(registry);
+    binary::register(registry);
     string_multi_args::register(registry);
     tuple::register(registry);
     geo::register(registry);
+    geometry::register(registry);
     hash::register(registry);
     other::register(registry);
     decimal::register(registry);
@@ -26,6 +26,7 @@ use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::number::F64;
 use common_expression::types::string::StringColumn;
@@ -73,6 +74,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_inet_ntoa(registry);
     register_run_diff(registry);
     register_grouping(registry);
+    register_nextval(registry);
 
     registry.properties.insert(
         "rand".to_string(),
@@ -379,6 +381,48 @@ fn register_grouping(registry: &mut FunctionRegistry) {
     })
 }
 
+fn register_nextval(registry: &mut FunctionRegistry) {
+    registry.properties.insert(
+        "nextval".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+
+    registry.register_1_arg_core::<StringType, UInt64Type, _, _>(
+        "nextval",
+        |_| FunctionDomain::MayThrow,
+        |name, ctx| {
+            let num_rows = ctx.num_rows;
+            if num_rows == 0 {
+                return Value::Column(vec![].into());
+            }
+
+            let name = match name.as_scalar() {
+                Some(name) => String::from_utf8_lossy(name).to_string(),
+                None => {
+                    ctx.set_error(0, "The sequence name of nextval must be a constant");
+                    return Value::Column(vec![0; num_rows].into());
+                }
+            };
+
+            let values = match &ctx.func_ctx.sequence_values {
+                Some(sequence_values) => sequence_values.next_values(&name, num_rows as u64),
+                None => {
+                    ctx.set_error(0, "nextval is not supported in this context");
+                    return Value::Column(vec![0; num_rows].into());
+                }
+            };
+
+            match values {
+                Ok(values) => Value::Column(values.into()),
+                Err(e) => {
+                    ctx.set_error(0, e.message());
+                    Value::Column(vec![0; num_rows].into())
+                }
+            }
+        },
+    );
+}
+
 /// Compute `grouping` by `grouping_id` and `cols`.
 ///
 /// `cols` are indices of the column represented in `_grouping_id`.
@@ -506,6 +506,8 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
         }
         common_ast::ast::TypeName::String => DataType::String,
         common_ast::ast::TypeName::Timestamp => DataType::Timestamp,
+        common_ast::ast::TypeName::TimestampTz => DataType::TimestampTz,
+        common_ast::ast::TypeName::Interval => DataType::Interval,
         common_ast::ast::TypeName::Date => DataType::Date,
         common_ast::ast::TypeName::Array(item_type) => {
             DataType::Array(Box::new(transform_data_type(*item_type)))
@@ -515,6 +517,8 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
             let val_type = transform_data_type(*val_type);
             DataType::Map(Box::new(DataType::Tuple(vec![key_type, val_type])))
         }
+        common_ast::ast::TypeName::Binary => DataType::Binary,
+        common_ast::ast::TypeName::Geometry => DataType::Geometry,
         common_ast::ast::TypeName::Bitmap => DataType::Bitmap,
         common_ast::ast::TypeName::Tuple { fields_type, .. } => {
             DataType::Tuple(fields_type.into_iter().map(transform_data_type).collect())
@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod masking_policy;
 mod setting;
 mod stage;
 mod udf;
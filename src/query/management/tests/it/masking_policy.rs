@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::MaskingPolicy;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_get_drop_masking_policy() -> Result<()> {
+    let (_, policy_api) = new_masking_policy_api().await?;
+
+    let policy = create_test_masking_policy();
+    policy_api.add_masking_policy(policy.clone()).await?;
+
+    let got = policy_api
+        .get_masking_policy(&policy.name, MatchSeq::GE(0))
+        .await?;
+    assert_eq!(got.data, policy);
+    assert_eq!(policy_api.get_masking_policies().await?, vec![
+        policy.clone()
+    ]);
+
+    match policy_api.add_masking_policy(policy.clone()).await {
+        Ok(_) => panic!("Already exists add masking policy must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2729),
+    }
+
+    policy_api
+        .drop_masking_policy(&policy.name, MatchSeq::GE(1))
+        .await?;
+    assert_eq!(policy_api.get_masking_policies().await?, vec![]);
+
+    match policy_api
+        .drop_masking_policy(&policy.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown masking policy drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2730),
+    }
+
+    Ok(())
+}
+
+fn create_test_masking_policy() -> MaskingPolicy {
+    MaskingPolicy {
+        name: "mask_email".to_string(),
+        args: vec![("val".to_string(), "VARCHAR".to_string())],
+        return_type: "VARCHAR".to_string(),
+        body: "CASE WHEN current_role() IN ('etl') THEN val ELSE '***' END".to_string(),
+        comment: "".to_string(),
+        created_on: Default::default(),
+    }
+}
+
+async fn new_masking_policy_api() -> Result<(Arc<MetaEmbedded>, MaskingPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = MaskingPolicyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
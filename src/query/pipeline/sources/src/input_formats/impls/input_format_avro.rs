@@ -0,0 +1,447 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use apache_avro::schema::Schema as AvroSchema;
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader as AvroReader;
+use common_compress::DecompressDecoder;
+use common_compress::DecompressState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldJsonAstDecoder;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+use crate::input_formats::input_format_text::decompress;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Reads avro object container files.
+///
+/// The blocks of a container file can not be located without reading the file from
+/// the beginning, so each file is a single split. The writer schema stored in the file
+/// header is used to decode the rows, and the columns of the table are matched with the
+/// fields of the top level record by name.
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatAvro {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let mut infos = Vec::with_capacity(file_infos.len());
+        for info in file_infos {
+            let size = info.size as usize;
+            let compress_alg = InputContext::get_compression_alg_copy(
+                stage_info.file_format_params.compression(),
+                &info.path,
+            )?;
+            let file = Arc::new(FileInfo {
+                path: info.path,
+                size,
+                num_splits: 1,
+                compress_alg,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let data = op.read(path).await?;
+        let reader = AvroReader::new(data.as_slice()).map_err(|e| {
+            ErrorCode::BadBytes(format!("fail to read avro header of {}: {}", path, e))
+        })?;
+        let fields = match reader.writer_schema() {
+            AvroSchema::Record { fields, .. } => fields
+                .iter()
+                .map(|f| TableField::new(&f.name, avro_schema_to_table_type(&f.schema)))
+                .collect(),
+            other => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "the schema of avro file {} must be a record, but got {:?}",
+                    path, other
+                )));
+            }
+        };
+        Ok(Arc::new(TableSchema::new(fields)))
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct AvroFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for AvroFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = AvroRowBatch;
+    type AligningState = AvroAligningState;
+    type BlockBuilder = AvroBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        let decompressor = ctx
+            .get_compression_alg(&split_info.file.path)?
+            .map(DecompressDecoder::new);
+        Ok(AvroAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            decompressor,
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<Self::BlockBuilder> {
+        Ok(AvroBlockBuilder::create(ctx.clone()))
+    }
+}
+
+pub struct AvroRowBatch {
+    pub split_info: String,
+    // the rows of the top level record, with the avro values converted to json values
+    pub rows: Vec<JsonValue>,
+    pub size: usize,
+}
+
+impl RowBatchTrait for AvroRowBatch {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn rows(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+pub struct AvroAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    decompressor: Option<DecompressDecoder>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for AvroAligningState {
+    type Pipe = AvroFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<AvroRowBatch>> {
+        if let Some(data) = read_batch {
+            let buf = if let Some(decoder) = self.decompressor.as_mut() {
+                decompress(decoder, &data)?
+            } else {
+                data
+            };
+            self.buffers.push(buf);
+            return Ok(vec![]);
+        }
+
+        if let Some(decoder) = &self.decompressor {
+            let state = decoder.state();
+            if !matches!(state, DecompressState::Done | DecompressState::Reading) {
+                tracing::warn!("decompressor end with state {:?}", state)
+            }
+        }
+
+        let file_in_memory = std::mem::take(&mut self.buffers).concat();
+        let size = file_in_memory.len();
+        let split_info = self.split_info.to_string();
+        let reader = AvroReader::new(file_in_memory.as_slice()).map_err(|e| {
+            ErrorCode::BadBytes(format!("fail to read avro header of {}: {}", split_info, e))
+        })?;
+
+        let ident_case_sensitive = self.ctx.file_format_options_ext.ident_case_sensitive;
+        let mut rows = vec![];
+        for value in reader {
+            let value = value.map_err(|e| {
+                ErrorCode::BadBytes(format!("fail to read avro file {}: {}", split_info, e))
+            })?;
+            let mut row = avro_value_to_json(value)?;
+            if !ident_case_sensitive {
+                if let JsonValue::Object(x) = row {
+                    row = JsonValue::Object(
+                        x.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect(),
+                    );
+                }
+            }
+            rows.push(row);
+        }
+        tracing::debug!(
+            "align avro file {} of {} bytes to {} rows",
+            self.split_info.file.path,
+            size,
+            rows.len()
+        );
+
+        let max_rows = std::cmp::max(self.ctx.block_compact_thresholds.max_rows_per_block, 1);
+        let num_rows = std::cmp::max(rows.len(), 1);
+        let mut row_batches = Vec::with_capacity(rows.len() / max_rows + 1);
+        let mut rows = rows.into_iter().peekable();
+        while rows.peek().is_some() {
+            let batch = rows.by_ref().take(max_rows).collect::<Vec<_>>();
+            row_batches.push(AvroRowBatch {
+                split_info: split_info.clone(),
+                size: size * batch.len() / num_rows,
+                rows: batch,
+            });
+        }
+        Ok(row_batches)
+    }
+}
+
+pub struct AvroBlockBuilder {
+    ctx: Arc<InputContext>,
+    field_decoder: FieldJsonAstDecoder,
+    mutable_columns: Vec<ColumnBuilder>,
+    num_rows: usize,
+}
+
+impl AvroBlockBuilder {
+    fn create(ctx: Arc<InputContext>) -> Self {
+        let mutable_columns = ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity_hint(&f.data_type().into(), 1024, false))
+            .collect();
+        let field_decoder = FieldJsonAstDecoder::create(&ctx.file_format_options_ext);
+        AvroBlockBuilder {
+            ctx,
+            field_decoder,
+            mutable_columns,
+            num_rows: 0,
+        }
+    }
+
+    fn read_row(&mut self, row: &JsonValue) -> Result<()> {
+        let ident_case_sensitive = self.field_decoder.ident_case_sensitive;
+        for (f, column) in self
+            .ctx
+            .schema
+            .fields()
+            .iter()
+            .zip(self.mutable_columns.iter_mut())
+        {
+            let value = if ident_case_sensitive {
+                &row[f.name().to_owned()]
+            } else {
+                &row[f.name().to_lowercase()]
+            };
+            self.field_decoder.read_field(column, value).map_err(|e| {
+                ErrorCode::BadBytes(format!("{}. column={} value={:?}", e, f.name(), value))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<Vec<DataBlock>> {
+        let columns: Vec<Column> = self
+            .mutable_columns
+            .iter_mut()
+            .map(|col| {
+                let empty_builder =
+                    ColumnBuilder::with_capacity_hint(&col.data_type(), 1024, false);
+                std::mem::replace(col, empty_builder).build()
+            })
+            .collect();
+
+        self.num_rows = 0;
+
+        if columns.is_empty() || columns[0].len() == 0 {
+            Ok(vec![])
+        } else {
+            Ok(vec![DataBlock::new_from_columns(columns)])
+        }
+    }
+
+    fn memory_size(&self) -> usize {
+        self.mutable_columns.iter().map(|x| x.memory_size()).sum()
+    }
+}
+
+impl BlockBuilderTrait for AvroBlockBuilder {
+    type Pipe = AvroFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<AvroRowBatch>) -> Result<Vec<DataBlock>> {
+        if let Some(b) = batch {
+            for (i, row) in b.rows.iter().enumerate() {
+                self.read_row(row).map_err(|e| {
+                    e.add_message_back(format!(" (at row {} of {})", i, b.split_info))
+                })?;
+            }
+            self.num_rows += b.rows.len();
+            if self.num_rows >= self.ctx.block_compact_thresholds.min_rows_per_block
+                || self.memory_size() > self.ctx.block_compact_thresholds.max_bytes_per_block
+            {
+                self.flush()
+            } else {
+                Ok(vec![])
+            }
+        } else {
+            self.flush()
+        }
+    }
+}
+
+// Records and maps are converted to json objects, which are read as Tuple and Map columns,
+// or as Variant if the column of the table is a Variant.
+fn avro_value_to_json(value: AvroValue) -> Result<JsonValue> {
+    let json = match value {
+        AvroValue::Null => JsonValue::Null,
+        AvroValue::Boolean(v) => JsonValue::Bool(v),
+        AvroValue::Int(v) => JsonValue::from(v),
+        AvroValue::Long(v) => JsonValue::from(v),
+        AvroValue::Float(v) => JsonValue::from(v),
+        AvroValue::Double(v) => JsonValue::from(v),
+        // there is no binary type yet, the bytes are kept as string
+        AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => {
+            JsonValue::String(String::from_utf8_lossy(&v).into_owned())
+        }
+        AvroValue::String(v) => JsonValue::String(v),
+        AvroValue::Enum(_, v) => JsonValue::String(v),
+        AvroValue::Uuid(v) => JsonValue::String(v.to_string()),
+        AvroValue::Union(_, v) => avro_value_to_json(*v)?,
+        AvroValue::Array(values) => JsonValue::Array(
+            values
+                .into_iter()
+                .map(avro_value_to_json)
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        AvroValue::Map(values) => {
+            let mut map = JsonMap::with_capacity(values.len());
+            for (k, v) in values {
+                map.insert(k, avro_value_to_json(v)?);
+            }
+            JsonValue::Object(map)
+        }
+        AvroValue::Record(fields) => {
+            let mut map = JsonMap::with_capacity(fields.len());
+            for (k, v) in fields {
+                map.insert(k, avro_value_to_json(v)?);
+            }
+            JsonValue::Object(map)
+        }
+        // days since epoch
+        AvroValue::Date(v) => JsonValue::from(v),
+        // the timestamp columns are in microseconds
+        AvroValue::TimestampMillis(v) => JsonValue::from(v * 1000),
+        AvroValue::TimestampMicros(v) => JsonValue::from(v),
+        AvroValue::TimeMillis(v) => JsonValue::from(v),
+        AvroValue::TimeMicros(v) => JsonValue::from(v),
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported avro value {:?}",
+                other
+            )));
+        }
+    };
+    Ok(json)
+}
+
+fn avro_schema_to_table_type(schema: &AvroSchema) -> TableDataType {
+    match schema {
+        AvroSchema::Null => TableDataType::Null,
+        AvroSchema::Boolean => TableDataType::Boolean,
+        AvroSchema::Int | AvroSchema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        AvroSchema::Long | AvroSchema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+        AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+        AvroSchema::Bytes
+        | AvroSchema::String
+        | AvroSchema::Fixed { .. }
+        | AvroSchema::Enum { .. }
+        | AvroSchema::Uuid => TableDataType::String,
+        AvroSchema::Date => TableDataType::Date,
+        AvroSchema::TimestampMillis | AvroSchema::TimestampMicros => TableDataType::Timestamp,
+        AvroSchema::Array(item) => TableDataType::Array(Box::new(avro_schema_to_table_type(item))),
+        AvroSchema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_schema_to_table_type(value)],
+        })),
+        AvroSchema::Record { fields, .. } => TableDataType::Tuple {
+            fields_name: fields.iter().map(|f| f.name.clone()).collect(),
+            fields_type: fields
+                .iter()
+                .map(|f| avro_schema_to_table_type(&f.schema))
+                .collect(),
+        },
+        AvroSchema::Union(union) => {
+            let variants = union.variants();
+            let non_null = variants
+                .iter()
+                .filter(|s| !matches!(s, AvroSchema::Null))
+                .collect::<Vec<_>>();
+            // ["null", T] is the way to declare an optional field in avro
+            if non_null.len() == 1 {
+                let inner = avro_schema_to_table_type(non_null[0]);
+                if variants.len() == 1 {
+                    inner
+                } else {
+                    inner.wrap_nullable()
+                }
+            } else {
+                TableDataType::Variant
+            }
+        }
+        _ => TableDataType::Variant,
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::Field;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::deserialize_orc_stripe;
+use common_storage::infer_orc_schema;
+use common_storage::orc_column_ids;
+use common_storage::orc_stripe_num_rows;
+use common_storage::orc_stripe_range;
+use common_storage::read_orc_metadata;
+use common_storage::read_orc_metadata_async;
+use common_storage::read_orc_stripe_async;
+use common_storage::slice_orc_stripe;
+use common_storage::OrcFileMeta;
+use common_storage::OrcStripe;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::ReadBatchTrait;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatOrc;
+
+impl InputFormatOrc {
+    fn make_splits(
+        file_infos: Vec<StageFileInfo>,
+        metas: Vec<OrcFileMeta>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let mut infos = vec![];
+        let mut schema = None;
+        for (info, file_meta) in file_infos.into_iter().zip(metas.into_iter()) {
+            if schema.is_none() {
+                schema = Some(infer_orc_schema(&file_meta)?);
+            }
+            let fields = Arc::new(schema.clone().unwrap().fields);
+            let num_file_splits = file_meta.footer.stripes.len();
+            let read_file_meta = Arc::new(FileMeta {
+                fields,
+                meta: file_meta,
+            });
+
+            let file_info = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size: info.size as usize,
+                num_splits: num_file_splits,
+                compress_alg: None,
+            });
+
+            for stripe in 0..num_file_splits {
+                if orc_stripe_num_rows(&read_file_meta.meta, stripe) == 0 {
+                    continue;
+                }
+                let (offset, size) = orc_stripe_range(&read_file_meta.meta, stripe);
+                let meta = Arc::new(SplitMeta {
+                    file: read_file_meta.clone(),
+                    stripe,
+                });
+                infos.push(Arc::new(SplitInfo {
+                    file: file_info.clone(),
+                    seq_in_file: stripe,
+                    offset: offset as usize,
+                    size: size as usize,
+                    num_file_splits,
+                    format_info: Some(meta),
+                }));
+            }
+        }
+
+        Ok(infos)
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let metas = futures::future::try_join_all(
+            file_infos
+                .iter()
+                .map(|f| read_orc_metadata_async(op, &f.path, Some(f.size))),
+        )
+        .await?;
+        Self::make_splits(file_infos, metas)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let file_meta = read_orc_metadata_async(op, path, None).await?;
+        let arrow_schema = infer_orc_schema(&file_meta)?;
+        Ok(Arc::new(TableSchema::from(&arrow_schema)))
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_aligned(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = SplitMeta;
+    type ReadBatch = ReadBatch;
+    type RowBatch = StripeInMemory;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    #[async_backtrace::framed]
+    async fn read_split(
+        ctx: Arc<InputContext>,
+        split_info: Arc<SplitInfo>,
+    ) -> Result<Self::RowBatch> {
+        let meta = Self::get_split_meta(&split_info).expect("must success");
+        let op = ctx.source.get_operator()?;
+        let fields = Arc::new(get_used_fields(&meta.file.fields, &ctx.schema)?);
+        let stripe =
+            read_orc_stripe_async(&op, &split_info.file.path, &meta.file.meta, meta.stripe).await?;
+        StripeInMemory::try_create(split_info.to_string(), meta.file.clone(), stripe, fields)
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(_ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder {})
+    }
+}
+
+pub struct FileMeta {
+    // all fields in the orc file
+    pub fields: Arc<Vec<Field>>,
+    pub meta: OrcFileMeta,
+}
+
+#[derive(Clone)]
+pub struct SplitMeta {
+    pub file: Arc<FileMeta>,
+    pub stripe: usize,
+}
+
+impl Debug for SplitMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "orc split meta")
+    }
+}
+
+impl serde::Serialize for SplitMeta {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for SplitMeta {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+#[typetag::serde(name = "orc_split")]
+impl DynData for SplitMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct StripeInMemory {
+    pub split_info: String,
+    pub file: Arc<FileMeta>,
+    pub stripe: OrcStripe,
+    // in the order of schema, used both in read and deserialize.
+    pub fields_to_read: Arc<Vec<Field>>,
+    pub column_ids: Vec<u32>,
+}
+
+impl RowBatchTrait for StripeInMemory {
+    fn size(&self) -> usize {
+        self.stripe.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        orc_stripe_num_rows(&self.file.meta, self.stripe.index)
+    }
+}
+
+impl StripeInMemory {
+    fn try_create(
+        split_info: String,
+        file: Arc<FileMeta>,
+        stripe: OrcStripe,
+        fields: Arc<Vec<Field>>,
+    ) -> Result<Self> {
+        let field_names = fields.iter().map(|x| x.name.as_str()).collect::<Vec<_>>();
+        let column_ids = orc_column_ids(&file.meta, &field_names)?;
+        Ok(Self {
+            split_info,
+            file,
+            stripe,
+            fields_to_read: fields,
+            column_ids,
+        })
+    }
+}
+
+impl Debug for StripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StripeInMemory")
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadBatch {
+    Buffer(Vec<u8>),
+}
+
+impl From<Vec<u8>> for ReadBatch {
+    fn from(v: Vec<u8>) -> Self {
+        Self::Buffer(v)
+    }
+}
+
+impl ReadBatchTrait for ReadBatch {
+    fn size(&self) -> usize {
+        match self {
+            ReadBatch::Buffer(v) => v.len(),
+        }
+    }
+}
+
+pub struct OrcBlockBuilder {}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<StripeInMemory>) -> Result<Vec<DataBlock>> {
+        if let Some(stripe) = batch {
+            let chunk = deserialize_orc_stripe(
+                &stripe.file.meta,
+                &stripe.stripe,
+                &stripe.fields_to_read,
+                &stripe.column_ids,
+            )
+            .map_err(|e| e.add_message(format!("failed to read orc {}", stripe.split_info)))?;
+
+            let fields: Vec<DataField> = stripe
+                .fields_to_read
+                .iter()
+                .map(DataField::from)
+                .collect::<Vec<_>>();
+
+            let input_schema = DataSchema::new(fields);
+            let block = DataBlock::from_arrow_chunk(&chunk, &input_schema)?;
+            Ok(vec![block])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+pub struct OrcAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<ReadBatch>) -> Result<Vec<StripeInMemory>> {
+        if let Some(ReadBatch::Buffer(b)) = read_batch {
+            self.buffers.push(b);
+            Ok(vec![])
+        } else {
+            let file_in_memory = self.buffers.concat();
+            let size = file_in_memory.len();
+            tracing::debug!(
+                "aligning orc file {} of {} bytes",
+                self.split_info.file.path,
+                size,
+            );
+            let meta = read_orc_metadata(&file_in_memory)?;
+            let fields = infer_orc_schema(&meta)?.fields;
+            let fields_to_read = Arc::new(get_used_fields(&fields, &self.ctx.schema)?);
+            let num_stripes = meta.footer.stripes.len();
+            let file = Arc::new(FileMeta {
+                fields: Arc::new(fields),
+                meta,
+            });
+
+            let mut row_batches = Vec::with_capacity(num_stripes);
+            for index in 0..num_stripes {
+                let stripe = slice_orc_stripe(&file_in_memory, &file.meta, index);
+                row_batches.push(StripeInMemory::try_create(
+                    self.split_info.to_string(),
+                    file.clone(),
+                    stripe,
+                    fields_to_read.clone(),
+                )?);
+            }
+            tracing::info!(
+                "align orc file {} of {} bytes to {} stripes",
+                self.split_info.file.path,
+                size,
+                row_batches.len()
+            );
+            Ok(row_batches)
+        }
+    }
+}
+
+fn get_used_fields(fields: &[Field], schema: &TableSchemaRef) -> Result<Vec<Field>> {
+    let mut read_fields = Vec::with_capacity(fields.len());
+    for f in schema.fields().iter() {
+        if let Some(m) = fields
+            .iter()
+            .filter(|c| c.name.eq_ignore_ascii_case(f.name()))
+            .last()
+        {
+            read_fields.push(m.clone());
+        } else {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "schema field size mismatch, expected to find column: {}",
+                f.name()
+            )));
+        }
+    }
+    Ok(read_fields)
+}
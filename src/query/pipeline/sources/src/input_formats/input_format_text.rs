@@ -577,7 +577,7 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
     }
 }
 
-fn decompress(decoder: &mut DecompressDecoder, compressed: &[u8]) -> Result<Vec<u8>> {
+pub(crate) fn decompress(decoder: &mut DecompressDecoder, compressed: &[u8]) -> Result<Vec<u8>> {
     let mut decompress_bufs = vec![];
     let mut amt = 0;
     loop {
@@ -12,15 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::compute::sort::row::Row as ArrowRow;
 use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
 use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
 use common_arrow::arrow::compute::sort::row::SortField as ArrowSortField;
 use common_arrow::arrow::compute::sort::SortOptions as ArrowSortOptions;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_exception::Result;
 use common_expression::arrow::column_to_arrow_array;
 use common_expression::BlockEntry;
+use common_expression::Column;
 use common_expression::DataSchemaRef;
+use common_expression::DataType;
 use common_expression::SortColumnDescription;
 
 use super::RowConverter;
@@ -45,25 +51,85 @@ impl RowConverter<ArrowRows> for ArrowRowConverter {
     ) -> Result<Self> {
         let sort_fields = sort_columns_descriptions
             .iter()
-            .map(|d| {
+            .flat_map(|d| {
                 let data_type = output_schema.field(d.offset).data_type();
-                Ok(ArrowSortField::new_with_options(
-                    data_type.into(),
-                    ArrowSortOptions {
-                        descending: !d.asc,
-                        nulls_first: d.nulls_first,
-                    },
-                ))
+                let options = ArrowSortOptions {
+                    descending: !d.asc,
+                    nulls_first: d.nulls_first,
+                };
+                sort_key_types(data_type)
+                    .into_iter()
+                    .map(move |ty| ArrowSortField::new_with_options(ty, options))
             })
-            .collect::<Result<Vec<_>>>()?;
+            .collect::<Vec<_>>();
         Ok(ArrowRowConverter::new(sort_fields))
     }
 
     fn convert(&mut self, columns: &[BlockEntry], num_rows: usize) -> Result<ArrowRows> {
         let arrays = columns
             .iter()
-            .map(|col| column_to_arrow_array(col, num_rows))
+            .flat_map(|col| sort_key_arrays(col, num_rows))
             .collect::<Vec<_>>();
         Ok(self.convert_columns(&arrays)?)
     }
 }
+
+/// The row format compares the raw values, which doesn't match the order of
+/// some types. An interval is ordered by its approximate length and then by
+/// its raw value, so it's encoded as two keys. A timestamp with time zone keeps
+/// its offset in the high bits, but it's ordered by the instant first.
+fn sort_key_types(data_type: &DataType) -> Vec<ArrowDataType> {
+    match data_type.remove_nullable() {
+        DataType::Interval => vec![ArrowDataType::Decimal(38, 0); 2],
+        DataType::TimestampTz => vec![ArrowDataType::Decimal(38, 0)],
+        _ => vec![data_type.into()],
+    }
+}
+
+fn sort_key_arrays(entry: &BlockEntry, num_rows: usize) -> Vec<Box<dyn Array>> {
+    match entry.data_type.remove_nullable() {
+        DataType::Interval => {
+            let (column, validity) = full_column(entry, num_rows);
+            let values = column.as_interval().unwrap();
+            let lengths = values.iter().map(|v| v.total_micros()).collect();
+            let raw_values = values.iter().map(|v| v.0).collect();
+            vec![
+                decimal_array(lengths, validity.clone()),
+                decimal_array(raw_values, validity),
+            ]
+        }
+        DataType::TimestampTz => {
+            let (column, validity) = full_column(entry, num_rows);
+            let values = column
+                .as_timestamp_tz()
+                .unwrap()
+                .iter()
+                .map(|v| {
+                    // The offset is biased into the unsigned low 32 bits.
+                    let offset = (v.offset() as i64 - i32::MIN as i64) as i128;
+                    ((v.micros() as i128) << 32) | offset
+                })
+                .collect();
+            vec![decimal_array(values, validity)]
+        }
+        _ => vec![column_to_arrow_array(entry, num_rows)],
+    }
+}
+
+fn full_column(entry: &BlockEntry, num_rows: usize) -> (Column, Option<Bitmap>) {
+    match entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows)
+    {
+        Column::Nullable(col) => (col.column, Some(col.validity)),
+        col => (col, None),
+    }
+}
+
+fn decimal_array(values: Vec<i128>, validity: Option<Bitmap>) -> Box<dyn Array> {
+    Box::new(PrimitiveArray::<i128>::new(
+        ArrowDataType::Decimal(38, 0),
+        values.into(),
+        validity,
+    ))
+}
@@ -129,7 +129,7 @@ impl AuthMgr {
                     } => match p {
                         None => return Err(ErrorCode::AuthenticateFailure("password required")),
                         Some(p) => {
-                            if *h == t.hash(p) {
+                            if t.verify(h, p) {
                                 user
                             } else {
                                 return Err(ErrorCode::AuthenticateFailure("wrong password"));
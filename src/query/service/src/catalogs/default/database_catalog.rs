@@ -19,40 +19,70 @@ use common_catalog::table_args::TableArgs;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::AcquireTaskLeaseReply;
+use common_meta_app::schema::AcquireTaskLeaseReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReply;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateTaskReply;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::CreateTaskRunReply;
+use common_meta_app::schema::CreateTaskRunReq;
 use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropTaskReply;
+use common_meta_app::schema::DropTaskReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::GetTaskReply;
+use common_meta_app::schema::GetTaskReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListSequencesReq;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::ListTasksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRun;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateTaskRunReply;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_meta_app::schema::UpdateTaskStateReply;
+use common_meta_app::schema::UpdateTaskStateReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MetaId;
@@ -469,6 +499,14 @@ impl Catalog for DatabaseCatalog {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        self.mutable_catalog.update_multi_table_meta(req).await
+    }
+
     // Table index
 
     #[async_backtrace::framed]
@@ -486,6 +524,79 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.list_indexes(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn create_sequence(&self, req: CreateSequenceReq) -> Result<CreateSequenceReply> {
+        self.mutable_catalog.create_sequence(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
+        self.mutable_catalog.drop_sequence(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply> {
+        self.mutable_catalog.get_sequence(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_sequences(&self, req: ListSequencesReq) -> Result<Vec<(String, SequenceMeta)>> {
+        self.mutable_catalog.list_sequences(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply> {
+        self.mutable_catalog.get_sequence_next_value(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_task(&self, req: CreateTaskReq) -> Result<CreateTaskReply> {
+        self.mutable_catalog.create_task(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_task(&self, req: DropTaskReq) -> Result<DropTaskReply> {
+        self.mutable_catalog.drop_task(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_task(&self, req: GetTaskReq) -> Result<GetTaskReply> {
+        self.mutable_catalog.get_task(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tasks(&self, req: ListTasksReq) -> Result<Vec<(String, TaskMeta)>> {
+        self.mutable_catalog.list_tasks(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn update_task_state(&self, req: UpdateTaskStateReq) -> Result<UpdateTaskStateReply> {
+        self.mutable_catalog.update_task_state(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_task_run(&self, req: CreateTaskRunReq) -> Result<CreateTaskRunReply> {
+        self.mutable_catalog.create_task_run(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn update_task_run(&self, req: UpdateTaskRunReq) -> Result<UpdateTaskRunReply> {
+        self.mutable_catalog.update_task_run(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_task_runs(&self, req: ListTaskRunsReq) -> Result<Vec<TaskRun>> {
+        self.mutable_catalog.list_task_runs(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn acquire_task_lease(&self, req: AcquireTaskLeaseReq) -> Result<AcquireTaskLeaseReply> {
+        self.mutable_catalog.acquire_task_lease(req).await
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
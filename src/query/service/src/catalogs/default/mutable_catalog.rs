@@ -19,16 +19,24 @@ use chrono::Utc;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_api::SchemaApi;
+use common_meta_app::schema::AcquireTaskLeaseReply;
+use common_meta_app::schema::AcquireTaskLeaseReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReply;
 use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateTaskReply;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::CreateTaskRunReply;
+use common_meta_app::schema::CreateTaskRunReq;
 use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
@@ -39,31 +47,53 @@ use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropTaskReply;
+use common_meta_app::schema::DropTaskReq;
 use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::GetTaskReply;
+use common_meta_app::schema::GetTaskReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListSequencesReq;
 use common_meta_app::schema::ListTableLockRevReq;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::ListTasksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRun;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateTaskRunReply;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_meta_app::schema::UpdateTaskStateReply;
+use common_meta_app::schema::UpdateTaskStateReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_store::MetaStoreProvider;
@@ -176,9 +206,7 @@ impl Catalog for MutableCatalog {
         let dbs = self
             .ctx
             .meta
-            .list_databases(ListDatabaseReq {
-                tenant: tenant.to_string(),
-            })
+            .list_databases(ListDatabaseReq::new(tenant.to_string()))
             .await?;
 
         dbs.iter().try_fold(vec![], |mut acc, item| {
@@ -231,6 +259,79 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.list_indexes(req).await?)
     }
 
+    #[async_backtrace::framed]
+    async fn create_sequence(&self, req: CreateSequenceReq) -> Result<CreateSequenceReply> {
+        Ok(self.ctx.meta.create_sequence(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
+        Ok(self.ctx.meta.drop_sequence(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply> {
+        Ok(self.ctx.meta.get_sequence(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_sequences(&self, req: ListSequencesReq) -> Result<Vec<(String, SequenceMeta)>> {
+        Ok(self.ctx.meta.list_sequences(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply> {
+        Ok(self.ctx.meta.get_sequence_next_value(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn create_task(&self, req: CreateTaskReq) -> Result<CreateTaskReply> {
+        Ok(self.ctx.meta.create_task(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_task(&self, req: DropTaskReq) -> Result<DropTaskReply> {
+        Ok(self.ctx.meta.drop_task(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_task(&self, req: GetTaskReq) -> Result<GetTaskReply> {
+        Ok(self.ctx.meta.get_task(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tasks(&self, req: ListTasksReq) -> Result<Vec<(String, TaskMeta)>> {
+        Ok(self.ctx.meta.list_tasks(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_task_state(&self, req: UpdateTaskStateReq) -> Result<UpdateTaskStateReply> {
+        Ok(self.ctx.meta.update_task_state(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn create_task_run(&self, req: CreateTaskRunReq) -> Result<CreateTaskRunReply> {
+        Ok(self.ctx.meta.create_task_run(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_task_run(&self, req: UpdateTaskRunReq) -> Result<UpdateTaskRunReply> {
+        Ok(self.ctx.meta.update_task_run(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_task_runs(&self, req: ListTaskRunsReq) -> Result<Vec<TaskRun>> {
+        Ok(self.ctx.meta.list_task_runs(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn acquire_task_lease(&self, req: AcquireTaskLeaseReq) -> Result<AcquireTaskLeaseReply> {
+        Ok(self.ctx.meta.acquire_task_lease(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn undrop_database(&self, req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
         let res = self.ctx.meta.undrop_database(req).await?;
@@ -349,6 +450,21 @@ impl Catalog for MutableCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        info!(
+            "updating multi table meta. table ids: {:?}",
+            req.update_table_metas
+                .iter()
+                .map(|r| r.table_id)
+                .collect::<Vec<_>>()
+        );
+        Ok(self.ctx.meta.update_multi_table_meta(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
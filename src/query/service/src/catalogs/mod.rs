@@ -16,6 +16,7 @@ mod catalog;
 
 mod catalog_manager;
 pub mod default;
+mod sequence_cache;
 pub use catalog::Catalog;
 pub use catalog_manager::CatalogManager;
 pub use catalog_manager::CatalogManagerHelper;
@@ -24,3 +25,5 @@ pub use common_storages_hive as hive;
 pub use default::table_id_ranges::*;
 pub use default::table_memory_meta::InMemoryMetas;
 pub use default::DatabaseCatalog;
+pub use sequence_cache::QuerySequenceValues;
+pub use sequence_cache::SequenceCache;
@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::Thread;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::SequenceValues;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReq;
+use common_meta_app::schema::SequenceNameIdent;
+use parking_lot::Mutex;
+
+use crate::catalogs::Catalog;
+
+/// Values of a sequence reserved from the meta-service but not used yet.
+struct ReservedValues {
+    /// The creation time of the sequence the values are reserved from.
+    created_on: DateTime<Utc>,
+    next: u64,
+    end: u64,
+    increment: u64,
+}
+
+impl ReservedValues {
+    fn take(&mut self, count: u64, values: &mut Vec<u64>) {
+        while (values.len() as u64) < count && self.next < self.end {
+            values.push(self.next);
+            self.next += self.increment;
+        }
+    }
+}
+
+/// Caches the values of sequences reserved by this query node,
+/// so that `nextval()` does not have to ask the meta-service for every value.
+///
+/// The values reserved but not used are lost when the node restarts,
+/// thus the values of a sequence are unique and increasing on a node, but may have gaps.
+///
+/// A sequence may be dropped and created again by another node, the reserved values
+/// are only used for the sequence created at the same time as they are reserved from.
+pub struct SequenceCache {
+    reserved: Mutex<HashMap<SequenceNameIdent, ReservedValues>>,
+}
+
+impl SequenceCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(SequenceCache {
+            reserved: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SequenceCache> {
+        GlobalInstance::get()
+    }
+
+    /// Returns the next `count` values of a sequence, which is created at `created_on`.
+    ///
+    /// If the reserved values are used up, reserve at least `cache_size` values from the meta-service.
+    #[async_backtrace::framed]
+    pub async fn next_values(
+        &self,
+        catalog: Arc<dyn Catalog>,
+        name_ident: &SequenceNameIdent,
+        created_on: &DateTime<Utc>,
+        count: u64,
+        cache_size: u64,
+    ) -> Result<Vec<u64>> {
+        let mut values = Vec::with_capacity(count as usize);
+        self.take_reserved(name_ident, created_on, count, &mut values);
+
+        let remaining = count - values.len() as u64;
+        if remaining == 0 {
+            return Ok(values);
+        }
+
+        let reply = catalog
+            .get_sequence_next_value(GetSequenceNextValueReq {
+                name_ident: name_ident.clone(),
+                count: remaining.max(cache_size),
+            })
+            .await?;
+
+        let mut reserved = ReservedValues {
+            created_on: reply.created_on,
+            next: reply.start,
+            end: reply.end,
+            increment: reply.increment,
+        };
+        reserved.take(count, &mut values);
+        self.reserved.lock().insert(name_ident.clone(), reserved);
+
+        Ok(values)
+    }
+
+    /// Take at most `count` values from the values reserved for a sequence, which is created
+    /// at `created_on`, without asking the meta-service.
+    pub fn take_reserved(
+        &self,
+        name_ident: &SequenceNameIdent,
+        created_on: &DateTime<Utc>,
+        count: u64,
+        values: &mut Vec<u64>,
+    ) {
+        let mut reserved = self.reserved.lock();
+        match reserved.get_mut(name_ident) {
+            Some(r) if &r.created_on == created_on => r.take(count, values),
+            // Reserved from a dropped sequence of the same name.
+            Some(_) => {
+                reserved.remove(name_ident);
+            }
+            None => {}
+        }
+    }
+
+    /// Drop the values reserved for a sequence, e.g., when the sequence is dropped.
+    pub fn invalidate(&self, name_ident: &SequenceNameIdent) {
+        self.reserved.lock().remove(name_ident);
+    }
+}
+
+/// Provides the values of sequences to `nextval()` of a query.
+pub struct QuerySequenceValues {
+    tenant: String,
+    catalog: Arc<dyn Catalog>,
+    cache_size: u64,
+    /// The creation time of the sequences used by the query, which is resolved on the first use.
+    created_on: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl QuerySequenceValues {
+    pub fn create(tenant: String, catalog: Arc<dyn Catalog>, cache_size: u64) -> Self {
+        QuerySequenceValues {
+            tenant,
+            catalog,
+            cache_size,
+            created_on: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl SequenceValues for QuerySequenceValues {
+    fn next_values(&self, sequence: &str, count: u64) -> Result<Vec<u64>> {
+        let name_ident = SequenceNameIdent::new(&self.tenant, sequence);
+        let resolved = self.created_on.lock().get(sequence).cloned();
+
+        // Most of the values are taken from the reserved values of the node.
+        let mut values = Vec::with_capacity(count as usize);
+        if let Some(created_on) = &resolved {
+            SequenceCache::instance().take_reserved(&name_ident, created_on, count, &mut values);
+            if values.len() as u64 == count {
+                return Ok(values);
+            }
+        }
+
+        // The function may be evaluated on a thread of an async runtime, e.g. the values of
+        // `INSERT ... VALUES`, which can't block on a runtime. So the values are reserved
+        // from the meta-service on a dedicated thread.
+        let catalog = self.catalog.clone();
+        let cache_size = self.cache_size;
+        let remaining = count - values.len() as u64;
+        let (created_on, more) = Thread::named_spawn(Some("nextval".to_string()), move || {
+            GlobalIORuntime::instance().block_on(async move {
+                let created_on = match resolved {
+                    Some(created_on) => created_on,
+                    None => {
+                        let req = GetSequenceReq {
+                            name_ident: name_ident.clone(),
+                        };
+                        catalog.get_sequence(req).await?.meta.created_on
+                    }
+                };
+                let values = SequenceCache::instance()
+                    .next_values(catalog, &name_ident, &created_on, remaining, cache_size)
+                    .await?;
+                Ok::<_, ErrorCode>((created_on, values))
+            })
+        })
+        .join()??;
+
+        self.created_on
+            .lock()
+            .insert(sequence.to_string(), created_on);
+        values.extend(more);
+        Ok(values)
+    }
+}
@@ -41,11 +41,14 @@ use common_storages_system::ProcessesTable;
 use common_storages_system::QueryCacheTable;
 use common_storages_system::QueryLogTable;
 use common_storages_system::RolesTable;
+use common_storages_system::SequencesTable;
 use common_storages_system::SettingsTable;
 use common_storages_system::StagesTable;
 use common_storages_system::TableFunctionsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
+use common_storages_system::TaskHistoryTable;
+use common_storages_system::TasksTable;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
 
@@ -103,6 +106,9 @@ impl SystemDatabase {
             TableFunctionsTable::create(sys_db_meta.next_table_id()),
             CachesTable::create(sys_db_meta.next_table_id()),
             IndexesTable::create(sys_db_meta.next_table_id()),
+            SequencesTable::create(sys_db_meta.next_table_id()),
+            TasksTable::create(sys_db_meta.next_table_id()),
+            TaskHistoryTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
@@ -30,9 +30,11 @@ use storages_common_cache_manager::CacheManager;
 use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::CatalogManagerHelper;
+use crate::catalogs::SequenceCache;
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::task::TaskScheduler;
 
 pub struct GlobalServices;
 
@@ -80,6 +82,8 @@ impl GlobalServices {
         .await?;
         RoleCacheManager::init()?;
         ShareEndpointManager::init()?;
+        SequenceCache::init()?;
+        TaskScheduler::init(&config)?;
 
         Ok(())
     }
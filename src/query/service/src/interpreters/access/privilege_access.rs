@@ -53,7 +53,10 @@ impl AccessChecker for PrivilegeAccess {
                     | Some(RewriteKind::ShowColumns)
                     | Some(RewriteKind::ShowEngines)
                     | Some(RewriteKind::ShowFunctions)
-                    | Some(RewriteKind::ShowTableFunctions) => {
+                    | Some(RewriteKind::ShowTableFunctions)
+                    | Some(RewriteKind::ShowSequences)
+                    | Some(RewriteKind::ShowTasks)
+                    | Some(RewriteKind::ShowStreams) => {
                         return Ok(());
                     }
                     _ => {}
@@ -86,7 +89,12 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?
             }
-            Plan::CreateUDF(_) | Plan::CreateDatabase(_) | Plan::CreateIndex(_) => {
+            Plan::CreateUDF(_)
+            | Plan::CreateDatabase(_)
+            | Plan::CreateIndex(_)
+            | Plan::CreateSequence(_)
+            | Plan::CreateTask(_)
+            | Plan::CreateMaskingPolicy(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
@@ -94,7 +102,10 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropDatabase(_)
             | Plan::UndropDatabase(_)
             | Plan::DropUDF(_)
-            | Plan::DropIndex(_) => {
+            | Plan::DropIndex(_)
+            | Plan::DropSequence(_)
+            | Plan::DropTask(_)
+            | Plan::DropMaskingPolicy(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
                     .await?;
@@ -194,6 +205,30 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::ModifyTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::SetColumnMaskingPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::DropTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -315,6 +350,22 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![
+                            UserPrivilegeType::Insert,
+                            UserPrivilegeType::Update,
+                            UserPrivilegeType::Delete,
+                        ],
+                    )
+                    .await?;
+            }
             Plan::Delete(plan) => {
                 session
                     .validate_privilege(
@@ -363,6 +414,53 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                // The stream exposes the changes of its base table.
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                    )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -399,7 +497,11 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Grant])
                     .await?;
             }
-            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
+            Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_)
+            | Plan::AlterTask(_)
+            | Plan::ExecuteTask(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -479,6 +581,7 @@ impl AccessChecker for PrivilegeAccess {
             Plan::Presign(_) => {}
             Plan::ExplainAst { .. } => {}
             Plan::ExplainSyntax { .. } => {}
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
         }
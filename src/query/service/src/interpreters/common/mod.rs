@@ -21,3 +21,4 @@ pub use stage::fill_default_value;
 pub use stage::prepared_values;
 pub use stage::try_purge_files;
 pub use table::append2table;
+pub use table::check_referenced_computed_columns;
@@ -169,7 +169,11 @@ pub async fn exprs_to_scalar(
                 | DataType::Number(_)
                 | DataType::Decimal(_)
                 | DataType::Timestamp
+                | DataType::TimestampTz
                 | DataType::Date
+                | DataType::Interval
+                | DataType::Binary
+                | DataType::Geometry
                 | DataType::Bitmap
                 | DataType::Variant => wrap_cast(&scalar, field_data_type),
                 DataType::String => {
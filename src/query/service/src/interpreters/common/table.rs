@@ -18,9 +18,13 @@ use common_base::runtime::GlobalIORuntime;
 use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::DataSchemaRef;
+use common_expression::TableSchemaRef;
 use common_pipeline_core::Pipeline;
+use common_sql::parse_computed_expr;
 
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
@@ -90,3 +94,30 @@ pub fn append2table(
 
     Ok(())
 }
+
+/// Check the computed columns are still valid after the column is dropped or modified.
+pub fn check_referenced_computed_columns(
+    ctx: Arc<QueryContext>,
+    schema: TableSchemaRef,
+    column: &str,
+) -> Result<()> {
+    for field in schema.fields() {
+        if let Some(computed_expr) = field.computed_expr() {
+            if parse_computed_expr(
+                ctx.clone(),
+                schema.clone(),
+                &DataType::from(field.data_type()),
+                computed_expr.expr(),
+            )
+            .is_err()
+            {
+                return Err(ErrorCode::ColumnReferencedByComputedColumn(format!(
+                    "column `{}` is referenced by computed column `{}`",
+                    column,
+                    field.name()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
@@ -63,6 +63,7 @@ pub trait Interpreter: Sync + Send {
             Err(build_error) => {
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()));
+                ctx.txn_mgr().lock().set_fail();
                 return Err(build_error);
             }
         };
@@ -81,7 +82,10 @@ pub trait Interpreter: Sync + Send {
 
             match may_error {
                 None => Ok(()),
-                Some(error) => Err(error.clone()),
+                Some(error) => {
+                    query_ctx.txn_mgr().lock().set_fail();
+                    Err(error.clone())
+                }
             }
         });
 
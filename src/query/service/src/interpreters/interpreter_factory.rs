@@ -15,6 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -22,7 +25,13 @@ use tracing::error;
 use super::interpreter_catalog_create::CreateCatalogInterpreter;
 use super::interpreter_index_create::CreateIndexInterpreter;
 use super::interpreter_index_drop::DropIndexInterpreter;
+use super::interpreter_sequence_create::CreateSequenceInterpreter;
+use super::interpreter_sequence_drop::DropSequenceInterpreter;
 use super::interpreter_share_desc::DescShareInterpreter;
+use super::interpreter_task_alter::AlterTaskInterpreter;
+use super::interpreter_task_create::CreateTaskInterpreter;
+use super::interpreter_task_drop::DropTaskInterpreter;
+use super::interpreter_task_execute::ExecuteTaskInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
 use crate::interpreters::access::Accessor;
@@ -59,9 +68,42 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Only the queries and the DML statements are allowed in a transaction,
+    /// after a statement of the transaction failed, it can only be ended.
+    fn check_txn(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let state = ctx.txn_mgr().lock().state();
+        match state {
+            TxnState::AutoCommit => Ok(()),
+            TxnState::Active => match plan {
+                Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort => Ok(()),
+                _ => Err(ErrorCode::Unimplemented(format!(
+                    "{} is not supported in a transaction",
+                    plan
+                ))),
+            },
+            TxnState::Fail => match plan {
+                Plan::Commit | Plan::Abort => Ok(()),
+                _ => Err(ErrorCode::CurrentTransactionIsAborted(
+                    "Current transaction is aborted, commands ignored until end of transaction block",
+                )),
+            },
+        }
+    }
+
     /// This is used for handlers to get the schema of the plan.
     /// Some plan may miss the schema and return empty plan such as `CallPlan`
     /// So we need to map the plan into to `Interpreter` and get the right schema.
@@ -175,6 +217,12 @@ impl InterpreterFactory {
             Plan::AddTableColumn(add_table_column) => Ok(Arc::new(
                 AddTableColumnInterpreter::try_create(ctx, *add_table_column.clone())?,
             )),
+            Plan::ModifyTableColumn(modify_table_column) => Ok(Arc::new(
+                ModifyTableColumnInterpreter::try_create(ctx, *modify_table_column.clone())?,
+            )),
+            Plan::SetColumnMaskingPolicy(set_masking_policy) => Ok(Arc::new(
+                SetColumnMaskingPolicyInterpreter::try_create(ctx, *set_masking_policy.clone())?,
+            )),
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
@@ -219,6 +267,12 @@ impl InterpreterFactory {
                 ctx,
                 *drop_view.clone(),
             )?)),
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
@@ -231,6 +285,52 @@ impl InterpreterFactory {
                 *index.clone(),
             )?)),
 
+            // Sequences
+            Plan::CreateSequence(sequence) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *sequence.clone(),
+            )?)),
+            Plan::DropSequence(sequence) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *sequence.clone(),
+            )?)),
+
+            // Tasks
+            Plan::CreateTask(task) => Ok(Arc::new(CreateTaskInterpreter::try_create(
+                ctx,
+                *task.clone(),
+            )?)),
+            Plan::AlterTask(task) => Ok(Arc::new(AlterTaskInterpreter::try_create(
+                ctx,
+                *task.clone(),
+            )?)),
+            Plan::DropTask(task) => Ok(Arc::new(DropTaskInterpreter::try_create(
+                ctx,
+                *task.clone(),
+            )?)),
+            Plan::ExecuteTask(task) => Ok(Arc::new(ExecuteTaskInterpreter::try_create(
+                ctx,
+                *task.clone(),
+            )?)),
+
+            // Streams
+            Plan::CreateStream(stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *stream.clone(),
+            )?)),
+            Plan::DropStream(stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *stream.clone(),
+            )?)),
+
+            // Masking policies
+            Plan::CreateMaskingPolicy(policy) => Ok(Arc::new(
+                CreateMaskingPolicyInterpreter::try_create(ctx, *policy.clone())?,
+            )),
+            Plan::DropMaskingPolicy(policy) => Ok(Arc::new(
+                DropMaskingPolicyInterpreter::try_create(ctx, *policy.clone())?,
+            )),
+
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
                 ctx,
@@ -248,6 +348,10 @@ impl InterpreterFactory {
             Plan::Insert(insert) => InsertInterpreter::try_create(ctx, *insert.clone()),
 
             Plan::Replace(replace) => ReplaceInterpreter::try_create(ctx, *replace.clone()),
+            Plan::MergeInto(merge_into) => Ok(Arc::new(MergeIntoInterpreter::try_create(
+                ctx,
+                *merge_into.clone(),
+            )?)),
 
             Plan::Delete(delete) => Ok(Arc::new(DeleteInterpreter::try_create(
                 ctx,
@@ -259,6 +363,11 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            // Transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::principal::MaskingPolicy;
+use common_sql::plans::CreateMaskingPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateMaskingPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaskingPolicyPlan,
+}
+
+impl CreateMaskingPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaskingPolicyPlan) -> Result<Self> {
+        Ok(CreateMaskingPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaskingPolicyInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaskingPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let policy = MaskingPolicy {
+            name: plan.name,
+            args: plan.args,
+            return_type: plan.return_type,
+            body: plan.body,
+            comment: plan.comment.unwrap_or_default(),
+            created_on: Utc::now(),
+        };
+        let _ = UserApiProvider::instance()
+            .add_masking_policy(&plan.tenant, policy, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
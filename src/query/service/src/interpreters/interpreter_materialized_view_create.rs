@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_sql::plans::CreateMaterializedViewPlan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use tracing::error;
+use tracing::info;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The base table changes whenever data is written to it, the registration of the view
+/// is retried against the latest version of the base table.
+const MAX_REGISTER_RETRIES: usize = 10;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+
+    /// Register the view to the base table, so that the queries on the base table
+    /// can find the view.
+    #[async_backtrace::framed]
+    async fn register_view(&self) -> Result<()> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let tenant = self.ctx.get_tenant();
+        let mut retries = 0;
+        loop {
+            let base = catalog
+                .get_table(tenant.as_str(), &plan.base_database, &plan.base_table)
+                .await?;
+            if base.get_id() != plan.base_table_id {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "The base table {}.{} of materialized view {}.{} has been dropped",
+                    plan.base_database, plan.base_table, plan.database, plan.view_name
+                )));
+            }
+            let mut views: Vec<(String, String)> =
+                match base.options().get(OPT_KEY_MATERIALIZED_VIEWS) {
+                    Some(v) => serde_json::from_str(v)?,
+                    None => vec![],
+                };
+            let view = (plan.database.clone(), plan.view_name.clone());
+            if views.contains(&view) {
+                return Ok(());
+            }
+            views.push(view);
+
+            match catalog
+                .upsert_table_option(
+                    tenant.as_str(),
+                    &plan.base_database,
+                    UpsertTableOptionReq::new(
+                        &base.get_table_info().ident,
+                        OPT_KEY_MATERIALIZED_VIEWS,
+                        serde_json::to_string(&views)?,
+                    ),
+                )
+                .await
+            {
+                Err(e)
+                    if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                        && retries < MAX_REGISTER_RETRIES =>
+                {
+                    retries += 1;
+                    info!(
+                        "The base table {}.{} has been changed, retry registering materialized view {}.{}, retries: {}",
+                        plan.base_database, plan.base_table, plan.database, plan.view_name, retries
+                    );
+                }
+                res => return res.map(|_| ()),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+
+        let mut options = plan.options.clone();
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            plan.subquery.clone(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_BASE_DATABASE.to_string(),
+            plan.base_database.clone(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE.to_string(),
+            plan.base_table.clone(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID.to_string(),
+            plan.base_table_id.to_string(),
+        );
+
+        let req = CreateTableReq {
+            if_not_exists: plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.view_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: plan.schema.clone(),
+                engine: Engine::Fuse.to_string(),
+                options,
+                ..Default::default()
+            },
+        };
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // A view which isn't registered is never used by the queries on the base table,
+        // drop it so that the creation can be retried.
+        if let Err(cause) = self.register_view().await {
+            if let Err(drop_cause) = catalog
+                .drop_table_by_id(DropTableByIdReq {
+                    if_exists: true,
+                    tb_id: reply.table_id,
+                })
+                .await
+            {
+                error!(
+                    "Cannot drop materialized view {}.{}, cause: {:?}",
+                    plan.database, plan.view_name, drop_cause
+                );
+            }
+            return Err(cause);
+        }
+
+        // Populate the view.
+        let refresh_plan = RefreshMaterializedViewPlan {
+            tenant: plan.tenant.clone(),
+            catalog: plan.catalog.clone(),
+            database: plan.database.clone(),
+            view_name: plan.view_name.clone(),
+        };
+        RefreshMaterializedViewInterpreter::try_create(self.ctx.clone(), refresh_plan)?
+            .execute2()
+            .await
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_sql::Planner;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_BASE_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let view = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.view_name)
+            .await?;
+        let view_options = view.options();
+        let (query, base_database, base_table, base_table_id) = match (
+            view_options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY),
+            view_options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_DATABASE),
+            view_options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE),
+            view_options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID),
+        ) {
+            (Some(query), Some(database), Some(table), Some(table_id)) => {
+                (query, database, table, table_id)
+            }
+            _ => {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} is not a materialized view",
+                    plan.database, plan.view_name
+                )));
+            }
+        };
+
+        let base = self
+            .ctx
+            .get_table(&plan.catalog, base_database, base_table)
+            .await?;
+        if &base.get_id().to_string() != base_table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "The base table {}.{} of materialized view {}.{} has been dropped",
+                base_database, base_table, plan.database, plan.view_name
+            )));
+        }
+
+        let current = base.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned();
+        let refreshed = view_options
+            .get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT)
+            .cloned();
+        if current == refreshed {
+            // The view is fresh.
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // If the refreshed snapshot is a prefix of the current snapshot, only the
+        // newly appended segments need to be aggregated and merged into the view.
+        let incremental = match (&current, &refreshed) {
+            (Some(current), Some(refreshed)) => {
+                let fuse_table = FuseTable::try_from_table(base.as_ref())?;
+                let current = fuse_table
+                    .read_table_snapshot_by_location(current.clone())
+                    .await?;
+                let refreshed = fuse_table
+                    .read_table_snapshot_by_location(refreshed.clone())
+                    .await?;
+                let segments = current.segments.iter().collect::<HashSet<_>>();
+                refreshed.segments.iter().all(|s| segments.contains(s))
+            }
+            _ => false,
+        };
+        let merge_statement = if incremental {
+            self.merge_delta_statement(view.as_ref(), query)?
+        } else {
+            None
+        };
+        if merge_statement.is_some() {
+            let mut table_info = base.get_table_info().clone();
+            table_info.meta.options.insert(
+                OPT_KEY_BASE_SNAPSHOT_LOCATION.to_string(),
+                refreshed.unwrap(),
+            );
+            let base = catalog.get_table_by_info(&table_info)?;
+            self.ctx.replace_table(&plan.catalog, base_database, base);
+        }
+
+        // Buffer the mutation of the view in a transaction, so that the new data
+        // and the refreshed snapshot are committed atomically.
+        let txn_mgr = self.ctx.txn_mgr();
+        txn_mgr.lock().begin();
+        let sql = merge_statement.unwrap_or_else(|| {
+            format!(
+                "INSERT OVERWRITE `{}`.`{}` {}",
+                plan.database, plan.view_name, query
+            )
+        });
+        let res = self.execute_sql(&sql).await;
+        let mutated_tables = txn_mgr.lock().finish();
+        res?;
+
+        let view_info = view.get_table_info();
+        let mut update_table_metas = mutated_tables
+            .into_iter()
+            .map(|(_, req)| req)
+            .collect::<Vec<_>>();
+        let req = match update_table_metas
+            .iter_mut()
+            .find(|req| req.table_id == view_info.ident.table_id)
+        {
+            Some(req) => req,
+            None => {
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: view_info.ident.table_id,
+                    seq: MatchSeq::Exact(view_info.ident.seq),
+                    new_table_meta: view_info.meta.clone(),
+                    copied_files: None,
+                });
+                update_table_metas.last_mut().unwrap()
+            }
+        };
+        match current {
+            Some(current) => req.new_table_meta.options.insert(
+                OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT.to_string(),
+                current,
+            ),
+            None => req
+                .new_table_meta
+                .options
+                .remove(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT),
+        };
+
+        catalog
+            .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+impl RefreshMaterializedViewInterpreter {
+    /// Builds the statement which merges the aggregation of the newly appended data into
+    /// the view, only the groups of the new data are updated or inserted.
+    ///
+    /// Returns None if the groups can't be merged, e.g. some group keys are not selected.
+    fn merge_delta_statement(&self, view: &dyn Table, query: &str) -> Result<Option<String>> {
+        let tokens = tokenize_sql(query)?;
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let (stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        let select = match &stmt {
+            Statement::Query(query) => match &query.body {
+                SetExpr::Select(select) => select,
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let group_by = match &select.group_by {
+            Some(GroupBy::Normal(items)) => items,
+            _ => return Ok(None),
+        };
+
+        let schema = view.schema();
+        if schema.num_fields() != select.select_list.len() {
+            return Ok(None);
+        }
+        let mut keys = Vec::new();
+        let mut columns = Vec::with_capacity(select.select_list.len());
+        let mut updates = Vec::new();
+        for (target, field) in select.select_list.iter().zip(schema.fields()) {
+            let expr = match target {
+                SelectTarget::AliasedExpr { expr, .. } => expr.as_ref(),
+                SelectTarget::QualifiedName { .. } => return Ok(None),
+            };
+            let column = format!("`{}`", field.name());
+            let (view_value, delta_value) = (format!("_view.{column}"), format!("_delta.{column}"));
+            let merged = match expr {
+                Expr::ColumnRef { .. } => {
+                    // The NULL keys can't be matched by the join of the merge.
+                    if field.data_type().is_nullable() {
+                        return Ok(None);
+                    }
+                    keys.push((expr.to_string(), column.clone()));
+                    None
+                }
+                // The partial counts and sums are added up.
+                Expr::CountAll { .. } => Some(format!("{view_value} + {delta_value}")),
+                Expr::FunctionCall { name, .. } => match name.name.to_lowercase().as_str() {
+                    "count" => Some(format!("{view_value} + {delta_value}")),
+                    "sum" => Some(format!(
+                        "CASE WHEN {view_value} IS NULL THEN {delta_value} WHEN {delta_value} IS NULL THEN {view_value} ELSE {view_value} + {delta_value} END"
+                    )),
+                    "min" => Some(format!(
+                        "CASE WHEN {view_value} IS NULL OR {delta_value} < {view_value} THEN {delta_value} ELSE {view_value} END"
+                    )),
+                    "max" => Some(format!(
+                        "CASE WHEN {view_value} IS NULL OR {delta_value} > {view_value} THEN {delta_value} ELSE {view_value} END"
+                    )),
+                    _ => return Ok(None),
+                },
+                _ => return Ok(None),
+            };
+            if let Some(merged) = merged {
+                updates.push(format!("{column} = {merged}"));
+            }
+            columns.push(column);
+        }
+        // The groups of the view must be identified by the selected columns.
+        if !group_by
+            .iter()
+            .all(|item| keys.iter().any(|(key, _)| *key == item.to_string()))
+        {
+            return Ok(None);
+        }
+
+        let condition = keys
+            .iter()
+            .map(|(_, column)| format!("_view.{column} = _delta.{column}"))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let mut sql = format!(
+            "MERGE INTO `{}`.`{}` AS _view USING ({}) AS _delta({}) ON {}",
+            self.plan.database,
+            self.plan.view_name,
+            query,
+            columns.join(", "),
+            condition
+        );
+        if !updates.is_empty() {
+            sql.push_str(&format!(
+                " WHEN MATCHED THEN UPDATE SET {}",
+                updates.join(", ")
+            ));
+        }
+        sql.push_str(&format!(
+            " WHEN NOT MATCHED THEN INSERT VALUES ({})",
+            columns
+                .iter()
+                .map(|column| format!("_delta.{column}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        Ok(Some(sql))
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_sql(&self, sql: &str) -> Result<()> {
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), &plan).await?;
+        let mut build_res = interpreter.execute2().await?;
+        if build_res.main_pipeline.is_empty() {
+            return Ok(());
+        }
+
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        self.ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::plan::MatchedClauseInfo;
+use common_catalog::plan::MergeIntoInfo;
+use common_catalog::plan::UnmatchedClauseInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::RemoteExpr;
+use common_sql::executor::cast_expr_to_non_null_boolean;
+use common_sql::plans::MergeInto;
+use common_sql::IndexType;
+use common_sql::ScalarExpr;
+use table_lock::TableLockHandlerWrapper;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlanBuilder;
+
+/// interprets MergeInto plan
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeInto,
+}
+
+impl MergeIntoInterpreter {
+    /// Create the MergeIntoInterpreter from MergeInto plan
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeInto) -> Result<Self> {
+        Ok(MergeIntoInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    /// Get the name of current interpreter
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", name = "merge_into_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        let table_info = tbl.get_table_info().clone();
+
+        // Add table lock heartbeat.
+        let handler = TableLockHandlerWrapper::instance(self.ctx.clone());
+        let mut heartbeat = handler
+            .try_lock(self.ctx.clone(), table_info.clone())
+            .await?;
+
+        // refresh table.
+        let tbl = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        // The `_row_id` of the matched rows are generated from the table bound in the plan,
+        // they are meaningless if the table is changed before the table lock is acquired.
+        if tbl.get_table_info().ident != table_info.ident {
+            heartbeat.shutdown().await?;
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "table {} has been changed during merge into, please retry",
+                tbl_name
+            )));
+        }
+
+        // The output of the join, it may be executed in cluster.
+        let mut builder = PhysicalPlanBuilder::new(self.plan.meta_data.clone(), self.ctx.clone());
+        let physical_plan = builder.build(&self.plan.input).await?;
+        let columns = &self.plan.bind_context.columns;
+        let mut build_res =
+            build_query_pipeline(&self.ctx, columns, &physical_plan, false, false).await?;
+
+        // The columns of the blocks are in the same order as `columns`.
+        let offsets: HashMap<IndexType, usize> = columns
+            .iter()
+            .enumerate()
+            .map(|(offset, column)| (column.index, offset))
+            .collect();
+        let merge_into_info = self.build_merge_into_info(&offsets)?;
+
+        tbl.merge_into(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            merge_into_info,
+        )
+        .await?;
+
+        if build_res.main_pipeline.is_empty() {
+            heartbeat.shutdown().await?;
+        } else {
+            build_res.main_pipeline.set_on_finished(move |may_error| {
+                // shutdown table lock heartbeat.
+                GlobalIORuntime::instance().block_on(async move { heartbeat.shutdown().await })?;
+                match may_error {
+                    None => Ok(()),
+                    Some(error_code) => Err(error_code.clone()),
+                }
+            });
+        }
+        Ok(build_res)
+    }
+}
+
+impl MergeIntoInterpreter {
+    fn build_merge_into_info(&self, offsets: &HashMap<IndexType, usize>) -> Result<MergeIntoInfo> {
+        let row_id_offset = *offsets.get(&self.plan.row_id_index).ok_or_else(|| {
+            ErrorCode::Internal("Internal column _row_id is not found in merge into")
+        })?;
+
+        let mut matched_clauses = Vec::with_capacity(self.plan.matched_evaluators.len());
+        for evaluator in &self.plan.matched_evaluators {
+            let condition = match &evaluator.condition {
+                Some(condition) => Some(Self::build_condition(condition, offsets)?),
+                None => None,
+            };
+            let update = match &evaluator.update {
+                Some(update) => Some(
+                    update
+                        .iter()
+                        .map(|scalar| Self::build_expr(scalar, offsets))
+                        .collect::<Result<Vec<_>>>()?,
+                ),
+                None => None,
+            };
+            matched_clauses.push(MatchedClauseInfo { condition, update });
+        }
+
+        let mut unmatched_clauses = Vec::with_capacity(self.plan.unmatched_evaluators.len());
+        for evaluator in &self.plan.unmatched_evaluators {
+            let condition = match &evaluator.condition {
+                Some(condition) => Some(Self::build_condition(condition, offsets)?),
+                None => None,
+            };
+            let values = evaluator
+                .values
+                .iter()
+                .map(|scalar| Self::build_expr(scalar, offsets))
+                .collect::<Result<Vec<_>>>()?;
+            unmatched_clauses.push(UnmatchedClauseInfo { condition, values });
+        }
+
+        Ok(MergeIntoInfo {
+            row_id_offset,
+            matched_clauses,
+            unmatched_clauses,
+        })
+    }
+
+    fn build_condition(
+        scalar: &ScalarExpr,
+        offsets: &HashMap<IndexType, usize>,
+    ) -> Result<RemoteExpr> {
+        let expr = scalar
+            .as_expr()?
+            .project_column_ref(|col| *offsets.get(&col.index).unwrap());
+        Ok(cast_expr_to_non_null_boolean(expr)?.as_remote_expr())
+    }
+
+    fn build_expr(scalar: &ScalarExpr, offsets: &HashMap<IndexType, usize>) -> Result<RemoteExpr> {
+        let expr = scalar
+            .as_expr()?
+            .project_column_ref(|col| *offsets.get(&col.index).unwrap());
+        Ok(expr.as_remote_expr())
+    }
+}
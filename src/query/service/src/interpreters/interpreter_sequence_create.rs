@@ -0,0 +1,70 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::Result;
+use common_meta_app::schema::CreateSequenceReq;
+use common_meta_app::schema::SequenceMeta;
+use common_meta_app::schema::SequenceNameIdent;
+use common_sql::plans::CreateSequencePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateSequenceInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateSequencePlan,
+}
+
+impl CreateSequenceInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateSequencePlan) -> Result<Self> {
+        Ok(CreateSequenceInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateSequenceInterpreter {
+    fn name(&self) -> &str {
+        "CreateSequenceInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let now = Utc::now();
+        let create_sequence_req = CreateSequenceReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: SequenceNameIdent::new(tenant, &self.plan.sequence),
+            meta: SequenceMeta {
+                created_on: now,
+                updated_on: now,
+                comment: self.plan.comment.clone(),
+                start: self.plan.start,
+                increment: self.plan.increment,
+                current: self.plan.start,
+            },
+        };
+
+        catalog.create_sequence(create_sequence_req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::Result;
+use common_meta_app::schema::DropSequenceReq;
+use common_meta_app::schema::SequenceNameIdent;
+use common_sql::plans::DropSequencePlan;
+
+use crate::catalogs::SequenceCache;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropSequenceInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropSequencePlan,
+}
+
+impl DropSequenceInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropSequencePlan) -> Result<Self> {
+        Ok(DropSequenceInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropSequenceInterpreter {
+    fn name(&self) -> &str {
+        "DropSequenceInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let name_ident = SequenceNameIdent::new(tenant, &self.plan.sequence);
+        let drop_sequence_req = DropSequenceReq {
+            if_exists: self.plan.if_exists,
+            name_ident: name_ident.clone(),
+        };
+
+        catalog.drop_sequence(drop_sequence_req).await?;
+
+        // The values reserved by this node are dropped with the sequence.
+        SequenceCache::instance().invalidate(&name_ident);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
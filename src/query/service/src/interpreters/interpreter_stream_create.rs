@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::StreamTable;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let base = self
+            .ctx
+            .get_table(&plan.catalog, &plan.table_database, &plan.table_name)
+            .await?;
+        let base_info = base.get_table_info();
+        if base.engine() != "FUSE" || base_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Table engine {} does not support stream",
+                base.engine()
+            )));
+        }
+        let fuse_table = FuseTable::try_from_table(base.as_ref())?;
+
+        // The stream starts with no changes: its offset is the current snapshot of the base table.
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_STREAM_BASE_DATABASE.to_string(),
+            plan.table_database.clone(),
+        );
+        options.insert(
+            OPT_KEY_STREAM_BASE_TABLE.to_string(),
+            plan.table_name.clone(),
+        );
+        options.insert(
+            OPT_KEY_STREAM_BASE_TABLE_ID.to_string(),
+            base.get_id().to_string(),
+        );
+        if let Some(location) = fuse_table.snapshot_loc().await? {
+            options.insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT.to_string(), location);
+        }
+        // The stream reads the data of the base table with these.
+        for key in [
+            OPT_KEY_DATABASE_ID,
+            OPT_KEY_STORAGE_FORMAT,
+            OPT_KEY_TABLE_COMPRESSION,
+        ] {
+            if let Some(value) = base.options().get(key) {
+                options.insert(key.to_string(), value.clone());
+            }
+        }
+
+        let mut schema = base.schema().as_ref().clone();
+        schema.add_columns(&StreamTable::stream_columns())?;
+
+        let req = CreateTableReq {
+            if_not_exists: plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(schema),
+                engine: "STREAM".to_string(),
+                options,
+                storage_params: base_info.meta.storage_params.clone(),
+                part_prefix: base_info.meta.part_prefix.clone(),
+                comment: plan.comment.clone().unwrap_or_default(),
+                ..Default::default()
+            },
+        };
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
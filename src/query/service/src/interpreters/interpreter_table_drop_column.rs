@@ -23,6 +23,7 @@ use common_sql::plans::DropTableColumnPlan;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -73,6 +74,11 @@ impl Interpreter for DropTableColumnInterpreter {
         let catalog = self.ctx.get_catalog(catalog_name)?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
+        check_referenced_computed_columns(
+            self.ctx.clone(),
+            new_table_meta.schema.clone(),
+            &self.plan.column,
+        )?;
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -0,0 +1,145 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::SetColumnMaskingPolicyPlan;
+use common_sql::resolve_type_name_by_str;
+use common_storages_share::save_share_table_info;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_MASKING_POLICIES;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetColumnMaskingPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetColumnMaskingPolicyPlan,
+}
+
+impl SetColumnMaskingPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetColumnMaskingPolicyPlan) -> Result<Self> {
+        Ok(SetColumnMaskingPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetColumnMaskingPolicyInterpreter {
+    fn name(&self) -> &str {
+        "SetColumnMaskingPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if table_info.engine() == VIEW_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is VIEW that doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let schema = table.schema();
+        let field = schema.field_with_name(&self.plan.column)?;
+        if field.computed_expr().is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Masking computed column {} is not supported",
+                field.name()
+            )));
+        }
+
+        if let Some(policy_name) = &self.plan.policy {
+            let policy = UserApiProvider::instance()
+                .get_masking_policy(tenant.as_str(), policy_name)
+                .await?;
+            let (_, arg_type) = &policy.args[0];
+            let arg_type = resolve_type_name_by_str(arg_type)?;
+            if arg_type.remove_nullable() != field.data_type().remove_nullable() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Masking policy {} of type {} can't be applied to column {} of type {}",
+                    policy_name,
+                    arg_type.sql_name(),
+                    field.name(),
+                    field.data_type().sql_name()
+                )));
+            }
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        let mut policies: BTreeMap<ColumnId, String> =
+            match new_table_meta.options.get(OPT_KEY_COLUMN_MASKING_POLICIES) {
+                Some(policies) => serde_json::from_str(policies)?,
+                None => BTreeMap::new(),
+            };
+        match &self.plan.policy {
+            Some(policy_name) => {
+                policies.insert(field.column_id(), policy_name.clone());
+            }
+            None => {
+                policies.remove(&field.column_id());
+            }
+        }
+        if policies.is_empty() {
+            new_table_meta
+                .options
+                .remove(OPT_KEY_COLUMN_MASKING_POLICIES);
+        } else {
+            new_table_meta.options.insert(
+                OPT_KEY_COLUMN_MASKING_POLICIES.to_string(),
+                serde_json::to_string(&policies)?,
+            );
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        let res = catalog.update_table_meta(table_info, req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::Result;
+use common_meta_app::schema::TaskNameIdent;
+use common_meta_app::schema::UpdateTaskStateReq;
+use common_sql::plans::AlterTaskPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AlterTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTaskPlan,
+}
+
+impl AlterTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTaskPlan) -> Result<Self> {
+        Ok(AlterTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTaskInterpreter {
+    fn name(&self) -> &str {
+        "AlterTaskInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let update_task_state_req = UpdateTaskStateReq {
+            name_ident: TaskNameIdent::new(tenant, &self.plan.task),
+            state: self.plan.state,
+        };
+
+        catalog.update_task_state(update_task_state_req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
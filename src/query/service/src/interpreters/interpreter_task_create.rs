@@ -0,0 +1,122 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::ListTasksReq;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskNameIdent;
+use common_meta_app::schema::TaskState;
+use common_sql::plans::CreateTaskPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::task::CronSchedule;
+
+pub struct CreateTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTaskPlan,
+}
+
+impl CreateTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTaskPlan) -> Result<Self> {
+        Ok(CreateTaskInterpreter { ctx, plan })
+    }
+
+    /// The tasks to run after must exist, and must not run after the new task, directly or not.
+    fn check_after(&self, tasks: &HashMap<String, TaskMeta>) -> Result<()> {
+        for after in self.plan.after.iter() {
+            if !tasks.contains_key(after) {
+                return Err(ErrorCode::UnknownTask(format!(
+                    "Unknown task '{}' in AFTER",
+                    after
+                )));
+            }
+        }
+
+        let mut stack = self.plan.after.clone();
+        let mut visited = vec![];
+        while let Some(name) = stack.pop() {
+            if name == self.plan.task {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Task '{}' can not run after the tasks that run after it",
+                    self.plan.task
+                )));
+            }
+            if visited.contains(&name) {
+                continue;
+            }
+            if let Some(meta) = tasks.get(&name) {
+                stack.extend(meta.after.iter().cloned());
+            }
+            visited.push(name);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTaskInterpreter {
+    fn name(&self) -> &str {
+        "CreateTaskInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        if let Some(schedule) = &self.plan.schedule {
+            CronSchedule::parse(schedule)?;
+        }
+
+        let tasks = catalog
+            .list_tasks(ListTasksReq::new(&tenant))
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        // An existing task is left to the meta-service to tell.
+        if !tasks.contains_key(&self.plan.task) {
+            self.check_after(&tasks)?;
+        }
+
+        let now = Utc::now();
+        let create_task_req = CreateTaskReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TaskNameIdent::new(tenant, &self.plan.task),
+            meta: TaskMeta {
+                query: self.plan.query.clone(),
+                schedule: self.plan.schedule.clone(),
+                after: self.plan.after.clone(),
+                state: TaskState::Started,
+                comment: self.plan.comment.clone(),
+                owner: self.ctx.get_current_user()?.identity(),
+                created_on: now,
+                updated_on: now,
+            },
+        };
+
+        catalog.create_task(create_task_req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::Result;
+use common_meta_app::schema::DropTaskReq;
+use common_meta_app::schema::TaskNameIdent;
+use common_sql::plans::DropTaskPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTaskPlan,
+}
+
+impl DropTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTaskPlan) -> Result<Self> {
+        Ok(DropTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTaskInterpreter {
+    fn name(&self) -> &str {
+        "DropTaskInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let drop_task_req = DropTaskReq {
+            if_exists: self.plan.if_exists,
+            name_ident: TaskNameIdent::new(tenant, &self.plan.task),
+        };
+
+        catalog.drop_task(drop_task_req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
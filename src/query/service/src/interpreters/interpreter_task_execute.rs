@@ -0,0 +1,83 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::GetTaskReq;
+use common_meta_app::schema::TaskNameIdent;
+use common_meta_app::schema::TaskRunState;
+use common_sql::plans::ExecuteTaskPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::task::TaskRunner;
+
+pub struct ExecuteTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExecuteTaskPlan,
+}
+
+impl ExecuteTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExecuteTaskPlan) -> Result<Self> {
+        Ok(ExecuteTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExecuteTaskInterpreter {
+    fn name(&self) -> &str {
+        "ExecuteTaskInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let reply = catalog
+            .get_task(GetTaskReq {
+                name_ident: TaskNameIdent::new(&tenant, &self.plan.task),
+            })
+            .await?;
+
+        // Run it now even if it is suspended, as its owner.
+        let runner = TaskRunner::create(tenant);
+        let run = runner
+            .run(
+                &self.plan.task,
+                &reply.meta,
+                "EXECUTE".to_string(),
+                Utc::now(),
+            )
+            .await?;
+
+        if run.state != TaskRunState::Succeeded {
+            return Err(ErrorCode::create(
+                run.error_code as u16,
+                run.error_message.unwrap_or_default(),
+                None,
+                None,
+            ));
+        }
+
+        runner.spawn_successors(self.plan.task.clone());
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let (state, mutated_tables) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            (txn_mgr.state(), txn_mgr.finish())
+        };
+
+        match state {
+            // COMMIT outside a transaction does nothing.
+            TxnState::AutoCommit => {}
+            TxnState::Fail => {
+                return Err(ErrorCode::CurrentTransactionIsAborted(
+                    "Current transaction is aborted, it has been rolled back",
+                ));
+            }
+            TxnState::Active => {
+                let mut update_table_metas: BTreeMap<String, Vec<UpdateTableMetaReq>> =
+                    BTreeMap::new();
+                for (catalog, req) in mutated_tables {
+                    update_table_metas.entry(catalog).or_default().push(req);
+                }
+                if update_table_metas.len() > 1 {
+                    return Err(ErrorCode::Unimplemented(
+                        "Transaction across multiple catalogs is not supported",
+                    ));
+                }
+
+                for (catalog, update_table_metas) in update_table_metas {
+                    let catalog = self.ctx.get_catalog(&catalog)?;
+                    catalog
+                        .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                        .await?;
+                }
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -47,6 +47,7 @@ pub mod servers;
 pub mod sessions;
 pub mod stream;
 pub mod table_functions;
+pub mod task;
 pub mod test_kits;
 
 mod global_services;
@@ -96,6 +96,7 @@ use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
+use crate::pipelines::processors::transforms::TransformSortSpill;
 use crate::pipelines::processors::transforms::TransformWindow;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
@@ -205,13 +206,19 @@ impl PipelineBuilder {
     }
 
     fn build_join_state(&mut self, join: &HashJoin) -> Result<Arc<JoinHashTable>> {
-        JoinHashTable::create_join_state(
+        let state = JoinHashTable::create_join_state(
             self.ctx.clone(),
             &join.build_keys,
             join.build.output_schema()?,
             join.probe.output_schema()?,
             HashJoinDesc::create(join)?,
-        )
+        )?;
+        if self.enable_profiling {
+            if let Some(spill_state) = &state.spill_state {
+                spill_state.set_prof_span(join.plan_id, self.prof_span_set.clone());
+            }
+        }
+        Ok(state)
     }
 
     fn expand_build_side_pipeline(
@@ -961,16 +968,40 @@ impl PipelineBuilder {
             }
         })?;
 
-        // Merge
+        // Merge, spill the sorted runs to storage if they use too much memory
+        let spilling_threshold = self
+            .ctx
+            .get_settings()
+            .get_sort_spilling_bytes_threshold_per_proc()?;
+        let operator = DataOperator::instance().operator();
+        let location_prefix = format!(
+            "_sort_spill/{}/{}",
+            self.ctx.get_tenant(),
+            self.ctx.get_id()
+        );
         self.main_pipeline.add_transform(|input, output| {
-            let transform = try_create_transform_sort_merge(
-                input,
-                output,
-                input_schema.clone(),
-                block_size,
-                limit,
-                sort_desc.clone(),
-            )?;
+            let transform = match limit.is_none() && spilling_threshold != 0 {
+                true => TransformSortSpill::try_create(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    sort_desc.clone(),
+                    operator.clone(),
+                    location_prefix.clone(),
+                    spilling_threshold,
+                    self.enable_profiling
+                        .then(|| (plan_id, self.prof_span_set.clone())),
+                )?,
+                false => try_create_transform_sort_merge(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    limit,
+                    sort_desc.clone(),
+                )?,
+            };
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
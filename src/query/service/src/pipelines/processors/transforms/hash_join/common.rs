@@ -386,25 +386,30 @@ impl JoinHashTable {
         self.merge_eq_block(&build_block, &probe_block)
     }
 
-    // Add `data_block` for build table to `row_space`
-    pub(crate) fn add_build_block(&self, data_block: DataBlock) -> Result<()> {
-        let func_ctx = self.ctx.get_function_context()?;
-        let mut data_block = data_block;
-        if matches!(
+    // Wrap nullable for the block of build side if the join type is left or full join.
+    pub(crate) fn wrap_nullable_build_block(&self, data_block: DataBlock) -> DataBlock {
+        if !matches!(
             self.hash_join_desc.join_type,
             JoinType::Left | JoinType::Full
         ) {
-            let mut validity = MutableBitmap::new();
-            validity.extend_constant(data_block.num_rows(), true);
-            let validity: Bitmap = validity.into();
-
-            let nullable_columns = data_block
-                .columns()
-                .iter()
-                .map(|c| Self::set_validity(c, validity.len(), &validity))
-                .collect::<Vec<_>>();
-            data_block = DataBlock::new(nullable_columns, data_block.num_rows());
+            return data_block;
         }
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(data_block.num_rows(), true);
+        let validity: Bitmap = validity.into();
+
+        let nullable_columns = data_block
+            .columns()
+            .iter()
+            .map(|c| Self::set_validity(c, validity.len(), &validity))
+            .collect::<Vec<_>>();
+        DataBlock::new(nullable_columns, data_block.num_rows())
+    }
+
+    // Add `data_block` for build table to `row_space`
+    pub(crate) fn add_build_block(&self, data_block: DataBlock) -> Result<()> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let data_block = self.wrap_nullable_build_block(data_block);
         let evaluator = Evaluator::new(&data_block, &func_ctx, &BUILTIN_FUNCTIONS);
 
         let build_cols = self
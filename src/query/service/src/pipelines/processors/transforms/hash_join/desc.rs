@@ -91,6 +91,22 @@ impl HashJoinDesc {
         })
     }
 
+    /// Create a description with the same keys and predicates but a fresh join state,
+    /// it's used to join a spilled partition with its own hash table.
+    pub fn create_partition_desc(&self) -> Result<HashJoinDesc> {
+        Ok(HashJoinDesc {
+            join_type: self.join_type.clone(),
+            build_keys: self.build_keys.clone(),
+            probe_keys: self.probe_keys.clone(),
+            other_predicate: self.other_predicate.clone(),
+            marker_join_desc: MarkJoinDesc {
+                has_null: RwLock::new(false),
+            },
+            from_correlated_subquery: self.from_correlated_subquery,
+            join_state: JoinState::create()?,
+        })
+    }
+
     fn join_predicate(non_equi_conditions: &[RemoteExpr]) -> Result<Option<Expr>> {
         non_equi_conditions
             .iter()
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::Result;
 use common_expression::DataBlock;
 
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::JoinState;
+use crate::pipelines::processors::transforms::hash_join::spill::HashJoinSpillState;
 
 #[async_trait::async_trait]
 /// Concurrent hash table for hash join.
@@ -24,6 +27,19 @@ pub trait HashJoinState: Send + Sync {
     /// Add input `DataBlock` to `row_space`.
     fn build(&self, input: DataBlock) -> Result<()>;
 
+    /// Add input `DataBlock` to `row_space` while the build side fits in memory, otherwise
+    /// returns the blocks that should be spilled to storage.
+    fn build_or_spill(&self, input: DataBlock) -> Result<Vec<DataBlock>>;
+
+    /// Get the state of grace hash join, None if the join doesn't spill.
+    fn spill_state(&self) -> Option<&HashJoinSpillState>;
+
+    /// Build the hash table of a spilled partition with the blocks of build side.
+    fn build_spilled_partition(
+        &self,
+        build_blocks: Vec<DataBlock>,
+    ) -> Result<Arc<dyn HashJoinState>>;
+
     /// Probe the hash table and retrieve matched rows as DataBlocks.
     fn probe(&self, input: &DataBlock, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>>;
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
@@ -39,6 +40,7 @@ use crate::pipelines::processors::transforms::hash_join::desc::JoinState;
 use crate::pipelines::processors::transforms::hash_join::join_hash_table::HashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::join_hash_table::SerializerHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::join_hash_table::SingleStringHashJoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::spill::HashJoinSpillState;
 use crate::pipelines::processors::transforms::FixedKeyHashJoinHashTable;
 use crate::pipelines::processors::HashJoinState;
 use crate::pipelines::processors::JoinHashTable;
@@ -62,6 +64,21 @@ impl HashJoinState for JoinHashTable {
         }
     }
 
+    fn build_or_spill(&self, input: DataBlock) -> Result<Vec<DataBlock>> {
+        self.build_or_spill_impl(input)
+    }
+
+    fn spill_state(&self) -> Option<&HashJoinSpillState> {
+        self.spill_state.as_ref()
+    }
+
+    fn build_spilled_partition(
+        &self,
+        build_blocks: Vec<DataBlock>,
+    ) -> Result<Arc<dyn HashJoinState>> {
+        self.build_spilled_partition_impl(build_blocks)
+    }
+
     fn probe(&self, input: &DataBlock, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>> {
         match self.hash_join_desc.join_type {
             JoinType::Inner
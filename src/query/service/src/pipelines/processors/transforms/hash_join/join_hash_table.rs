@@ -48,6 +48,7 @@ use parking_lot::RwLock;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
+use crate::pipelines::processors::transforms::hash_join::spill::HashJoinSpillState;
 use crate::pipelines::processors::transforms::hash_join::util::build_schema_wrap_nullable;
 use crate::pipelines::processors::transforms::hash_join::util::probe_schema_wrap_nullable;
 use crate::sessions::QueryContext;
@@ -104,6 +105,9 @@ pub struct JoinHashTable {
     pub(crate) worker_num: Arc<AtomicU32>,
     pub(crate) finalize_tasks: Arc<RwLock<Vec<(usize, usize)>>>,
     pub(crate) unfinished_task_num: Arc<AtomicI32>,
+    /// Spill the build side and the probe side to storage if the build side is too large,
+    /// it's None if spilling is disabled or not supported by the join type.
+    pub(crate) spill_state: Option<HashJoinSpillState>,
 }
 
 impl JoinHashTable {
@@ -147,6 +151,25 @@ impl JoinHashTable {
             build_data_schema = build_schema_wrap_nullable(&build_data_schema);
             probe_data_schema = probe_schema_wrap_nullable(&probe_data_schema);
         }
+        let spill_state = HashJoinSpillState::try_create(&ctx, &hash_join_desc)?;
+        let mut join_hash_table = Self::new(
+            ctx,
+            build_data_schema,
+            probe_data_schema,
+            hash_join_desc,
+            Arc::new(method),
+        )?;
+        join_hash_table.spill_state = spill_state;
+        Ok(join_hash_table)
+    }
+
+    pub(crate) fn new(
+        ctx: Arc<QueryContext>,
+        build_data_schema: DataSchemaRef,
+        probe_data_schema: DataSchemaRef,
+        hash_join_desc: HashJoinDesc,
+        method: Arc<HashMethodKind>,
+    ) -> Result<Self> {
         Ok(Self {
             row_space: RowSpace::new(ctx.clone(), build_data_schema)?,
             ctx,
@@ -157,7 +180,7 @@ impl JoinHashTable {
             built_notify: Arc::new(Notify::new()),
             finalized_notify: Arc::new(Notify::new()),
             hash_table: Arc::new(SyncUnsafeCell::new(HashJoinHashTable::Null)),
-            method: Arc::new(method),
+            method,
             entry_size: Arc::new(AtomicUsize::new(0)),
             raw_entry_spaces: Mutex::new(vec![]),
             hash_join_desc,
@@ -167,6 +190,7 @@ impl JoinHashTable {
             worker_num: Arc::new(AtomicU32::new(0)),
             finalize_tasks: Arc::new(RwLock::new(vec![])),
             unfinished_task_num: Arc::new(AtomicI32::new(0)),
+            spill_state: None,
         })
     }
 
@@ -0,0 +1,673 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::RemoteExpr;
+use common_expression::Value;
+use common_profile::ProfSpan;
+use common_profile::ProfSpanSetRef;
+use common_storage::DataOperator;
+use futures_util::future::BoxFuture;
+use opendal::Operator;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+
+use crate::api::FlightScatter;
+use crate::api::HashFlightScatter;
+use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
+use crate::pipelines::processors::HashJoinState;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The number of partitions that the build side and the probe side are scattered into
+/// once the hash join spills, a partition which is still too large is scattered again
+/// into this number of sub partitions.
+pub const SPILL_PARTITION_NUM: usize = 16;
+
+/// The max times that a partition is scattered, the partitions of the last level are joined
+/// in memory however large they are, e.g. all the rows have the same join key.
+pub const MAX_SPILL_LEVEL: usize = 3;
+
+/// A block written to storage, the columns are serialized one after another.
+pub struct SpilledBlock {
+    pub location: String,
+    pub num_rows: usize,
+    pub memory_size: usize,
+    pub columns_layout: Vec<usize>,
+}
+
+/// The spilled blocks of both sides which have the same hash of the join keys,
+/// `level` is the times the blocks have been scattered.
+pub struct SpilledPartition {
+    level: usize,
+    build_blocks: Vec<SpilledBlock>,
+    probe_blocks: Vec<SpilledBlock>,
+}
+
+#[derive(Default)]
+pub struct SpillMetrics {
+    pub write_count: AtomicU64,
+    pub write_bytes: AtomicU64,
+    pub read_count: AtomicU64,
+    pub read_bytes: AtomicU64,
+}
+
+/// The state of grace hash join shared by all the build and probe processors.
+///
+/// Once the build side uses more memory than the threshold, both sides are scattered into
+/// partitions by the hash of the join keys and written to storage. After the probe side is
+/// spilled, the partitions are joined one by one, each with a hash table of its own. The
+/// partitions whose build side is still larger than the threshold are scattered again before
+/// being joined.
+pub struct HashJoinSpillState {
+    pub(crate) operator: Operator,
+    /// All the spill files of the join are under this prefix, which is removed on drop.
+    pub(crate) location_prefix: String,
+    /// The build side spills if it uses more than `threshold_per_proc` bytes per build processor.
+    pub(crate) threshold_per_proc: usize,
+    pub(crate) build_bytes: AtomicUsize,
+    pub(crate) spilled: RwLock<bool>,
+    /// The scatters of each level, the scatter of level `n` scatters the blocks into
+    /// `SPILL_PARTITION_NUM ^ (n + 1)` partitions, so the rows of a partition of level `n`
+    /// are scattered into at most `SPILL_PARTITION_NUM` partitions of level `n + 1`.
+    build_scatters: Vec<Box<dyn FlightScatter>>,
+    probe_scatters: Vec<Box<dyn FlightScatter>>,
+    /// Spilled blocks of each partition
+    build_partitions: Mutex<Vec<Vec<SpilledBlock>>>,
+    probe_partitions: Mutex<Vec<Vec<SpilledBlock>>>,
+    /// The number of probe processors which haven't finished spilling
+    spilling_probe_count: Mutex<usize>,
+    probe_spilled_notify: Arc<Notify>,
+    /// The number of probe processors which haven't finished joining the partitions
+    probe_workers: AtomicUsize,
+    /// The partitions waiting to be joined, filled once all the probe side has been spilled.
+    pending_partitions: Mutex<VecDeque<SpilledPartition>>,
+    pub(crate) metrics: SpillMetrics,
+    prof_span: Mutex<Option<(u32, ProfSpanSetRef)>>,
+}
+
+impl HashJoinSpillState {
+    /// Returns None if spilling is disabled or the join type can't be joined partition by
+    /// partition, e.g. right joins need to know the unmatched rows of the whole build side.
+    pub fn try_create(
+        ctx: &Arc<QueryContext>,
+        hash_join_desc: &HashJoinDesc,
+    ) -> Result<Option<Self>> {
+        let threshold_per_proc = ctx
+            .get_settings()
+            .get_join_spilling_bytes_threshold_per_proc()?;
+        if threshold_per_proc == 0
+            || hash_join_desc.build_keys.is_empty()
+            || !matches!(
+                hash_join_desc.join_type,
+                JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+            )
+        {
+            return Ok(None);
+        }
+
+        let func_ctx = ctx.get_function_context()?;
+        let build_keys = hash_join_desc
+            .build_keys
+            .iter()
+            .map(|expr| expr.as_remote_expr())
+            .collect::<Vec<_>>();
+        let probe_keys = hash_join_desc
+            .probe_keys
+            .iter()
+            .map(|expr| expr.as_remote_expr())
+            .collect::<Vec<_>>();
+
+        let create_scatters = |keys: &Vec<RemoteExpr>| {
+            (0..MAX_SPILL_LEVEL)
+                .map(|level| {
+                    HashFlightScatter::try_create(
+                        func_ctx.clone(),
+                        keys.clone(),
+                        SPILL_PARTITION_NUM.pow(level as u32 + 1),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(Some(HashJoinSpillState {
+            operator: DataOperator::instance().operator(),
+            location_prefix: format!(
+                "_hash_join_spill/{}/{}",
+                ctx.get_tenant(),
+                GlobalUniqName::unique()
+            ),
+            threshold_per_proc,
+            build_bytes: AtomicUsize::new(0),
+            spilled: RwLock::new(false),
+            build_scatters: create_scatters(&build_keys)?,
+            probe_scatters: create_scatters(&probe_keys)?,
+            build_partitions: Mutex::new(Self::empty_partitions()),
+            probe_partitions: Mutex::new(Self::empty_partitions()),
+            spilling_probe_count: Mutex::new(0),
+            probe_spilled_notify: Arc::new(Notify::new()),
+            probe_workers: AtomicUsize::new(0),
+            pending_partitions: Mutex::new(VecDeque::new()),
+            metrics: SpillMetrics::default(),
+            prof_span: Mutex::new(None),
+        }))
+    }
+
+    fn empty_partitions() -> Vec<Vec<SpilledBlock>> {
+        (0..SPILL_PARTITION_NUM).map(|_| vec![]).collect()
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        *self.spilled.read()
+    }
+
+    /// Report the spill metrics to the profile of the join when all the probe processors finish.
+    pub fn set_prof_span(&self, plan_id: u32, prof_span_set: ProfSpanSetRef) {
+        *self.prof_span.lock() = Some((plan_id, prof_span_set));
+    }
+
+    /// Scatter the block into partitions by the hash of the join keys.
+    pub fn partition(&self, data_block: DataBlock, is_build_side: bool) -> Result<Vec<DataBlock>> {
+        self.partition_at_level(0, data_block, is_build_side)
+    }
+
+    fn partition_at_level(
+        &self,
+        level: usize,
+        data_block: DataBlock,
+        is_build_side: bool,
+    ) -> Result<Vec<DataBlock>> {
+        match is_build_side {
+            true => self.build_scatters[level].execute(data_block),
+            false => self.probe_scatters[level].execute(data_block),
+        }
+    }
+
+    /// Serialize the block to be written to storage.
+    pub(crate) fn serialize_block(&self, block: DataBlock) -> (SpilledBlock, Vec<u8>) {
+        let block = block.convert_to_full();
+        let mut data = Vec::new();
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        for entry in block.columns() {
+            let column_data = serialize_column(entry.value.as_column().unwrap());
+            columns_layout.push(column_data.len());
+            data.extend(column_data);
+        }
+
+        self.metrics.write_count.fetch_add(1, Ordering::Relaxed);
+        self.metrics
+            .write_bytes
+            .fetch_add(data.len() as u64, Ordering::Relaxed);
+
+        let spilled_block = SpilledBlock {
+            location: format!("{}/{}", self.location_prefix, GlobalUniqName::unique()),
+            num_rows: block.num_rows(),
+            memory_size: block.memory_size(),
+            columns_layout,
+        };
+        (spilled_block, data)
+    }
+
+    pub fn add_spilled_partitions(&self, is_build_side: bool, spilled: Vec<Vec<SpilledBlock>>) {
+        let mut partitions = match is_build_side {
+            true => self.build_partitions.lock(),
+            false => self.probe_partitions.lock(),
+        };
+        for (partition, blocks) in partitions.iter_mut().zip(spilled.into_iter()) {
+            partition.extend(blocks);
+        }
+    }
+
+    /// Attach to state: `spilling_probe_count` and `probe_workers`.
+    pub fn probe_attach(&self) {
+        *self.spilling_probe_count.lock() += 1;
+        self.probe_workers.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Detach to state: `spilling_probe_count`, wake up the probe processors waiting for
+    /// the probe side to be spilled.
+    pub fn probe_spill_end(&self) {
+        let mut count = self.spilling_probe_count.lock();
+        *count -= 1;
+        if *count == 0 {
+            let build_partitions = std::mem::take(&mut *self.build_partitions.lock());
+            let probe_partitions = std::mem::take(&mut *self.probe_partitions.lock());
+            self.pending_partitions.lock().extend(
+                build_partitions
+                    .into_iter()
+                    .zip(probe_partitions.into_iter())
+                    .map(|(build_blocks, probe_blocks)| SpilledPartition {
+                        level: 0,
+                        build_blocks,
+                        probe_blocks,
+                    }),
+            );
+            self.probe_spilled_notify.notify_waiters();
+        }
+    }
+
+    /// Detach to state: `probe_workers`, the spill metrics are reported by the last one.
+    pub fn probe_detach(&self) {
+        if self.probe_workers.fetch_sub(1, Ordering::SeqCst) != 1 {
+            return;
+        }
+        if let Some((plan_id, prof_span_set)) = self.prof_span.lock().take() {
+            let prof_span = ProfSpan {
+                spill_write_count: self.metrics.write_count.load(Ordering::Relaxed),
+                spill_write_bytes: self.metrics.write_bytes.load(Ordering::Relaxed),
+                spill_read_count: self.metrics.read_count.load(Ordering::Relaxed),
+                spill_read_bytes: self.metrics.read_bytes.load(Ordering::Relaxed),
+                ..Default::default()
+            };
+            prof_span_set.lock().unwrap().update(plan_id, prof_span);
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn wait_probe_spill_finish(&self) -> Result<()> {
+        let notified = {
+            let count = self.spilling_probe_count.lock();
+
+            match *count {
+                0 => None,
+                _ => Some(self.probe_spilled_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+
+        Ok(())
+    }
+
+    /// Read the blocks of build side and probe side of the next partition from storage,
+    /// returns None if all the partitions have been taken.
+    ///
+    /// The partition whose build side is larger than the threshold is scattered into sub
+    /// partitions by the scatter of the next level, which are joined later.
+    #[async_backtrace::framed]
+    pub async fn restore_next_partition(&self) -> Result<Option<(Vec<DataBlock>, Vec<DataBlock>)>> {
+        loop {
+            let partition = match self.pending_partitions.lock().pop_front() {
+                Some(partition) => partition,
+                None => return Ok(None),
+            };
+            if partition.build_blocks.is_empty() && partition.probe_blocks.is_empty() {
+                continue;
+            }
+
+            let build_bytes = partition
+                .build_blocks
+                .iter()
+                .map(|block| block.memory_size)
+                .sum::<usize>();
+            if build_bytes > self.threshold_per_proc {
+                if partition.level + 1 < MAX_SPILL_LEVEL {
+                    let sub_partitions = self.repartition(partition).await?;
+                    self.pending_partitions.lock().extend(sub_partitions);
+                    continue;
+                }
+                warn!(
+                    "Hash join spill partition of {} bytes can't be scattered any more, join it in memory",
+                    build_bytes
+                );
+            }
+
+            let instant = Instant::now();
+            let build_blocks = self.read_spilled_blocks(partition.build_blocks).await?;
+            let probe_blocks = self.read_spilled_blocks(partition.probe_blocks).await?;
+            info!(
+                "Read hash join spill partition of level {} successfully, elapsed: {:?}",
+                partition.level,
+                instant.elapsed()
+            );
+            return Ok(Some((build_blocks, probe_blocks)));
+        }
+    }
+
+    /// Scatter the blocks of the partition into the partitions of the next level, the blocks
+    /// are read and scattered one by one to keep the memory usage low.
+    #[async_backtrace::framed]
+    async fn repartition(&self, partition: SpilledPartition) -> Result<Vec<SpilledPartition>> {
+        let instant = Instant::now();
+        let level = partition.level + 1;
+        let mut sub_partitions = BTreeMap::new();
+        let sides = [
+            (true, partition.build_blocks),
+            (false, partition.probe_blocks),
+        ];
+        for (is_build_side, blocks) in sides {
+            for block in blocks {
+                let data_blocks = self.read_spilled_blocks(vec![block]).await?;
+                let data_block = DataBlock::concat(&data_blocks)?;
+                let partitioned = self.partition_at_level(level, data_block, is_build_side)?;
+                let mut write_data = Vec::new();
+                for (index, block) in partitioned.into_iter().enumerate() {
+                    if block.is_empty() {
+                        continue;
+                    }
+                    let (spilled_block, data) = self.serialize_block(block);
+                    write_data.push((spilled_block.location.clone(), data));
+
+                    let sub_partition =
+                        sub_partitions
+                            .entry(index)
+                            .or_insert_with(|| SpilledPartition {
+                                level,
+                                build_blocks: vec![],
+                                probe_blocks: vec![],
+                            });
+                    match is_build_side {
+                        true => sub_partition.build_blocks.push(spilled_block),
+                        false => sub_partition.probe_blocks.push(spilled_block),
+                    }
+                }
+                self.write_spilled_blocks(write_data).await?;
+            }
+        }
+
+        let mut sub_partitions = sub_partitions.into_values().collect::<Vec<_>>();
+        // All the rows of the build side have the same hash, scattering again doesn't help.
+        if sub_partitions
+            .iter()
+            .filter(|partition| !partition.build_blocks.is_empty())
+            .count()
+            <= 1
+        {
+            for sub_partition in sub_partitions.iter_mut() {
+                sub_partition.level = MAX_SPILL_LEVEL - 1;
+            }
+        }
+        info!(
+            "Scatter hash join spill partition into {} partitions of level {}, elapsed: {:?}",
+            sub_partitions.len(),
+            level,
+            instant.elapsed()
+        );
+        Ok(sub_partitions)
+    }
+
+    async fn write_spilled_blocks(&self, write_data: Vec<(String, Vec<u8>)>) -> Result<()> {
+        futures::future::try_join_all(write_data.into_iter().map(|(location, data)| {
+            let operator = self.operator.clone();
+            async move { operator.write(&location, data).await }
+        }))
+        .await?;
+        Ok(())
+    }
+
+    async fn read_spilled_blocks(&self, blocks: Vec<SpilledBlock>) -> Result<Vec<DataBlock>> {
+        let read_data = futures::future::try_join_all(blocks.iter().map(|block| {
+            let operator = self.operator.clone();
+            async move {
+                let data = operator.read(&block.location).await?;
+
+                if let Err(cause) = operator.delete(&block.location).await {
+                    error!(
+                        "Cannot delete spill file {}, cause: {:?}",
+                        &block.location, cause
+                    );
+                }
+
+                Ok::<_, ErrorCode>(data)
+            }
+        }))
+        .await?;
+
+        let mut data_blocks = Vec::with_capacity(blocks.len());
+        for (block, data) in blocks.into_iter().zip(read_data.into_iter()) {
+            self.metrics.read_count.fetch_add(1, Ordering::Relaxed);
+            self.metrics
+                .read_bytes
+                .fetch_add(data.len() as u64, Ordering::Relaxed);
+
+            let mut begin = 0;
+            let mut columns = Vec::with_capacity(block.columns_layout.len());
+            for column_layout in block.columns_layout {
+                let column =
+                    deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Cannot deserialize hash join spill file {}",
+                            block.location
+                        ))
+                    })?;
+                columns.push(BlockEntry {
+                    data_type: column.data_type(),
+                    value: Value::Column(column),
+                });
+                begin += column_layout;
+            }
+            data_blocks.push(DataBlock::new(columns, block.num_rows));
+        }
+        Ok(data_blocks)
+    }
+}
+
+impl Drop for HashJoinSpillState {
+    fn drop(&mut self) {
+        if self.metrics.write_count.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+
+        // The spill files are deleted once they are read, remove the rest of them in case
+        // the query failed or was aborted before all the partitions were joined.
+        let operator = self.operator.clone();
+        let location_prefix = format!("{}/", self.location_prefix);
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(cause) = operator.remove_all(&location_prefix).await {
+                error!(
+                    "Cannot remove hash join spill files under {}, cause: {:?}",
+                    location_prefix, cause
+                );
+            }
+        });
+    }
+}
+
+impl JoinHashTable {
+    pub(crate) fn build_or_spill_impl(&self, input: DataBlock) -> Result<Vec<DataBlock>> {
+        let spill_state = self.spill_state.as_ref().unwrap();
+        {
+            // Hold the read lock while building, so the blocks are either taken by the spill
+            // or added after the spill and returned to be spilled.
+            let spilled = spill_state.spilled.read();
+            if *spilled {
+                return Ok(vec![self.wrap_nullable_build_block(input)]);
+            }
+
+            let threshold = spill_state.threshold_per_proc
+                * self.worker_num.load(Ordering::Relaxed).max(1) as usize;
+            let input_bytes = input.memory_size();
+            let build_bytes = spill_state
+                .build_bytes
+                .fetch_add(input_bytes, Ordering::SeqCst)
+                + input_bytes;
+            if build_bytes <= threshold {
+                self.build(input)?;
+                return Ok(vec![]);
+            }
+        }
+
+        let mut spilled = spill_state.spilled.write();
+        let mut blocks = vec![self.wrap_nullable_build_block(input)];
+        if !*spilled {
+            *spilled = true;
+            // The blocks in `row_space` have been wrapped nullable.
+            blocks.extend(self.row_space.buffer.write().unwrap().drain(..));
+            blocks.extend(
+                self.row_space
+                    .chunks
+                    .write()
+                    .unwrap()
+                    .drain(..)
+                    .map(|chunk| chunk.data_block),
+            );
+            info!(
+                "Hash join build side uses more than {} bytes, spill it to storage",
+                spill_state.threshold_per_proc
+            );
+        }
+        Ok(blocks)
+    }
+
+    /// Create an empty hash table to join a spilled partition.
+    pub(crate) fn create_partition_table(&self) -> Result<JoinHashTable> {
+        let mut table = JoinHashTable::new(
+            self.ctx.clone(),
+            self.row_space.data_schema.clone(),
+            self.probe_schema.clone(),
+            self.hash_join_desc.create_partition_desc()?,
+            self.method.clone(),
+        )?;
+        table.interrupt = self.interrupt.clone();
+        Ok(table)
+    }
+
+    pub(crate) fn build_spilled_partition_impl(
+        &self,
+        build_blocks: Vec<DataBlock>,
+    ) -> Result<Arc<dyn HashJoinState>> {
+        let table = self.create_partition_table()?;
+        table.attach()?;
+        for block in build_blocks {
+            table.build(block)?;
+        }
+        table.build_end()?;
+        while table.finalize()? {}
+        table.finalize_end()?;
+        Ok(Arc::new(table))
+    }
+}
+
+/// Scatter the blocks of one processor into partitions and write them to storage.
+pub struct HashJoinSpiller {
+    join_state: Arc<dyn HashJoinState>,
+    is_build_side: bool,
+    partitions: Vec<Vec<DataBlock>>,
+    buffered_bytes: usize,
+    spilled_partitions: Vec<Vec<SpilledBlock>>,
+}
+
+impl HashJoinSpiller {
+    /// Returns None if the hash join can't spill.
+    pub fn create(join_state: Arc<dyn HashJoinState>, is_build_side: bool) -> Option<Self> {
+        join_state.spill_state()?;
+        Some(HashJoinSpiller {
+            join_state,
+            is_build_side,
+            partitions: (0..SPILL_PARTITION_NUM).map(|_| vec![]).collect(),
+            buffered_bytes: 0,
+            spilled_partitions: HashJoinSpillState::empty_partitions(),
+        })
+    }
+
+    fn spill_state(&self) -> &HashJoinSpillState {
+        self.join_state.spill_state().unwrap()
+    }
+
+    /// Scatter the blocks into the partitions, returns the future writing the partitions
+    /// to storage once the buffered blocks are larger than the threshold.
+    pub fn spill(
+        &mut self,
+        blocks: Vec<DataBlock>,
+    ) -> Result<Option<BoxFuture<'static, Result<()>>>> {
+        for block in blocks {
+            let partitioned = self.spill_state().partition(block, self.is_build_side)?;
+            for (index, block) in partitioned.into_iter().enumerate() {
+                if block.is_empty() {
+                    continue;
+                }
+                self.buffered_bytes += block.memory_size();
+                self.partitions[index].push(block);
+            }
+        }
+
+        if self.buffered_bytes < self.spill_state().threshold_per_proc {
+            return Ok(None);
+        }
+        self.flush()
+    }
+
+    /// Write all the buffered partitions to storage.
+    pub fn flush(&mut self) -> Result<Option<BoxFuture<'static, Result<()>>>> {
+        let spill_state = self.join_state.spill_state().unwrap();
+        let mut write_data = Vec::new();
+        for (index, blocks) in self.partitions.iter_mut().enumerate() {
+            if blocks.is_empty() {
+                continue;
+            }
+            let block = DataBlock::concat(&std::mem::take(blocks))?;
+            let (spilled_block, data) = spill_state.serialize_block(block);
+            write_data.push((spilled_block.location.clone(), data));
+            self.spilled_partitions[index].push(spilled_block);
+        }
+        self.buffered_bytes = 0;
+
+        if write_data.is_empty() {
+            return Ok(None);
+        }
+
+        let operator = spill_state.operator.clone();
+        Ok(Some(Box::pin(async move {
+            let instant = Instant::now();
+            let files = write_data.len();
+            futures::future::try_join_all(write_data.into_iter().map(|(location, data)| {
+                let operator = operator.clone();
+                async move { operator.write(&location, data).await }
+            }))
+            .await?;
+
+            info!(
+                "Write {} hash join spill files successfully, elapsed: {:?}",
+                files,
+                instant.elapsed()
+            );
+
+            Ok(())
+        })))
+    }
+
+    /// Register the spilled blocks to the shared state, called after all the blocks are flushed.
+    pub fn finish(&mut self) {
+        let spilled = std::mem::replace(
+            &mut self.spilled_partitions,
+            HashJoinSpillState::empty_partitions(),
+        );
+        self.spill_state()
+            .add_spilled_partitions(self.is_build_side, spilled);
+    }
+}
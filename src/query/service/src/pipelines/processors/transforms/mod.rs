@@ -32,6 +32,7 @@ mod transform_right_join;
 mod transform_right_semi_anti_join;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sort_spill;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -89,6 +90,7 @@ pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
@@ -19,7 +19,9 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use futures_util::future::BoxFuture;
 
+use super::hash_join::HashJoinSpiller;
 use super::hash_join::ProbeState;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
@@ -33,6 +35,10 @@ enum HashJoinStep {
     Build,
     Finalize,
     Probe,
+    /// The build side has been spilled, spill the probe side too.
+    Spill,
+    /// Join the spilled partitions one by one.
+    Restore,
 }
 
 pub struct TransformHashJoinProbe {
@@ -45,6 +51,21 @@ pub struct TransformHashJoinProbe {
     join_state: Arc<dyn HashJoinState>,
     probe_state: ProbeState,
     block_size: u64,
+
+    spiller: Option<HashJoinSpiller>,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+    called_on_spill_end: bool,
+    called_on_detach: bool,
+    restored_partition: Option<(Vec<DataBlock>, Vec<DataBlock>)>,
+    partition_table: Option<Arc<dyn HashJoinState>>,
+    restore_finished: bool,
+}
+
+impl Drop for TransformHashJoinProbe {
+    fn drop(&mut self) {
+        self.spill_end();
+        self.detach();
+    }
 }
 
 impl TransformHashJoinProbe {
@@ -56,6 +77,9 @@ impl TransformHashJoinProbe {
         _output_schema: DataSchemaRef,
     ) -> Result<Box<dyn Processor>> {
         let default_block_size = ctx.get_settings().get_max_block_size()?;
+        if let Some(spill_state) = join_state.spill_state() {
+            spill_state.probe_attach();
+        }
         Ok(Box::new(TransformHashJoinProbe {
             input_data: VecDeque::new(),
             output_data_blocks: VecDeque::new(),
@@ -65,15 +89,50 @@ impl TransformHashJoinProbe {
             join_state,
             probe_state: ProbeState::with_capacity(default_block_size as usize),
             block_size: default_block_size,
+            spiller: None,
+            spilling_future: None,
+            called_on_spill_end: false,
+            called_on_detach: false,
+            restored_partition: None,
+            partition_table: None,
+            restore_finished: false,
         }))
     }
 
     fn probe(&mut self, block: &DataBlock) -> Result<()> {
         self.probe_state.clear();
+        let join_state = self.partition_table.as_ref().unwrap_or(&self.join_state);
         self.output_data_blocks
-            .extend(self.join_state.probe(block, &mut self.probe_state)?);
+            .extend(join_state.probe(block, &mut self.probe_state)?);
         Ok(())
     }
+
+    fn push_input_data(&mut self, data: DataBlock) {
+        // Split data to `block_size` rows per sub block.
+        let (sub_blocks, remain_block) = data.split_by_rows(self.block_size as usize);
+        self.input_data.extend(sub_blocks);
+        if let Some(remain) = remain_block {
+            self.input_data.push_back(remain);
+        }
+    }
+
+    fn spill_end(&mut self) {
+        if !self.called_on_spill_end {
+            self.called_on_spill_end = true;
+            if let Some(spill_state) = self.join_state.spill_state() {
+                spill_state.probe_spill_end();
+            }
+        }
+    }
+
+    fn detach(&mut self) {
+        if !self.called_on_detach {
+            self.called_on_detach = true;
+            if let Some(spill_state) = self.join_state.spill_state() {
+                spill_state.probe_detach();
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -113,12 +172,7 @@ impl Processor for TransformHashJoinProbe {
 
                 if self.input_port.has_data() {
                     let data = self.input_port.pull_data().unwrap()?;
-                    // Split data to `block_size` rows per sub block.
-                    let (sub_blocks, remain_block) = data.split_by_rows(self.block_size as usize);
-                    self.input_data.extend(sub_blocks);
-                    if let Some(remain) = remain_block {
-                        self.input_data.push_back(remain);
-                    }
+                    self.push_input_data(data);
                     return Ok(Event::Sync);
                 }
 
@@ -130,6 +184,66 @@ impl Processor for TransformHashJoinProbe {
                 self.input_port.set_need_data();
                 Ok(Event::NeedData)
             }
+            HashJoinStep::Spill => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    self.spill_end();
+                    return Ok(Event::Finished);
+                }
+
+                if self.spilling_future.is_some() {
+                    self.input_port.set_not_need_data();
+                    return Ok(Event::Async);
+                }
+
+                if !self.input_data.is_empty() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.has_data() {
+                    let data = self.input_port.pull_data().unwrap()?;
+                    self.input_data.push_back(data);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    return Ok(Event::Async);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+            HashJoinStep::Restore => {
+                if self.output_port.is_finished() {
+                    self.detach();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.output_data_blocks.is_empty() {
+                    let data = self.output_data_blocks.pop_front().unwrap();
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if self.restored_partition.is_some()
+                    || !self.input_data.is_empty()
+                    || self.partition_table.is_some()
+                {
+                    return Ok(Event::Sync);
+                }
+
+                if self.restore_finished {
+                    self.detach();
+                    self.output_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                Ok(Event::Async)
+            }
         }
     }
 
@@ -148,14 +262,70 @@ impl Processor for TransformHashJoinProbe {
                 }
                 Ok(())
             }
+            HashJoinStep::Spill => {
+                if let Some(data) = self.input_data.pop_front() {
+                    let spiller = self.spiller.as_mut().unwrap();
+                    self.spilling_future = spiller.spill(vec![data.convert_to_full()])?;
+                }
+                Ok(())
+            }
+            HashJoinStep::Restore => {
+                if let Some((build_blocks, probe_blocks)) = self.restored_partition.take() {
+                    self.partition_table =
+                        Some(self.join_state.build_spilled_partition(build_blocks)?);
+                    for data in probe_blocks {
+                        self.push_input_data(data);
+                    }
+                } else if let Some(data) = self.input_data.pop_front() {
+                    self.probe(&data)?;
+                } else if let Some(partition_table) = self.partition_table.take() {
+                    // The rest blocks of the partition, e.g. left join.
+                    self.output_data_blocks
+                        .extend(partition_table.left_join_blocks(&[])?);
+                }
+                Ok(())
+            }
         }
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        if let HashJoinStep::Build = &self.step {
-            self.join_state.wait_finalize_finish().await?;
-            self.step = HashJoinStep::Probe;
+        match self.step {
+            HashJoinStep::Build => {
+                self.join_state.wait_finalize_finish().await?;
+                self.step = match self.join_state.spill_state() {
+                    Some(spill_state) if spill_state.is_spilled() => {
+                        self.spiller = HashJoinSpiller::create(self.join_state.clone(), false);
+                        HashJoinStep::Spill
+                    }
+                    _ => HashJoinStep::Probe,
+                };
+            }
+            HashJoinStep::Spill => {
+                if let Some(spilling_future) = self.spilling_future.take() {
+                    return spilling_future.await;
+                }
+
+                // All the probe blocks have been received.
+                let spiller = self.spiller.as_mut().unwrap();
+                if let Some(spilling_future) = spiller.flush()? {
+                    spilling_future.await?;
+                }
+                spiller.finish();
+                self.spill_end();
+
+                let spill_state = self.join_state.spill_state().unwrap();
+                spill_state.wait_probe_spill_finish().await?;
+                self.step = HashJoinStep::Restore;
+            }
+            HashJoinStep::Restore => {
+                let spill_state = self.join_state.spill_state().unwrap();
+                match spill_state.restore_next_partition().await? {
+                    Some(restored_partition) => self.restored_partition = Some(restored_partition),
+                    None => self.restore_finished = true,
+                }
+            }
+            _ => {}
         }
 
         Ok(())
@@ -170,6 +340,9 @@ pub struct TransformHashJoinBuild {
     join_state: Arc<dyn HashJoinState>,
     called_on_build_end: bool,
     called_on_finalize_end: bool,
+
+    spiller: Option<HashJoinSpiller>,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
 }
 
 impl Drop for TransformHashJoinBuild {
@@ -190,9 +363,11 @@ impl TransformHashJoinBuild {
             input_port,
             input_data: None,
             step: HashJoinStep::Build,
+            spiller: HashJoinSpiller::create(join_state.clone(), true),
             join_state,
             called_on_build_end: false,
             called_on_finalize_end: false,
+            spilling_future: None,
         })
     }
 
@@ -215,6 +390,10 @@ impl Processor for TransformHashJoinBuild {
     fn event(&mut self) -> Result<Event> {
         match self.step {
             HashJoinStep::Build => {
+                if self.spilling_future.is_some() {
+                    return Ok(Event::Async);
+                }
+
                 if self.input_data.is_some() {
                     return Ok(Event::Sync);
                 }
@@ -241,7 +420,7 @@ impl Processor for TransformHashJoinBuild {
                 true => Ok(Event::Sync),
                 false => Ok(Event::Finished),
             },
-            HashJoinStep::Probe => unreachable!(),
+            HashJoinStep::Probe | HashJoinStep::Spill | HashJoinStep::Restore => unreachable!(),
         }
     }
 
@@ -253,8 +432,22 @@ impl Processor for TransformHashJoinBuild {
         match self.step {
             HashJoinStep::Build => {
                 if let Some(data_block) = self.input_data.take() {
-                    self.join_state.build(data_block)?;
+                    match self.spiller.as_mut() {
+                        Some(spiller) => {
+                            let spilled_blocks = self.join_state.build_or_spill(data_block)?;
+                            self.spilling_future = spiller.spill(spilled_blocks)?;
+                        }
+                        None => self.join_state.build(data_block)?,
+                    }
                 } else if !self.called_on_build_end {
+                    if let Some(spiller) = self.spiller.as_mut() {
+                        // Write the rest partitions before the hash table is built.
+                        self.spilling_future = spiller.flush()?;
+                        if self.spilling_future.is_some() {
+                            return Ok(());
+                        }
+                        spiller.finish();
+                    }
                     self.called_on_build_end = true;
                     self.join_state.build_end()?;
                 }
@@ -267,12 +460,16 @@ impl Processor for TransformHashJoinBuild {
                 }
                 Ok(())
             }
-            HashJoinStep::Probe => unreachable!(),
+            HashJoinStep::Probe | HashJoinStep::Spill | HashJoinStep::Restore => unreachable!(),
         }
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_future) = self.spilling_future.take() {
+            return spilling_future.await;
+        }
+
         if let HashJoinStep::Build = &self.step {
             self.join_state.wait_build_finish().await?;
             self.step = HashJoinStep::Finalize;
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
@@ -23,6 +25,7 @@ use common_expression::Expr;
 use common_expression::Scalar;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
+use common_sql::parse_computed_expr;
 use common_sql::parse_exprs;
 use common_storages_factory::Table;
 
@@ -35,7 +38,6 @@ use crate::sessions::QueryContext;
 
 pub struct TransformResortAddOn {
     expression_transform: CompoundBlockOperator,
-    input_len: usize,
 }
 
 impl TransformResortAddOn
@@ -48,8 +50,8 @@ where Self: Transform
         input_schema: DataSchemaRef,
         table: Arc<dyn Table>,
     ) -> Result<ProcessorPtr> {
-        let fields = table
-            .schema()
+        let table_schema = table.schema();
+        let fields = table_schema
             .fields()
             .iter()
             .map(DataField::from)
@@ -57,7 +59,7 @@ where Self: Transform
 
         let mut exprs = Vec::with_capacity(fields.len());
         for f in fields.iter() {
-            let expr = if !input_schema.has_field(f.name()) {
+            let expr = if f.computed_expr().is_some() || !input_schema.has_field(f.name()) {
                 if let Some(default_expr) = f.default_expr() {
                     let mut expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
                     let mut expr = expr.remove(0);
@@ -91,10 +93,41 @@ where Self: Transform
             exprs.push(expr);
         }
 
+        // The stored computed columns are evaluated from the resorted columns,
+        // the virtual computed columns are not written and evaluated when reading.
+        let input_len = input_schema.num_fields();
+        let mut computed_exprs = Vec::new();
+        let mut projection = Vec::with_capacity(fields.len());
+        for (i, f) in table_schema.fields().iter().enumerate() {
+            match f.computed_expr() {
+                Some(ComputedExpr::Stored(stored_expr)) => {
+                    let expr = parse_computed_expr(
+                        ctx.clone(),
+                        table_schema.clone(),
+                        &DataType::from(f.data_type()),
+                        stored_expr,
+                    )?;
+                    let expr = expr.project_column_ref(|index| *index + input_len);
+                    projection.push(input_len + fields.len() + computed_exprs.len());
+                    computed_exprs.push(expr);
+                }
+                Some(ComputedExpr::Virtual(_)) => {}
+                None => projection.push(input_len + i),
+            }
+        }
+
+        let mut operators = vec![BlockOperator::Map { exprs }];
+        if !computed_exprs.is_empty() {
+            operators.push(BlockOperator::Map {
+                exprs: computed_exprs,
+            });
+        }
+        operators.push(BlockOperator::Project { projection });
+
         let func_ctx = ctx.get_function_context()?;
         let expression_transform = CompoundBlockOperator {
             ctx: func_ctx,
-            operators: vec![BlockOperator::Map { exprs }],
+            operators,
         };
 
         Ok(ProcessorPtr::create(Transformer::create(
@@ -102,7 +135,6 @@ where Self: Transform
             output,
             Self {
                 expression_transform,
-                input_len: input_schema.num_fields(),
             },
         )))
     }
@@ -111,9 +143,7 @@ where Self: Transform
 impl Transform for TransformResortAddOn {
     const NAME: &'static str = "AddOnTransform";
 
-    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
-        block = self.expression_transform.transform(block)?;
-        let columns = block.columns()[self.input_len..].to_owned();
-        Ok(DataBlock::new(columns, block.num_rows()))
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        self.expression_transform.transform(block)
     }
 }
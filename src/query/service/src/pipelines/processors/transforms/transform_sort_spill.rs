@@ -0,0 +1,478 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
+use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::RowConverter;
+use common_profile::ProfSpan;
+use common_profile::ProfSpanSetRef;
+use futures_util::future::BoxFuture;
+use opendal::Operator;
+use tracing::error;
+use tracing::info;
+
+use crate::pipelines::processors::transforms::Compactor;
+use crate::pipelines::processors::transforms::SortMergeCompactor;
+
+type CommonCompactor = SortMergeCompactor<ArrowRows, ArrowRowConverter>;
+
+/// A sorted block written to storage, the columns are serialized one after another.
+struct SpilledBlock {
+    location: String,
+    num_rows: usize,
+    columns_layout: Vec<usize>,
+}
+
+/// A sorted run, the blocks of a run are sorted as a whole.
+struct SortedRun {
+    spilled_blocks: VecDeque<SpilledBlock>,
+    /// The last run is not spilled, it's merged directly from memory.
+    memory_blocks: VecDeque<DataBlock>,
+    /// The rows of the current block which haven't been merged yet.
+    current: Option<DataBlock>,
+}
+
+impl SortedRun {
+    fn need_restore(&self) -> bool {
+        self.current.is_none() && !self.spilled_blocks.is_empty() && self.memory_blocks.is_empty()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current.is_none() && self.spilled_blocks.is_empty() && self.memory_blocks.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortSpillStep {
+    Collect,
+    Merge,
+    Finished,
+}
+
+/// External merge sort of a sort processor.
+///
+/// Sorted blocks are buffered until they use more memory than the threshold, then they are
+/// merged into a sorted run and written to storage. Once the input is finished, the runs are
+/// read back block by block and k-way merged into a sorted stream.
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    step: SortSpillStep,
+    input_finished: bool,
+
+    schema: DataSchemaRef,
+    sort_desc: Vec<SortColumnDescription>,
+    compactor: CommonCompactor,
+
+    operator: Operator,
+    /// All the spill files of the processor are under this prefix, which is removed on drop.
+    location_prefix: String,
+    threshold: usize,
+    buffered_blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    runs: Vec<SortedRun>,
+    output_blocks: VecDeque<DataBlock>,
+    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+
+    prof_span: Option<(u32, ProfSpanSetRef)>,
+    spill_write_count: u64,
+    spill_write_bytes: u64,
+    spill_read_count: u64,
+    spill_read_bytes: u64,
+}
+
+impl TransformSortSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_desc: Vec<SortColumnDescription>,
+        operator: Operator,
+        location_prefix: String,
+        threshold: usize,
+        prof_span: Option<(u32, ProfSpanSetRef)>,
+    ) -> Result<Box<dyn Processor>> {
+        let compactor =
+            CommonCompactor::try_create(schema.clone(), block_size, None, sort_desc.clone())?;
+
+        Ok(Box::new(TransformSortSpill {
+            input,
+            output,
+            step: SortSpillStep::Collect,
+            input_finished: false,
+            schema,
+            sort_desc,
+            compactor,
+            operator,
+            location_prefix: format!("{}/{}", location_prefix, GlobalUniqName::unique()),
+            threshold,
+            buffered_blocks: vec![],
+            buffered_bytes: 0,
+            runs: vec![],
+            output_blocks: VecDeque::new(),
+            spilling_future: None,
+            prof_span,
+            spill_write_count: 0,
+            spill_write_bytes: 0,
+            spill_read_count: 0,
+            spill_read_bytes: 0,
+        }))
+    }
+
+    fn take_buffered_blocks(&mut self) -> Result<Vec<DataBlock>> {
+        self.buffered_bytes = 0;
+        self.compactor
+            .compact_final(&std::mem::take(&mut self.buffered_blocks))
+    }
+
+    /// Merge the buffered blocks into a sorted run and write it to storage.
+    fn spill_buffered_blocks(&mut self) -> Result<()> {
+        let blocks = self.take_buffered_blocks()?;
+        if blocks.is_empty() {
+            return Ok(());
+        }
+
+        let mut spilled_blocks = VecDeque::with_capacity(blocks.len());
+        let mut write_data = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let block = block.convert_to_full();
+
+            let mut data = Vec::new();
+            let mut columns_layout = Vec::with_capacity(block.num_columns());
+            for entry in block.columns() {
+                let column_data = serialize_column(entry.value.as_column().unwrap());
+                columns_layout.push(column_data.len());
+                data.extend(column_data);
+            }
+
+            let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+            self.spill_write_count += 1;
+            self.spill_write_bytes += data.len() as u64;
+
+            spilled_blocks.push_back(SpilledBlock {
+                location: location.clone(),
+                num_rows: block.num_rows(),
+                columns_layout,
+            });
+            write_data.push((location, data));
+        }
+
+        self.runs.push(SortedRun {
+            spilled_blocks,
+            memory_blocks: VecDeque::new(),
+            current: None,
+        });
+
+        let operator = self.operator.clone();
+        self.spilling_future = Some(Box::pin(async move {
+            let instant = Instant::now();
+            let files = write_data.len();
+            futures::future::try_join_all(write_data.into_iter().map(|(location, data)| {
+                let operator = operator.clone();
+                async move { operator.write(&location, data).await }
+            }))
+            .await?;
+
+            info!(
+                "Write {} sort spill files successfully, elapsed: {:?}",
+                files,
+                instant.elapsed()
+            );
+
+            Ok(())
+        }));
+
+        Ok(())
+    }
+
+    /// Merge the rows which are not greater than the smallest last row of the current blocks,
+    /// rows after them in any run can't be smaller than these rows.
+    fn merge_runs(&mut self) -> Result<()> {
+        for run in self.runs.iter_mut() {
+            if run.current.is_none() {
+                run.current = run.memory_blocks.pop_front();
+            }
+        }
+        self.runs.retain(|run| !run.is_finished());
+
+        if self.runs.is_empty() {
+            self.step = SortSpillStep::Finished;
+            return Ok(());
+        }
+
+        let mut row_converter =
+            ArrowRowConverter::create(self.sort_desc.clone(), self.schema.clone())?;
+        let mut runs_rows = Vec::with_capacity(self.runs.len());
+        for run in self.runs.iter() {
+            let block = run.current.as_ref().unwrap();
+            let columns = self
+                .sort_desc
+                .iter()
+                .map(|desc| block.get_by_offset(desc.offset).clone())
+                .collect::<Vec<_>>();
+            runs_rows.push(row_converter.convert(&columns, block.num_rows())?);
+        }
+
+        let bound = runs_rows
+            .iter()
+            .map(|rows| rows.row_unchecked(rows.len() - 1))
+            .min()
+            .unwrap();
+
+        let mut merging_blocks = Vec::with_capacity(self.runs.len());
+        for (run, rows) in self.runs.iter_mut().zip(runs_rows.iter()) {
+            // The rows are sorted, find the first row which is greater than the bound.
+            let (mut begin, mut end) = (0, rows.len());
+            while begin < end {
+                let mid = (begin + end) / 2;
+                match rows.row_unchecked(mid) <= bound {
+                    true => begin = mid + 1,
+                    false => end = mid,
+                }
+            }
+
+            let block = run.current.take().unwrap();
+            if begin == block.num_rows() {
+                merging_blocks.push(block);
+            } else {
+                if begin > 0 {
+                    merging_blocks.push(block.slice(0..begin));
+                }
+                run.current = Some(block.slice(begin..block.num_rows()));
+            }
+        }
+
+        self.output_blocks
+            .extend(self.compactor.compact_final(&merging_blocks)?);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn restore_runs(&mut self) -> Result<()> {
+        let mut restoring_runs = Vec::with_capacity(self.runs.len());
+        let mut restoring = Vec::with_capacity(self.runs.len());
+        for (index, run) in self.runs.iter_mut().enumerate() {
+            if run.need_restore() {
+                restoring_runs.push(index);
+                restoring.push(run.spilled_blocks.pop_front().unwrap());
+            }
+        }
+
+        let read_data = futures::future::try_join_all(restoring.iter().map(|block| {
+            let operator = self.operator.clone();
+            async move {
+                let data = operator.read(&block.location).await?;
+
+                if let Err(cause) = operator.delete(&block.location).await {
+                    error!(
+                        "Cannot delete spill file {}, cause: {:?}",
+                        &block.location, cause
+                    );
+                }
+
+                Ok::<_, ErrorCode>(data)
+            }
+        }))
+        .await?;
+
+        let mut restored_blocks = Vec::with_capacity(restoring.len());
+        for (block, data) in restoring.into_iter().zip(read_data.into_iter()) {
+            self.spill_read_count += 1;
+            self.spill_read_bytes += data.len() as u64;
+
+            let mut begin = 0;
+            let mut columns = Vec::with_capacity(block.columns_layout.len());
+            for column_layout in block.columns_layout {
+                let column =
+                    deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Cannot deserialize sort spill file {}",
+                            block.location
+                        ))
+                    })?;
+                columns.push(BlockEntry {
+                    data_type: column.data_type(),
+                    value: Value::Column(column),
+                });
+                begin += column_layout;
+            }
+            restored_blocks.push(DataBlock::new(columns, block.num_rows));
+        }
+
+        for (index, block) in restoring_runs.into_iter().zip(restored_blocks.into_iter()) {
+            self.runs[index].current = Some(block);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.spilling_future.is_some() {
+            self.input.set_not_need_data();
+            return Ok(Event::Async);
+        }
+
+        if let Some(output_block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(output_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.step {
+            SortSpillStep::Collect => {
+                if self.input.has_data() {
+                    let data_block = self.input.pull_data().unwrap()?;
+                    self.buffered_bytes += data_block.memory_size();
+                    self.buffered_blocks.push(data_block);
+
+                    if self.buffered_bytes >= self.threshold {
+                        self.input.set_not_need_data();
+                        return Ok(Event::Sync);
+                    }
+                }
+
+                if self.input.is_finished() {
+                    self.input_finished = true;
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            SortSpillStep::Merge => match self.runs.iter().any(SortedRun::need_restore) {
+                true => Ok(Event::Async),
+                false => Ok(Event::Sync),
+            },
+            SortSpillStep::Finished => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn interrupt(&self) {
+        self.compactor.interrupt();
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.step {
+            SortSpillStep::Collect if !self.input_finished => self.spill_buffered_blocks(),
+            SortSpillStep::Collect => {
+                let blocks = self.take_buffered_blocks()?;
+                if self.runs.is_empty() {
+                    // Nothing is spilled, the data is sorted in memory.
+                    self.output_blocks.extend(blocks);
+                    self.step = SortSpillStep::Finished;
+                } else {
+                    self.runs.push(SortedRun {
+                        spilled_blocks: VecDeque::new(),
+                        memory_blocks: blocks.into(),
+                        current: None,
+                    });
+                    self.step = SortSpillStep::Merge;
+                }
+                Ok(())
+            }
+            SortSpillStep::Merge => self.merge_runs(),
+            SortSpillStep::Finished => Ok(()),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_future) = self.spilling_future.take() {
+            return spilling_future.await;
+        }
+
+        if self.step == SortSpillStep::Merge {
+            return self.restore_runs().await;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for TransformSortSpill {
+    fn drop(&mut self) {
+        if self.spill_write_count == 0 {
+            return;
+        }
+
+        if let Some((plan_id, prof_span_set)) = self.prof_span.take() {
+            let prof_span = ProfSpan {
+                spill_write_count: self.spill_write_count,
+                spill_write_bytes: self.spill_write_bytes,
+                spill_read_count: self.spill_read_count,
+                spill_read_bytes: self.spill_read_bytes,
+                ..Default::default()
+            };
+            prof_span_set.lock().unwrap().update(plan_id, prof_span);
+        }
+
+        // The spill files are deleted once they are read, remove the rest of them in case
+        // the query failed or was aborted before all the runs were merged.
+        let operator = self.operator.clone();
+        let location_prefix = format!("{}/", self.location_prefix);
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(cause) = operator.remove_all(&location_prefix).await {
+                error!(
+                    "Cannot remove sort spill files under {}, cause: {:?}",
+                    location_prefix, cause
+                );
+            }
+        });
+    }
+}
@@ -29,6 +29,7 @@ use common_expression::BlockEntry;
 use common_expression::Column;
 use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::ScalarRef;
 use common_expression::SortColumnDescription;
 use common_expression::Value;
@@ -39,7 +40,10 @@ use common_pipeline_core::processors::Processor;
 use common_sql::plans::WindowFuncFrameUnits;
 
 use super::frame_bound::FrameBound;
+use super::window_function::LagLeadDefault;
 use super::window_function::WindowFuncAggImpl;
+use super::window_function::WindowFuncLagLeadImpl;
+use super::window_function::WindowFuncNthValueImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
 
@@ -128,6 +132,9 @@ pub struct TransformWindow<T: Number> {
     current_rank: usize,
     current_rank_count: usize,
     current_dense_rank: usize,
+    // used for cume_dist, the number of rows preceding or peer with the current row in the partition,
+    // 0 if not computed for current peer group.
+    current_cume_rows: usize,
 
     // If `is_empty_frame`, the window function result of non-NULL rows will be NULL.
     is_empty_frame: bool,
@@ -198,6 +205,7 @@ impl<T: Number> TransformWindow<T> {
 
         let partition_by_columns = self.partition_indices.len();
         if partition_by_columns == 0 {
+            self.partition_size += self.block_rows(&self.partition_end) - self.partition_end.row;
             self.partition_end = end;
             return;
         }
@@ -325,7 +333,7 @@ impl<T: Number> TransformWindow<T> {
 
     // Advance the current row to the next row
     // if the current row is the last row of the current block, advance the current block and row = 0
-    fn advance_row(&mut self, mut row: RowPtr) -> RowPtr {
+    fn advance_row(&self, mut row: RowPtr) -> RowPtr {
         debug_assert!(row.block >= self.first_block);
 
         if row == self.blocks_end() {
@@ -450,8 +458,78 @@ impl<T: Number> TransformWindow<T> {
         Ok(())
     }
 
+    /// The value of the row at the offset, or the default value if the offset row is out of the partition.
+    fn lag_lead_value(&self, lag_lead: &WindowFuncLagLeadImpl) -> Scalar {
+        if self.frame_start == self.frame_end {
+            match lag_lead.default {
+                LagLeadDefault::Null => Scalar::Null,
+                LagLeadDefault::Index(index) => {
+                    let col = self.column_at(&self.current_row, index);
+                    unsafe { col.index_unchecked(self.current_row.row) }.to_owned()
+                }
+            }
+        } else {
+            let col = self.column_at(&self.frame_start, lag_lead.arg);
+            unsafe { col.index_unchecked(self.frame_start.row) }.to_owned()
+        }
+    }
+
+    /// The value of the nth (or the last) row in the frame, NULL if there is no such row.
+    fn nth_value(&self, nth: &WindowFuncNthValueImpl) -> Scalar {
+        if self.is_empty_frame && !self.is_null_frame {
+            return Scalar::Null;
+        }
+
+        let mut target = None;
+        let mut count = 0;
+        let mut row = self.frame_start;
+        while row < self.frame_end {
+            let is_null = nth.ignore_nulls
+                && unsafe { self.column_at(&row, nth.arg).index_unchecked(row.row) }.is_null();
+            if !is_null {
+                count += 1;
+                target = Some(row);
+                if nth.n == Some(count) {
+                    break;
+                }
+            }
+            row = self.advance_row(row);
+        }
+
+        match target {
+            Some(row) if nth.n.is_none() || nth.n == Some(count) => {
+                let col = self.column_at(&row, nth.arg);
+                unsafe { col.index_unchecked(row.row) }.to_owned()
+            }
+            _ => Scalar::Null,
+        }
+    }
+
+    /// The number of rows preceding or peer with the current row in the partition.
+    fn cume_rows(&self) -> usize {
+        let mut rows = self.current_row_in_partition;
+        let mut row = self.advance_row(self.current_row);
+        while row < self.partition_end && self.are_peers(&self.current_row, &row, false) {
+            rows += 1;
+            row = self.advance_row(row);
+        }
+        rows
+    }
+
     #[inline]
     fn merge_result_of_current_row(&mut self) -> Result<()> {
+        let value = match &self.func {
+            WindowFunctionImpl::LagLead(lag_lead) => Some(self.lag_lead_value(lag_lead)),
+            WindowFunctionImpl::NthValue(nth) => Some(self.nth_value(nth)),
+            WindowFunctionImpl::CumeDist => {
+                if self.current_cume_rows == 0 {
+                    self.current_cume_rows = self.cume_rows();
+                }
+                None
+            }
+            _ => None,
+        };
+
         let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
 
         match &self.func {
@@ -481,6 +559,18 @@ impl<T: Number> TransformWindow<T> {
                 };
                 builder.push(ScalarRef::Number(NumberScalar::Float64(percent.into())));
             }
+            WindowFunctionImpl::LagLead(_) | WindowFunctionImpl::NthValue(_) => {
+                builder.push(value.unwrap().as_ref());
+            }
+            WindowFunctionImpl::Ntile(ntile) => {
+                let bucket =
+                    ntile.compute_bucket(self.current_row_in_partition, self.partition_size);
+                builder.push(ScalarRef::Number(NumberScalar::UInt64(bucket as u64)));
+            }
+            WindowFunctionImpl::CumeDist => {
+                let cume_dist = (self.current_cume_rows as f64) / (self.partition_size as f64);
+                builder.push(ScalarRef::Number(NumberScalar::Float64(cume_dist.into())));
+            }
         };
 
         Ok(())
@@ -558,6 +648,7 @@ impl TransformWindow<u64> {
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            current_cume_rows: 0,
             input_is_finished: false,
             is_empty_frame,
         })
@@ -623,6 +714,7 @@ where T: Number + ResultTypeOfUnary
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            current_cume_rows: 0,
             input_is_finished: false,
             is_empty_frame,
         })
@@ -795,6 +887,7 @@ where T: Number + ResultTypeOfUnary
                     self.peer_group_start = self.current_row;
                     self.current_dense_rank += 1;
                     self.current_rank = self.current_row_in_partition;
+                    self.current_cume_rows = 0;
 
                     // If current peer group is a null frame, there will be no null frame in this partition again;
                     // if current peer group is not a null frame, we may need to check it in the codes below.
@@ -883,6 +976,7 @@ where T: Number + ResultTypeOfUnary
                 self.current_rank = 1;
                 self.current_rank_count = 1;
                 self.current_dense_rank = 1;
+                self.current_cume_rows = 0;
             }
         }
 
@@ -36,6 +36,10 @@ pub enum WindowFunctionInfo {
     Rank,
     DenseRank,
     PercentRank,
+    LagLead(WindowFuncLagLeadImpl),
+    NthValue(WindowFuncNthValueImpl),
+    Ntile(WindowFuncNtileImpl),
+    CumeDist,
 }
 
 pub struct WindowFuncAggImpl {
@@ -87,12 +91,63 @@ impl Drop for WindowFuncAggImpl {
     }
 }
 
+/// `LAG` and `LEAD` take the value of the only row in the frame,
+/// or the default value if the frame is empty.
+#[derive(Clone)]
+pub struct WindowFuncLagLeadImpl {
+    pub arg: usize,
+    pub default: LagLeadDefault,
+    pub return_type: DataType,
+}
+
+#[derive(Clone)]
+pub enum LagLeadDefault {
+    Null,
+    Index(usize),
+}
+
+#[derive(Clone)]
+pub struct WindowFuncNthValueImpl {
+    /// The nth row of the frame (counting from 1), None for the last row.
+    pub n: Option<usize>,
+    pub arg: usize,
+    pub ignore_nulls: bool,
+    pub return_type: DataType,
+}
+
+#[derive(Clone)]
+pub struct WindowFuncNtileImpl {
+    pub n: usize,
+}
+
+impl WindowFuncNtileImpl {
+    /// The bucket (counting from 1) of the row in the partition.
+    ///
+    /// The first `partition_size % n` buckets have one more row than the others.
+    #[inline]
+    pub fn compute_bucket(&self, row_in_partition: usize, partition_size: usize) -> usize {
+        let row = row_in_partition - 1;
+        let small_bucket_rows = partition_size / self.n;
+        let large_buckets = partition_size % self.n;
+        let large_bucket_rows = small_bucket_rows + 1;
+        if row < large_buckets * large_bucket_rows {
+            row / large_bucket_rows + 1
+        } else {
+            (row - large_buckets * large_bucket_rows) / small_bucket_rows + large_buckets + 1
+        }
+    }
+}
+
 pub enum WindowFunctionImpl {
     Aggregate(WindowFuncAggImpl),
     RowNumber,
     Rank,
     DenseRank,
     PercentRank,
+    LagLead(WindowFuncLagLeadImpl),
+    NthValue(WindowFuncNthValueImpl),
+    Ntile(WindowFuncNtileImpl),
+    CumeDist,
 }
 
 impl WindowFunctionInfo {
@@ -118,6 +173,29 @@ impl WindowFunctionInfo {
             WindowFunction::Rank => Self::Rank,
             WindowFunction::DenseRank => Self::DenseRank,
             WindowFunction::PercentRank => Self::PercentRank,
+            WindowFunction::LagLead(lag_lead) => {
+                let default = match &lag_lead.default {
+                    common_sql::executor::LagLeadDefault::Null => LagLeadDefault::Null,
+                    common_sql::executor::LagLeadDefault::Index(index) => {
+                        LagLeadDefault::Index(schema.index_of(&index.to_string())?)
+                    }
+                };
+                Self::LagLead(WindowFuncLagLeadImpl {
+                    arg: schema.index_of(&lag_lead.arg.to_string())?,
+                    default,
+                    return_type: lag_lead.return_type.clone(),
+                })
+            }
+            WindowFunction::NthValue(nth) => Self::NthValue(WindowFuncNthValueImpl {
+                n: nth.n.map(|n| n as usize),
+                arg: schema.index_of(&nth.arg.to_string())?,
+                ignore_nulls: nth.ignore_nulls,
+                return_type: nth.return_type.clone(),
+            }),
+            WindowFunction::Ntile(ntile) => Self::Ntile(WindowFuncNtileImpl {
+                n: ntile.n as usize,
+            }),
+            WindowFunction::CumeDist => Self::CumeDist,
         })
     }
 }
@@ -144,16 +222,22 @@ impl WindowFunctionImpl {
             WindowFunctionInfo::Rank => Self::Rank,
             WindowFunctionInfo::DenseRank => Self::DenseRank,
             WindowFunctionInfo::PercentRank => Self::PercentRank,
+            WindowFunctionInfo::LagLead(lag_lead) => Self::LagLead(lag_lead),
+            WindowFunctionInfo::NthValue(nth) => Self::NthValue(nth),
+            WindowFunctionInfo::Ntile(ntile) => Self::Ntile(ntile),
+            WindowFunctionInfo::CumeDist => Self::CumeDist,
         })
     }
 
     pub fn return_type(&self) -> Result<DataType> {
         Ok(match self {
             Self::Aggregate(agg) => agg.agg.return_type()?,
-            Self::RowNumber | Self::Rank | Self::DenseRank => {
+            Self::RowNumber | Self::Rank | Self::DenseRank | Self::Ntile(_) => {
                 DataType::Number(NumberDataType::UInt64)
             }
-            Self::PercentRank => DataType::Number(NumberDataType::Float64),
+            Self::PercentRank | Self::CumeDist => DataType::Number(NumberDataType::Float64),
+            Self::LagLead(lag_lead) => lag_lead.return_type.clone(),
+            Self::NthValue(nth) => nth.return_type.clone(),
         })
     }
 
@@ -14,10 +14,14 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
@@ -25,13 +29,28 @@ use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
+use common_expression::DataSchema;
+use common_functions::scalars::like;
 use futures_util::stream;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+/// Table types reported to the clients, the same as `information_schema.tables`.
+const TABLE_TYPE_TABLE: &str = "BASE TABLE";
+const TABLE_TYPE_VIEW: &str = "VIEW";
+
 pub(super) struct CatalogInfoProvider {}
 
+#[derive(Default)]
+struct TablesInfo {
+    catalog_names: Vec<String>,
+    database_names: Vec<String>,
+    table_names: Vec<String>,
+    table_types: Vec<String>,
+    table_schemas: Vec<Vec<u8>>,
+}
+
 impl CatalogInfoProvider {
     fn batch_to_get_stream(batch: RecordBatch) -> Result<DoGetStream, Status> {
         let schema = (*batch.schema()).clone();
@@ -44,14 +63,20 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    /// Filter pattern of Flight SQL uses the syntax of `LIKE`, `None` matches everything.
+    fn matches(pattern: &Option<String>, name: &str) -> bool {
+        match pattern {
+            Some(pattern) => like(name.as_bytes(), pattern.as_bytes()),
+            None => true,
+        }
+    }
+
+    fn get_catalogs_internal(
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)> {
-        let tenant = ctx.get_tenant();
+    ) -> common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
+        let mut catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name
+        {
             vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&catalog_name)?,
@@ -63,22 +88,53 @@ impl CatalogInfoProvider {
                 .map(|r| (r.key().to_string(), r.value().clone()))
                 .collect()
         };
+        catalogs.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(catalogs)
+    }
 
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
         let mut catalog_names = vec![];
         let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(tenant.as_str(), database_name).await?]
-            } else {
-                catalog.list_databases(tenant.as_str()).await?
-            };
+        for (catalog_name, catalog) in Self::get_catalogs_internal(catalog_name)? {
+            let mut dbs = catalog
+                .list_databases(tenant.as_str())
+                .await?
+                .iter()
+                .map(|db| db.name().to_string())
+                .filter(|name| Self::matches(&db_schema_filter_pattern, name))
+                .collect::<Vec<_>>();
+            dbs.sort();
+            for db in dbs {
+                catalog_names.push(catalog_name.clone());
+                database_names.push(db);
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+        table_name_filter_pattern: Option<String>,
+        table_types: Vec<String>,
+        include_schema: bool,
+    ) -> common_exception::Result<TablesInfo> {
+        let tenant = ctx.get_tenant();
+        let mut info = TablesInfo::default();
+        for (catalog_name, catalog) in Self::get_catalogs_internal(catalog_name)? {
+            let mut dbs = catalog.list_databases(tenant.as_str()).await?;
+            dbs.retain(|db| Self::matches(&db_schema_filter_pattern, db.name()));
+            dbs.sort_by(|a, b| a.name().cmp(b.name()));
             for db in dbs {
                 let db_name = db.name().to_string().into_boxed_str();
                 let db_name: &str = Box::leak(db_name);
-                let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
+                let mut tables = match catalog.list_tables(tenant.as_str(), db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
                         tracing::warn!("list tables failed on db {}: {}", db.name(), err);
@@ -86,42 +142,168 @@ impl CatalogInfoProvider {
                     }
                     Err(err) => return Err(err),
                 };
+                tables.sort_by(|a, b| a.name().cmp(b.name()));
                 for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    let table_type = if table.engine() == "VIEW" {
+                        TABLE_TYPE_VIEW
+                    } else {
+                        TABLE_TYPE_TABLE
+                    };
+                    if !Self::matches(&table_name_filter_pattern, table.name())
+                        || !(table_types.is_empty() || table_types.iter().any(|t| t == table_type))
+                    {
+                        continue;
+                    }
+                    if include_schema {
+                        let schema = Schema::from(&DataSchema::from(table.schema()));
+                        let IpcMessage(schema) =
+                            SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+                                .try_into()
+                                .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+                        info.table_schemas.push(schema.to_vec());
+                    }
+                    info.catalog_names.push(catalog_name.clone());
+                    info.database_names.push(db_name.to_string());
+                    info.table_names.push(table.name().to_string());
+                    info.table_types.push(table_type.to_string());
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok(info)
     }
 
-    pub(crate) async fn get_tables(
+    pub(crate) fn get_catalogs() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_names = Self::get_catalogs_internal(None)
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(catalog_names)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
             Field::new("catalog_name", DataType::Utf8, false),
             Field::new("db_schema_name", DataType::Utf8, false),
-            Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
+        let (catalog_name, db_schema_name) =
+            Self::get_schemas_internal(ctx, catalog_name, db_schema_filter_pattern)
                 .await
                 .map_err(|e| Status::internal(format!("{e:?}")))?;
         let batch = RecordBatch::try_new(schema, vec![
             Self::string_array(catalog_name),
             Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
         ])
         .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
         Self::batch_to_get_stream(batch)
     }
 
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+        table_name_filter_pattern: Option<String>,
+        table_types: Vec<String>,
+        include_schema: bool,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("db_schema_name", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        if include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        let schema = Arc::new(Schema::new(fields));
+        let info = Self::get_tables_internal(
+            ctx.clone(),
+            catalog_name,
+            db_schema_filter_pattern,
+            table_name_filter_pattern,
+            table_types,
+            include_schema,
+        )
+        .await
+        .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut columns = vec![
+            Self::string_array(info.catalog_names),
+            Self::string_array(info.database_names),
+            Self::string_array(info.table_names),
+            Self::string_array(info.table_types),
+        ];
+        if include_schema {
+            let mut builder = BinaryBuilder::new();
+            for v in &info.table_schemas {
+                builder.append_value(v);
+            }
+            columns.push(Arc::new(builder.finish()));
+        }
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(vec![
+            TABLE_TYPE_TABLE.to_string(),
+            TABLE_TYPE_VIEW.to_string(),
+        ])])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Databend has no primary key constraints (`information_schema.key_column_usage` is
+    /// always empty), so the result only carries the schema.
+    pub(crate) fn get_primary_keys() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("key_name", DataType::Utf8, true),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
+    /// Shared by the imported keys, exported keys and cross reference, they are always
+    /// empty since Databend has no foreign key constraints.
+    pub(crate) fn get_foreign_keys() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -15,6 +15,7 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 mod catalog;
+mod prepared;
 mod query;
 mod service;
 mod session;
@@ -25,11 +26,10 @@ use std::sync::Arc;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
-use common_sql::plans::Plan;
-use common_sql::PlanExtras;
 use dashmap::DashMap;
 use futures::Stream;
 use parking_lot::Mutex;
+use prepared::PreparedStatement;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
@@ -51,7 +51,7 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Arc<DashMap<Uuid, PreparedStatement>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -0,0 +1,238 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_flight::utils::flight_data_to_arrow_batch;
+use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::FlightData;
+use arrow_ipc::MessageHeader;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::SchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_sql::plans::Plan;
+use common_sql::PlanExtras;
+
+use crate::servers::parameters::for_each_placeholder;
+use crate::servers::parameters::replace_placeholders;
+use crate::servers::parameters::scalar_to_literal;
+use crate::servers::parameters::Placeholder;
+
+/// A statement created by `do_action_create_prepared_statement`.
+///
+/// Databend has no server side parameters, the bound values are substituted into the `?`
+/// placeholders as SQL literals, and the query is planned again before each execution.
+pub(super) struct PreparedStatement {
+    pub(super) query: String,
+    pub(super) num_params: usize,
+    pub(super) plan: Plan,
+    pub(super) plan_extras: PlanExtras,
+    /// One row of literals for each execution, bound by `do_put_prepared_statement_query`.
+    pub(super) parameters: Vec<Vec<String>>,
+}
+
+impl PreparedStatement {
+    /// The query used to plan the statement when it's created, the placeholders are
+    /// replaced by `NULL` to get the schema of the result set, except the arguments of
+    /// `LIMIT` and `OFFSET`, which must be integers.
+    pub(super) fn query_for_schema(query: &str) -> String {
+        let literals = limit_placeholders(query)
+            .into_iter()
+            .map(|is_limit| if is_limit { "0" } else { "NULL" }.to_string())
+            .collect::<Vec<_>>();
+        bind_placeholders(query, &literals)
+    }
+
+    pub(super) fn create(query: &str, plan: Plan, plan_extras: PlanExtras) -> Self {
+        PreparedStatement {
+            query: query.to_string(),
+            num_params: count_placeholders(query),
+            plan,
+            plan_extras,
+            parameters: vec![],
+        }
+    }
+
+    /// The parameters are always sent as strings, the server can't infer their types.
+    pub(super) fn parameter_schema(&self) -> ArrowSchema {
+        let fields = (0..self.num_params)
+            .map(|i| Field::new(format!("{}", i + 1), DataType::Utf8, true))
+            .collect::<Vec<_>>();
+        ArrowSchema::new(fields)
+    }
+
+    /// Bind the parameters sent by `DoPut`.
+    pub(super) fn bind(&mut self, flight_data: &[FlightData]) -> Result<()> {
+        let batches = decode_batches(flight_data, Arc::new(self.parameter_schema()))?;
+        let limit_placeholders = limit_placeholders(&self.query);
+        let mut parameters = vec![];
+        for batch in batches {
+            let (block, _) = DataBlock::from_record_batch(&batch)
+                .map_err(|e| ErrorCode::BadArguments(format!("invalid parameters: {e}")))?;
+            if block.num_columns() != self.num_params {
+                return Err(ErrorCode::BadArguments(format!(
+                    "prepared statement expects {} parameters, but got {}",
+                    self.num_params,
+                    block.num_columns()
+                )));
+            }
+            for row in 0..block.num_rows() {
+                let literals = block
+                    .columns()
+                    .iter()
+                    .zip(limit_placeholders.iter())
+                    .map(|(entry, is_limit)| {
+                        let scalar = entry
+                            .value
+                            .index(row)
+                            .ok_or_else(|| ErrorCode::BadArguments("invalid parameters"))?;
+                        if *is_limit {
+                            limit_literal(scalar)
+                        } else {
+                            Ok(scalar_to_literal(scalar))
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                parameters.push(literals);
+            }
+        }
+        self.parameters = parameters;
+        Ok(())
+    }
+
+    /// The queries to run, one for each row of the bound parameters.
+    /// `None` means the plan of the statement can be used directly.
+    pub(super) fn bound_queries(&self) -> Result<Vec<Option<String>>> {
+        if self.num_params == 0 {
+            return Ok(vec![None]);
+        }
+        if self.parameters.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "prepared statement expects {} parameters, but none is bound",
+                self.num_params
+            )));
+        }
+        Ok(self
+            .parameters
+            .iter()
+            .map(|literals| Some(bind_placeholders(&self.query, literals)))
+            .collect())
+    }
+}
+
+/// The first message of `DoPut` (the schema of the parameters) is consumed by
+/// `FlightSqlService::do_put` to get the command, so fall back to the parameter
+/// schema of the statement if the stream starts with a record batch.
+fn decode_batches(flight_data: &[FlightData], schema: SchemaRef) -> Result<Vec<RecordBatch>> {
+    let flight_data = flight_data
+        .iter()
+        .filter(|data| !data.data_header.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    let first = match flight_data.first() {
+        Some(first) => first,
+        None => return Ok(vec![]),
+    };
+    let is_schema = arrow_ipc::root_as_message(&first.data_header[..])
+        .map(|message| message.header_type() == MessageHeader::Schema)
+        .unwrap_or(false);
+    let batches = if is_schema {
+        flight_data_to_batches(&flight_data)
+    } else {
+        flight_data
+            .iter()
+            .map(|data| flight_data_to_arrow_batch(data, schema.clone(), &HashMap::new()))
+            .collect()
+    };
+    batches.map_err(|e| ErrorCode::BadArguments(format!("invalid parameters: {e}")))
+}
+
+fn count_placeholders(query: &str) -> usize {
+    let mut count = 0;
+    for_each_placeholder(query, |_, placeholder| {
+        if placeholder == Placeholder::QuestionMark {
+            count += 1;
+        }
+    });
+    count
+}
+
+/// Replace the `?` placeholders with the literals in order.
+fn bind_placeholders(query: &str, literals: &[String]) -> String {
+    let mut literals = literals.iter();
+    replace_placeholders(query, |_, placeholder| match placeholder {
+        Placeholder::QuestionMark => literals.next().cloned(),
+        Placeholder::Numbered(_) => None,
+    })
+}
+
+/// Whether each `?` placeholder is an argument of `LIMIT` or `OFFSET`,
+/// which only accept integer literals.
+fn limit_placeholders(query: &str) -> Vec<bool> {
+    let mut placeholders = vec![];
+    let mut last_limit_end = None;
+    for_each_placeholder(query, |range, placeholder| {
+        if placeholder != Placeholder::QuestionMark {
+            return;
+        }
+        let before = query[..range.start].trim_end();
+        // The second argument of `LIMIT ?, ?`.
+        let after_limit =
+            matches!(last_limit_end, Some(end) if query[end..range.start].trim() == ",");
+        let is_limit = after_limit
+            || ends_with_keyword(before, "LIMIT")
+            || ends_with_keyword(before, "OFFSET");
+        if is_limit {
+            last_limit_end = Some(range.end);
+        }
+        placeholders.push(is_limit);
+    });
+    placeholders
+}
+
+/// The parameters are usually sent as strings, so the argument of `LIMIT` is parsed.
+fn limit_literal(scalar: ScalarRef) -> Result<String> {
+    let value = match scalar {
+        ScalarRef::String(s) => std::str::from_utf8(s)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok()),
+        ScalarRef::Number(n) => n.to_string().parse::<u64>().ok(),
+        _ => None,
+    };
+    value.map(|v| v.to_string()).ok_or_else(|| {
+        ErrorCode::BadArguments(format!(
+            "the argument of LIMIT or OFFSET must be a non-negative integer, but got {}",
+            scalar
+        ))
+    })
+}
+
+/// Whether the text ends with the keyword, which is not a part of an identifier.
+fn ends_with_keyword(text: &str, keyword: &str) -> bool {
+    match text.len().checked_sub(keyword.len()) {
+        Some(start) => {
+            text.get(start..)
+                .map_or(false, |word| word.eq_ignore_ascii_case(keyword))
+                && !text[..start].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        }
+        None => false,
+    }
+}
@@ -35,6 +35,7 @@ use futures::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
 use super::DoGetStream;
@@ -82,6 +83,67 @@ impl FlightSqlServiceImpl {
         planner.plan_sql(query).await
     }
 
+    /// Execute a prepared statement without result set, returns the total affected rows
+    /// of all the bound parameter rows.
+    #[async_backtrace::framed]
+    pub(super) async fn execute_prepared_update(
+        &self,
+        session: Arc<Session>,
+        handle: &Uuid,
+    ) -> Result<i64> {
+        let (queries, plan, plan_extras) = self.get_prepared_statement(handle)?;
+        let mut affected_rows = 0;
+        for query in queries {
+            affected_rows += match query {
+                Some(query) => {
+                    let (plan, plan_extras) = self.plan_sql(&session, &query).await?;
+                    self.execute_update(session.clone(), &plan, &plan_extras)
+                        .await?
+                }
+                None => {
+                    self.execute_update(session.clone(), &plan, &plan_extras)
+                        .await?
+                }
+            };
+        }
+        Ok(affected_rows)
+    }
+
+    #[async_backtrace::framed]
+    pub(super) async fn execute_prepared_query(
+        &self,
+        session: Arc<Session>,
+        handle: &Uuid,
+    ) -> Result<DoGetStream> {
+        let (mut queries, plan, plan_extras) = self.get_prepared_statement(handle)?;
+        if queries.len() > 1 {
+            return Err(ErrorCode::BadArguments(
+                "only one row of parameters can be bound to a query with result set",
+            ));
+        }
+        match queries.pop().flatten() {
+            Some(query) => {
+                let (plan, plan_extras) = self.plan_sql(&session, &query).await?;
+                self.execute_query(session, &plan, &plan_extras).await
+            }
+            None => self.execute_query(session, &plan, &plan_extras).await,
+        }
+    }
+
+    fn get_prepared_statement(
+        &self,
+        handle: &Uuid,
+    ) -> Result<(Vec<Option<String>>, Plan, PlanExtras)> {
+        let stmt = self.statements.get(handle).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement {handle} not found"))
+        })?;
+        Ok((
+            stmt.bound_queries()?,
+            stmt.plan.clone(),
+            stmt.plan_extras.clone(),
+        ))
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn execute_update(
         &self,
@@ -57,6 +57,7 @@ use common_base::base::uuid::Uuid;
 use common_exception::Result;
 use common_expression::DataSchema;
 use futures::Stream;
+use futures::TryStreamExt;
 use prost::Message;
 use tonic::metadata::MetadataValue;
 use tonic::transport::NamedService;
@@ -65,6 +66,7 @@ use tonic::Response;
 use tonic::Status;
 use tonic::Streaming;
 
+use super::prepared::PreparedStatement;
 use super::status;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 
@@ -179,9 +181,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_prepared_query(session, &handle)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -211,8 +212,14 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
+        let schema = match self.statements.get(&handle) {
+            Some(stmt) => stmt.plan.schema().as_ref().into(),
+            None => {
+                return Err(Status::invalid_argument(format!(
+                    "prepared statement {handle} not found"
+                )));
+            }
+        };
         let loc = Location {
             uri: "grpc+tcp://127.0.0.1".to_string(),
         };
@@ -307,48 +314,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     // do_get
@@ -366,32 +369,52 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        tracing::info!("do_get_prepared_statement with handle={handle}");
+
+        let stream = self
+            .execute_prepared_query(session, &handle)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
         _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_catalogs()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        tracing::info!("do_get_schemas({query:?})");
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(
+                context.clone(),
+                query.catalog,
+                query.db_schema_filter_pattern,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -407,8 +430,15 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            super::CatalogInfoProvider::get_tables(
+                context.clone(),
+                query.catalog,
+                query.db_schema_filter_pattern,
+                query.table_name_filter_pattern,
+                query.table_types,
+                query.include_schema,
+            )
+            .await?,
         ))
     }
 
@@ -416,10 +446,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
@@ -436,21 +467,25 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys()?
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -458,11 +493,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -470,11 +506,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -512,9 +549,30 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let flight_data: Vec<FlightData> = request.into_inner().try_collect().await?;
+        let has_result_set = match self.statements.get_mut(&handle) {
+            Some(mut stmt) => {
+                if !flight_data.is_empty() {
+                    stmt.bind(&flight_data)
+                        .map_err(|e| status!("fail to bind parameters", e))?;
+                }
+                stmt.plan.has_result_set()
+            }
+            None => {
+                return Err(Status::invalid_argument(format!(
+                    "prepared statement {handle} not found"
+                )));
+            }
+        };
+        if has_result_set {
+            // The parameters are bound, the query is executed by `DoGet`.
+            let result = futures::stream::iter(Vec::<Result<PutResult, Status>>::new());
+            return Ok(Response::new(Box::pin(result)));
+        }
+
+        // JDBC executes the statements without result set through `DoPut` too.
         let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_prepared_update(session, &handle)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -538,9 +596,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_prepared_update(session, &handle)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -557,8 +614,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let session = self.get_session(&request)?;
         let sql = query.query.clone();
         let handle = Uuid::new_v4();
-        let plan = self
-            .plan_sql(&session, &sql)
+        let (plan, plan_extras) = self
+            .plan_sql(&session, &PreparedStatement::query_for_schema(&sql))
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
         tracing::info!(
@@ -566,8 +623,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         // JDBC client use call put when schema.fields == 0
-        let data_schema = if plan.0.has_result_set() {
-            plan.0.schema()
+        let data_schema = if plan.has_result_set() {
+            plan.schema()
         } else {
             Arc::new(DataSchema::empty())
         };
@@ -576,15 +633,26 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        let stmt = PreparedStatement::create(&sql, plan, plan_extras);
+        let parameter_schema = stmt.parameter_schema();
+        self.statements.insert(handle, stmt);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
         let IpcMessage(schema_bytes) = message;
+        let parameter_schema = if parameter_schema.fields().is_empty() {
+            Default::default()
+        } else {
+            let message = SchemaAsIpc::new(&parameter_schema, &IpcWriteOptions::default())
+                .try_into()
+                .map_err(|e| status!("Unable to serialize schema", e))?;
+            let IpcMessage(parameter_schema_bytes) = message;
+            parameter_schema_bytes
+        };
         let res = ActionCreatePreparedStatementResult {
             prepared_statement_handle: handle.as_bytes().to_vec().into(),
             dataset_schema: schema_bytes,
-            parameter_schema: Default::default(), // TODO: parameters
+            parameter_schema,
         };
         Ok(res)
     }
@@ -595,24 +663,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionClosePreparedStatementRequest,
         request: Request<Action>,
     ) {
-        let handle = query.prepared_statement_handle.as_ref();
-        if let Ok(handle) = std::str::from_utf8(handle) {
-            tracing::info!(
-                "do_action_close_prepared_statement with handle {:?}",
-                handle
-            );
-            match Uuid::try_parse(handle) {
-                Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
-                    }
-                }
-                Err(e) => {
-                    Status::internal(format!(
-                        "do_action_close_prepared_statement Error decoding handle: {e} {handle:?}"
-                    ));
+        match Uuid::from_slice(query.prepared_statement_handle.as_ref()) {
+            Ok(handle) => {
+                tracing::info!("do_action_close_prepared_statement with handle {handle}");
+                if self.get_session(&request).is_ok() {
+                    self.statements.remove(&handle);
                 }
             }
+            Err(e) => {
+                tracing::warn!("do_action_close_prepared_statement Error decoding handle: {e}");
+            }
         }
     }
 
@@ -113,7 +113,7 @@ impl FlightSqlServiceImpl {
             } => match password {
                 None => return Err(Status::unauthenticated("password required")),
                 Some(p) => {
-                    if *h == t.hash(&p) {
+                    if t.verify(h, &p) {
                         user
                     } else {
                         return Err(Status::unauthenticated("wrong password"));
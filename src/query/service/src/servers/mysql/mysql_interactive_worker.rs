@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
@@ -28,12 +29,17 @@ use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
+use common_sql::plans::Plan;
+use common_sql::PlanExtras;
 use common_sql::Planner;
 use common_users::CertifiedInfo;
 use common_users::UserApiProvider;
 use futures_util::StreamExt;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -47,6 +53,9 @@ use tracing::Instrument;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_prepared::decode_params;
+use crate::servers::mysql::mysql_prepared::MySQLPreparedStatement;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -58,9 +67,15 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+/// The max number of prepared statements of a session, same as the default
+/// `max_prepared_stmt_count` of MySQL.
+const MAX_PREPARED_STATEMENTS: usize = 16382;
+
 struct InteractiveWorkerBase<W: AsyncWrite + Send + Unpin> {
     session: Arc<Session>,
     generic_hold: PhantomData<W>,
+    prepared_statements: HashMap<u32, MySQLPreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker<W: AsyncWrite + Send + Unpin> {
@@ -270,34 +285,108 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
     }
 
     #[async_backtrace::framed]
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+    async fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        info!("Prepare query: {}", query);
+        let prepared = self.prepare(query).await.and_then(|(statement, schema)| {
+            let columns = convert_schema(&schema)?;
+            Ok((statement, columns))
+        });
+        match prepared {
+            Ok((statement, columns)) => {
+                let params = (0..statement.num_params())
+                    .map(|_| Column {
+                        table: "".to_string(),
+                        column: "?".to_string(),
+                        coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                        colflags: ColumnFlags::empty(),
+                    })
+                    .collect::<Vec<_>>();
+
+                let id = self.next_statement_id;
+                self.next_statement_id = self.next_statement_id.wrapping_add(1);
+                self.prepared_statements.insert(id, statement);
+                writer.reply(id, &params, &columns).await?;
+            }
+            Err(error) => {
+                let error = error.display_with_sql(query);
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                    .await?;
+            }
+        }
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    async fn prepare(&mut self, query: &str) -> Result<(MySQLPreparedStatement, DataSchemaRef)> {
+        if self.prepared_statements.len() >= MAX_PREPARED_STATEMENTS {
+            return Err(ErrorCode::BadArguments(format!(
+                "Can't create more than {} prepared statements in a session",
+                MAX_PREPARED_STATEMENTS
+            )));
+        }
+        let context = self.session.create_query_context().await?;
+        MySQLPreparedStatement::try_create(context, query).await
+    }
+
     #[async_backtrace::framed]
     async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let instant = Instant::now();
+        let query_result = self.execute(id, params).await;
+
+        let format = self.session.get_format_settings();
+        let write_result = writer.write(query_result, &format).await;
+
+        histogram!(
+            super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+            instant.elapsed()
+        );
+
+        write_result
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params))]
+    #[async_backtrace::framed]
+    async fn execute(
+        &mut self,
+        id: u32,
+        params: ParamParser<'_>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        let params = decode_params(params)?;
+        let statement = self.prepared_statements.get_mut(&id).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("Unknown prepared statement id: {}", id))
+        })?;
+        if params.len() != statement.num_params() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                statement.num_params(),
+                params.len()
+            )));
+        }
+
+        let context = self.session.create_query_context().await?;
+        let query = statement.bound_query(&params);
+        match statement.bound_plan(context.clone(), &params).await {
+            Ok(Some((plan, extras))) => {
+                info!("Execute prepared query: {}", query);
+                Self::exec_plan(context, &query, plan, extras).await
+            }
+            Ok(None) => self.do_query(&query).await,
+            Err(error) => Err(error.display_with_sql(&query)),
+        }
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -338,32 +427,41 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
 
-                context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = interpreter.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+                Self::exec_plan(context, query, plan, extras).await
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn exec_plan(
+        context: Arc<QueryContext>,
+        query: &str,
+        plan: Plan,
+        extras: PlanExtras,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = interpreter.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -443,6 +541,8 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
             base: InteractiveWorkerBase::<W> {
                 session,
                 generic_hold: PhantomData::default(),
+                prepared_statements: HashMap::new(),
+                next_statement_id: 1,
             },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
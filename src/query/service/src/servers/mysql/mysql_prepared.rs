@@ -0,0 +1,268 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::tokenize_sql;
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
+use common_sql::binder::bind_placeholders;
+use common_sql::binder::read_table_statistics;
+use common_sql::plans::Plan;
+use common_sql::MetadataRef;
+use common_sql::PlanExtras;
+use common_sql::Planner;
+use common_storages_fuse::FuseTable;
+use opensrv_mysql::ParamParser;
+use opensrv_mysql::ValueInner;
+use parking_lot::RwLock;
+
+use crate::servers::parameters::scalar_to_literal;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// A statement created by COM_STMT_PREPARE, cached in the session until COM_STMT_CLOSE.
+///
+/// A query is bound once with its `?` placeholders kept as `PlaceholderExpr`s, and each
+/// COM_STMT_EXECUTE binds the parameters to the cached plan and optimizes it with the latest
+/// statistics of the tables. The plan is bound again if the types of the parameters or the
+/// schema of the tables change.
+///
+/// The other statements, e.g. `INSERT`, and the queries without parameters, have the
+/// parameters substituted into the SQL text as literals and are planned again for each
+/// execution.
+pub struct MySQLPreparedStatement {
+    query: String,
+    /// The start offsets of the `?` placeholders in the query.
+    placeholders: Vec<usize>,
+    is_query: bool,
+    /// The cached plan with the parameter types it's bound for, it's not optimized.
+    plan: Option<(Vec<DataType>, Plan, PlanExtras)>,
+}
+
+impl MySQLPreparedStatement {
+    /// Prepare the statement, returns the schema of the result set if it's a query.
+    #[async_backtrace::framed]
+    pub async fn try_create(
+        ctx: Arc<QueryContext>,
+        query: &str,
+    ) -> Result<(MySQLPreparedStatement, DataSchemaRef)> {
+        let tokens = tokenize_sql(query)?;
+        let placeholders = tokens
+            .iter()
+            .filter(|token| token.kind == TokenKind::Placeholder)
+            .map(|token| token.span.start)
+            .collect::<Vec<_>>();
+        let sql_dialect = ctx.get_settings().get_sql_dialect()?;
+        let (stmt, _) = parse_sql(&tokens, sql_dialect)?;
+
+        let mut statement = MySQLPreparedStatement {
+            query: query.to_string(),
+            placeholders,
+            is_query: matches!(stmt, Statement::Query(_)),
+            plan: None,
+        };
+        if !statement.is_query {
+            return Ok((statement, DataSchemaRefExt::create(vec![])));
+        }
+
+        // The types of the parameters are unknown until the first execution.
+        let types = vec![DataType::Null; statement.num_params()];
+        let schema = statement.plan(ctx, types).await?.schema();
+        // Nothing is saved by caching the plan of a query without parameters.
+        if statement.num_params() == 0 {
+            statement.plan = None;
+        }
+        Ok((statement, schema))
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.placeholders.len()
+    }
+
+    /// The plan to run with the parameters, returns `None` if the statement can't be
+    /// bound with a cached plan, then the query returned by `bound_query` should be run.
+    #[async_backtrace::framed]
+    pub async fn bound_plan(
+        &mut self,
+        ctx: Arc<QueryContext>,
+        params: &[Scalar],
+    ) -> Result<Option<(Plan, PlanExtras)>> {
+        if !self.is_query || self.num_params() == 0 {
+            return Ok(None);
+        }
+
+        let types = params
+            .iter()
+            .map(|param| param.as_ref().infer_data_type())
+            .collect::<Vec<_>>();
+        let cached = match &self.plan {
+            Some((cached_types, _, extras)) if cached_types == &types => {
+                refresh_tables(ctx.clone(), &extras.metadata).await?
+            }
+            _ => false,
+        };
+        if !cached {
+            self.plan(ctx.clone(), types).await?;
+            if !self.is_query {
+                return Ok(None);
+            }
+        }
+
+        let (_, plan, extras) = self.plan.as_ref().unwrap();
+        // The optimizer may add columns to the metadata, each execution has its own copy.
+        let metadata = Arc::new(RwLock::new(extras.metadata.read().clone()));
+        let statistics = read_table_statistics(&metadata).await?;
+        let plan = bind_placeholders(plan, params, metadata.clone(), &statistics)?;
+        let plan = Planner::new(ctx).optimize_plan(plan)?;
+        let extras = PlanExtras {
+            metadata,
+            ..extras.clone()
+        };
+        Ok(Some((plan, extras)))
+    }
+
+    /// The query with the parameters substituted as literals.
+    pub fn bound_query(&self, params: &[Scalar]) -> String {
+        let mut query = String::with_capacity(self.query.len());
+        let mut last = 0;
+        for (offset, param) in self.placeholders.iter().zip(params) {
+            query.push_str(&self.query[last..*offset]);
+            query.push_str(&scalar_to_literal(param.as_ref()));
+            last = offset + 1;
+        }
+        query.push_str(&self.query[last..]);
+        query
+    }
+
+    #[async_backtrace::framed]
+    async fn plan(&mut self, ctx: Arc<QueryContext>, types: Vec<DataType>) -> Result<&Plan> {
+        let mut planner = Planner::new(ctx);
+        let (plan, extras) = planner
+            .bind_sql_with_placeholders(&self.query, &types)
+            .await?;
+        // Some queries, e.g. `SELECT * FROM system.tables`, are rewritten to other plans.
+        self.is_query = matches!(plan, Plan::Query { .. });
+        let (_, plan, _) = self.plan.insert((types, plan, extras));
+        Ok(plan)
+    }
+}
+
+/// Read the latest snapshots of the fuse tables used by a cached plan,
+/// returns `false` if any schema is changed and the plan must be bound again.
+#[async_backtrace::framed]
+async fn refresh_tables(ctx: Arc<QueryContext>, metadata: &MetadataRef) -> Result<bool> {
+    let tables = metadata.read().tables().to_vec();
+    for entry in tables {
+        let table = entry.table();
+        if FuseTable::try_from_table(table.as_ref()).is_err() {
+            continue;
+        }
+        let latest = table.as_ref().refresh(ctx.as_ref()).await?;
+        if latest.schema() != table.schema() {
+            return Ok(false);
+        }
+        metadata.write().replace_table(entry.index(), latest);
+    }
+    Ok(true)
+}
+
+/// Decode the parameters of COM_STMT_EXECUTE.
+///
+/// Dates, times and decimals are sent as strings, and cast by the functions they are passed to,
+/// as they would be written in the text protocol.
+pub fn decode_params(params: ParamParser<'_>) -> Result<Vec<Scalar>> {
+    params
+        .into_iter()
+        .map(|param| match param.value.into_inner() {
+            ValueInner::NULL => Ok(Scalar::Null),
+            ValueInner::Int(v) => Ok(Scalar::Number(NumberScalar::Int64(v))),
+            ValueInner::UInt(v) => Ok(Scalar::Number(NumberScalar::UInt64(v))),
+            ValueInner::Double(v) => Ok(Scalar::Number(NumberScalar::Float64(v.into()))),
+            ValueInner::Bytes(v) => Ok(Scalar::String(v.to_vec())),
+            ValueInner::Date(v) | ValueInner::Datetime(v) => {
+                decode_datetime(v).map(|v| Scalar::String(v.into_bytes()))
+            }
+            ValueInner::Time(v) => decode_time(v).map(|v| Scalar::String(v.into_bytes())),
+        })
+        .collect()
+}
+
+/// https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_binary_resultset.html#sect_protocol_binary_resultset_row_value_date
+fn decode_datetime(bytes: &[u8]) -> Result<String> {
+    let le_u16 = |b: &[u8]| u16::from_le_bytes([b[0], b[1]]);
+    let le_u32 = |b: &[u8]| u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+    match bytes.len() {
+        0 => Ok("0000-00-00 00:00:00".to_string()),
+        4 => Ok(format!(
+            "{:04}-{:02}-{:02}",
+            le_u16(bytes),
+            bytes[2],
+            bytes[3]
+        )),
+        7 => Ok(format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            le_u16(bytes),
+            bytes[2],
+            bytes[3],
+            bytes[4],
+            bytes[5],
+            bytes[6]
+        )),
+        11 => Ok(format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}",
+            le_u16(bytes),
+            bytes[2],
+            bytes[3],
+            bytes[4],
+            bytes[5],
+            bytes[6],
+            le_u32(&bytes[7..])
+        )),
+        len => Err(ErrorCode::BadBytes(format!(
+            "Invalid length of datetime parameter: {}",
+            len
+        ))),
+    }
+}
+
+/// https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_binary_resultset.html#sect_protocol_binary_resultset_row_value_time
+fn decode_time(bytes: &[u8]) -> Result<String> {
+    let le_u32 = |b: &[u8]| u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+    match bytes.len() {
+        0 => Ok("00:00:00".to_string()),
+        8 | 12 => {
+            let sign = if bytes[0] == 1 { "-" } else { "" };
+            let hours = le_u32(&bytes[1..]) as u64 * 24 + bytes[5] as u64;
+            let mut time = format!("{}{:02}:{:02}:{:02}", sign, hours, bytes[6], bytes[7]);
+            if bytes.len() == 12 {
+                time.push_str(&format!(".{:06}", le_u32(&bytes[8..])));
+            }
+            Ok(time)
+        }
+        len => Err(ErrorCode::BadBytes(format!(
+            "Invalid length of time parameter: {}",
+            len
+        ))),
+    }
+}
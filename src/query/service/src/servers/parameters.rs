@@ -0,0 +1,170 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The parameters of the prepared statements that are substituted into the query text.
+//!
+//! Databend has no server side parameters for the postgres and flight sql protocols, and for
+//! the statements of the mysql protocol which are not queries, so the bound values replace
+//! their placeholders as SQL literals.
+
+use std::ops::Range;
+
+use chrono_tz::Tz;
+use common_expression::types::date::date_to_string;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
+use common_expression::ScalarRef;
+
+/// A placeholder of a parameter: `?` or `$n`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placeholder {
+    QuestionMark,
+    /// The parameter number starting from 1.
+    Numbered(usize),
+}
+
+/// Iterate the placeholders outside of the string literals, quoted identifiers and comments,
+/// calls `f` with the byte range of each placeholder.
+pub fn for_each_placeholder(query: &str, mut f: impl FnMut(Range<usize>, Placeholder)) {
+    let bytes = query.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' && quote == b'\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'?' => {
+                f(i..i + 1, Placeholder::QuestionMark);
+                i += 1;
+            }
+            b'$' if i == 0 || !(bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_') => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                if let Ok(n) = query[start + 1..i].parse::<usize>() {
+                    if n > 0 {
+                        f(start..i, Placeholder::Numbered(n));
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+}
+
+/// Replace the placeholders with the literals returned by `literal`,
+/// the placeholders for which it returns `None` are kept.
+pub fn replace_placeholders(
+    query: &str,
+    mut literal: impl FnMut(Range<usize>, Placeholder) -> Option<String>,
+) -> String {
+    let mut result = String::with_capacity(query.len());
+    let mut last = 0;
+    for_each_placeholder(query, |range, placeholder| {
+        if let Some(literal) = literal(range.clone(), placeholder) {
+            result.push_str(&query[last..range.start]);
+            result.push_str(&literal);
+            last = range.end;
+        }
+    });
+    result.push_str(&query[last..]);
+    result
+}
+
+/// Render a value as a SQL literal of its type.
+pub fn scalar_to_literal(scalar: ScalarRef) -> String {
+    match scalar {
+        ScalarRef::Null => "NULL".to_string(),
+        ScalarRef::Boolean(v) => (if v { "TRUE" } else { "FALSE" }).to_string(),
+        ScalarRef::Number(NumberScalar::Float32(v)) => float_literal(v.0 as f64),
+        ScalarRef::Number(NumberScalar::Float64(v)) => float_literal(v.0),
+        ScalarRef::Number(v) => number_literal(&v.to_string()),
+        ScalarRef::Decimal(v) => number_literal(&v.to_string()),
+        ScalarRef::String(s) => quote_string(&String::from_utf8_lossy(s)),
+        ScalarRef::Binary(v) => format!("from_hex('{}')", hex::encode(v)),
+        ScalarRef::Date(v) => {
+            format!(
+                "{}::DATE",
+                quote_string(&date_to_string(v, Tz::UTC).to_string())
+            )
+        }
+        ScalarRef::Timestamp(v) => {
+            // The timestamp is an instant, it's not in the timezone of the session.
+            let ts = format!("{}+00:00", timestamp_to_string(v, Tz::UTC));
+            format!("{}::TIMESTAMP", quote_string(&ts))
+        }
+        ScalarRef::TimestampTz(v) => {
+            format!(
+                "{}::TIMESTAMP_TZ",
+                quote_string(&timestamp_tz_to_string(v).to_string())
+            )
+        }
+        ScalarRef::Interval(v) => format!("{}::INTERVAL", quote_string(&interval_to_string(v))),
+        ScalarRef::Variant(v) => format!("{}::VARIANT", quote_string(&jsonb::to_string(v))),
+        ScalarRef::Geometry(_) => format!("{}::GEOMETRY", quote_string(&scalar.to_string())),
+        // The nested values, e.g. arrays and tuples.
+        scalar => format!("({})", scalar),
+    }
+}
+
+/// A number is enclosed in parentheses, so that a negative number after `-`
+/// doesn't start a comment, e.g. `a - ?` with `-1`.
+pub fn number_literal(number: &str) -> String {
+    format!("({})", number)
+}
+
+pub fn float_literal(v: f64) -> String {
+    if v.is_finite() {
+        number_literal(&v.to_string())
+    } else {
+        format!("'{}'::DOUBLE", v)
+    }
+}
+
+pub fn quote_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('\'');
+    for c in s.chars() {
+        match c {
+            '\'' => quoted.push_str("\\'"),
+            '\\' => quoted.push_str("\\\\"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
@@ -0,0 +1,25 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod postgres_auth;
+mod postgres_handler;
+mod postgres_interactive_worker;
+mod postgres_message;
+mod postgres_session;
+mod postgres_types;
+
+pub use self::postgres_handler::PostgresHandler;
+
+// Reported as `server_version`, the drivers enable features by the version.
+const PG_VERSION: &str = "15.0";
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::ScramSha256Secret;
+use rand::RngCore;
+
+use crate::servers::postgres::postgres_message::MessageBuf;
+
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+
+/// The server side of a SCRAM-SHA-256 exchange (RFC 5802, RFC 7677).
+///
+/// Channel binding is not supported, so only `SCRAM-SHA-256` is offered
+/// and the client must send the `n` or `y` gs2 header.
+pub struct ScramServer {
+    secret: ScramSha256Secret,
+    gs2_header: String,
+    client_first_bare: String,
+    server_first: String,
+    nonce: String,
+}
+
+impl ScramServer {
+    /// Handle the `SASLInitialResponse`, returns the server and the server-first-message.
+    pub fn start(secret: ScramSha256Secret, initial_response: &[u8]) -> Result<(Self, String)> {
+        let mut buf = MessageBuf::new(initial_response);
+        let mechanism = buf.read_cstr()?;
+        if mechanism != SCRAM_SHA_256 {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "unsupported SASL mechanism {}",
+                mechanism
+            )));
+        }
+        let len = buf.read_i32()?;
+        let client_first = match len {
+            -1 => "",
+            len => std::str::from_utf8(buf.read_bytes(len.max(0) as usize)?)
+                .map_err(|_| Self::malformed())?,
+        };
+
+        // gs2-header: gs2-cbind-flag "," [ authzid ] ","
+        let mut parts = client_first.splitn(3, ',');
+        let (cbind_flag, authzid, client_first_bare) =
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(flag), Some(authzid), Some(bare)) => (flag, authzid, bare),
+                _ => return Err(Self::malformed()),
+            };
+        if cbind_flag != "n" && cbind_flag != "y" {
+            return Err(ErrorCode::AuthenticateFailure(
+                "SCRAM channel binding is not supported",
+            ));
+        }
+
+        // The user name is ignored, the user of the startup message is authenticated.
+        let client_nonce = client_first_bare
+            .split(',')
+            .find_map(|attr| attr.strip_prefix("r="))
+            .ok_or_else(Self::malformed)?;
+
+        let mut server_nonce = [0u8; 18];
+        rand::thread_rng().fill_bytes(&mut server_nonce);
+        let nonce = format!("{}{}", client_nonce, BASE64_STANDARD.encode(server_nonce));
+        let server_first = format!(
+            "r={},s={},i={}",
+            nonce,
+            BASE64_STANDARD.encode(&secret.salt),
+            ScramSha256Secret::ITERATIONS
+        );
+
+        let server = ScramServer {
+            secret,
+            gs2_header: format!("{},{},", cbind_flag, authzid),
+            client_first_bare: client_first_bare.to_string(),
+            server_first: server_first.clone(),
+            nonce,
+        };
+        Ok((server, server_first))
+    }
+
+    /// Verify the client-final-message of `SASLResponse`, returns the server-final-message.
+    pub fn finish(&self, response: &[u8]) -> Result<String> {
+        let client_final = std::str::from_utf8(response).map_err(|_| Self::malformed())?;
+        let (without_proof, proof) = client_final
+            .rsplit_once(",p=")
+            .ok_or_else(Self::malformed)?;
+
+        let mut channel_binding = None;
+        let mut nonce = None;
+        for attr in without_proof.split(',') {
+            if let Some(v) = attr.strip_prefix("c=") {
+                channel_binding = Some(v);
+            } else if let Some(v) = attr.strip_prefix("r=") {
+                nonce = Some(v);
+            }
+        }
+
+        let expected_binding = BASE64_STANDARD.encode(self.gs2_header.as_bytes());
+        if channel_binding != Some(expected_binding.as_str()) {
+            return Err(ErrorCode::AuthenticateFailure(
+                "SCRAM channel binding check failed",
+            ));
+        }
+        if nonce != Some(self.nonce.as_str()) {
+            return Err(ErrorCode::AuthenticateFailure("SCRAM nonce mismatch"));
+        }
+
+        let proof = BASE64_STANDARD
+            .decode(proof)
+            .map_err(|_| Self::malformed())?;
+        let auth_message = format!(
+            "{},{},{}",
+            self.client_first_bare, self.server_first, without_proof
+        );
+        if !self
+            .secret
+            .verify_client_proof(auth_message.as_bytes(), &proof)
+        {
+            return Err(ErrorCode::AuthenticateFailure("wrong password"));
+        }
+
+        let signature = self.secret.server_signature(auth_message.as_bytes());
+        Ok(format!("v={}", BASE64_STANDARD.encode(signature)))
+    }
+
+    fn malformed() -> ErrorCode {
+        ErrorCode::AuthenticateFailure("malformed SCRAM message")
+    }
+}
@@ -0,0 +1,749 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::runtime::TrySpawn;
+use common_catalog::txn::TxnState;
+use common_config::DATABEND_COMMIT_VERSION;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::Column;
+use common_expression::DataSchemaRef;
+use common_expression::SendableDataBlockStream;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordHashMethod;
+use common_meta_app::principal::ScramSha256Secret;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures_util::StreamExt;
+use rand::Rng;
+use tracing::error;
+use tracing::info;
+use tracing::Instrument;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::parameters::for_each_placeholder;
+use crate::servers::parameters::replace_placeholders;
+use crate::servers::parameters::Placeholder;
+use crate::servers::postgres::postgres_auth::ScramServer;
+use crate::servers::postgres::postgres_auth::SCRAM_SHA_256;
+use crate::servers::postgres::postgres_message::BackendMessage;
+use crate::servers::postgres::postgres_message::FieldDescription;
+use crate::servers::postgres::postgres_message::FrontendMessage;
+use crate::servers::postgres::postgres_message::MessageWriter;
+use crate::servers::postgres::postgres_message::StartupMessage;
+use crate::servers::postgres::postgres_message::FORMAT_TEXT;
+use crate::servers::postgres::postgres_types::field_description;
+use crate::servers::postgres::postgres_types::oid;
+use crate::servers::postgres::postgres_types::param_to_literal;
+use crate::servers::postgres::postgres_types::PgValueEncoder;
+use crate::servers::postgres::PG_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+
+// Flush the buffered data rows to the client once they exceed this size.
+const FLUSH_THRESHOLD: usize = 64 * 1024;
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+    running: Option<RunningQuery>,
+}
+
+struct RunningQuery {
+    query: String,
+    context: Arc<QueryContext>,
+    schema: DataSchemaRef,
+    has_result_set: bool,
+    blocks: SendableDataBlockStream,
+    // The columns of the block being sent and the index of the next row.
+    pending: Option<(Vec<Column>, usize)>,
+    sent_rows: usize,
+}
+
+enum ExecuteStatus {
+    Suspended,
+    Completed(String),
+}
+
+pub struct InteractiveWorker<R: AsyncRead + Send + Unpin, W: AsyncWrite + Send + Unpin> {
+    session: Arc<Session>,
+    client_addr: String,
+    reader: R,
+    writer: MessageWriter<W>,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+}
+
+impl<R: AsyncRead + Send + Unpin, W: AsyncWrite + Send + Unpin> InteractiveWorker<R, W> {
+    pub fn create(session: Arc<Session>, client_addr: String, reader: R, writer: W) -> Self {
+        InteractiveWorker {
+            session,
+            client_addr,
+            reader,
+            writer: MessageWriter::new(writer),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run(mut self) -> Result<()> {
+        let params = match self.startup().await? {
+            None => return Ok(()),
+            Some(params) => params,
+        };
+
+        if let Err(cause) = self.authenticate(&params).await {
+            error!(
+                "PostgreSQL handler authenticate failed, \
+                    user_name: {:?}, \
+                    client_address: {}, \
+                    failure_cause: {}",
+                params.get("user"),
+                self.client_addr,
+                cause
+            );
+            self.write_error("FATAL", &cause);
+            return self.writer.flush().await;
+        }
+
+        if let Err(cause) = self.do_init(&params).await {
+            self.write_error("FATAL", &cause);
+            return self.writer.flush().await;
+        }
+        self.write_ready_for_query();
+        self.writer.flush().await?;
+
+        // Whether an error occurred in the extended query protocol,
+        // the following messages are discarded until `Sync`.
+        let mut ignore_till_sync = false;
+        while let Some(message) = FrontendMessage::read(&mut self.reader).await? {
+            if self.session.is_aborting() {
+                let cause = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.write_error("FATAL", &cause);
+                self.writer.flush().await?;
+                return Err(cause);
+            }
+
+            match message {
+                FrontendMessage::Terminate => break,
+                FrontendMessage::Sync => {
+                    ignore_till_sync = false;
+                    self.write_ready_for_query();
+                    self.writer.flush().await?;
+                }
+                _ if ignore_till_sync => {}
+                FrontendMessage::Flush => self.writer.flush().await?,
+                FrontendMessage::Query(query) => {
+                    // The unnamed statement and portal are destroyed by a simple query.
+                    self.statements.remove("");
+                    self.portals.remove("");
+                    if let Err(cause) = self.on_query(&query).await {
+                        self.write_error("ERROR", &cause.display_with_sql(&query));
+                    }
+                    self.write_ready_for_query();
+                    self.writer.flush().await?;
+                }
+                message => {
+                    if let Err(cause) = self.on_extended_message(message).await {
+                        self.write_error("ERROR", &cause);
+                        ignore_till_sync = true;
+                    }
+                }
+            }
+        }
+
+        self.writer.flush().await
+    }
+
+    /// Read the startup message, returns `None` if the connection should be closed.
+    #[async_backtrace::framed]
+    async fn startup(&mut self) -> Result<Option<HashMap<String, String>>> {
+        loop {
+            match StartupMessage::read(&mut self.reader).await? {
+                // TLS and GSSAPI encryption are not supported, the client may continue
+                // with an unencrypted connection.
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    self.writer.write_raw(b'N');
+                    self.writer.flush().await?;
+                }
+                StartupMessage::CancelRequest => {
+                    info!(
+                        "PostgreSQL cancel request is not supported, client_address: {}",
+                        self.client_addr
+                    );
+                    return Ok(None);
+                }
+                StartupMessage::Startup { params } => return Ok(Some(params)),
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate(&mut self, params: &HashMap<String, String>) -> Result<()> {
+        let user_name = params
+            .get("user")
+            .ok_or_else(|| ErrorCode::AuthenticateFailure("no user name specified"))?;
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0].to_string();
+
+        let tenant = self.session.get_current_tenant();
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, user_name, &client_ip)
+            .await?;
+
+        match &user_info.auth_info {
+            AuthInfo::None => {
+                self.session.set_authed_user(user_info, None).await?;
+            }
+            AuthInfo::Password {
+                hash_value,
+                hash_method: PasswordHashMethod::ScramSha256,
+            } => {
+                let secret = ScramSha256Secret::from_hash_value(hash_value)?;
+                self.writer
+                    .write(BackendMessage::AuthenticationSasl(&[SCRAM_SHA_256]));
+                let initial_response = self.read_password_message().await?;
+                let (scram, server_first) = ScramServer::start(secret, &initial_response)?;
+
+                self.writer
+                    .write(BackendMessage::AuthenticationSaslContinue(
+                        server_first.as_bytes(),
+                    ));
+                let response = self.read_password_message().await?;
+                let server_final = scram.finish(&response)?;
+
+                self.writer.write(BackendMessage::AuthenticationSaslFinal(
+                    server_final.as_bytes(),
+                ));
+                self.session.set_authed_user(user_info, None).await?;
+            }
+            // The stored hashes of the other methods can only be verified with the
+            // cleartext password, which is also taken as the token of JWT users.
+            auth_info => {
+                self.writer
+                    .write(BackendMessage::AuthenticationCleartextPassword);
+                let mut password = self.read_password_message().await?;
+                // The password is a null-terminated string.
+                password.pop();
+
+                let credential = match auth_info {
+                    AuthInfo::JWT => Credential::Jwt {
+                        token: String::from_utf8(password).map_err(|_| {
+                            ErrorCode::AuthenticateFailure("invalid utf8 jwt token")
+                        })?,
+                    },
+                    _ => Credential::Password {
+                        name: user_name.clone(),
+                        password: Some(password),
+                        hostname: Some(client_ip),
+                    },
+                };
+                AuthMgr::instance()
+                    .auth(self.session.clone(), &credential)
+                    .await?;
+            }
+        }
+
+        self.writer.write(BackendMessage::AuthenticationOk);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_password_message(&mut self) -> Result<Vec<u8>> {
+        self.writer.flush().await?;
+        match FrontendMessage::read(&mut self.reader).await? {
+            Some(FrontendMessage::Password(data)) => Ok(data),
+            Some(_) => Err(ErrorCode::AuthenticateFailure("expected password response")),
+            None => Err(ErrorCode::AuthenticateFailure(
+                "connection closed during authentication",
+            )),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn do_init(&mut self, params: &HashMap<String, String>) -> Result<()> {
+        if let Some(database) = params.get("database") {
+            // psql connects to the database with the same name as the user by default.
+            if !database.is_empty() && Some(database) != params.get("user") {
+                let init_query = format!("USE `{}`;", database);
+                let mut running = self.do_query(&init_query).await?;
+                while let Some(block) = running.blocks.next().await {
+                    block?;
+                }
+            }
+        }
+
+        let timezone = self.session.get_settings().get_timezone()?;
+        let user = self.session.get_current_user()?.name;
+        let application_name = params.get("application_name").cloned().unwrap_or_default();
+        for (name, value) in [
+            ("server_version", PG_VERSION.to_string()),
+            (
+                "server_version_detail",
+                format!("Databend {}", *DATABEND_COMMIT_VERSION),
+            ),
+            ("server_encoding", "UTF8".to_string()),
+            ("client_encoding", "UTF8".to_string()),
+            ("DateStyle", "ISO, MDY".to_string()),
+            ("IntervalStyle", "postgres".to_string()),
+            ("TimeZone", timezone),
+            ("integer_datetimes", "on".to_string()),
+            ("standard_conforming_strings", "on".to_string()),
+            ("is_superuser", "off".to_string()),
+            ("session_authorization", user),
+            ("application_name", application_name),
+        ] {
+            self.writer
+                .write(BackendMessage::ParameterStatus(name, &value));
+        }
+
+        // Cancel request is not supported, the key data is only sent for the drivers requiring it.
+        let mut rng = rand::thread_rng();
+        self.writer.write(BackendMessage::BackendKeyData {
+            process_id: rng.gen(),
+            secret_key: rng.gen(),
+        });
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_query(&mut self, query: &str) -> Result<()> {
+        if is_empty_query(query) {
+            self.writer.write(BackendMessage::EmptyQueryResponse);
+            return Ok(());
+        }
+
+        let mut running = self.do_query(query).await?;
+        if running.has_result_set {
+            let fields = row_description(&running.schema, &[])?;
+            self.writer.write(BackendMessage::RowDescription(&fields));
+        }
+        match self.send_rows(&mut running, &[], 0).await? {
+            ExecuteStatus::Completed(tag) => {
+                self.writer.write(BackendMessage::CommandComplete(&tag));
+            }
+            ExecuteStatus::Suspended => unreachable!("simple query is never suspended"),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_extended_message(&mut self, message: FrontendMessage) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                self.writer.write(BackendMessage::ParseComplete);
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let stmt = self.get_statement(&statement)?;
+                let num_params = count_params(&stmt.query);
+                if params.len() != num_params {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "bind message supplies {} parameters, but prepared statement \"{}\" requires {}",
+                        params.len(),
+                        statement,
+                        num_params
+                    )));
+                }
+
+                let literals = params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, param)| {
+                        let format = match param_formats.len() {
+                            0 => FORMAT_TEXT,
+                            1 => param_formats[0],
+                            _ => param_formats.get(i).cloned().unwrap_or(FORMAT_TEXT),
+                        };
+                        let type_oid = stmt.param_types.get(i).cloned().unwrap_or(oid::UNKNOWN);
+                        param_to_literal(param.as_deref(), format, type_oid)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let query = replace_params(&stmt.query, &literals);
+
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                    running: None,
+                });
+                self.writer.write(BackendMessage::BindComplete);
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let stmt = self.get_statement(&name)?;
+                let num_params = count_params(&stmt.query).max(stmt.param_types.len());
+                let param_types = (0..num_params)
+                    .map(|i| match stmt.param_types.get(i) {
+                        None | Some(&oid::UNKNOWN) | Some(0) => oid::TEXT,
+                        Some(oid) => *oid,
+                    })
+                    .collect::<Vec<_>>();
+                let nulls = vec!["NULL".to_string(); num_params];
+                let query = replace_params(&stmt.query, &nulls);
+
+                self.writer
+                    .write(BackendMessage::ParameterDescription(&param_types));
+                // The statement may be unable to plan without the parameters, e.g. `LIMIT $1`.
+                match self.describe_query(&query, &[]).await {
+                    Ok(Some(fields)) => self.writer.write(BackendMessage::RowDescription(&fields)),
+                    Ok(None) | Err(_) => self.writer.write(BackendMessage::NoData),
+                }
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let portal = self.get_portal(&name)?;
+                let (query, result_formats) = (portal.query.clone(), portal.result_formats.clone());
+                match self.describe_query(&query, &result_formats).await? {
+                    Some(fields) => self.writer.write(BackendMessage::RowDescription(&fields)),
+                    None => self.writer.write(BackendMessage::NoData),
+                }
+            }
+            FrontendMessage::Execute { portal, max_rows } => {
+                let running = self.get_portal(&portal)?.running.take();
+                let mut running = match running {
+                    Some(running) => running,
+                    None => {
+                        let query = self.get_portal(&portal)?.query.clone();
+                        if is_empty_query(&query) {
+                            self.writer.write(BackendMessage::EmptyQueryResponse);
+                            return Ok(());
+                        }
+                        self.do_query(&query).await?
+                    }
+                };
+
+                let result_formats = self.get_portal(&portal)?.result_formats.clone();
+                match self
+                    .send_rows(&mut running, &result_formats, max_rows.max(0) as usize)
+                    .await?
+                {
+                    ExecuteStatus::Suspended => {
+                        self.writer.write(BackendMessage::PortalSuspended);
+                        self.get_portal(&portal)?.running = Some(running);
+                    }
+                    ExecuteStatus::Completed(tag) => {
+                        self.writer.write(BackendMessage::CommandComplete(&tag));
+                    }
+                }
+            }
+            FrontendMessage::Close { kind, name } => {
+                match kind {
+                    b'S' => {
+                        self.statements.remove(&name);
+                    }
+                    _ => {
+                        self.portals.remove(&name);
+                    }
+                }
+                self.writer.write(BackendMessage::CloseComplete);
+            }
+            FrontendMessage::Describe { kind, .. } => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid describe message type '{}'",
+                    kind as char
+                )));
+            }
+            FrontendMessage::CopyFail => {}
+            FrontendMessage::Password(_) => {
+                return Err(ErrorCode::BadBytes("unexpected password message"));
+            }
+            FrontendMessage::Query(_)
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!(),
+        }
+        Ok(())
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    fn get_portal(&mut self, name: &str) -> Result<&mut Portal> {
+        self.portals
+            .get_mut(name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name)))
+    }
+
+    #[async_backtrace::framed]
+    async fn describe_query(
+        &self,
+        query: &str,
+        result_formats: &[i16],
+    ) -> Result<Option<Vec<FieldDescription>>> {
+        if is_empty_query(query) {
+            return Ok(None);
+        }
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner.plan_sql(query).await?;
+        if !plan.has_result_set() {
+            return Ok(None);
+        }
+        row_description(&plan.schema(), result_formats).map(Some)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    #[async_backtrace::framed]
+    async fn do_query(&mut self, query: &str) -> Result<RunningQuery> {
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let blocks = Self::exec_query(interpreter.clone(), &context).await?;
+                Ok(RunningQuery {
+                    query: query.to_string(),
+                    context,
+                    schema: interpreter.schema(),
+                    has_result_set,
+                    blocks,
+                    pending: None,
+                    sent_rows: 0,
+                })
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(interpreter, context))]
+    #[async_backtrace::framed]
+    async fn exec_query(
+        interpreter: Arc<dyn Interpreter>,
+        context: &Arc<QueryContext>,
+    ) -> Result<SendableDataBlockStream> {
+        let query_result = context.try_spawn({
+            let ctx = context.clone();
+            async move { interpreter.execute(ctx.clone()).await }.in_current_span()
+        })?;
+
+        query_result.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?
+    }
+
+    /// Send the rows of the query, at most `max_rows` rows are sent if it is not zero.
+    #[async_backtrace::framed]
+    async fn send_rows(
+        &mut self,
+        running: &mut RunningQuery,
+        result_formats: &[i16],
+        max_rows: usize,
+    ) -> Result<ExecuteStatus> {
+        let format = running.context.get_format_settings()?;
+        let encoder = PgValueEncoder::create(format.timezone);
+        let mut values = Vec::with_capacity(running.schema.num_fields());
+        let mut sent = 0;
+
+        loop {
+            let (columns, next_row) = match running.pending.take() {
+                Some(pending) => pending,
+                None => match running.blocks.next().await {
+                    None => break,
+                    Some(block) => {
+                        let block = block?;
+                        let columns = block
+                            .convert_to_full()
+                            .columns()
+                            .iter()
+                            .map(|column| column.value.clone().into_column().unwrap())
+                            .collect::<Vec<_>>();
+                        (columns, 0)
+                    }
+                },
+            };
+
+            // Statements without result set may still return blocks, e.g. the progress.
+            if !running.has_result_set {
+                continue;
+            }
+
+            let num_rows = columns.first().map(|c| c.len()).unwrap_or(0);
+            for row_index in next_row..num_rows {
+                if max_rows > 0 && sent == max_rows {
+                    running.pending = Some((columns, row_index));
+                    return Ok(ExecuteStatus::Suspended);
+                }
+
+                values.clear();
+                for (col_index, column) in columns.iter().enumerate() {
+                    let format = result_format(result_formats, col_index);
+                    values.push(encoder.encode(column, row_index, format));
+                }
+                self.writer.write(BackendMessage::DataRow(&values));
+                sent += 1;
+
+                if self.writer.buffered_len() > FLUSH_THRESHOLD {
+                    self.writer.flush().await?;
+                }
+            }
+        }
+
+        running.sent_rows += sent;
+        let tag = if running.has_result_set {
+            format!("SELECT {}", running.sent_rows)
+        } else {
+            let affected_rows = running.context.get_write_progress_value().rows;
+            command_tag(&running.query, affected_rows)
+        };
+        Ok(ExecuteStatus::Completed(tag))
+    }
+
+    fn write_ready_for_query(&mut self) {
+        let status = match self.session.txn_mgr().lock().state() {
+            TxnState::AutoCommit => b'I',
+            TxnState::Active => b'T',
+            TxnState::Fail => b'E',
+        };
+        self.writer.write(BackendMessage::ReadyForQuery(status));
+    }
+
+    fn write_error(&mut self, severity: &str, error: &ErrorCode) {
+        if error.code() != ErrorCode::ABORTED_QUERY && error.code() != ErrorCode::ABORTED_SESSION {
+            error!("OnQuery Error: {:?}", error);
+        }
+        let message = format!("Code: {}, {}", error.code(), error.message());
+        self.writer.write(BackendMessage::ErrorResponse {
+            severity,
+            code: sql_state(error.code()),
+            message: &message,
+        });
+    }
+}
+
+fn row_description(
+    schema: &DataSchemaRef,
+    result_formats: &[i16],
+) -> Result<Vec<FieldDescription>> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| field_description(field, result_format(result_formats, i)))
+        .collect()
+}
+
+fn result_format(result_formats: &[i16], col_index: usize) -> i16 {
+    match result_formats.len() {
+        0 => FORMAT_TEXT,
+        1 => result_formats[0],
+        _ => result_formats
+            .get(col_index)
+            .cloned()
+            .unwrap_or(FORMAT_TEXT),
+    }
+}
+
+fn is_empty_query(query: &str) -> bool {
+    query
+        .trim_matches(|c: char| c.is_whitespace() || c == ';')
+        .is_empty()
+}
+
+/// The tag of `CommandComplete` for the statements without result set.
+fn command_tag(query: &str, affected_rows: usize) -> String {
+    let mut words = query.split_whitespace().map(|w| w.to_ascii_uppercase());
+    let first = words.next().unwrap_or_default();
+    match first.as_str() {
+        "INSERT" => format!("INSERT 0 {}", affected_rows),
+        "UPDATE" | "DELETE" | "COPY" | "MERGE" => format!("{} {}", first, affected_rows),
+        "CREATE" | "DROP" | "ALTER" => match words.next() {
+            Some(second) => format!("{} {}", first, second),
+            None => first,
+        },
+        _ => first,
+    }
+}
+
+fn sql_state(code: u16) -> &'static str {
+    match code {
+        ErrorCode::AUTHENTICATE_FAILURE | ErrorCode::UNKNOWN_USER => "28P01",
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::SEMANTIC_ERROR => "42000",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::UNIMPLEMENTED => "0A000",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::ABORTED_SESSION => "57P01",
+        ErrorCode::TOO_MANY_USER_CONNECTIONS => "53300",
+        ErrorCode::CURRENT_TRANSACTION_IS_ABORTED => "25P02",
+        ErrorCode::BAD_BYTES => "08P01",
+        _ => "XX000",
+    }
+}
+
+fn count_params(query: &str) -> usize {
+    let mut count = 0;
+    for_each_placeholder(query, |_, placeholder| {
+        if let Placeholder::Numbered(n) = placeholder {
+            count = count.max(n);
+        }
+    });
+    count
+}
+
+fn replace_params(query: &str, literals: &[String]) -> String {
+    replace_placeholders(query, |_, placeholder| match placeholder {
+        Placeholder::Numbered(n) => literals.get(n - 1).cloned(),
+        Placeholder::QuestionMark => None,
+    })
+}
@@ -0,0 +1,481 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! See <https://www.postgresql.org/docs/current/protocol-message-formats.html>.
+
+use std::collections::HashMap;
+
+use byteorder::BigEndian;
+use byteorder::WriteBytesExt;
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// Same as the limit of the postgres server, larger messages are rejected.
+const MAX_MESSAGE_LENGTH: usize = 1024 * 1024 * 1024;
+const MAX_STARTUP_LENGTH: usize = 10000;
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+/// The first message sent by the client, it has no type byte.
+#[derive(Debug)]
+pub enum StartupMessage {
+    Startup { params: HashMap<String, String> },
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+}
+
+#[derive(Debug)]
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    /// `PasswordMessage`, `SASLInitialResponse` and `SASLResponse` share the `p` type byte,
+    /// the body is interpreted by the authentication exchange.
+    Password(Vec<u8>),
+    CopyFail,
+}
+
+#[derive(Debug)]
+pub enum BackendMessage<'a> {
+    AuthenticationOk,
+    AuthenticationCleartextPassword,
+    AuthenticationSasl(&'a [&'a str]),
+    AuthenticationSaslContinue(&'a [u8]),
+    AuthenticationSaslFinal(&'a [u8]),
+    ParameterStatus(&'a str, &'a str),
+    BackendKeyData {
+        process_id: i32,
+        secret_key: i32,
+    },
+    ReadyForQuery(u8),
+    RowDescription(&'a [FieldDescription]),
+    DataRow(&'a [Option<Vec<u8>>]),
+    CommandComplete(&'a str),
+    EmptyQueryResponse,
+    ParseComplete,
+    BindComplete,
+    CloseComplete,
+    NoData,
+    PortalSuspended,
+    ParameterDescription(&'a [u32]),
+    ErrorResponse {
+        severity: &'a str,
+        code: &'a str,
+        message: &'a str,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+impl StartupMessage {
+    #[async_backtrace::framed]
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+        let len = reader.read_i32().await? as usize;
+        if !(8..=MAX_STARTUP_LENGTH).contains(&len) {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid length of startup packet: {}",
+                len
+            )));
+        }
+
+        let mut body = vec![0; len - 4];
+        reader.read_exact(&mut body).await?;
+        let mut buf = MessageBuf::new(&body);
+        match buf.read_i32()? {
+            SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+            GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+            CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+            PROTOCOL_VERSION_3 => {
+                let mut params = HashMap::new();
+                loop {
+                    let key = buf.read_cstr()?;
+                    if key.is_empty() {
+                        break;
+                    }
+                    params.insert(key, buf.read_cstr()?);
+                }
+                Ok(StartupMessage::Startup { params })
+            }
+            version => Err(ErrorCode::BadBytes(format!(
+                "unsupported frontend protocol {}.{}",
+                version >> 16,
+                version & 0xffff
+            ))),
+        }
+    }
+}
+
+impl FrontendMessage {
+    /// Read the next message, returns `None` if the client closed the connection.
+    #[async_backtrace::framed]
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+        let tag = match reader.read_u8().await {
+            Ok(tag) => tag,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let len = reader.read_i32().await? as usize;
+        if !(4..=MAX_MESSAGE_LENGTH).contains(&len) {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid message length {} of message type '{}'",
+                len, tag as char
+            )));
+        }
+
+        let mut body = vec![0; len - 4];
+        reader.read_exact(&mut body).await?;
+        Self::decode(tag, &body).map(Some)
+    }
+
+    fn decode(tag: u8, body: &[u8]) -> Result<FrontendMessage> {
+        let mut buf = MessageBuf::new(body);
+        let message = match tag {
+            b'Q' => FrontendMessage::Query(buf.read_cstr()?),
+            b'P' => {
+                let name = buf.read_cstr()?;
+                let query = buf.read_cstr()?;
+                let num_params = buf.read_i16()?;
+                let param_types = (0..num_params)
+                    .map(|_| buf.read_i32().map(|oid| oid as u32))
+                    .collect::<Result<_>>()?;
+                FrontendMessage::Parse {
+                    name,
+                    query,
+                    param_types,
+                }
+            }
+            b'B' => {
+                let portal = buf.read_cstr()?;
+                let statement = buf.read_cstr()?;
+                let num_formats = buf.read_i16()?;
+                let param_formats = (0..num_formats)
+                    .map(|_| buf.read_i16())
+                    .collect::<Result<_>>()?;
+                let num_params = buf.read_i16()?;
+                let params = (0..num_params)
+                    .map(|_| {
+                        let len = buf.read_i32()?;
+                        match len {
+                            -1 => Ok(None),
+                            len if len < 0 => Err(ErrorCode::BadBytes(format!(
+                                "invalid parameter length {}",
+                                len
+                            ))),
+                            len => buf.read_bytes(len as usize).map(|v| Some(v.to_vec())),
+                        }
+                    })
+                    .collect::<Result<_>>()?;
+                let num_formats = buf.read_i16()?;
+                let result_formats = (0..num_formats)
+                    .map(|_| buf.read_i16())
+                    .collect::<Result<_>>()?;
+                FrontendMessage::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                }
+            }
+            b'D' => FrontendMessage::Describe {
+                kind: buf.read_u8()?,
+                name: buf.read_cstr()?,
+            },
+            b'E' => FrontendMessage::Execute {
+                portal: buf.read_cstr()?,
+                max_rows: buf.read_i32()?,
+            },
+            b'C' => FrontendMessage::Close {
+                kind: buf.read_u8()?,
+                name: buf.read_cstr()?,
+            },
+            b'S' => FrontendMessage::Sync,
+            b'H' => FrontendMessage::Flush,
+            b'X' => FrontendMessage::Terminate,
+            b'p' => FrontendMessage::Password(body.to_vec()),
+            b'f' => FrontendMessage::CopyFail,
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid frontend message type '{}'",
+                    tag as char
+                )));
+            }
+        };
+        Ok(message)
+    }
+}
+
+impl<'a> BackendMessage<'a> {
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        let (tag, start) = (self.tag(), out.len());
+        out.push(tag);
+        // Placeholder of the length, filled after the body is written.
+        out.extend_from_slice(&[0; 4]);
+
+        match self {
+            BackendMessage::AuthenticationOk => put_i32(out, 0),
+            BackendMessage::AuthenticationCleartextPassword => put_i32(out, 3),
+            BackendMessage::AuthenticationSasl(mechanisms) => {
+                put_i32(out, 10);
+                for mechanism in mechanisms.iter() {
+                    put_cstr(out, mechanism);
+                }
+                out.push(0);
+            }
+            BackendMessage::AuthenticationSaslContinue(data) => {
+                put_i32(out, 11);
+                out.extend_from_slice(data);
+            }
+            BackendMessage::AuthenticationSaslFinal(data) => {
+                put_i32(out, 12);
+                out.extend_from_slice(data);
+            }
+            BackendMessage::ParameterStatus(name, value) => {
+                put_cstr(out, name);
+                put_cstr(out, value);
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                put_i32(out, *process_id);
+                put_i32(out, *secret_key);
+            }
+            BackendMessage::ReadyForQuery(status) => out.push(*status),
+            BackendMessage::RowDescription(fields) => {
+                put_i16(out, fields.len() as i16);
+                for field in fields.iter() {
+                    put_cstr(out, &field.name);
+                    // table oid and column attribute number
+                    put_i32(out, 0);
+                    put_i16(out, 0);
+                    put_i32(out, field.type_oid as i32);
+                    put_i16(out, field.type_size);
+                    // type modifier
+                    put_i32(out, -1);
+                    put_i16(out, field.format);
+                }
+            }
+            BackendMessage::DataRow(values) => {
+                put_i16(out, values.len() as i16);
+                for value in values.iter() {
+                    match value {
+                        None => put_i32(out, -1),
+                        Some(v) => {
+                            put_i32(out, v.len() as i32);
+                            out.extend_from_slice(v);
+                        }
+                    }
+                }
+            }
+            BackendMessage::CommandComplete(tag) => put_cstr(out, tag),
+            BackendMessage::ParameterDescription(types) => {
+                put_i16(out, types.len() as i16);
+                for oid in types.iter() {
+                    put_i32(out, *oid as i32);
+                }
+            }
+            BackendMessage::ErrorResponse {
+                severity,
+                code,
+                message,
+            } => {
+                out.push(b'S');
+                put_cstr(out, severity);
+                // Non-localized severity, since protocol 9.6.
+                out.push(b'V');
+                put_cstr(out, severity);
+                out.push(b'C');
+                put_cstr(out, code);
+                out.push(b'M');
+                put_cstr(out, message);
+                out.push(0);
+            }
+            BackendMessage::EmptyQueryResponse
+            | BackendMessage::ParseComplete
+            | BackendMessage::BindComplete
+            | BackendMessage::CloseComplete
+            | BackendMessage::NoData
+            | BackendMessage::PortalSuspended => {}
+        }
+
+        let len = (out.len() - start - 1) as i32;
+        out[start + 1..start + 5].copy_from_slice(&len.to_be_bytes());
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            BackendMessage::AuthenticationOk
+            | BackendMessage::AuthenticationCleartextPassword
+            | BackendMessage::AuthenticationSasl(_)
+            | BackendMessage::AuthenticationSaslContinue(_)
+            | BackendMessage::AuthenticationSaslFinal(_) => b'R',
+            BackendMessage::ParameterStatus(_, _) => b'S',
+            BackendMessage::BackendKeyData { .. } => b'K',
+            BackendMessage::ReadyForQuery(_) => b'Z',
+            BackendMessage::RowDescription(_) => b'T',
+            BackendMessage::DataRow(_) => b'D',
+            BackendMessage::CommandComplete(_) => b'C',
+            BackendMessage::EmptyQueryResponse => b'I',
+            BackendMessage::ParseComplete => b'1',
+            BackendMessage::BindComplete => b'2',
+            BackendMessage::CloseComplete => b'3',
+            BackendMessage::NoData => b'n',
+            BackendMessage::PortalSuspended => b's',
+            BackendMessage::ParameterDescription(_) => b't',
+            BackendMessage::ErrorResponse { .. } => b'E',
+        }
+    }
+}
+
+/// Buffers the backend messages and writes them to the client on `flush`.
+pub struct MessageWriter<W: AsyncWrite + Unpin> {
+    writer: W,
+    buf: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> MessageWriter<W> {
+    pub fn new(writer: W) -> Self {
+        MessageWriter {
+            writer,
+            buf: Vec::with_capacity(8 * 1024),
+        }
+    }
+
+    pub fn write(&mut self, message: BackendMessage<'_>) {
+        message.encode(&mut self.buf);
+    }
+
+    /// Write a byte without message framing, used to answer `SSLRequest`.
+    pub fn write_raw(&mut self, byte: u8) {
+        self.buf.push(byte);
+    }
+
+    pub fn buffered_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn flush(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.writer.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        self.writer.flush().await?;
+        Ok(())
+    }
+}
+
+pub struct MessageBuf<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> MessageBuf<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        MessageBuf { data, pos: 0 }
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16> {
+        let bytes = self.read_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() - self.pos < len {
+            return Err(ErrorCode::BadBytes("unexpected end of message"));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub fn read_cstr(&mut self) -> Result<String> {
+        let rest = &self.data[self.pos..];
+        match rest.iter().position(|b| *b == 0) {
+            None => Err(ErrorCode::BadBytes("unterminated string in message")),
+            Some(end) => {
+                self.pos += end + 1;
+                String::from_utf8(rest[..end].to_vec())
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 string: {}", e)))
+            }
+        }
+    }
+}
+
+fn put_i16(out: &mut Vec<u8>, v: i16) {
+    out.write_i16::<BigEndian>(v).unwrap();
+}
+
+fn put_i32(out: &mut Vec<u8>, v: i32) {
+    out.write_i32::<BigEndian>(v).unwrap();
+}
+
+fn put_cstr(out: &mut Vec<u8>, s: &str) {
+    out.extend_from_slice(s.as_bytes());
+    out.push(0);
+}
@@ -0,0 +1,380 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::DataField;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::parameters::float_literal;
+use crate::servers::parameters::number_literal;
+use crate::servers::parameters::quote_string;
+use crate::servers::postgres::postgres_message::FieldDescription;
+use crate::servers::postgres::postgres_message::MessageBuf;
+use crate::servers::postgres::postgres_message::FORMAT_BINARY;
+use crate::servers::postgres::postgres_message::FORMAT_TEXT;
+
+/// Type OIDs from `pg_type.dat` of postgres.
+pub mod oid {
+    pub const BOOL: u32 = 16;
+    pub const BYTEA: u32 = 17;
+    pub const INT8: u32 = 20;
+    pub const INT2: u32 = 21;
+    pub const INT4: u32 = 23;
+    pub const TEXT: u32 = 25;
+    pub const OID: u32 = 26;
+    pub const JSON: u32 = 114;
+    pub const FLOAT4: u32 = 700;
+    pub const FLOAT8: u32 = 701;
+    pub const UNKNOWN: u32 = 705;
+    pub const VARCHAR: u32 = 1043;
+    pub const DATE: u32 = 1082;
+    pub const TIMESTAMP: u32 = 1114;
+    pub const TIMESTAMPTZ: u32 = 1184;
+    pub const INTERVAL: u32 = 1186;
+    pub const NUMERIC: u32 = 1700;
+}
+
+// Postgres counts dates and timestamps from 2000-01-01.
+const PG_EPOCH_DAYS: i32 = 10957;
+const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+const NUMERIC_POS: i16 = 0x0000;
+const NUMERIC_NEG: i16 = 0x4000;
+const NUMERIC_NBASE: i16 = 10000;
+
+/// Map the result column to a postgres type, the unsigned integers are widened
+/// since postgres has no unsigned types.
+pub fn field_description(field: &DataField, format: i16) -> Result<FieldDescription> {
+    let (type_oid, type_size) = match field.data_type().remove_nullable() {
+        DataType::Null => (oid::TEXT, -1),
+        DataType::EmptyArray | DataType::EmptyMap => (oid::TEXT, -1),
+        DataType::Boolean => (oid::BOOL, 1),
+        DataType::String => (oid::TEXT, -1),
+        DataType::Binary => (oid::BYTEA, -1),
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => (oid::INT2, 2),
+            NumberDataType::UInt16 | NumberDataType::Int32 => (oid::INT4, 4),
+            NumberDataType::UInt32 | NumberDataType::Int64 => (oid::INT8, 8),
+            NumberDataType::UInt64 => (oid::NUMERIC, -1),
+            NumberDataType::Float32 => (oid::FLOAT4, 4),
+            NumberDataType::Float64 => (oid::FLOAT8, 8),
+        },
+        DataType::Decimal(_) => (oid::NUMERIC, -1),
+        DataType::Date => (oid::DATE, 4),
+        DataType::Timestamp => (oid::TIMESTAMP, 8),
+        DataType::TimestampTz => (oid::TIMESTAMPTZ, 8),
+        DataType::Interval => (oid::INTERVAL, 16),
+        DataType::Variant => (oid::JSON, -1),
+        DataType::Array(_)
+        | DataType::Map(_)
+        | DataType::Tuple(_)
+        | DataType::Bitmap
+        | DataType::Geometry => (oid::TEXT, -1),
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Unsupported column type:{:?}",
+                field.data_type()
+            )));
+        }
+    };
+
+    Ok(FieldDescription {
+        name: field.name().to_string(),
+        type_oid,
+        type_size,
+        format,
+    })
+}
+
+/// Encodes the values of result columns in the text or binary format of postgres.
+pub struct PgValueEncoder {
+    text_encoder: FieldEncoderValues,
+}
+
+impl PgValueEncoder {
+    pub fn create(timezone: Tz) -> Self {
+        PgValueEncoder {
+            text_encoder: FieldEncoderValues::create_for_postgres_handler(timezone),
+        }
+    }
+
+    pub fn encode(&self, column: &Column, row_index: usize, format: i16) -> Option<Vec<u8>> {
+        let value = unsafe { column.index_unchecked(row_index) };
+        if let ScalarRef::Null = value {
+            return None;
+        }
+
+        if format == FORMAT_BINARY {
+            let bytes = match value {
+                ScalarRef::Boolean(v) => vec![v as u8],
+                ScalarRef::Binary(v) => v.to_vec(),
+                ScalarRef::Number(number) => match number {
+                    NumberScalar::Int8(v) => (v as i16).to_be_bytes().to_vec(),
+                    NumberScalar::UInt8(v) => (v as i16).to_be_bytes().to_vec(),
+                    NumberScalar::Int16(v) => v.to_be_bytes().to_vec(),
+                    NumberScalar::UInt16(v) => (v as i32).to_be_bytes().to_vec(),
+                    NumberScalar::Int32(v) => v.to_be_bytes().to_vec(),
+                    NumberScalar::UInt32(v) => (v as i64).to_be_bytes().to_vec(),
+                    NumberScalar::Int64(v) => v.to_be_bytes().to_vec(),
+                    NumberScalar::UInt64(v) => encode_numeric(&v.to_string()),
+                    NumberScalar::Float32(v) => v.0.to_be_bytes().to_vec(),
+                    NumberScalar::Float64(v) => v.0.to_be_bytes().to_vec(),
+                },
+                ScalarRef::Decimal(_) => encode_numeric(&self.encode_text(column, row_index)),
+                ScalarRef::Date(v) => (v - PG_EPOCH_DAYS).to_be_bytes().to_vec(),
+                ScalarRef::Timestamp(v) => (v - PG_EPOCH_MICROS).to_be_bytes().to_vec(),
+                ScalarRef::TimestampTz(v) => (v.micros() - PG_EPOCH_MICROS).to_be_bytes().to_vec(),
+                ScalarRef::Interval(v) => {
+                    let mut bytes = Vec::with_capacity(16);
+                    bytes.extend_from_slice(&v.micros().to_be_bytes());
+                    bytes.extend_from_slice(&v.days().to_be_bytes());
+                    bytes.extend_from_slice(&v.months().to_be_bytes());
+                    bytes
+                }
+                // The binary format of text and json is the same as the text format.
+                _ => self.encode_text(column, row_index),
+            };
+            return Some(bytes);
+        }
+
+        // The text format of bytea is the hex format, e.g. `\xdeadbeef`.
+        if let ScalarRef::Binary(v) = value {
+            return Some(format!("\\x{}", hex::encode(v)).into_bytes());
+        }
+
+        Some(self.encode_text(column, row_index))
+    }
+
+    fn encode_text(&self, column: &Column, row_index: usize) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.text_encoder
+            .write_field(column, row_index, &mut buf, true);
+        buf
+    }
+}
+
+/// Convert a bound parameter into a SQL literal, which replaces the `$n` placeholder.
+pub fn param_to_literal(value: Option<&[u8]>, format: i16, type_oid: u32) -> Result<String> {
+    let value = match value {
+        None => return Ok("NULL".to_string()),
+        Some(value) => value,
+    };
+
+    if format == FORMAT_TEXT {
+        let text = std::str::from_utf8(value)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 parameter: {}", e)))?;
+        return Ok(match type_oid {
+            oid::INT2
+            | oid::INT4
+            | oid::INT8
+            | oid::OID
+            | oid::FLOAT4
+            | oid::FLOAT8
+            | oid::NUMERIC
+                if text.parse::<f64>().map_or(false, |v| v.is_finite()) =>
+            {
+                number_literal(text.trim())
+            }
+            oid::BOOL => match text.to_ascii_lowercase().as_str() {
+                "t" | "true" | "y" | "yes" | "on" | "1" => "TRUE".to_string(),
+                "f" | "false" | "n" | "no" | "off" | "0" => "FALSE".to_string(),
+                _ => quote_string(text),
+            },
+            _ => quote_string(text),
+        });
+    }
+
+    let invalid = || {
+        ErrorCode::BadBytes(format!(
+            "invalid binary parameter of type oid {}, length {}",
+            type_oid,
+            value.len()
+        ))
+    };
+    let literal = match type_oid {
+        oid::BOOL => match value {
+            [v] => (if *v != 0 { "TRUE" } else { "FALSE" }).to_string(),
+            _ => return Err(invalid()),
+        },
+        oid::INT2 => number_literal(
+            &i16::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+        ),
+        oid::INT4 => number_literal(
+            &i32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+        ),
+        oid::OID => number_literal(
+            &u32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+        ),
+        oid::INT8 => number_literal(
+            &i64::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+        ),
+        oid::FLOAT4 => {
+            float_literal(f32::from_be_bytes(value.try_into().map_err(|_| invalid())?) as f64)
+        }
+        oid::FLOAT8 => float_literal(f64::from_be_bytes(value.try_into().map_err(|_| invalid())?)),
+        oid::NUMERIC => number_literal(&decode_numeric(value)?),
+        oid::DATE => {
+            let days = i32::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+            let date = pg_epoch()
+                .checked_add_signed(Duration::days(days as i64))
+                .ok_or_else(invalid)?;
+            quote_string(&date.format("%Y-%m-%d").to_string())
+        }
+        oid::TIMESTAMP => {
+            let micros = i64::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+            let ts = pg_epoch()
+                .and_hms_opt(0, 0, 0)
+                .and_then(|ts| ts.checked_add_signed(Duration::microseconds(micros)))
+                .ok_or_else(invalid)?;
+            quote_string(&ts.format("%Y-%m-%d %H:%M:%S%.6f").to_string())
+        }
+        oid::TIMESTAMPTZ => {
+            let micros = i64::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+            let ts = pg_epoch()
+                .and_hms_opt(0, 0, 0)
+                .and_then(|ts| ts.checked_add_signed(Duration::microseconds(micros)))
+                .ok_or_else(invalid)?;
+            quote_string(&ts.format("%Y-%m-%d %H:%M:%S%.6f+00:00").to_string())
+        }
+        oid::BYTEA => format!("from_hex('{}')", hex::encode(value)),
+        oid::TEXT | oid::VARCHAR | oid::JSON | oid::UNKNOWN => {
+            let text = std::str::from_utf8(value)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 parameter: {}", e)))?;
+            quote_string(text)
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Unsupported binary parameter of type oid {}",
+                type_oid
+            )));
+        }
+    };
+    Ok(literal)
+}
+
+fn pg_epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()
+}
+
+/// Encode a decimal string like `-123.4500` into the binary format of `numeric`:
+/// `ndigits, weight, sign, dscale` followed by the base-10000 digits.
+pub fn encode_numeric(text: &str) -> Vec<u8> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((text, ""));
+    let int_part = int_part.trim_start_matches('0');
+
+    let mut digits = Vec::new();
+    let int_pad = (4 - int_part.len() % 4) % 4;
+    let padded_int = "0".repeat(int_pad) + int_part;
+    for chunk in padded_int.as_bytes().chunks(4) {
+        digits.push(std::str::from_utf8(chunk).unwrap().parse::<i16>().unwrap());
+    }
+    let mut weight = digits.len() as i16 - 1;
+    let mut padded_frac = frac_part.to_string();
+    padded_frac.push_str(&"0".repeat((4 - frac_part.len() % 4) % 4));
+    for chunk in padded_frac.as_bytes().chunks(4) {
+        digits.push(std::str::from_utf8(chunk).unwrap().parse::<i16>().unwrap());
+    }
+
+    let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..leading_zeros);
+    weight -= leading_zeros as i16;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    let sign = if negative && !digits.is_empty() {
+        NUMERIC_NEG
+    } else {
+        NUMERIC_POS
+    };
+    let mut out = Vec::with_capacity(8 + digits.len() * 2);
+    out.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    out.extend_from_slice(&weight.to_be_bytes());
+    out.extend_from_slice(&sign.to_be_bytes());
+    out.extend_from_slice(&(frac_part.len() as i16).to_be_bytes());
+    for digit in digits {
+        out.extend_from_slice(&digit.to_be_bytes());
+    }
+    out
+}
+
+/// Decode the binary format of `numeric` into a decimal string.
+pub fn decode_numeric(value: &[u8]) -> Result<String> {
+    let mut buf = MessageBuf::new(value);
+    let ndigits = buf.read_i16()?;
+    let weight = buf.read_i16()? as i32;
+    let sign = buf.read_i16()?;
+    let dscale = buf.read_i16()?.max(0) as usize;
+    let digits = (0..ndigits)
+        .map(|_| buf.read_i16())
+        .collect::<Result<Vec<_>>>()?;
+    if sign != NUMERIC_POS && sign != NUMERIC_NEG {
+        return Err(ErrorCode::BadBytes(
+            "NaN or infinite numeric is not supported",
+        ));
+    }
+    if digits.iter().any(|d| !(0..NUMERIC_NBASE).contains(d)) {
+        return Err(ErrorCode::BadBytes("invalid digit in numeric"));
+    }
+
+    let digit_at = |i: i32| -> i16 {
+        if i >= 0 && (i as usize) < digits.len() {
+            digits[i as usize]
+        } else {
+            0
+        }
+    };
+
+    let mut s = String::new();
+    if sign == NUMERIC_NEG {
+        s.push('-');
+    }
+    if weight < 0 {
+        s.push('0');
+    } else {
+        s.push_str(&digit_at(0).to_string());
+        for i in 1..=weight {
+            s.push_str(&format!("{:04}", digit_at(i)));
+        }
+    }
+    if dscale > 0 {
+        let mut frac = String::new();
+        let mut i = weight + 1;
+        while frac.len() < dscale {
+            frac.push_str(&format!("{:04}", digit_at(i)));
+            i += 1;
+        }
+        frac.truncate(dscale);
+        s.push('.');
+        s.push_str(&frac);
+    }
+    Ok(s)
+}
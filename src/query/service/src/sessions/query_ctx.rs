@@ -29,6 +29,7 @@ use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_base::runtime::TrySpawn;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
@@ -36,6 +37,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -51,6 +53,7 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -69,6 +72,7 @@ use tracing::debug;
 
 use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
+use crate::catalogs::QuerySequenceValues;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
@@ -163,6 +167,21 @@ impl QueryContext {
         Ok(())
     }
 
+    /// The values of the sequences are shared by the function contexts of the query.
+    fn get_sequence_values(&self) -> Result<Arc<QuerySequenceValues>> {
+        let mut sequence_values = self.shared.sequence_values.write();
+        if let Some(values) = sequence_values.as_ref() {
+            return Ok(values.clone());
+        }
+        let values = Arc::new(QuerySequenceValues::create(
+            self.get_tenant(),
+            self.get_catalog(CATALOG_DEFAULT)?,
+            self.get_settings().get_sequence_cache_size()?,
+        ));
+        *sequence_values = Some(values.clone());
+        Ok(values)
+    }
+
     pub fn get_exchange_manager(&self) -> Arc<DataExchangeManager> {
         DataExchangeManager::instance()
     }
@@ -200,6 +219,10 @@ impl QueryContext {
         self.shared.get_data_metrics()
     }
 
+    pub fn replace_table(&self, catalog: &str, database: &str, table: Arc<dyn Table>) {
+        self.shared.replace_table(catalog, database, table)
+    }
+
     pub fn set_affect(self: &Arc<Self>, affect: QueryAffect) {
         self.shared.set_affect(affect)
     }
@@ -414,6 +437,8 @@ impl TableContext for QueryContext {
 
         let query_config = &GlobalConfig::instance().query;
 
+        let sequence_values = self.get_sequence_values()?;
+
         Ok(FunctionContext {
             tz,
 
@@ -421,6 +446,8 @@ impl TableContext for QueryContext {
             openai_api_base_url: query_config.openai_api_base_url.clone(),
             openai_api_embedding_model: query_config.openai_api_embedding_model.clone(),
             openai_api_completion_model: query_config.openai_api_completion_model.clone(),
+
+            sequence_values: Some(sequence_values),
         })
     }
 
@@ -525,6 +552,10 @@ impl TableContext for QueryContext {
         self.query_settings.get_changes()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
     // Get the storage data accessor operator from the session manager.
     fn get_data_operator(&self) -> Result<DataOperator> {
         Ok(self.shared.data_operator.clone())
@@ -538,6 +569,14 @@ impl TableContext for QueryContext {
         self.shared.consume_precommit_blocks()
     }
 
+    fn add_stream_offset(&self, req: UpdateTableMetaReq) {
+        self.shared.add_stream_offset(req)
+    }
+
+    fn get_stream_offsets(&self) -> Vec<UpdateTableMetaReq> {
+        self.shared.get_stream_offsets()
+    }
+
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
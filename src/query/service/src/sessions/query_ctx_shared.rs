@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -29,6 +30,8 @@ use common_expression::DataBlock;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MetaId;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -40,6 +43,7 @@ use parking_lot::RwLock;
 use uuid::Uuid;
 
 use crate::catalogs::CatalogManager;
+use crate::catalogs::QuerySequenceValues;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
@@ -80,6 +84,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
     pub(in crate::sessions) precommit_blocks: Arc<RwLock<Vec<DataBlock>>>,
+    /// The new offsets of the streams read by the query, keyed by the table id of the stream.
+    pub(in crate::sessions) stream_offsets: Arc<RwLock<BTreeMap<MetaId, UpdateTableMetaReq>>>,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
     // DashMap<file_path, HashMap<ErrorCode::code, (ErrorCode, Number of occurrences)>>
@@ -93,6 +99,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    /// The values of the sequences used by `nextval()`, created on the first use.
+    pub(in crate::sessions) sequence_values: Arc<RwLock<Option<Arc<QuerySequenceValues>>>>,
 }
 
 impl QueryContextShared {
@@ -119,6 +127,7 @@ impl QueryContextShared {
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             precommit_blocks: Arc::new(RwLock::new(vec![])),
+            stream_offsets: Arc::new(RwLock::new(BTreeMap::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
@@ -126,6 +135,7 @@ impl QueryContextShared {
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
+            sequence_values: Arc::new(RwLock::new(None)),
         }))
     }
 
@@ -263,6 +273,17 @@ impl QueryContextShared {
         }
     }
 
+    /// Replace the table cached in this query, the later accesses of the table
+    /// in this query will get the given table.
+    pub fn replace_table(&self, catalog: &str, database: &str, table: Arc<dyn Table>) {
+        let table_meta_key = (
+            catalog.to_string(),
+            database.to_string(),
+            table.name().to_string(),
+        );
+        self.tables_refs.lock().insert(table_meta_key, table);
+    }
+
     #[async_backtrace::framed]
     async fn get_table_to_cache(
         &self,
@@ -272,8 +293,28 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
-        let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let catalog_name = catalog;
+        let catalog = self.catalog_manager.get_catalog(catalog_name)?;
+        let txn_mgr = self.session.txn_mgr();
+        let cache_table = if txn_mgr.lock().is_active() {
+            // The tables are read at the same snapshot in a transaction.
+            let table_info = txn_mgr.lock().get_table_info(catalog_name, database, table);
+            match table_info {
+                Some(table_info) => catalog.get_table_by_info(&table_info)?,
+                None => {
+                    let tbl = catalog.get_table(tenant.as_str(), database, table).await?;
+                    txn_mgr.lock().add_table_info(
+                        catalog_name,
+                        database,
+                        table,
+                        tbl.get_table_info().clone(),
+                    );
+                    tbl
+                }
+            }
+        } else {
+            catalog.get_table(tenant.as_str(), database, table).await?
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -372,6 +413,15 @@ impl QueryContextShared {
         swapped_precommit_blocks
     }
 
+    pub fn add_stream_offset(&self, req: UpdateTableMetaReq) {
+        let mut offsets = self.stream_offsets.write();
+        offsets.insert(req.table_id, req);
+    }
+
+    pub fn get_stream_offsets(&self) -> Vec<UpdateTableMetaReq> {
+        self.stream_offsets.read().values().cloned().collect()
+    }
+
     pub fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.stage_attachment.read().clone()
     }
@@ -18,6 +18,7 @@ use std::fmt;
 pub enum SessionType {
     Clickhouse,
     MySQL,
+    PostgreSQL,
     HTTPQuery,
     HTTPStreamingLoad,
     ClickHouseHttpHandler,
@@ -27,13 +28,14 @@ pub enum SessionType {
     Dummy,
     Fuzz,
     Local,
+    Task,
 }
 
 impl SessionType {
     pub fn is_user_session(&self) -> bool {
         !matches!(
             self,
-            SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz
+            SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz | SessionType::Task
         )
     }
 }
@@ -44,6 +46,7 @@ impl fmt::Display for SessionType {
             SessionType::ClickHouseHttpHandler => "ClickhouseHTTPHandler".to_string(),
             SessionType::Clickhouse => "Clickhouse".to_string(),
             SessionType::MySQL => "MySQL".to_string(),
+            SessionType::PostgreSQL => "PostgreSQL".to_string(),
             SessionType::HTTPQuery => "HTTPQuery".to_string(),
             SessionType::HTTPStreamingLoad => "HTTPStreamingLoad".to_string(),
             SessionType::Dummy => "Dummy".to_string(),
@@ -52,6 +55,7 @@ impl fmt::Display for SessionType {
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
             SessionType::Fuzz => "Fuzz".to_string(),
             SessionType::Local => "Local".to_string(),
+            SessionType::Task => "Task".to_string(),
         };
         write!(f, "{}", name)
     }
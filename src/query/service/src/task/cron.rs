@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::TimeZone;
+use chrono::Timelike;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// A cron schedule of 5 fields: `minute hour day-of-month month day-of-week`, in UTC.
+///
+/// A field is `*`, a value, a range `a-b`, a step `*/n`, `a/n` or `a-b/n`,
+/// or a comma separated list of them. Both 0 and 7 in day-of-week are Sunday.
+/// `@hourly`, `@daily`, `@weekly`, `@monthly` and `@yearly` are also accepted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<CronSchedule> {
+        let expanded = match expr.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            other => other,
+        };
+
+        let fields = expanded.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid cron expression '{}', expect 5 fields: minute hour day-of-month month day-of-week",
+                expr
+            )));
+        }
+
+        let mut days_of_week = parse_field(fields[4], 0, 7)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(CronSchedule {
+            minutes: parse_field(fields[0], 0, 59)?,
+            hours: parse_field(fields[1], 0, 23)?,
+            days_of_month: parse_field(fields[2], 1, 31)?,
+            months: parse_field(fields[3], 1, 12)?,
+            days_of_week,
+            any_day_of_month: fields[2] == "*",
+            any_day_of_week: fields[4] == "*",
+        })
+    }
+
+    /// Returns the first minute matching the schedule strictly after `after`.
+    ///
+    /// Returns `None` if there is no such minute in the next 5 years, e.g., for `0 0 30 2 *`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut t = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let end = t + Duration::days(366 * 5);
+
+        while t < end {
+            if !contains(self.months, t.month()) {
+                let (year, month) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()?;
+                continue;
+            }
+
+            if !self.matches_day(t.date_naive()) {
+                let next_day = t.date_naive().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                t = Utc.from_utc_datetime(&next_day);
+                continue;
+            }
+
+            if !contains(self.hours, t.hour()) {
+                t = t.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+
+            if !contains(self.minutes, t.minute()) {
+                t += Duration::minutes(1);
+                continue;
+            }
+
+            return Some(t);
+        }
+
+        None
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day_of_month = contains(self.days_of_month, date.day());
+        let day_of_week = contains(self.days_of_week, date.weekday().num_days_from_sunday());
+
+        // As the standard cron, if both day fields are restricted, a day matching either of them matches.
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (false, true) => day_of_month,
+            (true, false) => day_of_week,
+            (false, false) => day_of_month || day_of_week,
+        }
+    }
+}
+
+fn contains(bits: u64, v: u32) -> bool {
+    bits & (1 << v) != 0
+}
+
+/// Parse a cron field into a bit set of the values in `[min, max]`.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64> {
+    let invalid = || {
+        ErrorCode::BadArguments(format!(
+            "Invalid cron field '{}', expect values in [{}, {}]",
+            field, min, max
+        ))
+    };
+    let parse_value = |s: &str| s.parse::<u32>().map_err(|_| invalid());
+
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(parse_value(step)?)),
+            None => (part, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start)?, parse_value(end)?)
+        } else {
+            let v = parse_value(range)?;
+            // `a/n` is from `a` to the max value.
+            if step.is_some() { (v, max) } else { (v, v) }
+        };
+
+        let step = step.unwrap_or(1);
+        if start < min || end > max || start > end || step == 0 {
+            return Err(invalid());
+        }
+
+        for v in (start..=end).step_by(step as usize) {
+            bits |= 1 << v;
+        }
+    }
+
+    Ok(bits)
+}
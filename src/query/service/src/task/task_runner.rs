@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::Result;
+use common_meta_app::schema::CreateTaskRunReq;
+use common_meta_app::schema::ListTasksReq;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRun;
+use common_meta_app::schema::TaskRunState;
+use common_meta_app::schema::TaskState;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures_util::StreamExt;
+use tracing::info;
+use tracing::warn;
+
+use crate::clusters::ClusterHelper;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// How long a run is kept in the task history, in seconds.
+const TASK_RUN_HISTORY_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Runs the tasks of a tenant and records the runs in the task history.
+#[derive(Clone)]
+pub struct TaskRunner {
+    tenant: String,
+}
+
+impl TaskRunner {
+    pub fn create(tenant: impl Into<String>) -> TaskRunner {
+        TaskRunner {
+            tenant: tenant.into(),
+        }
+    }
+
+    pub fn tenant(&self) -> &str {
+        &self.tenant
+    }
+
+    /// Run a task once, as the owner of the task.
+    ///
+    /// A failure of the task query does not return an error, it is recorded in the returned run.
+    #[async_backtrace::framed]
+    pub async fn run(
+        &self,
+        task_name: &str,
+        meta: &TaskMeta,
+        trigger: String,
+        scheduled_on: DateTime<Utc>,
+    ) -> Result<TaskRun> {
+        let catalog = CatalogManager::instance().get_catalog(CATALOG_DEFAULT)?;
+        let session = SessionManager::instance()
+            .create_session(SessionType::Task)
+            .await?;
+        let ctx = session.create_query_context().await?;
+
+        let mut run = TaskRun {
+            task_name: task_name.to_string(),
+            query: meta.query.clone(),
+            state: TaskRunState::Executing,
+            trigger,
+            query_id: ctx.get_id(),
+            node_id: ctx.get_cluster().local_id(),
+            scheduled_on,
+            started_on: Utc::now(),
+            ..Default::default()
+        };
+
+        let reply = catalog
+            .create_task_run(CreateTaskRunReq {
+                tenant: self.tenant.clone(),
+                run: run.clone(),
+                expire_at: Self::history_expire_at(),
+            })
+            .await?;
+        run.run_id = reply.run_id;
+        info!(
+            "Task {} run {} started, trigger: {}",
+            task_name, run.run_id, run.trigger
+        );
+
+        let res = self.execute(session, ctx, meta).await;
+        run.completed_on = Some(Utc::now());
+        match res {
+            Ok(_) => {
+                run.state = TaskRunState::Succeeded;
+                info!("Task {} run {} succeeded", task_name, run.run_id);
+            }
+            Err(cause) => {
+                warn!("Task {} run {} failed: {}", task_name, run.run_id, cause);
+                run.state = TaskRunState::Failed;
+                run.error_code = cause.code() as u32;
+                run.error_message = Some(cause.message());
+            }
+        }
+
+        catalog
+            .update_task_run(UpdateTaskRunReq {
+                tenant: self.tenant.clone(),
+                run: run.clone(),
+                expire_at: Self::history_expire_at(),
+            })
+            .await?;
+        Ok(run)
+    }
+
+    /// Run the started tasks after a task in background, after a successful run of it.
+    pub fn spawn_successors(&self, task_name: String) {
+        let runner = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(cause) = runner.run_successors(&task_name).await {
+                warn!(
+                    "Failed to run the tasks after task {}: {}",
+                    task_name, cause
+                );
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn run_successors(&self, task_name: &str) -> Result<()> {
+        let catalog = CatalogManager::instance().get_catalog(CATALOG_DEFAULT)?;
+        let tasks = catalog.list_tasks(ListTasksReq::new(&self.tenant)).await?;
+
+        let now = Utc::now();
+        for (name, meta) in tasks {
+            if meta.state != TaskState::Started || !meta.after.iter().any(|t| t == task_name) {
+                continue;
+            }
+
+            let run = self
+                .run(&name, &meta, format!("AFTER {}", task_name), now)
+                .await?;
+            if run.state == TaskRunState::Succeeded {
+                self.spawn_successors(name);
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn execute(
+        &self,
+        session: Arc<Session>,
+        ctx: Arc<QueryContext>,
+        meta: &TaskMeta,
+    ) -> Result<()> {
+        let user = UserApiProvider::instance()
+            .get_user(&self.tenant, meta.owner.clone())
+            .await?;
+        session.set_authed_user(user, None).await?;
+
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(&meta.query).await?;
+        ctx.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    fn history_expire_at() -> u64 {
+        Utc::now().timestamp() as u64 + TASK_RUN_HISTORY_SECS
+    }
+}
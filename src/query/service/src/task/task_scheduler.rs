@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_app::schema::AcquireTaskLeaseReq;
+use common_meta_app::schema::ListTasksReq;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRunState;
+use common_meta_app::schema::TaskState;
+use parking_lot::Mutex;
+use tracing::info;
+use tracing::warn;
+
+use crate::task::CronSchedule;
+use crate::task::TaskRunner;
+
+/// How often the scheduler checks the scheduled tasks.
+const TICK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long the task lease lasts, the holder extends it on every tick.
+const TASK_LEASE_SECS: u64 = 30;
+
+/// Runs the scheduled tasks of the tenant.
+///
+/// The query nodes of a tenant compete for the task lease in the meta-service,
+/// only the node holding it runs the scheduled tasks.
+pub struct TaskScheduler {
+    runner: TaskRunner,
+    state: Mutex<SchedulerState>,
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    /// The seq of the task lease held by this node, 0 if not held.
+    lease_seq: u64,
+    scheduled: HashMap<String, ScheduledTask>,
+    /// The tasks being run by the scheduler, a task is not run again before the last run completes.
+    running: HashSet<String>,
+}
+
+struct ScheduledTask {
+    /// Tells a task from a dropped one with the same name.
+    created_on: DateTime<Utc>,
+    cron: CronSchedule,
+    next_run: Option<DateTime<Utc>>,
+}
+
+impl TaskScheduler {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(TaskScheduler::create(&config.query.tenant_id)));
+        Ok(())
+    }
+
+    pub fn create(tenant: impl Into<String>) -> TaskScheduler {
+        TaskScheduler {
+            runner: TaskRunner::create(tenant),
+            state: Mutex::new(SchedulerState::default()),
+        }
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    /// Start checking the scheduled tasks in background.
+    pub fn start(self: &Arc<Self>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                if let Err(cause) = scheduler.tick(Utc::now()).await {
+                    warn!("Task scheduler failed to check the tasks: {}", cause);
+                }
+                sleep(TICK_INTERVAL).await;
+            }
+        });
+    }
+
+    /// Run the tasks due at `now` in background, if this node holds the task lease.
+    #[async_backtrace::framed]
+    pub async fn tick(self: &Arc<Self>, now: DateTime<Utc>) -> Result<()> {
+        if !self.hold_lease().await? {
+            return Ok(());
+        }
+
+        let catalog = CatalogManager::instance().get_catalog(CATALOG_DEFAULT)?;
+        let tasks = catalog
+            .list_tasks(ListTasksReq::new(self.runner.tenant()))
+            .await?;
+
+        let due = self.collect_due_tasks(tasks, now);
+        for (name, meta, scheduled_on) in due {
+            let scheduler = self.clone();
+            GlobalIORuntime::instance().spawn(async move {
+                let res = scheduler
+                    .runner
+                    .run(&name, &meta, "SCHEDULE".to_string(), scheduled_on)
+                    .await;
+                match res {
+                    Ok(run) if run.state == TaskRunState::Succeeded => {
+                        scheduler.runner.spawn_successors(name.clone());
+                    }
+                    Ok(_) => {}
+                    Err(cause) => warn!("Failed to run task {}: {}", name, cause),
+                }
+                scheduler.state.lock().running.remove(&name);
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the started tasks due to run, and schedules their next runs.
+    fn collect_due_tasks(
+        &self,
+        tasks: Vec<(String, TaskMeta)>,
+        now: DateTime<Utc>,
+    ) -> Vec<(String, TaskMeta, DateTime<Utc>)> {
+        let mut state = self.state.lock();
+        let mut scheduled = HashMap::with_capacity(tasks.len());
+        let mut due = vec![];
+
+        for (name, meta) in tasks {
+            if meta.state != TaskState::Started {
+                continue;
+            }
+            let schedule = match &meta.schedule {
+                Some(schedule) => schedule,
+                None => continue,
+            };
+
+            let mut task = match state.scheduled.remove(&name) {
+                Some(task) if task.created_on == meta.created_on => task,
+                _ => match CronSchedule::parse(schedule) {
+                    Ok(cron) => ScheduledTask {
+                        created_on: meta.created_on,
+                        next_run: cron.next_after(now),
+                        cron,
+                    },
+                    Err(cause) => {
+                        warn!("Invalid schedule of task {}: {}", name, cause);
+                        continue;
+                    }
+                },
+            };
+
+            if let Some(next_run) = task.next_run {
+                if next_run <= now {
+                    task.next_run = task.cron.next_after(now);
+                    if state.running.insert(name.clone()) {
+                        due.push((name.clone(), meta.clone(), next_run));
+                    }
+                }
+            }
+            scheduled.insert(name, task);
+        }
+
+        state.scheduled = scheduled;
+        due
+    }
+
+    /// Acquire or extend the task lease, returns whether this node holds it.
+    #[async_backtrace::framed]
+    pub async fn hold_lease(&self) -> Result<bool> {
+        let catalog = CatalogManager::instance().get_catalog(CATALOG_DEFAULT)?;
+        let seq = self.state.lock().lease_seq;
+        let reply = catalog
+            .acquire_task_lease(AcquireTaskLeaseReq {
+                tenant: self.runner.tenant().to_string(),
+                seq,
+                expire_at: Utc::now().timestamp() as u64 + TASK_LEASE_SECS,
+            })
+            .await?;
+
+        let mut state = self.state.lock();
+        match reply.seq {
+            Some(seq) => {
+                if state.lease_seq == 0 {
+                    info!("Task scheduler acquired the task lease");
+                }
+                state.lease_seq = seq;
+                Ok(true)
+            }
+            None => {
+                if state.lease_seq != 0 {
+                    info!("Task scheduler lost the task lease");
+                }
+                state.lease_seq = 0;
+                state.scheduled.clear();
+                Ok(false)
+            }
+        }
+    }
+}
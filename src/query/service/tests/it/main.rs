@@ -31,4 +31,5 @@ mod sessions;
 mod sql;
 mod storages;
 mod table_functions;
+mod task;
 mod tests;
@@ -16,13 +16,38 @@
 
 use std::fs;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::Date32Array;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_array::UInt64Array;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::ActionCreatePreparedStatementRequest;
+use arrow_flight::sql::ActionCreatePreparedStatementResult;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
+use arrow_flight::sql::CommandPreparedStatementQuery;
+use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::utils::batches_to_flight_data;
 use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::Action;
 use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
+use arrow_flight::PutResult;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema as ArrowSchema;
 use common_base::base::tokio;
 use common_config::InnerConfig;
 use common_exception::Result;
@@ -33,6 +58,8 @@ use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
 use futures::TryStreamExt;
 use goldenfile::Mint;
+use prost::bytes::Bytes;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -40,6 +67,8 @@ use tokio_stream::wrappers::UnixListenerStream;
 use tonic::transport::Channel;
 use tonic::transport::Endpoint;
 use tonic::transport::Server;
+use tonic::Request;
+use tonic::Streaming;
 use tower::service_fn;
 use tracing::debug;
 
@@ -75,6 +104,104 @@ async fn run_query(
     Ok(res)
 }
 
+async fn fetch_batches(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<Vec<RecordBatch>, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    flight_data_to_batches(&flight_data)
+}
+
+fn string_values(batches: &[RecordBatch], column: usize) -> Vec<String> {
+    let mut values = vec![];
+    for batch in batches {
+        let array = batch
+            .column(column)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        for i in 0..array.len() {
+            values.push(array.value(i).to_string());
+        }
+    }
+    values
+}
+
+fn string_batch(schema: &ArrowSchema, columns: Vec<Vec<&str>>) -> RecordBatch {
+    let columns = columns
+        .into_iter()
+        .map(|values| Arc::new(StringArray::from(values)) as ArrayRef)
+        .collect();
+    RecordBatch::try_new(Arc::new(schema.clone()), columns).unwrap()
+}
+
+/// Create a prepared statement and return its handle, which is not exposed by
+/// the `PreparedStatement` of the client.
+async fn create_prepared_statement(
+    client: &mut FlightSqlServiceClient<Channel>,
+    query: &str,
+) -> std::result::Result<Bytes, ArrowError> {
+    let cmd = ActionCreatePreparedStatementRequest {
+        query: query.to_string(),
+    };
+    let action = Action {
+        r#type: "CreatePreparedStatement".to_string(),
+        body: cmd.as_any().encode_to_vec().into(),
+    };
+    let mut result = client.do_action(action).await?;
+    let result = result
+        .message()
+        .await
+        .map_err(|e| ArrowError::IoError(e.to_string()))?
+        .unwrap();
+    let any = Any::decode(&*result.body).map_err(|e| ArrowError::IoError(e.to_string()))?;
+    let result: ActionCreatePreparedStatementResult = any.unpack()?.unwrap();
+    Ok(result.prepared_statement_handle)
+}
+
+/// Bind the parameters by `DoPut`, the first message only carries the command.
+async fn bind_parameters(
+    client: &mut FlightSqlServiceClient<Channel>,
+    handle: Bytes,
+    parameters: RecordBatch,
+) -> std::result::Result<Streaming<PutResult>, ArrowError> {
+    let cmd = CommandPreparedStatementQuery {
+        prepared_statement_handle: handle,
+    };
+    let mut flight_data = vec![FlightData {
+        flight_descriptor: Some(FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec())),
+        ..Default::default()
+    }];
+    flight_data.extend(batches_to_flight_data(
+        parameters.schema().as_ref().clone(),
+        vec![parameters],
+    )?);
+    client.do_put(futures::stream::iter(flight_data)).await
+}
+
+async fn execute_prepared_query(
+    client: &mut FlightSqlServiceClient<Channel>,
+    token: &str,
+    handle: Bytes,
+) -> std::result::Result<Vec<RecordBatch>, ArrowError> {
+    let cmd = CommandPreparedStatementQuery {
+        prepared_statement_handle: handle,
+    };
+    let mut request = Request::new(FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec()));
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    let flight_info = client
+        .inner_mut()
+        .get_flight_info(request)
+        .await
+        .map_err(|e| ArrowError::IoError(e.to_string()))?
+        .into_inner();
+    fetch_batches(client, flight_info).await
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -148,3 +275,250 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_metadata() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        let flight_info = client.get_catalogs().await.unwrap();
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        assert!(string_values(&batches, 0).contains(&"default".to_string()));
+
+        let flight_info = client
+            .get_db_schemas(CommandGetDbSchemas {
+                catalog: Some("default".to_string()),
+                db_schema_filter_pattern: Some("sys%".to_string()),
+            })
+            .await
+            .unwrap();
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        assert_eq!(string_values(&batches, 1), vec!["system".to_string()]);
+
+        let flight_info = client.get_table_types().await.unwrap();
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        assert_eq!(string_values(&batches, 0), vec![
+            "BASE TABLE".to_string(),
+            "VIEW".to_string()
+        ]);
+
+        run_query(&mut client, "create table test_metadata(a int)")
+            .await
+            .unwrap();
+        let flight_info = client
+            .get_tables(CommandGetTables {
+                catalog: None,
+                db_schema_filter_pattern: Some("default".to_string()),
+                table_name_filter_pattern: Some("test\\_meta%".to_string()),
+                table_types: vec!["BASE TABLE".to_string()],
+                include_schema: true,
+            })
+            .await
+            .unwrap();
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        assert_eq!(string_values(&batches, 2), vec![
+            "test_metadata".to_string()
+        ]);
+        assert_eq!(batches[0].num_columns(), 5);
+
+        let flight_info = client
+            .get_primary_keys(CommandGetPrimaryKeys {
+                catalog: None,
+                db_schema: Some("default".to_string()),
+                table: "test_metadata".to_string(),
+            })
+            .await
+            .unwrap();
+        let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prepared_statement_with_params() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        // The parameters are always sent as strings.
+        let sql = "select ?::int + 1, ?";
+        let stmt = client.prepare(sql.to_string()).await.unwrap();
+        let parameter_schema = stmt.parameter_schema().unwrap().clone();
+        assert_eq!(parameter_schema.fields().len(), 2);
+        assert!(
+            parameter_schema
+                .fields()
+                .iter()
+                .all(|f| f.data_type() == &DataType::Utf8)
+        );
+        stmt.close().await.unwrap();
+
+        // The statement can be executed repeatedly with different parameters.
+        let handle = create_prepared_statement(&mut client, sql).await.unwrap();
+        for (param, expected) in [("1", 2), ("41", 42)] {
+            let params = string_batch(&parameter_schema, vec![vec![param], vec!["it's"]]);
+            bind_parameters(&mut client, handle.clone(), params)
+                .await
+                .unwrap();
+            let batches = execute_prepared_query(&mut client, &token, handle.clone())
+                .await
+                .unwrap();
+            let array = batches[0]
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            assert_eq!(array.value(0), expected);
+            assert_eq!(string_values(&batches, 1), vec!["it's".to_string()]);
+        }
+
+        // Only one row of parameters can be bound to a query with result set.
+        let params = string_batch(&parameter_schema, vec![vec!["1", "2"], vec!["a", "b"]]);
+        bind_parameters(&mut client, handle.clone(), params)
+            .await
+            .unwrap();
+        assert!(
+            execute_prepared_query(&mut client, &token, handle)
+                .await
+                .is_err()
+        );
+
+        // The arguments of `LIMIT` and `OFFSET` are integers.
+        let sql = "select number from numbers(10) order by number limit ? offset ?";
+        let handle = create_prepared_statement(&mut client, sql).await.unwrap();
+        let params = string_batch(&parameter_schema, vec![vec!["2"], vec!["3"]]);
+        bind_parameters(&mut client, handle.clone(), params)
+            .await
+            .unwrap();
+        let batches = execute_prepared_query(&mut client, &token, handle)
+            .await
+            .unwrap();
+        let values = batches
+            .iter()
+            .flat_map(|batch| {
+                let array = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()
+                    .unwrap();
+                array.values().to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![3, 4]);
+
+        // The parameters of other types are typed literals, a negative number after `-`
+        // doesn't start a comment.
+        let handle = create_prepared_statement(&mut client, "select 10 -?, ?::string")
+            .await
+            .unwrap();
+        let schema = ArrowSchema::new(vec![
+            Field::new("1", DataType::Int64, true),
+            Field::new("2", DataType::Date32, true),
+        ]);
+        let params = RecordBatch::try_new(Arc::new(schema), vec![
+            Arc::new(Int64Array::from(vec![-5])) as ArrayRef,
+            Arc::new(Date32Array::from(vec![19478])) as ArrayRef,
+        ])
+        .unwrap();
+        bind_parameters(&mut client, handle.clone(), params)
+            .await
+            .unwrap();
+        let batches = execute_prepared_query(&mut client, &token, handle)
+            .await
+            .unwrap();
+        let array = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(array.value(0), 15);
+        assert_eq!(string_values(&batches, 1), vec!["2023-05-01".to_string()]);
+
+        // The statement without result set is executed once for each row of the parameters.
+        run_query(&mut client, "create table test_params(a int, b string)")
+            .await
+            .unwrap();
+        let handle =
+            create_prepared_statement(&mut client, "insert into test_params values (?, ?)")
+                .await
+                .unwrap();
+        let params = string_batch(&parameter_schema, vec![vec!["1", "2"], vec!["x", "y"]]);
+        let mut results = bind_parameters(&mut client, handle, params).await.unwrap();
+        let result = results.message().await.unwrap().unwrap();
+        let any = Any::decode(&*result.app_metadata).unwrap();
+        let result: DoPutUpdateResult = any.unpack().unwrap().unwrap();
+        assert_eq!(result.record_count, 2);
+
+        let res = run_query(&mut client, "select * from test_params order by a")
+            .await
+            .unwrap();
+        assert_eq!(
+            res,
+            [
+                "+---+---+",
+                "| a | b |",
+                "+---+---+",
+                "| 1 | x |",
+                "| 2 | y |",
+                "+---+---+",
+            ]
+            .join("\n")
+        );
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
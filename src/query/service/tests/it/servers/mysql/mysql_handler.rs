@@ -49,6 +49,77 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement_with_params() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let statement = connection
+        .prep("SELECT ? + 1, ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(statement.num_params(), 2);
+
+    // The second execution binds the parameters to the cached plan.
+    for (param, expected) in [(1i64, 2i64), (41, 42)] {
+        let rows: Vec<(i64, String)> = connection
+            .exec(&statement, (param, "databend"))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+        assert_eq!(rows, vec![(expected, "databend".to_string())]);
+    }
+
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close failed")?;
+
+    // The parameters of the statements other than queries are substituted as literals,
+    // a negative number after `-` doesn't start a comment.
+    connection
+        .query_drop("CREATE TABLE default.t_prepared(a INT)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table failed")?;
+    connection
+        .exec_drop("INSERT INTO default.t_prepared VALUES (10 -?)", (-5i64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Insert failed")?;
+
+    // The cached plan is optimized with the latest statistics for each execution,
+    // e.g. the count of the rows isn't folded from a stale snapshot.
+    let statement = connection
+        .prep("SELECT count(*), ? FROM default.t_prepared")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let rows: Vec<(u64, i64)> = connection
+        .exec(&statement, (1i64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![(1, 1)]);
+    connection
+        .query_drop("INSERT INTO default.t_prepared VALUES (1)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Insert failed")?;
+    let rows: Vec<(u64, i64)> = connection
+        .exec(&statement, (2i64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![(2, 2)]);
+    let rows: Vec<(i32,)> = connection
+        .query("SELECT a FROM default.t_prepared ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(rows, vec![(1,), (15,)]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let _guard =
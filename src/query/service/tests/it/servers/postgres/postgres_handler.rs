@@ -0,0 +1,347 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordHashMethod;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+use tokio_postgres::types::FromSql;
+use tokio_postgres::types::Type;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+
+const TEST_USER: &str = "pg_user";
+const TEST_PASSWORD: &str = "pg_password";
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_startup_and_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let port = start_server().await?;
+    let client = create_connection(port, "root", None).await?;
+
+    let messages = client
+        .simple_query("SELECT 1, 'databend', NULL")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Simple query failed")?;
+    let rows = messages
+        .iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some(row),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get(0), Some("1"));
+    assert_eq!(rows[0].get(1), Some("databend"));
+    assert_eq!(rows[0].get(2), None);
+    assert!(matches!(
+        messages.last(),
+        Some(SimpleQueryMessage::CommandComplete(1))
+    ));
+
+    client
+        .batch_execute("CREATE TABLE t_pg(a INT, b VARCHAR)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table failed")?;
+    let messages = client
+        .simple_query("INSERT INTO t_pg VALUES (1, 'x'), (2, 'y')")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Insert failed")?;
+    assert!(matches!(
+        messages.last(),
+        Some(SimpleQueryMessage::CommandComplete(2))
+    ));
+
+    // An error does not break the session.
+    let error = client
+        .simple_query("SELECT * FROM t_not_exists")
+        .await
+        .unwrap_err();
+    let db_error = error.as_db_error().unwrap();
+    assert_eq!(db_error.code().code(), "42P01");
+
+    let rows = client
+        .query("SELECT a, b FROM t_pg ORDER BY a", &[])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let rows = rows
+        .iter()
+        .map(|row| (row.get::<_, i32>(0), row.get::<_, String>(1)))
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![(1, "x".to_string()), (2, "y".to_string())]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_cleartext_password_authentication() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config(PasswordHashMethod::Sha256)).await?;
+    let port = start_server().await?;
+
+    let client = create_connection(port, TEST_USER, Some(TEST_PASSWORD)).await?;
+    let row = client
+        .query_one("SELECT current_user()", &[])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert!(row.get::<_, String>(0).contains(TEST_USER));
+
+    let error = try_connect(port, TEST_USER, Some("wrong_password"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.as_db_error().unwrap().code().code(), "28P01");
+
+    // The server asks for the cleartext password, the client fails without one.
+    assert!(try_connect(port, TEST_USER, None).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_scram_sha256_authentication() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config(PasswordHashMethod::ScramSha256)).await?;
+    let port = start_server().await?;
+
+    let client = create_connection(port, TEST_USER, Some(TEST_PASSWORD)).await?;
+    let row = client
+        .query_one("SELECT 1", &[])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(row.get::<_, i16>(0), 1);
+
+    let error = try_connect(port, TEST_USER, Some("wrong_password"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.as_db_error().unwrap().code().code(), "28P01");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query_with_params() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let port = start_server().await?;
+    let client = create_connection(port, "root", None).await?;
+
+    // The types of untyped parameters are described as text.
+    let statement = client
+        .prepare("SELECT $1::INT + 1, $2")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(statement.params(), &[Type::TEXT, Type::TEXT]);
+    assert_eq!(statement.columns()[0].type_(), &Type::INT8);
+
+    // The statement can be executed repeatedly with different parameters.
+    for (param, expected) in [("1", 2i64), ("41", 42)] {
+        let row = client
+            .query_one(&statement, &[&param, &"it's"])
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+        assert_eq!(row.get::<_, i64>(0), expected);
+        assert_eq!(row.get::<_, String>(1), "it's");
+    }
+
+    // Binary parameters of the declared types, the casts keep the described result types.
+    let statement = client
+        .prepare_typed("SELECT $1::INT * 2, $2::DOUBLE, $3::BOOLEAN", &[
+            Type::INT4,
+            Type::FLOAT8,
+            Type::BOOL,
+        ])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let row = client
+        .query_one(&statement, &[&21i32, &1.5f64, &true])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row.get::<_, i64>(0), 42);
+    assert_eq!(row.get::<_, f64>(1), 1.5);
+    assert!(row.get::<_, bool>(2));
+
+    // A negative parameter after `-` doesn't start a comment.
+    let statement = client
+        .prepare_typed("SELECT (10 -$1)::BIGINT, (1.5 -$2)::DOUBLE", &[
+            Type::INT4,
+            Type::FLOAT8,
+        ])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let row = client
+        .query_one(&statement, &[&-5i32, &-0.5f64])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row.get::<_, i64>(0), 15);
+    assert_eq!(row.get::<_, f64>(1), 2.0);
+
+    // NULL parameters are bound as NULL literals.
+    let row = client
+        .query_one("SELECT $1::INT IS NULL", &[&None::<&str>])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert!(row.get::<_, bool>(0));
+
+    // A `$n` inside a string literal is not a placeholder.
+    let row = client
+        .query_one("SELECT '$1', $1", &[&"x"])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row.get::<_, String>(0), "$1");
+    assert_eq!(row.get::<_, String>(1), "x");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_binary_result_encoding() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let port = start_server().await?;
+    let client = create_connection(port, "root", None).await?;
+
+    // The extended query protocol of tokio-postgres requests results in the binary format.
+    let row = client
+        .query_one(
+            "SELECT 1::TINYINT, 2::SMALLINT, 3::INT, 4::BIGINT, 5::UINT32, 1.5::FLOAT, 2.5::DOUBLE, \
+            TRUE, 'text', DATE '2023-05-01', TIMESTAMP '2023-05-01 10:00:00', \
+            18446744073709551615::UINT64, 123.45::DECIMAL(10, 2), -0.5::DECIMAL(10, 2), NULL",
+            &[],
+        )
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+
+    let types = row
+        .columns()
+        .iter()
+        .map(|column| column.type_().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(types, vec![
+        Type::INT2,
+        Type::INT2,
+        Type::INT4,
+        Type::INT8,
+        Type::INT8,
+        Type::FLOAT4,
+        Type::FLOAT8,
+        Type::BOOL,
+        Type::TEXT,
+        Type::DATE,
+        Type::TIMESTAMP,
+        Type::NUMERIC,
+        Type::NUMERIC,
+        Type::NUMERIC,
+        Type::TEXT,
+    ]);
+
+    assert_eq!(row.get::<_, i16>(0), 1);
+    assert_eq!(row.get::<_, i16>(1), 2);
+    assert_eq!(row.get::<_, i32>(2), 3);
+    assert_eq!(row.get::<_, i64>(3), 4);
+    assert_eq!(row.get::<_, i64>(4), 5);
+    assert_eq!(row.get::<_, f32>(5), 1.5);
+    assert_eq!(row.get::<_, f64>(6), 2.5);
+    assert!(row.get::<_, bool>(7));
+    assert_eq!(row.get::<_, String>(8), "text");
+
+    // Days and microseconds since 2000-01-01.
+    assert_eq!(row.get::<_, Raw>(9).0, 8521i32.to_be_bytes());
+    assert_eq!(
+        row.get::<_, Raw>(10).0,
+        736_250_400_000_000i64.to_be_bytes()
+    );
+
+    // ndigits, weight, sign, dscale and the base-10000 digits.
+    assert_eq!(
+        row.get::<_, Raw>(11).0,
+        numeric(4, 0x0000, 0, &[1844, 6744, 737, 955, 1615])
+    );
+    assert_eq!(row.get::<_, Raw>(12).0, numeric(0, 0x0000, 2, &[123, 4500]));
+    assert_eq!(row.get::<_, Raw>(13).0, numeric(-1, 0x4000, 2, &[5000]));
+
+    assert_eq!(row.get::<_, Option<String>>(14), None);
+
+    Ok(())
+}
+
+fn prepare_config(hash_method: PasswordHashMethod) -> InnerConfig {
+    let auth_info = AuthInfo::Password {
+        hash_value: hash_method.hash(TEST_PASSWORD.as_bytes()),
+        hash_method,
+    };
+    ConfigBuilder::create()
+        .add_user(TEST_USER, auth_info)
+        .build()
+}
+
+async fn start_server() -> Result<u16> {
+    let mut handler = PostgresHandler::create()?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    Ok(handler.start(listening).await?.port())
+}
+
+async fn create_connection(port: u16, user: &str, password: Option<&str>) -> Result<Client> {
+    try_connect(port, user, password)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
+}
+
+async fn try_connect(
+    port: u16,
+    user: &str,
+    password: Option<&str>,
+) -> std::result::Result<Client, tokio_postgres::Error> {
+    let mut config = format!("host=127.0.0.1 port={} user={} dbname=default", port, user);
+    if let Some(password) = password {
+        config.push_str(&format!(" password={}", password));
+    }
+
+    let (client, connection) = tokio_postgres::connect(&config, NoTls).await?;
+    tokio::spawn(connection);
+    Ok(client)
+}
+
+fn numeric(weight: i16, sign: i16, dscale: i16, digits: &[i16]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    bytes.extend_from_slice(&weight.to_be_bytes());
+    bytes.extend_from_slice(&sign.to_be_bytes());
+    bytes.extend_from_slice(&dscale.to_be_bytes());
+    for digit in digits {
+        bytes.extend_from_slice(&digit.to_be_bytes());
+    }
+    bytes
+}
+
+/// The raw bytes of a value in the binary format.
+struct Raw(Vec<u8>);
+
+impl<'a> FromSql<'a> for Raw {
+    fn from_sql(
+        _: &Type,
+        raw: &'a [u8],
+    ) -> std::result::Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Raw(raw.to_vec()))
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+}
@@ -30,6 +30,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
@@ -40,32 +41,56 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::AcquireTaskLeaseReply;
+use common_meta_app::schema::AcquireTaskLeaseReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReply;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateTaskReply;
+use common_meta_app::schema::CreateTaskReq;
+use common_meta_app::schema::CreateTaskRunReply;
+use common_meta_app::schema::CreateTaskRunReq;
 use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropTaskReply;
+use common_meta_app::schema::DropTaskReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::GetTaskReply;
+use common_meta_app::schema::GetTaskReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListSequencesReq;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::ListTasksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TaskMeta;
+use common_meta_app::schema::TaskRun;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
@@ -74,6 +99,10 @@ use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateTaskRunReply;
+use common_meta_app::schema::UpdateTaskRunReq;
+use common_meta_app::schema::UpdateTaskStateReply;
+use common_meta_app::schema::UpdateTaskStateReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MetaId;
@@ -573,6 +602,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
     fn get_data_operator(&self) -> Result<DataOperator> {
         self.ctx.get_data_operator()
     }
@@ -585,6 +618,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn add_stream_offset(&self, req: UpdateTableMetaReq) {
+        self.ctx.add_stream_offset(req)
+    }
+
+    fn get_stream_offsets(&self) -> Vec<UpdateTableMetaReq> {
+        self.ctx.get_stream_offsets()
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatParams> {
         todo!()
     }
@@ -744,6 +785,79 @@ impl Catalog for FakedCatalog {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
+    async fn create_sequence(&self, _req: CreateSequenceReq) -> Result<CreateSequenceReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_sequence(&self, _req: DropSequenceReq) -> Result<DropSequenceReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence(&self, _req: GetSequenceReq) -> Result<GetSequenceReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn list_sequences(&self, _req: ListSequencesReq) -> Result<Vec<(String, SequenceMeta)>> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence_next_value(
+        &self,
+        _req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn create_task(&self, _req: CreateTaskReq) -> Result<CreateTaskReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_task(&self, _req: DropTaskReq) -> Result<DropTaskReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn get_task(&self, _req: GetTaskReq) -> Result<GetTaskReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tasks(&self, _req: ListTasksReq) -> Result<Vec<(String, TaskMeta)>> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn update_task_state(&self, _req: UpdateTaskStateReq) -> Result<UpdateTaskStateReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn create_task_run(&self, _req: CreateTaskRunReq) -> Result<CreateTaskRunReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn update_task_run(&self, _req: UpdateTaskRunReq) -> Result<UpdateTaskRunReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn list_task_runs(&self, _req: ListTaskRunsReq) -> Result<Vec<TaskRun>> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn acquire_task_lease(&self, _req: AcquireTaskLeaseReq) -> Result<AcquireTaskLeaseReply> {
+        unimplemented!()
+    }
+
     fn as_any(&self) -> &dyn Any {
         todo!()
     }
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_exception::Result;
+use databend_query::task::CronSchedule;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_cron_schedule_next_after() -> Result<()> {
+    let t = |y, m, d, h, min| Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap();
+    // 2023-06-05 is a Monday.
+    let now = Utc.with_ymd_and_hms(2023, 6, 5, 10, 7, 30).unwrap();
+
+    let cases = vec![
+        ("* * * * *", Some(t(2023, 6, 5, 10, 8))),
+        ("*/15 * * * *", Some(t(2023, 6, 5, 10, 15))),
+        ("5 * * * *", Some(t(2023, 6, 5, 11, 5))),
+        ("0 2 * * *", Some(t(2023, 6, 6, 2, 0))),
+        ("@daily", Some(t(2023, 6, 6, 0, 0))),
+        ("30 9-17/4 * * *", Some(t(2023, 6, 5, 13, 30))),
+        ("0 0 * * 0", Some(t(2023, 6, 11, 0, 0))),
+        ("0 0 * * 7", Some(t(2023, 6, 11, 0, 0))),
+        ("0 0 1 * *", Some(t(2023, 7, 1, 0, 0))),
+        ("0 0 1,15 * 6", Some(t(2023, 6, 10, 0, 0))),
+        ("0 0 29 2 *", Some(t(2024, 2, 29, 0, 0))),
+        ("0 0 30 2 *", None),
+    ];
+
+    for (expr, want) in cases {
+        let cron = CronSchedule::parse(expr)?;
+        assert_eq!(want, cron.next_after(now), "{}", expr);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_schedule_parse_error() -> Result<()> {
+    for expr in [
+        "",
+        "* * * *",
+        "* * * * * *",
+        "60 * * * *",
+        "* 24 * * *",
+        "* * 0 * *",
+        "* * * 13 *",
+        "* * * * 8",
+        "*/0 * * * *",
+        "5-1 * * * *",
+        "a * * * *",
+    ] {
+        assert!(CronSchedule::parse(expr).is_err(), "{}", expr);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep;
+use common_exception::Result;
+use common_expression::block_debug::pretty_format_blocks;
+use common_expression::DataBlock;
+use databend_query::sessions::QueryContext;
+use databend_query::task::TaskScheduler;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use futures_util::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_task_scheduler_hold_lease() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let tenant = fixture.default_tenant();
+    let db = fixture.default_db_name();
+
+    // Both schedulers share the meta-service of the fixture, like two query nodes of a tenant.
+    let holder = Arc::new(TaskScheduler::create(&tenant));
+    let other = Arc::new(TaskScheduler::create(&tenant));
+
+    assert!(holder.hold_lease().await?);
+    assert!(!other.hold_lease().await?);
+    // The holder extends the lease, the other node still can not take it.
+    assert!(holder.hold_lease().await?);
+    assert!(!other.hold_lease().await?);
+
+    execute_command(ctx.clone(), &format!("CREATE TABLE {db}.t_task(a INT)")).await?;
+    execute_command(
+        ctx.clone(),
+        &format!("CREATE TASK task1 SCHEDULE = '* * * * *' AS INSERT INTO {db}.t_task VALUES (1)"),
+    )
+    .await?;
+
+    // The first check schedules the task, the next one after a minute runs it.
+    let now = Utc::now();
+    let later = now + chrono::Duration::minutes(2);
+    other.tick(now).await?;
+    other.tick(later).await?;
+    holder.tick(now).await?;
+    holder.tick(later).await?;
+
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ];
+    let query = format!("SELECT count(*) FROM {db}.t_task");
+    let mut actual = String::new();
+    for _ in 0..100 {
+        actual = query_result(ctx.clone(), &query).await?;
+        if actual == expected.join("\n") {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(expected.join("\n"), actual);
+
+    // The task is only run by the lease holder.
+    other.tick(later + chrono::Duration::minutes(2)).await?;
+    sleep(Duration::from_secs(1)).await;
+    assert_eq!(expected.join("\n"), query_result(ctx, &query).await?);
+
+    Ok(())
+}
+
+async fn query_result(ctx: Arc<QueryContext>, query: &str) -> Result<String> {
+    let stream = execute_query(ctx, query).await?;
+    let blocks: Vec<DataBlock> = stream.try_collect().await?;
+    pretty_format_blocks(&blocks)
+}
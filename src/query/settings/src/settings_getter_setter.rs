@@ -318,6 +318,22 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_join_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn set_join_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        self.try_set_u64("join_spilling_bytes_threshold_per_proc", value as u64)
+    }
+
+    pub fn get_sort_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("sort_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn set_sort_spilling_bytes_threshold_per_proc(&self, value: usize) -> Result<()> {
+        self.try_set_u64("sort_spilling_bytes_threshold_per_proc", value as u64)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -350,6 +366,14 @@ impl Settings {
         self.try_get_u64("table_lock_expire_secs")
     }
 
+    pub fn set_sequence_cache_size(&self, val: u64) -> Result<()> {
+        self.try_set_u64("sequence_cache_size", val)
+    }
+
+    pub fn get_sequence_cache_size(&self) -> Result<u64> {
+        self.try_get_u64("sequence_cache_size")
+    }
+
     pub fn get_enterprise_license(&self) -> Result<String> {
         self.try_get_string("enterprise_license")
     }
@@ -16,6 +16,7 @@ use common_ast::ast::FormatTreeNode;
 use common_catalog::plan::PartStatistics;
 use common_exception::Result;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_profile::ProfSpan;
 use common_profile::ProfSpanSetRef;
 use itertools::Itertools;
 
@@ -566,6 +567,7 @@ fn sort_to_format_tree(
         children.push(FormatTreeNode::new(format!(
             "total process time: {process_time}ms"
         )));
+        children.extend(prof_span_spill_to_format_tree(prof_span));
     }
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
@@ -685,6 +687,7 @@ fn hash_join_to_format_tree(
         children.push(FormatTreeNode::new(format!(
             "total process time: {process_time}ms"
         )));
+        children.extend(prof_span_spill_to_format_tree(prof_span));
     }
 
     children.push(build_child);
@@ -779,6 +782,23 @@ fn plan_stats_info_to_format_tree(info: &PlanStatsInfo) -> Vec<FormatTreeNode<St
     ))]
 }
 
+fn prof_span_spill_to_format_tree(prof_span: &ProfSpan) -> Vec<FormatTreeNode<String>> {
+    if prof_span.spill_write_count == 0 {
+        return vec![];
+    }
+
+    vec![
+        FormatTreeNode::new(format!(
+            "spilled blocks: {}, spilled bytes: {}",
+            prof_span.spill_write_count, prof_span.spill_write_bytes
+        )),
+        FormatTreeNode::new(format!(
+            "restored blocks: {}, restored bytes: {}",
+            prof_span.spill_read_count, prof_span.spill_read_bytes
+        )),
+    ]
+}
+
 fn exchange_source_to_format_tree(plan: &ExchangeSource) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
@@ -299,16 +299,55 @@ pub enum WindowFunction {
     Rank,
     DenseRank,
     PercentRank,
+    LagLead(LagLeadFunctionDesc),
+    NthValue(NthValueFunctionDesc),
+    Ntile(NtileFunctionDesc),
+    CumeDist,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct LagLeadFunctionDesc {
+    pub is_lag: bool,
+    pub offset: u64,
+    pub arg: IndexType,
+    pub default: LagLeadDefault,
+    pub return_type: DataType,
+}
+
+/// The value of `LAG` and `LEAD` if the offset row is out of the partition.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum LagLeadDefault {
+    Null,
+    Index(IndexType),
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NthValueFunctionDesc {
+    /// None for `last_value`.
+    pub n: Option<u64>,
+    pub arg: IndexType,
+    pub ignore_nulls: bool,
+    pub return_type: DataType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NtileFunctionDesc {
+    pub n: u64,
 }
 
 impl WindowFunction {
     fn data_type(&self) -> Result<DataType> {
         match self {
             WindowFunction::Aggregate(agg) => agg.sig.return_type(),
-            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
-                Ok(DataType::Number(NumberDataType::UInt64))
+            WindowFunction::RowNumber
+            | WindowFunction::Rank
+            | WindowFunction::DenseRank
+            | WindowFunction::Ntile(_) => Ok(DataType::Number(NumberDataType::UInt64)),
+            WindowFunction::PercentRank | WindowFunction::CumeDist => {
+                Ok(DataType::Number(NumberDataType::Float64))
             }
-            WindowFunction::PercentRank => Ok(DataType::Number(NumberDataType::Float64)),
+            WindowFunction::LagLead(lag_lead) => Ok(lag_lead.return_type.clone()),
+            WindowFunction::NthValue(nth) => Ok(nth.return_type.clone()),
         }
     }
 }
@@ -321,6 +360,15 @@ impl Display for WindowFunction {
             WindowFunction::Rank => write!(f, "rank"),
             WindowFunction::DenseRank => write!(f, "dense_rank"),
             WindowFunction::PercentRank => write!(f, "percent_rank"),
+            WindowFunction::LagLead(lag_lead) if lag_lead.is_lag => write!(f, "lag"),
+            WindowFunction::LagLead(_) => write!(f, "lead"),
+            WindowFunction::NthValue(nth) => match nth.n {
+                Some(1) => write!(f, "first_value"),
+                Some(_) => write!(f, "nth_value"),
+                None => write!(f, "last_value"),
+            },
+            WindowFunction::Ntile(_) => write!(f, "ntile"),
+            WindowFunction::CumeDist => write!(f, "cume_dist"),
         }
     }
 }
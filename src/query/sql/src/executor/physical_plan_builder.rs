@@ -54,7 +54,11 @@ use super::EvalScalar;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::HashJoin;
+use super::LagLeadDefault;
+use super::LagLeadFunctionDesc;
 use super::Limit;
+use super::NthValueFunctionDesc;
+use super::NtileFunctionDesc;
 use super::ProjectSet;
 use super::RowFetch;
 use super::Sort;
@@ -978,6 +982,56 @@ impl PhysicalPlanBuilder {
                     WindowFuncType::Rank => WindowFunction::Rank,
                     WindowFuncType::DenseRank => WindowFunction::DenseRank,
                     WindowFuncType::PercentRank => WindowFunction::PercentRank,
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let arg = if let ScalarExpr::BoundColumnRef(col) = lag_lead.arg.as_ref() {
+                            col.column.index
+                        } else {
+                            return Err(ErrorCode::Internal(
+                                "Window's lag function argument must be a BoundColumnRef".to_string(),
+                            ));
+                        };
+                        let default = match &lag_lead.default {
+                            None => LagLeadDefault::Null,
+                            Some(scalar) => match scalar.as_ref() {
+                                ScalarExpr::BoundColumnRef(col) => {
+                                    LagLeadDefault::Index(col.column.index)
+                                }
+                                _ => {
+                                    return Err(ErrorCode::Internal(
+                                        "Window's lag function default argument must be a BoundColumnRef"
+                                            .to_string(),
+                                    ));
+                                }
+                            },
+                        };
+                        WindowFunction::LagLead(LagLeadFunctionDesc {
+                            is_lag: lag_lead.is_lag,
+                            offset: lag_lead.offset,
+                            arg,
+                            default,
+                            return_type: *lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth) => {
+                        let arg = if let ScalarExpr::BoundColumnRef(col) = nth.arg.as_ref() {
+                            col.column.index
+                        } else {
+                            return Err(ErrorCode::Internal(
+                                "Window's nth_value function argument must be a BoundColumnRef"
+                                    .to_string(),
+                            ));
+                        };
+                        WindowFunction::NthValue(NthValueFunctionDesc {
+                            n: nth.n,
+                            arg,
+                            ignore_nulls: nth.ignore_nulls,
+                            return_type: *nth.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::Ntile(ntile) => {
+                        WindowFunction::Ntile(NtileFunctionDesc { n: ntile.n })
+                    }
+                    WindowFuncType::CumeDist => WindowFunction::CumeDist,
                 };
 
                 Ok(PhysicalPlan::Window(Window {
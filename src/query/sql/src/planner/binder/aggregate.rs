@@ -40,6 +40,9 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
+use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::WindowFunc;
@@ -100,7 +103,7 @@ impl<'a> AggregateRewriter<'a> {
     pub fn visit(&mut self, scalar: &ScalarExpr) -> Result<ScalarExpr> {
         match scalar {
             ScalarExpr::BoundColumnRef(_) => Ok(scalar.clone()),
-            ScalarExpr::ConstantExpr(_) => Ok(scalar.clone()),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => Ok(scalar.clone()),
             ScalarExpr::FunctionCall(func) => {
                 if func.func_name.eq_ignore_ascii_case("grouping") {
                     return self.replace_grouping(func);
@@ -118,6 +121,23 @@ impl<'a> AggregateRewriter<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
@@ -166,6 +186,25 @@ impl<'a> AggregateRewriter<'a> {
                             return_type: agg.return_type.clone(),
                         })
                     }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let default = match &lag_lead.default {
+                            Some(default) => Some(Box::new(self.visit(default)?)),
+                            None => None,
+                        };
+                        WindowFuncType::LagLead(LagLeadFunction {
+                            is_lag: lag_lead.is_lag,
+                            arg: Box::new(self.visit(&lag_lead.arg)?),
+                            offset: lag_lead.offset,
+                            default,
+                            return_type: lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth) => WindowFuncType::NthValue(NthValueFunction {
+                        n: nth.n,
+                        arg: Box::new(self.visit(&nth.arg)?),
+                        ignore_nulls: nth.ignore_nulls,
+                        return_type: nth.return_type.clone(),
+                    }),
                     func => func.clone(),
                 };
 
@@ -251,11 +251,48 @@ impl<'a> Binder {
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(bind_context, stmt)
+                    .await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
 
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
 
+            // Sequences
+            Statement::ShowSequences => {
+                self.bind_rewrite_to_query(bind_context, "SELECT name, start, increment, current, comment, created_on, updated_on FROM system.sequences ORDER BY name", RewriteKind::ShowSequences)
+                    .await?
+            }
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt).await?,
+            Statement::DropSequence(stmt) => self.bind_drop_sequence(stmt).await?,
+
+            // Tasks
+            Statement::ShowTasks => {
+                self.bind_rewrite_to_query(bind_context, "SELECT name, state, schedule, after, query, comment, owner, created_on, updated_on FROM system.tasks ORDER BY name", RewriteKind::ShowTasks)
+                    .await?
+            }
+            Statement::CreateTask(stmt) => self.bind_create_task(stmt).await?,
+            Statement::AlterTask(stmt) => self.bind_alter_task(stmt).await?,
+            Statement::DropTask(stmt) => self.bind_drop_task(stmt).await?,
+            Statement::ExecuteTask(stmt) => self.bind_execute_task(stmt).await?,
+
+            // Streams
+            Statement::ShowStreams => {
+                self.bind_rewrite_to_query(bind_context, "SELECT database, name, created_on FROM system.tables WHERE engine = 'STREAM' ORDER BY database, name", RewriteKind::ShowStreams)
+                    .await?
+            }
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
+
+            // Masking policies
+            Statement::CreateMaskingPolicy(stmt) => self.bind_create_masking_policy(stmt).await?,
+            Statement::DropMaskingPolicy(stmt) => self.bind_drop_masking_policy(stmt).await?,
+
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
             Statement::DropUser { if_exists, user } => Plan::DropUser(Box::new(DropUserPlan {
@@ -311,6 +348,14 @@ impl<'a> Binder {
                     }
                 }
                 self.bind_replace(bind_context, stmt).await?},
+            Statement::MergeInto(stmt) => {
+                if let Some(hints) = &stmt.hints {
+                    if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
+                        warn!("In MERGE INTO resolve optimize hints {:?} failed, err: {:?}", hints, e);
+                    }
+                }
+                self.bind_merge_into(bind_context, stmt).await?
+            }
             Statement::Delete {
                 hints,
                 table_reference,
@@ -333,6 +378,11 @@ impl<'a> Binder {
                 self.bind_update(bind_context, stmt).await?
             },
 
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
+
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
             Statement::ShowGrants { principal } => Plan::ShowGrants(Box::new(ShowGrantsPlan {
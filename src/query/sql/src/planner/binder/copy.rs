@@ -296,7 +296,7 @@ impl<'a> Binder {
 
         let schema = match columns {
             Some(cols) => self.schema_project(&table.schema(), cols)?,
-            None => table.schema(),
+            None => Arc::new(table.schema().remove_computed_fields()),
         };
 
         if matches!(stage_info.file_format_params, FileFormatParams::Parquet(_)) {
@@ -132,7 +132,7 @@ impl Binder {
         Ok(())
     }
 
-    fn rewrite_query_with_database(query: &mut Query, name: &str) {
+    pub(in crate::planner::binder) fn rewrite_query_with_database(query: &mut Query, name: &str) {
         if let SetExpr::Select(stmt) = &mut query.body {
             if let TableReference::Table { database, .. } = &mut stmt.from[0] {
                 if database.is_none() {
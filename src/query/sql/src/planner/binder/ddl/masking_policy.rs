@@ -0,0 +1,91 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateMaskingPolicyStmt;
+use common_ast::ast::DropMaskingPolicyStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::CreateMaskingPolicyPlan;
+use crate::plans::DropMaskingPolicyPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_masking_policy(
+        &mut self,
+        stmt: &CreateMaskingPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateMaskingPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        // A masking policy is applied to exactly one column, so it takes exactly
+        // one argument whose type must match the returned type.
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "Masking policy {} must take exactly one argument, but got {}",
+                name,
+                args.len()
+            )));
+        }
+        let (arg_name, arg_type) = &args[0];
+        let arg_type = resolve_type_name(arg_type)?;
+        let return_type = resolve_type_name(return_type)?;
+        if arg_type.remove_nullable() != return_type.remove_nullable() {
+            return Err(ErrorCode::SemanticError(format!(
+                "The return type {} of masking policy {} must be the same as the argument type {}",
+                return_type.sql_name(),
+                name,
+                arg_type.sql_name()
+            )));
+        }
+
+        let plan = CreateMaskingPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: self.normalize_object_identifier(name),
+            args: vec![(
+                self.normalize_object_identifier(arg_name),
+                arg_type.sql_name(),
+            )],
+            return_type: return_type.sql_name(),
+            body: body.to_string(),
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateMaskingPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_masking_policy(
+        &mut self,
+        stmt: &DropMaskingPolicyStmt,
+    ) -> Result<Plan> {
+        let DropMaskingPolicyStmt { if_exists, name } = stmt;
+
+        let plan = DropMaskingPolicyPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: self.normalize_object_identifier(name),
+        };
+        Ok(Plan::DropMaskingPolicy(Box::new(plan)))
+    }
+}
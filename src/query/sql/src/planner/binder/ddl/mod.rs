@@ -17,8 +17,12 @@ mod catalog;
 mod column;
 mod database;
 mod index;
+mod masking_policy;
 mod role;
+mod sequence;
 mod share;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod view;
@@ -20,6 +20,7 @@ use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
 use common_ast::ast::ColumnDefinition;
+use common_ast::ast::ColumnExpr;
 use common_ast::ast::CompactTarget;
 use common_ast::ast::CreateTableSource;
 use common_ast::ast::CreateTableStmt;
@@ -53,6 +54,7 @@ use common_exception::Result;
 use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
@@ -77,6 +79,7 @@ use crate::binder::Visibility;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::parse_computed_expr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
@@ -90,6 +93,7 @@ use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
@@ -97,6 +101,7 @@ use crate::plans::ReclusterTablePlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
+use crate::plans::SetColumnMaskingPolicyPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
@@ -209,6 +214,74 @@ impl Binder {
         })))
     }
 
+    /// Fill the options of a fuse table that should be persisted.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn fill_fuse_table_options(
+        &self,
+        catalog: &str,
+        database: &str,
+        storage_params: &Option<StorageParams>,
+        options: &mut BTreeMap<String, String>,
+    ) -> Result<()> {
+        // Currently, [Table] can not accesses its database id yet, thus
+        // here we keep the db id AS an entry of `table_meta.options`.
+        //
+        // To make the unit/stateless test cases (`show create ..`) easier,
+        // here we care about the FUSE engine only.
+        //
+        // Later, when database id is kept, let say in `TableInfo`, we can
+        // safely eliminate this "FUSE" constant and the table meta option entry.
+        let catalog = self.ctx.get_catalog(catalog)?;
+        let db = catalog
+            .get_database(&self.ctx.get_tenant(), database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+        options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+
+        let config = GlobalConfig::instance();
+        let is_blocking_fs = matches!(
+            storage_params.as_ref().unwrap_or(&config.storage.params),
+            StorageParams::Fs(_)
+        );
+
+        // we should persist the storage format and compression type instead of using the default value in fuse table
+        if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
+            let default_storage_format = match config.query.default_storage_format.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "native"
+                    } else {
+                        "parquet"
+                    }
+                }
+                _ => config.query.default_storage_format.as_str(),
+            };
+            options.insert(
+                OPT_KEY_STORAGE_FORMAT.to_owned(),
+                default_storage_format.to_owned(),
+            );
+        }
+
+        if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
+            let default_compression = match config.query.default_compression.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "lz4"
+                    } else {
+                        "zstd"
+                    }
+                }
+                _ => config.query.default_compression.as_str(),
+            };
+            options.insert(
+                OPT_KEY_TABLE_COMPRESSION.to_owned(),
+                default_compression.to_owned(),
+            );
+        }
+
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_describe_table(
         &mut self,
@@ -422,61 +495,12 @@ impl Binder {
         };
 
         if engine == Engine::Fuse {
-            // Currently, [Table] can not accesses its database id yet, thus
-            // here we keep the db id AS an entry of `table_meta.options`.
-            //
-            // To make the unit/stateless test cases (`show create ..`) easier,
-            // here we care about the FUSE engine only.
-            //
-            // Later, when database id is kept, let say in `TableInfo`, we can
-            // safely eliminate this "FUSE" constant and the table meta option entry.
-            let catalog = self.ctx.get_catalog(&catalog)?;
-            let db = catalog
-                .get_database(&self.ctx.get_tenant(), &database)
+            self.fill_fuse_table_options(&catalog, &database, &storage_params, &mut options)
                 .await?;
-            let db_id = db.get_db_info().ident.db_id;
-            options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
-
-            let config = GlobalConfig::instance();
-            let is_blocking_fs = matches!(
-                storage_params.as_ref().unwrap_or(&config.storage.params),
-                StorageParams::Fs(_)
-            );
-
-            // we should persist the storage format and compression type instead of using the default value in fuse table
-            if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
-                let default_storage_format = match config.query.default_storage_format.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "native"
-                        } else {
-                            "parquet"
-                        }
-                    }
-                    _ => config.query.default_storage_format.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_STORAGE_FORMAT.to_owned(),
-                    default_storage_format.to_owned(),
-                );
-            }
-
-            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
-                let default_compression = match config.query.default_compression.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "lz4"
-                        } else {
-                            "zstd"
-                        }
-                    }
-                    _ => config.query.default_compression.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_TABLE_COMPRESSION.to_owned(),
-                    default_compression.to_owned(),
-                );
-            }
+        } else if schema.fields().iter().any(|f| f.computed_expr().is_some()) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Computed columns are only supported by the FUSE engine, but got {engine}"
+            )));
         }
 
         let cluster_key = {
@@ -622,6 +646,40 @@ impl Binder {
                     field_comments,
                 })))
             }
+            AlterTableAction::ModifyColumn { column } => {
+                let (schema, field_default_exprs, mut field_comments) = self
+                    .analyze_create_table_schema_by_columns(&[column.clone()], true)
+                    .await?;
+                let field = schema
+                    .field(0)
+                    .clone()
+                    .with_default_expr(field_default_exprs[0].clone());
+                Ok(Plan::ModifyTableColumn(Box::new(ModifyTableColumnPlan {
+                    catalog,
+                    database,
+                    table,
+                    field,
+                    field_comment: field_comments.pop().unwrap_or_default(),
+                })))
+            }
+            AlterTableAction::SetMaskingPolicy { column, policy } => Ok(
+                Plan::SetColumnMaskingPolicy(Box::new(SetColumnMaskingPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                    column: self.normalize_object_identifier(column),
+                    policy: Some(self.normalize_object_identifier(policy)),
+                })),
+            ),
+            AlterTableAction::UnsetMaskingPolicy { column } => Ok(Plan::SetColumnMaskingPolicy(
+                Box::new(SetColumnMaskingPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                    column: self.normalize_object_identifier(column),
+                    policy: None,
+                }),
+            )),
             AlterTableAction::DropColumn { column } => {
                 Ok(Plan::DropTableColumn(Box::new(DropTableColumnPlan {
                     catalog,
@@ -920,9 +978,22 @@ impl Binder {
             let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
             let schema_data_type = resolve_type_name(&column.data_type)?;
 
-            fields.push(TableField::new(&name, schema_data_type.clone()));
+            let computed_expr = match &column.expr {
+                Some(ColumnExpr::Virtual(expr)) => Some(ComputedExpr::Virtual(expr.to_string())),
+                Some(ColumnExpr::Stored(expr)) => Some(ComputedExpr::Stored(expr.to_string())),
+                _ => None,
+            };
+            if is_add_column && computed_expr.is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Computed column `{name}` can only be defined when the table is created"
+                )));
+            }
+
+            fields.push(
+                TableField::new(&name, schema_data_type.clone()).with_computed_expr(computed_expr),
+            );
             fields_default_expr.push({
-                if let Some(default_expr) = &column.default_expr {
+                if let Some(ColumnExpr::Default(default_expr)) = &column.expr {
                     let (expr, _) = scalar_binder.bind(default_expr).await?;
                     let is_try = schema_data_type.is_nullable();
                     let cast_expr = ScalarExpr::CastExpr(CastExpr {
@@ -960,6 +1031,18 @@ impl Binder {
         }
         let schema = TableSchemaRefExt::create(fields);
         Self::validate_create_table_schema(&schema)?;
+
+        // Computed columns can only reference the non-computed columns of the table.
+        for field in schema.fields() {
+            if let Some(computed_expr) = field.computed_expr() {
+                parse_computed_expr(
+                    self.ctx.clone(),
+                    schema.clone(),
+                    &DataType::from(field.data_type()),
+                    computed_expr.expr(),
+                )?;
+            }
+        }
         Ok((schema, fields_default_expr, fields_comments))
     }
 
@@ -1040,7 +1123,9 @@ impl Binder {
         cluster_by: &[Expr],
         schema: TableSchemaRef,
     ) -> Result<Vec<String>> {
-        // Build a temporary BindContext to resolve the expr
+        // Build a temporary BindContext to resolve the expr,
+        // the virtual computed columns are not stored and can't be used as cluster keys.
+        let schema = schema.remove_virtual_computed_fields();
         let mut bind_context = BindContext::new();
         for (index, field) in schema.fields().iter().enumerate() {
             let column = ColumnBinding {
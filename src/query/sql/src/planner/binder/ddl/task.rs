@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::AlterTaskAction;
+use common_ast::ast::AlterTaskStmt;
+use common_ast::ast::CreateTaskStmt;
+use common_ast::ast::DropTaskStmt;
+use common_ast::ast::ExecuteTaskStmt;
+use common_ast::ast::Statement;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TaskState;
+
+use crate::binder::Binder;
+use crate::plans::AlterTaskPlan;
+use crate::plans::CreateTaskPlan;
+use crate::plans::DropTaskPlan;
+use crate::plans::ExecuteTaskPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_task(
+        &mut self,
+        stmt: &CreateTaskStmt,
+    ) -> Result<Plan> {
+        let CreateTaskStmt {
+            if_not_exists,
+            task,
+            schedule,
+            after,
+            comment,
+            statement,
+        } = stmt;
+
+        if schedule.is_none() && after.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "A task must have a SCHEDULE or be run AFTER other tasks",
+            ));
+        }
+
+        if matches!(
+            statement.as_ref(),
+            Statement::ShowTasks
+                | Statement::CreateTask(_)
+                | Statement::AlterTask(_)
+                | Statement::DropTask(_)
+                | Statement::ExecuteTask(_)
+        ) {
+            return Err(ErrorCode::SemanticError(
+                "A task can not run a task statement",
+            ));
+        }
+
+        let task = self.normalize_object_identifier(task);
+        let after = after
+            .iter()
+            .map(|t| self.normalize_object_identifier(t))
+            .collect::<Vec<_>>();
+        if after.contains(&task) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Task {task} can not run after itself"
+            )));
+        }
+
+        let plan = CreateTaskPlan {
+            if_not_exists: *if_not_exists,
+            task,
+            schedule: schedule.clone(),
+            after,
+            comment: comment.clone(),
+            query: statement.to_string(),
+        };
+        Ok(Plan::CreateTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_task(
+        &mut self,
+        stmt: &AlterTaskStmt,
+    ) -> Result<Plan> {
+        let AlterTaskStmt { task, action } = stmt;
+
+        let state = match action {
+            AlterTaskAction::Suspend => TaskState::Suspended,
+            AlterTaskAction::Resume => TaskState::Started,
+        };
+
+        let plan = AlterTaskPlan {
+            task: self.normalize_object_identifier(task),
+            state,
+        };
+        Ok(Plan::AlterTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_task(
+        &mut self,
+        stmt: &DropTaskStmt,
+    ) -> Result<Plan> {
+        let DropTaskStmt { if_exists, task } = stmt;
+
+        let plan = DropTaskPlan {
+            if_exists: *if_exists,
+            task: self.normalize_object_identifier(task),
+        };
+        Ok(Plan::DropTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_execute_task(
+        &mut self,
+        stmt: &ExecuteTaskStmt,
+    ) -> Result<Plan> {
+        let ExecuteTaskStmt { task } = stmt;
+
+        let plan = ExecuteTaskPlan {
+            task: self.normalize_object_identifier(task),
+        };
+        Ok(Plan::ExecuteTask(Box::new(plan)))
+    }
+}
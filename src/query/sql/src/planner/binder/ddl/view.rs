@@ -12,17 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::AlterViewStmt;
+use common_ast::ast::CreateMaterializedViewStmt;
 use common_ast::ast::CreateViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
+use common_ast::ast::Query;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_table_schema;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -117,4 +132,166 @@ impl Binder {
         };
         Ok(Plan::DropView(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        Self::check_materialized_view_support(query)?;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        // The materialized views of the base table should not be used to plan the view itself.
+        bind_context.planning_agg_index = true;
+        let (_, query_bind_context) = self.bind_query(bind_context, query).await?;
+        bind_context.planning_agg_index = false;
+
+        let tables = self.metadata.read().tables().to_vec();
+        if tables.len() != 1 {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view currently only support single table",
+            ));
+        }
+        let table_entry = &tables[0];
+        let table = table_entry.table();
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table engine {} does not support materialized view",
+                table.engine()
+            )));
+        }
+        if table_entry.catalog() != catalog {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view should be in the same catalog as its base table",
+            ));
+        }
+
+        let schema = infer_table_schema(&query_bind_context.output_schema())?;
+        let mut options = BTreeMap::new();
+        self.fill_fuse_table_options(&catalog, &database, &None, &mut options)
+            .await?;
+
+        let mut query = *query.clone();
+        Self::rewrite_query_with_database(&mut query, table_entry.database());
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+            schema,
+            options,
+            subquery: query.to_string(),
+            base_database: table_entry.database().to_string(),
+            base_table: table_entry.name().to_string(),
+            base_table_id: table.get_id(),
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
+
+    /// Materialized views are refreshed incrementally and re-aggregated by the queries
+    /// on the base table, so only the aggregations that can be merged are supported.
+    fn check_materialized_view_support(query: &Query) -> Result<()> {
+        let err = Err(ErrorCode::SemanticError(format!(
+            "Currently materialized view just support simple aggregation query, like: {}",
+            "SELECT <column>, <sum|count|min|max>(<column>) FROM <table> WHERE ... GROUP BY <column>"
+        )));
+
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return err;
+        }
+
+        let stmt = match &query.body {
+            SetExpr::Select(stmt) => stmt,
+            _ => return err,
+        };
+        if stmt.distinct
+            || stmt.having.is_some()
+            || stmt.window_list.is_some()
+            || stmt.from.len() != 1
+        {
+            return err;
+        }
+        if !matches!(&stmt.from[0], TableReference::Table {
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+            ..
+        }) {
+            return err;
+        }
+
+        // The data of the view is appended by incremental refreshing, so the
+        // aggregation must be grouped to be computed again over the view.
+        match &stmt.group_by {
+            Some(GroupBy::Normal(items))
+                if items
+                    .iter()
+                    .all(|item| matches!(item, Expr::ColumnRef { .. })) => {}
+            _ => return err,
+        }
+        for target in &stmt.select_list {
+            match target {
+                SelectTarget::AliasedExpr { expr, .. } => match expr.as_ref() {
+                    Expr::ColumnRef { .. } => {}
+                    Expr::CountAll { .. } => {}
+                    Expr::FunctionCall {
+                        distinct: false,
+                        name,
+                        args,
+                        params,
+                        window: None,
+                        ..
+                    } if params.is_empty()
+                        && args.iter().all(|arg| matches!(arg, Expr::ColumnRef { .. }))
+                        && ["sum", "count", "min", "max"]
+                            .contains(&name.name.to_lowercase().as_str()) => {}
+                    _ => return err,
+                },
+                SelectTarget::QualifiedName { .. } => return err,
+            }
+        }
+
+        Ok(())
+    }
 }
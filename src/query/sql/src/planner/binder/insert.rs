@@ -18,6 +18,7 @@ use common_ast::ast::Identifier;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
 use common_ast::ast::Statement;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
@@ -41,9 +42,16 @@ impl Binder {
         let fields = columns
             .iter()
             .map(|ident| {
-                schema
-                    .field_with_name(&normalize_identifier(ident, &self.name_resolution_ctx).name)
-                    .map(|v| v.clone())
+                let field = schema.field_with_name(
+                    &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                )?;
+                if field.computed_expr().is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "The value specified for computed column '{}' is not allowed",
+                        field.name()
+                    )));
+                }
+                Ok(field.clone())
             })
             .collect::<Result<Vec<_>>>()?;
         Ok(TableSchemaRefExt::create(fields))
@@ -73,7 +81,7 @@ impl Binder {
         let table_id = table.get_id();
 
         let schema = if columns.is_empty() {
-            table.schema()
+            Arc::new(table.schema().remove_computed_fields())
         } else {
             self.schema_project(&table.schema(), columns)?
         };
@@ -0,0 +1,415 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MatchedClause;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::ast::TableReference;
+use common_ast::ast::UnmatchedClause;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::FieldIndex;
+use common_expression::TableSchemaRef;
+use common_expression::ROW_ID_COL_NAME;
+
+use crate::binder::Binder;
+use crate::binder::Recursion;
+use crate::binder::ScalarBinder;
+use crate::binder::ScalarVisitor;
+use crate::binder::INTERNAL_COLUMN_FACTORY;
+use crate::field_default_value;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::MatchedEvaluator;
+use crate::plans::MergeInto;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::plans::UnmatchedEvaluator;
+use crate::BindContext;
+use crate::ColumnBinding;
+use crate::IndexType;
+use crate::Visibility;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table,
+            target_alias,
+            source,
+            join_expr,
+            merge_options,
+            ..
+        } = stmt;
+
+        if merge_options.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "at least one matched or unmatched clause for merge into",
+            ));
+        }
+        let (matched_clauses, unmatched_clauses) = stmt.split_clauses();
+
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let target_table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        if !target_table.support_row_id_column() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "merge into is not supported for table {}, engine: {}",
+                table_name,
+                target_table.engine()
+            )));
+        }
+        let table_id = target_table.get_id();
+        // The virtual computed columns are not stored, the merged rows are written without them.
+        let table_schema = Arc::new(target_table.schema().remove_virtual_computed_fields());
+
+        // Only the matched rows are needed if there is no `WHEN NOT MATCHED` clause,
+        // otherwise all the source rows must be kept.
+        let join_op = if unmatched_clauses.is_empty() {
+            JoinOperator::Inner
+        } else {
+            JoinOperator::RightOuter
+        };
+        let join = TableReference::Join {
+            span: None,
+            join: Join {
+                op: join_op.clone(),
+                condition: JoinCondition::On(Box::new(join_expr.clone())),
+                left: Box::new(TableReference::Table {
+                    span: None,
+                    catalog: catalog.clone(),
+                    database: database.clone(),
+                    table: table.clone(),
+                    alias: target_alias.clone(),
+                    travel_point: None,
+                    pivot: None,
+                    unpivot: None,
+                }),
+                right: Box::new(source.clone()),
+            },
+        };
+
+        // The target table is the left side of the join, so it will be bound first.
+        let target_table_index = self.metadata.read().tables().len();
+        let (join_s_expr, mut context) = self.bind_table_reference(bind_context, &join).await?;
+
+        // Add `_row_id` of target table, it's used to locate the matched rows.
+        let (s_expr, row_id_index) = self.add_row_id_column(
+            &mut context,
+            join_s_expr,
+            target_table_index,
+            &database_name,
+            target_alias.as_ref().map_or(table_name.clone(), |alias| {
+                normalize_identifier(&alias.name, &self.name_resolution_ctx).name
+            }),
+            join_op == JoinOperator::RightOuter,
+        );
+
+        let mut matched_evaluators = Vec::with_capacity(matched_clauses.len());
+        for clause in &matched_clauses {
+            matched_evaluators.push(
+                self.bind_matched_clause(
+                    &mut context,
+                    clause,
+                    table_schema.clone(),
+                    target_table_index,
+                )
+                .await?,
+            );
+        }
+
+        let mut unmatched_evaluators = Vec::with_capacity(unmatched_clauses.len());
+        for clause in &unmatched_clauses {
+            unmatched_evaluators.push(
+                self.bind_unmatched_clause(&mut context, clause, table_schema.clone())
+                    .await?,
+            );
+        }
+
+        Ok(Plan::MergeInto(Box::new(MergeInto {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id,
+            input: Box::new(s_expr),
+            bind_context: Box::new(context),
+            meta_data: self.metadata.clone(),
+            matched_evaluators,
+            unmatched_evaluators,
+            row_id_index,
+        })))
+    }
+
+    fn add_row_id_column(
+        &self,
+        context: &mut BindContext,
+        s_expr: SExpr,
+        table_index: IndexType,
+        database_name: &str,
+        table_name: String,
+        nullable: bool,
+    ) -> (SExpr, IndexType) {
+        let internal_column = INTERNAL_COLUMN_FACTORY
+            .get_internal_column(ROW_ID_COL_NAME)
+            .unwrap();
+        let column_index = self
+            .metadata
+            .write()
+            .add_internal_column(table_index, internal_column);
+
+        let data_type = DataType::Number(NumberDataType::UInt64);
+        context.add_column_binding(ColumnBinding {
+            database_name: Some(database_name.to_string()),
+            table_name: Some(table_name),
+            table_index: Some(table_index),
+            column_name: ROW_ID_COL_NAME.to_string(),
+            index: column_index,
+            data_type: Box::new(if nullable {
+                data_type.wrap_nullable()
+            } else {
+                data_type
+            }),
+            visibility: Visibility::InVisible,
+        });
+
+        let s_expr = SExpr::add_internal_column_index(&s_expr, table_index, column_index);
+        (s_expr, column_index)
+    }
+
+    async fn bind_matched_clause(
+        &mut self,
+        context: &mut BindContext,
+        clause: &MatchedClause,
+        table_schema: TableSchemaRef,
+        target_table_index: IndexType,
+    ) -> Result<MatchedEvaluator> {
+        let condition = self
+            .bind_merge_condition(context, &clause.selection)
+            .await?;
+
+        let update = match &clause.operation {
+            MatchOperation::Delete => None,
+            MatchOperation::Update { update_list } => {
+                let mut scalar_binder = ScalarBinder::new(
+                    context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                let mut update_columns: HashMap<FieldIndex, ScalarExpr> =
+                    HashMap::with_capacity(update_list.len());
+                for update_expr in update_list {
+                    let col_name =
+                        normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+                    let index = table_schema.index_of(&col_name)?;
+                    if update_columns.contains_key(&index) {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Multiple assignments in the single statement to column `{}`",
+                            col_name
+                        )));
+                    }
+
+                    let (scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
+                    check_no_subquery(&scalar)?;
+                    update_columns.insert(index, scalar);
+                }
+
+                // The columns not in the update list keep their values.
+                let mut values = Vec::with_capacity(table_schema.num_fields());
+                for (index, field) in table_schema.fields().iter().enumerate() {
+                    let scalar = match update_columns.remove(&index) {
+                        Some(scalar) => scalar,
+                        None => {
+                            let column = context
+                                .columns
+                                .iter()
+                                .find(|column| {
+                                    column.table_index == Some(target_table_index)
+                                        && column.column_name == field.name().as_str()
+                                })
+                                .ok_or_else(|| {
+                                    ErrorCode::Internal(format!(
+                                        "column {} of target table is not found",
+                                        field.name()
+                                    ))
+                                })?;
+                            ScalarExpr::BoundColumnRef(BoundColumnRef {
+                                span: None,
+                                column: column.clone(),
+                            })
+                        }
+                    };
+                    values.push(cast_to_field_type(
+                        scalar,
+                        DataType::from(field.data_type()),
+                    ));
+                }
+                Some(values)
+            }
+        };
+
+        Ok(MatchedEvaluator { condition, update })
+    }
+
+    async fn bind_unmatched_clause(
+        &mut self,
+        context: &mut BindContext,
+        clause: &UnmatchedClause,
+        table_schema: TableSchemaRef,
+    ) -> Result<UnmatchedEvaluator> {
+        let condition = self
+            .bind_merge_condition(context, &clause.selection)
+            .await?;
+
+        let insert = &clause.insert_operation;
+        let columns = match &insert.columns {
+            Some(columns) => {
+                let mut indices = Vec::with_capacity(columns.len());
+                for column in columns {
+                    let col_name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                    let index = table_schema.index_of(&col_name)?;
+                    if indices.contains(&index) {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Duplicate column `{}` in the insert clause of merge into",
+                            col_name
+                        )));
+                    }
+                    indices.push(index);
+                }
+                indices
+            }
+            None => (0..table_schema.num_fields()).collect(),
+        };
+        if columns.len() != insert.values.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Number of columns ({}) does not match number of values ({}) in merge into",
+                columns.len(),
+                insert.values.len()
+            )));
+        }
+
+        let mut scalar_binder = ScalarBinder::new(
+            context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let mut bound_values = HashMap::with_capacity(columns.len());
+        for (index, expr) in columns.iter().zip(insert.values.iter()) {
+            let (scalar, _) = scalar_binder.bind(expr).await?;
+            check_no_subquery(&scalar)?;
+            bound_values.insert(*index, scalar);
+        }
+
+        // The columns not in the insert list are filled with default values.
+        let mut values = Vec::with_capacity(table_schema.num_fields());
+        for (index, field) in table_schema.fields().iter().enumerate() {
+            let scalar = match bound_values.remove(&index) {
+                Some(scalar) => scalar,
+                None => ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: None,
+                    value: field_default_value(self.ctx.clone(), field)?,
+                }),
+            };
+            values.push(cast_to_field_type(
+                scalar,
+                DataType::from(field.data_type()),
+            ));
+        }
+
+        Ok(UnmatchedEvaluator { condition, values })
+    }
+
+    async fn bind_merge_condition(
+        &mut self,
+        context: &mut BindContext,
+        selection: &Option<Expr>,
+    ) -> Result<Option<ScalarExpr>> {
+        match selection {
+            Some(expr) => {
+                let mut scalar_binder = ScalarBinder::new(
+                    context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                let (scalar, _) = scalar_binder.bind(expr).await?;
+                check_no_subquery(&scalar)?;
+                Ok(Some(scalar))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+// Find subqueries nested anywhere in a scalar, e.g. `a = (SELECT ...)`.
+struct SubqueryFinder {
+    found: Option<Span>,
+}
+
+impl ScalarVisitor for SubqueryFinder {
+    fn pre_visit(mut self, scalar: &ScalarExpr) -> Result<Recursion<Self>> {
+        if let ScalarExpr::SubqueryExpr(subquery) = scalar {
+            self.found = Some(subquery.span);
+            return Ok(Recursion::Stop(self));
+        }
+        Ok(Recursion::Continue(self))
+    }
+}
+
+fn check_no_subquery(scalar: &ScalarExpr) -> Result<()> {
+    // TODO: support subquery in the clauses of merge into.
+    let finder = scalar.accept(SubqueryFinder { found: None })?;
+    if let Some(span) = finder.found {
+        return Err(
+            ErrorCode::SemanticError("Merge into does not support subquery temporarily")
+                .set_span(span),
+        );
+    }
+    Ok(())
+}
+
+fn cast_to_field_type(scalar: ScalarExpr, target_type: DataType) -> ScalarExpr {
+    ScalarExpr::CastExpr(CastExpr {
+        span: scalar.span(),
+        is_try: false,
+        argument: Box::new(scalar),
+        target_type: Box::new(target_type),
+    })
+}
@@ -29,6 +29,8 @@ mod join;
 mod kill;
 mod limit;
 mod location;
+mod merge_into;
+mod placeholder;
 mod presign;
 mod project;
 mod project_set;
@@ -52,6 +54,9 @@ pub use builders::*;
 pub use copy::parse_stage_location;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::parse_uri_location;
+pub use placeholder::bind_placeholders;
+pub use placeholder::read_table_statistics;
+pub use placeholder::TableStatisticsEntry;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
 pub use scalar_visitor::*;
@@ -0,0 +1,266 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_catalog::table::ColumnStatistics;
+use common_catalog::table::TableStatistics;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::Scalar;
+
+use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Exchange;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::WindowFuncType;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// The latest statistics of a table used by a prepared statement.
+pub struct TableStatisticsEntry {
+    statistics: Option<TableStatistics>,
+    /// Keyed by the index of the column.
+    col_stats: HashMap<IndexType, Option<ColumnStatistics>>,
+}
+
+/// Read the statistics of the tables in the metadata, which replace the statistics
+/// of the scans in a plan bound by `bind_placeholders`.
+#[async_backtrace::framed]
+pub async fn read_table_statistics(
+    metadata: &MetadataRef,
+) -> Result<HashMap<IndexType, TableStatisticsEntry>> {
+    let tables = metadata.read().tables().to_vec();
+    let mut entries = HashMap::with_capacity(tables.len());
+    for table in tables {
+        let columns = metadata.read().columns_by_table_index(table.index());
+        let provider = table.table().column_statistics_provider().await?;
+        let mut col_stats = HashMap::new();
+        for column in columns {
+            if let ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_index,
+                path_indices: None,
+                leaf_index: Some(leaf_index),
+                ..
+            }) = column
+            {
+                col_stats.insert(
+                    column_index,
+                    provider.column_statistics(leaf_index as ColumnId),
+                );
+            }
+        }
+        entries.insert(table.index(), TableStatisticsEntry {
+            statistics: table.table().table_statistics()?,
+            col_stats,
+        });
+    }
+    Ok(entries)
+}
+
+/// Bind the parameters of a prepared statement to a plan generated by
+/// `Planner::bind_sql_with_placeholders`, every `PlaceholderExpr` in the plan
+/// is replaced by a `ConstantExpr` of the parameter with the same index.
+///
+/// The bound plan uses `metadata`, a copy of the metadata of the cached plan that
+/// can be changed by the optimizer, and the scans read the latest `statistics`.
+///
+/// Only `Plan::Query` can be bound, the other plans keep the SQL text of the
+/// statement and must be planned again with the parameters.
+pub fn bind_placeholders(
+    plan: &Plan,
+    params: &[Scalar],
+    metadata: MetadataRef,
+    statistics: &HashMap<IndexType, TableStatisticsEntry>,
+) -> Result<Plan> {
+    match plan {
+        Plan::Query {
+            s_expr,
+            bind_context,
+            rewrite_kind,
+            ignore_result,
+            ..
+        } => {
+            let binder = PlaceholderBinder { params, statistics };
+            Ok(Plan::Query {
+                s_expr: Box::new(binder.bind_s_expr(s_expr)?),
+                metadata,
+                bind_context: bind_context.clone(),
+                rewrite_kind: rewrite_kind.clone(),
+                // The formatted AST contains `?` instead of the parameters,
+                // it can't be used as the key of the query result cache.
+                formatted_ast: None,
+                ignore_result: *ignore_result,
+            })
+        }
+        _ => Err(ErrorCode::Internal(format!(
+            "Cannot bind parameters to plan {}",
+            plan
+        ))),
+    }
+}
+
+struct PlaceholderBinder<'a> {
+    params: &'a [Scalar],
+    statistics: &'a HashMap<IndexType, TableStatisticsEntry>,
+}
+
+impl<'a> PlaceholderBinder<'a> {
+    fn bind_s_expr(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.bind_s_expr(child))
+            .collect::<Result<Vec<_>>>()?;
+        let plan = self.bind_rel_operator(s_expr.plan().clone())?;
+        Ok(s_expr.replace_children(children).replace_plan(plan))
+    }
+
+    fn bind_rel_operator(&self, mut plan: RelOperator) -> Result<RelOperator> {
+        match &mut plan {
+            RelOperator::Scan(scan) => {
+                if let Some(entry) = self.statistics.get(&scan.table_index) {
+                    scan.statistics.statistics = entry.statistics;
+                    for (column_index, col_stat) in scan.statistics.col_stats.iter_mut() {
+                        if let Some(latest) = entry.col_stats.get(column_index) {
+                            *col_stat = latest.clone();
+                        }
+                    }
+                }
+                if let Some(predicates) = &mut scan.push_down_predicates {
+                    self.bind_scalars(predicates)?;
+                }
+                if let Some(prewhere) = &mut scan.prewhere {
+                    self.bind_scalars(&mut prewhere.predicates)?;
+                }
+                if let Some(agg_index) = &mut scan.agg_index {
+                    self.bind_items(&mut agg_index.selection)?;
+                    self.bind_scalars(&mut agg_index.predicates)?;
+                }
+            }
+            RelOperator::Join(join) => {
+                self.bind_scalars(&mut join.left_conditions)?;
+                self.bind_scalars(&mut join.right_conditions)?;
+                self.bind_scalars(&mut join.non_equi_conditions)?;
+            }
+            RelOperator::EvalScalar(eval_scalar) => self.bind_items(&mut eval_scalar.items)?,
+            RelOperator::Filter(filter) => self.bind_scalars(&mut filter.predicates)?,
+            RelOperator::Aggregate(aggregate) => {
+                self.bind_items(&mut aggregate.group_items)?;
+                self.bind_items(&mut aggregate.aggregate_functions)?;
+            }
+            RelOperator::Window(window) => {
+                self.bind_window_func(&mut window.function)?;
+                self.bind_items(&mut window.arguments)?;
+                self.bind_items(&mut window.partition_by)?;
+                for order_by in window.order_by.iter_mut() {
+                    self.bind_scalar(&mut order_by.order_by_item.scalar)?;
+                }
+            }
+            RelOperator::ProjectSet(project_set) => {
+                for srf in project_set.srfs.iter_mut() {
+                    self.bind_scalar(&mut srf.scalar)?;
+                }
+            }
+            RelOperator::RuntimeFilterSource(source) => {
+                for scalar in source.left_runtime_filters.values_mut() {
+                    self.bind_scalar(scalar)?;
+                }
+                for scalar in source.right_runtime_filters.values_mut() {
+                    self.bind_scalar(scalar)?;
+                }
+            }
+            RelOperator::Exchange(Exchange::Hash(scalars)) => self.bind_scalars(scalars)?,
+            RelOperator::Exchange(_)
+            | RelOperator::Sort(_)
+            | RelOperator::Limit(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::DummyTableScan(_)
+            | RelOperator::Pattern(_) => {}
+        }
+        Ok(plan)
+    }
+
+    fn bind_items(&self, items: &mut [ScalarItem]) -> Result<()> {
+        for item in items.iter_mut() {
+            self.bind_scalar(&mut item.scalar)?;
+        }
+        Ok(())
+    }
+
+    fn bind_scalars(&self, scalars: &mut [ScalarExpr]) -> Result<()> {
+        for scalar in scalars.iter_mut() {
+            self.bind_scalar(scalar)?;
+        }
+        Ok(())
+    }
+
+    fn bind_window_func(&self, func: &mut WindowFuncType) -> Result<()> {
+        match func {
+            WindowFuncType::Aggregate(agg) => self.bind_scalars(&mut agg.args),
+            WindowFuncType::LagLead(lag_lead) => {
+                self.bind_scalar(&mut lag_lead.arg)?;
+                if let Some(default) = &mut lag_lead.default {
+                    self.bind_scalar(default)?;
+                }
+                Ok(())
+            }
+            WindowFuncType::NthValue(nth) => self.bind_scalar(&mut nth.arg),
+            _ => Ok(()),
+        }
+    }
+
+    fn bind_scalar(&self, scalar: &mut ScalarExpr) -> Result<()> {
+        match scalar {
+            ScalarExpr::PlaceholderExpr(placeholder) => {
+                let value = self.params.get(placeholder.index).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "Parameter {} of the prepared statement is not bound",
+                        placeholder.index + 1
+                    ))
+                })?;
+                *scalar = ConstantExpr {
+                    span: placeholder.span,
+                    value: value.clone(),
+                }
+                .into();
+            }
+            ScalarExpr::WindowFunction(expr) => {
+                self.bind_window_func(&mut expr.func)?;
+                self.bind_scalars(&mut expr.partition_by)?;
+                for order_by in expr.order_by.iter_mut() {
+                    self.bind_scalar(&mut order_by.expr)?;
+                }
+            }
+            ScalarExpr::AggregateFunction(expr) => self.bind_scalars(&mut expr.args)?,
+            ScalarExpr::FunctionCall(expr) => self.bind_scalars(&mut expr.arguments)?,
+            ScalarExpr::LambdaFunction(expr) => self.bind_scalars(&mut expr.args)?,
+            ScalarExpr::CastExpr(expr) => self.bind_scalar(&mut expr.argument)?,
+            ScalarExpr::SubqueryExpr(expr) => {
+                if let Some(child_expr) = &mut expr.child_expr {
+                    self.bind_scalar(child_expr)?;
+                }
+                expr.subquery = Box::new(self.bind_s_expr(&expr.subquery)?);
+            }
+            ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => {}
+        }
+        Ok(())
+    }
+}
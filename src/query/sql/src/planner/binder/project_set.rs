@@ -14,8 +14,9 @@
 
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
-use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -49,7 +50,8 @@ impl<'a> Visitor<'a> for SrfCollector {
         name: &'a Identifier,
         args: &'a [Expr],
         params: &'a [Literal],
-        over: &'a Option<Window>,
+        over: &'a Option<WindowDesc>,
+        lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
             .get_property(&name.name)
@@ -64,6 +66,7 @@ impl<'a> Visitor<'a> for SrfCollector {
                 args: args.to_vec(),
                 params: params.to_vec(),
                 window: over.clone(),
+                lambda: lambda.clone(),
             });
         } else {
             for arg in args.iter() {
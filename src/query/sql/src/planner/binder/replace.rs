@@ -63,16 +63,9 @@ impl Binder {
         let table_id = table.get_id();
 
         let schema = if columns.is_empty() {
-            table.schema()
+            Arc::new(table.schema().remove_computed_fields())
         } else {
-            let schema = table.schema();
-            let field_indexes = columns
-                .iter()
-                .map(|ident| {
-                    schema.index_of(&normalize_identifier(ident, &self.name_resolution_ctx).name)
-                })
-                .collect::<Result<Vec<_>>>()?;
-            Arc::new(schema.project(&field_indexes))
+            self.schema_project(&table.schema(), columns)?
         };
 
         let on_conflict_fields = on_conflict_columns
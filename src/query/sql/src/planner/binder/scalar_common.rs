@@ -156,11 +156,47 @@ pub fn contain_subquery(scalar: &ScalarExpr) -> bool {
             column.column_name == format!("scalar_subquery_{}", column.index)
         }
         ScalarExpr::FunctionCall(func) => func.arguments.iter().any(contain_subquery),
+        ScalarExpr::LambdaFunction(func) => func.args.iter().any(contain_subquery),
         ScalarExpr::CastExpr(CastExpr { argument, .. }) => contain_subquery(argument),
         _ => false,
     }
 }
 
+/// check if the scalar contains any placeholder of a prepared statement
+pub fn contain_placeholder(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::PlaceholderExpr(_) => true,
+        ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => false,
+        ScalarExpr::WindowFunction(scalar) => {
+            let flag = match &scalar.func {
+                WindowFuncType::Aggregate(agg) => agg.args.iter().any(contain_placeholder),
+                WindowFuncType::LagLead(lag_lead) => {
+                    contain_placeholder(&lag_lead.arg)
+                        || lag_lead
+                            .default
+                            .as_ref()
+                            .map_or(false, |d| contain_placeholder(d))
+                }
+                WindowFuncType::NthValue(nth) => contain_placeholder(&nth.arg),
+                _ => false,
+            };
+            flag || scalar.partition_by.iter().any(contain_placeholder)
+                || scalar
+                    .order_by
+                    .iter()
+                    .any(|order| contain_placeholder(&order.expr))
+        }
+        ScalarExpr::AggregateFunction(scalar) => scalar.args.iter().any(contain_placeholder),
+        ScalarExpr::FunctionCall(scalar) => scalar.arguments.iter().any(contain_placeholder),
+        ScalarExpr::LambdaFunction(scalar) => scalar.args.iter().any(contain_placeholder),
+        ScalarExpr::CastExpr(expr) => contain_placeholder(&expr.argument),
+        ScalarExpr::SubqueryExpr(expr) => expr
+            .child_expr
+            .as_ref()
+            .map_or(false, |child| contain_placeholder(child)),
+    }
+}
+
 /// check if the scalar could be constructed by the columns
 pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) -> bool {
     if columns.contains(scalar) {
@@ -169,12 +205,20 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
 
     match scalar {
         ScalarExpr::BoundColumnRef(_) => false,
-        ScalarExpr::ConstantExpr(_) => true,
+        ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => true,
         ScalarExpr::WindowFunction(scalar) => {
             let flag = match &scalar.func {
                 WindowFuncType::Aggregate(agg) => {
                     agg.args.iter().all(|arg| prune_by_children(arg, columns))
                 }
+                WindowFuncType::LagLead(lag_lead) => {
+                    prune_by_children(&lag_lead.arg, columns)
+                        && lag_lead
+                            .default
+                            .as_ref()
+                            .map_or(true, |default| prune_by_children(default, columns))
+                }
+                WindowFuncType::NthValue(nth) => prune_by_children(&nth.arg, columns),
                 _ => false,
             };
             flag || scalar
@@ -194,6 +238,10 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
             .arguments
             .iter()
             .all(|arg| prune_by_children(arg, columns)),
+        ScalarExpr::LambdaFunction(scalar) => scalar
+            .args
+            .iter()
+            .all(|arg| prune_by_children(arg, columns)),
         ScalarExpr::CastExpr(expr) => prune_by_children(expr.argument.as_ref(), columns),
         ScalarExpr::SubqueryExpr(_) => false,
     }
@@ -33,6 +33,7 @@ use common_ast::ast::SetExpr;
 use common_ast::ast::SetOperator;
 use common_ast::ast::TableReference;
 use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::ast::WindowSpec;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -726,6 +727,7 @@ impl<'a> SelectRewriter<'a> {
                 args,
                 params: vec![],
                 window: None,
+                lambda: None,
             }),
             alias,
         }
@@ -928,7 +930,7 @@ impl<'a> SelectRewriter<'a> {
             match target {
                 SelectTarget::AliasedExpr { expr, .. } => match expr {
                     box Expr::FunctionCall { window, .. } => {
-                        if let Some(window) = window {
+                        if let Some(WindowDesc { window, .. }) = window {
                             match window {
                                 Window::WindowReference(reference) => {
                                     let window_spec = window_definitions
@@ -958,7 +960,7 @@ impl<'a> SelectRewriter<'a> {
             for order in &mut new_order_by {
                 match &mut order.expr {
                     Expr::FunctionCall { window, .. } => {
-                        if let Some(window) = window {
+                        if let Some(WindowDesc { window, .. }) = window {
                             match window {
                                 Window::WindowReference(reference) => {
                                     let window_spec = window_definitions
@@ -28,11 +28,13 @@ use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::StageTableInfo;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
@@ -43,6 +45,7 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
 use common_expression::ColumnId;
+use common_expression::ComputedExpr;
 use common_expression::ConstantFolder;
 use common_expression::FunctionKind;
 use common_expression::Scalar;
@@ -53,6 +56,7 @@ use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
+use common_pipeline_sources::input_formats::InputContext;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -60,9 +64,19 @@ use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::ResultScan;
+use common_storages_stage::StageTable;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_MASKING_POLICIES;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_TABLE_ID;
 
 use crate::binder::copy::parse_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -75,6 +89,10 @@ use crate::binder::Visibility;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -82,6 +100,7 @@ use crate::BindContext;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
 use crate::IndexType;
+use crate::MaterializedView;
 use crate::TableInternalColumn;
 use crate::VirtualColumn;
 
@@ -258,6 +277,12 @@ impl Binder {
                     }
                 }
 
+                // Materialized views are only applicable to the latest snapshot of the table.
+                if !bind_context.planning_agg_index && navigation_point.is_none() {
+                    self.resolve_materialized_views(bind_context, &catalog, &table_meta)
+                        .await?;
+                }
+
                 match table_meta.engine() {
                     "VIEW" => {
                         Self::check_view_dep(bind_context, &database, &table_name)?;
@@ -424,6 +449,7 @@ impl Binder {
                                 params: vec![],
                                 args: params.clone(),
                                 window: None,
+                                lambda: None,
                             }),
                             alias: None,
                         }],
@@ -512,39 +538,58 @@ impl Binder {
         alias: &Option<TableAlias>,
         files_to_copy: Option<Vec<StageFileInfo>>,
     ) -> Result<(SExpr, BindContext)> {
-        if matches!(stage_info.file_format_params, FileFormatParams::Parquet(..)) {
-            let read_options = ParquetReadOptions::default();
-
-            let table =
+        let table = match &stage_info.file_format_params {
+            FileFormatParams::Parquet(..) => {
+                let read_options = ParquetReadOptions::default();
                 ParquetTable::create(stage_info.clone(), files_info, read_options, files_to_copy)
+                    .await?
+            }
+            FileFormatParams::Orc(..) => {
+                // The orc files are read by the input format pipeline of the stage table,
+                // the schema is inferred from the first file.
+                let operator = StageTable::get_op(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) => files[0].path.clone(),
+                    None => files_info.first_file(&operator).await?.path.clone(),
+                };
+                let schema = InputContext::get_input_format(&stage_info.file_format_params)?
+                    .infer_schema(&first_file, &operator)
                     .await?;
+                StageTable::try_create(StageTableInfo {
+                    schema,
+                    files_info,
+                    stage_info: stage_info.clone(),
+                    files_to_copy,
+                })?
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "stage table function only support parquet and orc format for now",
+                ));
+            }
+        };
 
-            let table_alias_name = if let Some(table_alias) = alias {
-                Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
-            } else {
-                None
-            };
+        let table_alias_name = if let Some(table_alias) = alias {
+            Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
+        } else {
+            None
+        };
 
-            let table_index = self.metadata.write().add_table(
-                CATALOG_DEFAULT.to_string(),
-                "system".to_string(),
-                table.clone(),
-                table_alias_name,
-                false,
-            );
+        let table_index = self.metadata.write().add_table(
+            CATALOG_DEFAULT.to_string(),
+            "system".to_string(),
+            table.clone(),
+            table_alias_name,
+            false,
+        );
 
-            let (s_expr, mut bind_context) = self
-                .bind_base_table(bind_context, "system", table_index)
-                .await?;
-            if let Some(alias) = alias {
-                bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
-            }
-            Ok((s_expr, bind_context))
-        } else {
-            Err(ErrorCode::Unimplemented(
-                "stage table function only support parquet format for now",
-            ))
+        let (s_expr, mut bind_context) = self
+            .bind_base_table(bind_context, "system", table_index)
+            .await?;
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
         }
+        Ok((s_expr, bind_context))
     }
 
     #[async_backtrace::framed]
@@ -691,6 +736,10 @@ impl Binder {
         let table = self.metadata.read().table(table_index).clone();
         let statistics_provider = table.table().column_statistics_provider().await?;
 
+        let table_schema = table.table().schema();
+        let masking_policies = self.table_masking_policies(&table.table()).await?;
+        let mut virtual_computed_columns = Vec::new();
+        let mut masked_columns = Vec::new();
         let mut col_stats: HashMap<IndexType, Option<ColumnStatistics>> = HashMap::new();
         for column in columns.iter() {
             match column {
@@ -718,6 +767,16 @@ impl Binder {
                     };
                     bind_context.add_column_binding(column_binding);
                     if path_indices.is_none() {
+                        if let Ok(field) = table_schema.field_with_name(column_name) {
+                            if let Some(ComputedExpr::Virtual(virtual_expr)) = field.computed_expr()
+                            {
+                                virtual_computed_columns
+                                    .push((*column_index, virtual_expr.clone()));
+                            }
+                            if let Some(policy) = masking_policies.get(&field.column_id()) {
+                                masked_columns.push((column_name.clone(), policy.clone()));
+                            }
+                        }
                         if let Some(col_id) = *leaf_index {
                             let col_stat =
                                 statistics_provider.column_statistics(col_id as ColumnId);
@@ -733,38 +792,218 @@ impl Binder {
 
         let stat = table.table().table_statistics()?;
 
-        Ok((
-            SExpr::create_leaf(
-                Scan {
-                    table_index,
-                    columns: columns
-                        .into_iter()
-                        .map(|col| match col {
-                            ColumnEntry::BaseTableColumn(BaseTableColumn {
-                                column_index, ..
-                            }) => column_index,
-                            ColumnEntry::DerivedColumn(DerivedColumn { column_index, .. }) => {
-                                column_index
-                            }
-                            ColumnEntry::InternalColumn(TableInternalColumn {
-                                column_index,
-                                ..
-                            }) => column_index,
-                            ColumnEntry::VirtualColumn(VirtualColumn { column_index, .. }) => {
-                                column_index
-                            }
-                        })
-                        .collect(),
-                    statistics: Statistics {
-                        statistics: stat,
-                        col_stats,
-                    },
-                    ..Default::default()
+        let s_expr = SExpr::create_leaf(
+            Scan {
+                table_index,
+                columns: columns
+                    .into_iter()
+                    .filter(|col| {
+                        // Virtual computed columns are evaluated from the other columns.
+                        !virtual_computed_columns
+                            .iter()
+                            .any(|(index, _)| *index == col.index())
+                    })
+                    .map(|col| match col {
+                        ColumnEntry::BaseTableColumn(BaseTableColumn { column_index, .. }) => {
+                            column_index
+                        }
+                        ColumnEntry::DerivedColumn(DerivedColumn { column_index, .. }) => {
+                            column_index
+                        }
+                        ColumnEntry::InternalColumn(TableInternalColumn {
+                            column_index, ..
+                        }) => column_index,
+                        ColumnEntry::VirtualColumn(VirtualColumn { column_index, .. }) => {
+                            column_index
+                        }
+                    })
+                    .collect(),
+                statistics: Statistics {
+                    statistics: stat,
+                    col_stats,
+                },
+                ..Default::default()
+            }
+            .into(),
+        );
+
+        let s_expr = if virtual_computed_columns.is_empty() {
+            s_expr
+        } else {
+            self.bind_virtual_computed_columns(&mut bind_context, virtual_computed_columns, s_expr)
+                .await?
+        };
+        let s_expr = if masked_columns.is_empty() {
+            s_expr
+        } else {
+            self.bind_masking_policies(&mut bind_context, masked_columns, s_expr)
+                .await?
+        };
+
+        Ok((s_expr, bind_context))
+    }
+
+    #[async_backtrace::framed]
+    async fn bind_virtual_computed_columns(
+        &mut self,
+        bind_context: &mut BindContext,
+        virtual_computed_columns: Vec<(IndexType, String)>,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(virtual_computed_columns.len());
+        for (column_index, virtual_expr) in virtual_computed_columns {
+            let tokens = tokenize_sql(&virtual_expr)?;
+            let ast = parse_expr(&tokens, Dialect::MySQL)?;
+            let (scalar, data_type) = {
+                let mut scalar_binder = ScalarBinder::new(
+                    bind_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                scalar_binder.bind(&ast).await?
+            };
+            let column_binding = bind_context
+                .columns
+                .iter_mut()
+                .find(|col| col.index == column_index)
+                .ok_or_else(|| ErrorCode::Internal("Invalid virtual computed column"))?;
+            let scalar = if data_type != *column_binding.data_type {
+                ScalarExpr::CastExpr(CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(scalar),
+                    target_type: column_binding.data_type.clone(),
+                })
+            } else {
+                scalar
+            };
+            let index = self.metadata.write().add_derived_column(
+                column_binding.column_name.clone(),
+                *column_binding.data_type.clone(),
+            );
+            column_binding.index = index;
+            items.push(ScalarItem { scalar, index });
+        }
+
+        Ok(SExpr::create_unary(EvalScalar { items }.into(), s_expr))
+    }
+
+    /// The masking policies of the columns of the table. A stream has the column ids of its
+    /// base table, and is masked by the policies of the base table.
+    #[async_backtrace::framed]
+    async fn table_masking_policies(
+        &self,
+        table: &Arc<dyn Table>,
+    ) -> Result<BTreeMap<ColumnId, String>> {
+        let options = table.options();
+        let policies = if table.engine() == "STREAM" {
+            let (database, table_name, table_id) = match (
+                options.get(OPT_KEY_STREAM_BASE_DATABASE),
+                options.get(OPT_KEY_STREAM_BASE_TABLE),
+                options.get(OPT_KEY_STREAM_BASE_TABLE_ID),
+            ) {
+                (Some(database), Some(table_name), Some(table_id)) => {
+                    (database, table_name, table_id)
+                }
+                _ => {
+                    return Err(ErrorCode::Internal(format!(
+                        "the base table of stream {} is missing",
+                        table.name()
+                    )));
                 }
-                .into(),
-            ),
-            bind_context,
-        ))
+            };
+            let base = self
+                .ctx
+                .get_table(table.get_table_info().catalog(), database, table_name)
+                .await?;
+            if &base.get_id().to_string() != table_id {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "The base table {}.{} of stream {} has been dropped",
+                    database,
+                    table_name,
+                    table.name()
+                )));
+            }
+            base.options().get(OPT_KEY_COLUMN_MASKING_POLICIES).cloned()
+        } else {
+            options.get(OPT_KEY_COLUMN_MASKING_POLICIES).cloned()
+        };
+        match policies {
+            Some(policies) => Ok(serde_json::from_str(&policies)?),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+
+    /// Replace the masked columns with the results of their masking policies, so that
+    /// the rest of the query only sees the masked values.
+    #[async_backtrace::framed]
+    async fn bind_masking_policies(
+        &mut self,
+        bind_context: &mut BindContext,
+        masked_columns: Vec<(String, String)>,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let tenant = self.ctx.get_tenant();
+        let dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let mut items = Vec::with_capacity(masked_columns.len());
+        for (column_name, policy_name) in masked_columns {
+            // Fail the query rather than expose the column if the policy is missing.
+            let policy = UserApiProvider::instance()
+                .get_masking_policy(&tenant, &policy_name)
+                .await?;
+            let column_binding = bind_context
+                .columns
+                .iter_mut()
+                .find(|col| col.column_name == column_name && col.visibility == Visibility::Visible)
+                .ok_or_else(|| ErrorCode::Internal("Invalid masked column"))?;
+
+            // The body of the policy only sees its argument, which is bound to the column.
+            let mut policy_context = BindContext::new();
+            policy_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: policy.args[0].0.clone(),
+                ..column_binding.clone()
+            });
+            let tokens = tokenize_sql(&policy.body)?;
+            let ast = parse_expr(&tokens, dialect)?;
+            let (scalar, data_type) = {
+                let mut scalar_binder = ScalarBinder::new(
+                    &mut policy_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                scalar_binder.bind(&ast).await?
+            };
+            let scalar = if data_type != *column_binding.data_type {
+                ScalarExpr::CastExpr(CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(scalar),
+                    target_type: column_binding.data_type.clone(),
+                })
+            } else {
+                scalar
+            };
+            let index = self.metadata.write().add_derived_column(
+                column_binding.column_name.clone(),
+                *column_binding.data_type.clone(),
+            );
+            column_binding.index = index;
+            items.push(ScalarItem { scalar, index });
+
+            // The inner columns of a masked tuple column would expose the raw values.
+            let inner_prefix = format!("{column_name}:");
+            bind_context.columns.retain(|col| {
+                col.visibility == Visibility::Visible || !col.column_name.starts_with(&inner_prefix)
+            });
+        }
+
+        Ok(SExpr::create_unary(EvalScalar { items }.into(), s_expr))
     }
 
     #[async_backtrace::framed]
@@ -844,6 +1083,72 @@ impl Binder {
 
         Ok(index_metas)
     }
+
+    /// Resolve the fresh materialized views built on the table,
+    /// which can be used to rewrite the aggregation queries on the table.
+    #[async_backtrace::framed]
+    async fn resolve_materialized_views(
+        &mut self,
+        bind_context: &BindContext,
+        catalog: &str,
+        table: &Arc<dyn Table>,
+    ) -> Result<()> {
+        let options = table.options();
+        let view_names: Vec<(String, String)> = match options.get(OPT_KEY_MATERIALIZED_VIEWS) {
+            Some(v) => serde_json::from_str(v)?,
+            None => return Ok(()),
+        };
+        let snapshot_location = options.get(OPT_KEY_SNAPSHOT_LOCATION);
+        let table_id = table.get_id().to_string();
+
+        let mut views = Vec::with_capacity(view_names.len());
+        for (database, name) in view_names {
+            // The view may be dropped, just ignore it.
+            let view = match self.ctx.get_table(catalog, &database, &name).await {
+                Ok(view) => view,
+                Err(_) => continue,
+            };
+            let view_options = view.options();
+            if view_options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID) != Some(&table_id)
+                || view_options.get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT)
+                    != snapshot_location
+            {
+                // The view is not built on this table or is stale.
+                continue;
+            }
+            let query = match view_options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+                Some(query) => query,
+                None => continue,
+            };
+
+            let tokens = tokenize_sql(query)?;
+            let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+            let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+            new_bind_context.planning_agg_index = true;
+            if let Statement::Query(query) = &stmt {
+                let (s_expr, view_bind_context) =
+                    self.bind_query(&mut new_bind_context, query).await?;
+                views.push(MaterializedView {
+                    catalog: catalog.to_string(),
+                    database,
+                    table: view.clone(),
+                    plan: s_expr,
+                    output_columns: view_bind_context
+                        .columns
+                        .iter()
+                        .map(|col| col.index)
+                        .collect(),
+                });
+            }
+        }
+
+        if !views.is_empty() {
+            self.metadata
+                .write()
+                .add_materialized_views(table.get_id(), views);
+        }
+        Ok(())
+    }
 }
 
 // copy from common-storages-fuse to avoid cyclic dependency.
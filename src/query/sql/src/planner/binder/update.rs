@@ -14,10 +14,17 @@
 
 use std::collections::HashMap;
 
+use common_ast::ast::Expr;
 use common_ast::ast::TableReference;
 use common_ast::ast::UpdateStmt;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr_mut;
+use common_ast::Dialect;
+use common_ast::VisitorMut;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ComputedExpr;
 
 use crate::binder::Binder;
 use crate::binder::ScalarBinder;
@@ -26,6 +33,29 @@ use crate::plans::Plan;
 use crate::plans::ScalarExpr;
 use crate::plans::UpdatePlan;
 use crate::BindContext;
+use crate::NameResolutionContext;
+
+/// Replaces the updated columns in the expression of a stored computed column
+/// with their new values.
+struct ComputedExprRewriter<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    update_exprs: &'a HashMap<String, Expr>,
+    rewritten: bool,
+}
+
+impl<'a> VisitorMut for ComputedExprRewriter<'a> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::ColumnRef { column, .. } = expr {
+            let name = normalize_identifier(column, self.name_resolution_ctx).name;
+            if let Some(update_expr) = self.update_exprs.get(&name) {
+                *expr = update_expr.clone();
+                self.rewritten = true;
+                return;
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
+}
 
 impl Binder {
     #[async_backtrace::framed]
@@ -83,6 +113,12 @@ impl Binder {
         for update_expr in update_list {
             let col_name = normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
             let index = schema.index_of(&col_name)?;
+            if schema.field(index).computed_expr().is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The value specified for computed column '{}' is not allowed",
+                    col_name
+                )));
+            }
             if update_columns.contains_key(&index) {
                 return Err(ErrorCode::BadArguments(format!(
                     "Multiple assignments in the single statement to column `{}`",
@@ -100,6 +136,33 @@ impl Binder {
             update_columns.insert(index, scalar);
         }
 
+        // The stored computed columns which depend on the updated columns need to be recomputed.
+        let update_exprs = update_list
+            .iter()
+            .map(|update_expr| {
+                (
+                    normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name,
+                    update_expr.expr.clone(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        for (index, field) in schema.fields().iter().enumerate() {
+            if let Some(ComputedExpr::Stored(stored_expr)) = field.computed_expr() {
+                let tokens = tokenize_sql(stored_expr)?;
+                let mut expr = parse_expr(&tokens, Dialect::MySQL)?;
+                let mut rewriter = ComputedExprRewriter {
+                    name_resolution_ctx: &self.name_resolution_ctx,
+                    update_exprs: &update_exprs,
+                    rewritten: false,
+                };
+                rewriter.visit_expr(&mut expr);
+                if rewriter.rewritten {
+                    let (scalar, _) = scalar_binder.bind(&expr).await?;
+                    update_columns.insert(index, scalar);
+                }
+            }
+        }
+
         let push_downs = if let Some(expr) = selection {
             let (scalar, _) = scalar_binder.bind(expr).await?;
             if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
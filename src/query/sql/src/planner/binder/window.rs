@@ -24,6 +24,9 @@ use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
+use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Window;
@@ -102,7 +105,7 @@ impl<'a> WindowRewriter<'a> {
     pub fn visit(&mut self, scalar: &ScalarExpr) -> Result<ScalarExpr> {
         match scalar {
             ScalarExpr::BoundColumnRef(_) => Ok(scalar.clone()),
-            ScalarExpr::ConstantExpr(_) => Ok(scalar.clone()),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => Ok(scalar.clone()),
             ScalarExpr::FunctionCall(func) => {
                 let new_args = func
                     .arguments
@@ -117,6 +120,23 @@ impl<'a> WindowRewriter<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
@@ -196,40 +216,11 @@ impl<'a> WindowRewriter<'a> {
                 for (i, arg) in agg.args.iter().enumerate() {
                     let arg = self.visit(arg)?;
                     let name = format!("{}_arg_{}", &window_func_name, i);
-                    if let ScalarExpr::BoundColumnRef(column_ref) = &arg {
-                        replaced_args.push(column_ref.clone().into());
-                        agg_args.push(ScalarItem {
-                            index: column_ref.column.index,
-                            scalar: arg.clone(),
-                        });
-                    } else {
-                        let index = self
-                            .metadata
-                            .write()
-                            .add_derived_column(name.clone(), arg.data_type()?);
-
-                        // Generate a ColumnBinding for each argument of aggregates
-                        let column_binding = ColumnBinding {
-                            database_name: None,
-                            table_name: None,
-                            table_index: None,
-                            column_name: name,
-                            index,
-                            data_type: Box::new(arg.data_type()?),
-                            visibility: Visibility::Visible,
-                        };
-                        replaced_args.push(
-                            BoundColumnRef {
-                                span: arg.span(),
-                                column: column_binding.clone(),
-                            }
-                            .into(),
-                        );
-                        agg_args.push(ScalarItem {
-                            index,
-                            scalar: arg.clone(),
-                        });
-                    }
+                    replaced_args.push(self.replace_window_function_arg(
+                        arg,
+                        name,
+                        &mut agg_args,
+                    )?);
                 }
                 WindowFuncType::Aggregate(AggregateFunction {
                     display_name: agg.display_name.clone(),
@@ -240,6 +231,41 @@ impl<'a> WindowRewriter<'a> {
                     return_type: agg.return_type.clone(),
                 })
             }
+            WindowFuncType::LagLead(lag_lead) => {
+                let arg = self.visit(&lag_lead.arg)?;
+                let name = format!("{}_arg_0", &window_func_name);
+                let arg = self.replace_window_function_arg(arg, name, &mut agg_args)?;
+                let default = match &lag_lead.default {
+                    Some(default) => {
+                        let default = self.visit(default)?;
+                        let name = format!("{}_default", &window_func_name);
+                        Some(Box::new(self.replace_window_function_arg(
+                            default,
+                            name,
+                            &mut agg_args,
+                        )?))
+                    }
+                    None => None,
+                };
+                WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: lag_lead.is_lag,
+                    arg: Box::new(arg),
+                    offset: lag_lead.offset,
+                    default,
+                    return_type: lag_lead.return_type.clone(),
+                })
+            }
+            WindowFuncType::NthValue(nth) => {
+                let arg = self.visit(&nth.arg)?;
+                let name = format!("{}_arg_0", &window_func_name);
+                let arg = self.replace_window_function_arg(arg, name, &mut agg_args)?;
+                WindowFuncType::NthValue(NthValueFunction {
+                    n: nth.n,
+                    arg: Box::new(arg),
+                    ignore_nulls: nth.ignore_nulls,
+                    return_type: nth.return_type.clone(),
+                })
+            }
             func => func.clone(),
         };
 
@@ -374,6 +400,45 @@ impl<'a> WindowRewriter<'a> {
 
         Ok(replaced_window.into())
     }
+
+    /// Replace the argument of window function with a column, which will be evaluated before the window.
+    fn replace_window_function_arg(
+        &mut self,
+        arg: ScalarExpr,
+        name: String,
+        window_args: &mut Vec<ScalarItem>,
+    ) -> Result<ScalarExpr> {
+        if let ScalarExpr::BoundColumnRef(column_ref) = &arg {
+            window_args.push(ScalarItem {
+                index: column_ref.column.index,
+                scalar: arg.clone(),
+            });
+            return Ok(arg);
+        }
+
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(name.clone(), arg.data_type()?);
+
+        // Generate a ColumnBinding for each argument of aggregates
+        let column_binding = ColumnBinding {
+            database_name: None,
+            table_name: None,
+            table_index: None,
+            column_name: name,
+            index,
+            data_type: Box::new(arg.data_type()?),
+            visibility: Visibility::Visible,
+        };
+        let replaced_arg = BoundColumnRef {
+            span: arg.span(),
+            column: column_binding,
+        }
+        .into();
+        window_args.push(ScalarItem { index, scalar: arg });
+        Ok(replaced_arg)
+    }
 }
 
 impl Binder {
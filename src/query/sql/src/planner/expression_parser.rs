@@ -24,6 +24,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::check_cast;
 use common_expression::types::DataType;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
@@ -32,6 +33,7 @@ use common_expression::FunctionContext;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableField;
+use common_expression::TableSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableInfo;
 use common_settings::Settings;
@@ -143,6 +145,84 @@ pub fn parse_to_remote_string_expr(
     }
 }
 
+/// Parse the expression of a computed column, the column references are bound to the
+/// non-computed fields of `schema` by their offsets, and the result is cast to `data_type`.
+pub fn parse_computed_expr(
+    ctx: Arc<dyn TableContext>,
+    schema: TableSchemaRef,
+    data_type: &DataType,
+    sql: &str,
+) -> Result<Expr> {
+    let settings = Settings::create("".to_string());
+    let mut bind_context = BindContext::new();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    for (index, field) in schema.fields().iter().enumerate() {
+        if field.computed_expr().is_some() {
+            continue;
+        }
+        bind_context.add_column_binding(ColumnBinding {
+            database_name: None,
+            table_name: None,
+            table_index: None,
+            column_name: field.name().clone(),
+            index,
+            data_type: Box::new(field.data_type().into()),
+            visibility: Visibility::Visible,
+        });
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::new(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        metadata,
+        &[],
+        false,
+    );
+
+    let sql_dialect = Dialect::MySQL;
+    let tokens = tokenize_sql(sql)?;
+    let mut ast_exprs = parse_comma_separated_exprs(&tokens, sql_dialect)?;
+    if ast_exprs.len() != 1 {
+        return Err(ErrorCode::SemanticError(format!(
+            "Expected single expr for computed column, but got {}",
+            ast_exprs.len()
+        )));
+    }
+    let ast = ast_exprs.remove(0);
+
+    let (scalar, _) = *block_in_place(|| Handle::current().block_on(type_checker.resolve(&ast)))?;
+    let expr = scalar.as_expr()?.project_column_ref(|col| col.index);
+    if expr
+        .column_refs()
+        .keys()
+        .any(|index| *index >= schema.num_fields())
+    {
+        return Err(ErrorCode::SemanticError(format!(
+            "Computed column expression `{sql}` can only reference the non-computed columns of the table"
+        )));
+    }
+    if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "Computed column expression `{sql}` is not deterministic"
+        )));
+    }
+
+    if expr.data_type() == data_type {
+        Ok(expr)
+    } else {
+        check_cast(
+            expr.span(),
+            data_type.is_nullable(),
+            expr,
+            data_type,
+            &BUILTIN_FUNCTIONS,
+        )
+    }
+}
+
 #[derive(Default)]
 struct DummyTable {
     info: TableInfo,
@@ -58,6 +58,12 @@ impl Plan {
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
+            Plan::ModifyTableColumn(modify_table_column) => {
+                Ok(format!("{:?}", modify_table_column))
+            }
+            Plan::SetColumnMaskingPolicy(set_masking_policy) => {
+                Ok(format!("{:?}", set_masking_policy))
+            }
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
@@ -76,16 +82,36 @@ impl Plan {
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
+            Plan::CreateMaterializedView(create_view) => Ok(format!("{:?}", create_view)),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(format!("{:?}", refresh_view)),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
 
+            // Sequences
+            Plan::CreateSequence(sequence) => Ok(format!("{:?}", sequence)),
+            Plan::DropSequence(sequence) => Ok(format!("{:?}", sequence)),
+
+            // Tasks
+            Plan::CreateTask(task) => Ok(format!("{:?}", task)),
+            Plan::AlterTask(task) => Ok(format!("{:?}", task)),
+            Plan::DropTask(task) => Ok(format!("{:?}", task)),
+            Plan::ExecuteTask(task) => Ok(format!("{:?}", task)),
+            Plan::CreateStream(stream) => Ok(format!("{:?}", stream)),
+            Plan::DropStream(stream) => Ok(format!("{:?}", stream)),
+            Plan::CreateMaskingPolicy(policy) => Ok(format!("{:?}", policy)),
+            Plan::DropMaskingPolicy(policy) => Ok(format!("{:?}", policy)),
+
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
             Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
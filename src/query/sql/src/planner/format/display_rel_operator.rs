@@ -98,6 +98,7 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
             }
         }
         ScalarExpr::ConstantExpr(constant) => constant.value.to_string(),
+        ScalarExpr::PlaceholderExpr(placeholder) => format!("?{}", placeholder.index + 1),
         ScalarExpr::WindowFunction(win) => win.display_name.clone(),
         ScalarExpr::AggregateFunction(agg) => agg.display_name.clone(),
         ScalarExpr::FunctionCall(func) => {
@@ -111,6 +112,19 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
                     .join(", ")
             )
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                lambda
+                    .args
+                    .iter()
+                    .map(|arg| { format_scalar(arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda.lambda_display,
+            )
+        }
         ScalarExpr::CastExpr(cast) => {
             format!(
                 "CAST({} AS {})",
@@ -59,6 +59,10 @@ pub struct Metadata {
     //// Columns that are lazy materialized.
     lazy_columns: HashSet<usize>,
     agg_indexes: HashMap<MetaId, Vec<(u64, SExpr)>>,
+    materialized_views: HashMap<MetaId, Vec<MaterializedView>>,
+    /// Placeholders of a prepared statement, keyed by the start offset of
+    /// the `?` in the SQL text, with the parameter index and its data type.
+    placeholders: HashMap<usize, (IndexType, DataType)>,
 }
 
 impl Metadata {
@@ -232,6 +236,37 @@ impl Metadata {
         self.agg_indexes.get(&table_id).map(|v| v.as_slice())
     }
 
+    pub fn add_materialized_views(&mut self, table_id: MetaId, views: Vec<MaterializedView>) {
+        self.materialized_views
+            .entry(table_id)
+            .and_modify(|v| v.extend_from_slice(&views))
+            .or_insert(views);
+    }
+
+    pub fn get_materialized_views(&self, table_id: MetaId) -> Option<&[MaterializedView]> {
+        self.materialized_views.get(&table_id).map(|v| v.as_slice())
+    }
+
+    pub fn add_placeholder(&mut self, offset: usize, data_type: DataType) -> IndexType {
+        let index = self.placeholders.len();
+        self.placeholders.insert(offset, (index, data_type));
+        index
+    }
+
+    pub fn placeholder(&self, offset: usize) -> Option<&(IndexType, DataType)> {
+        self.placeholders.get(&offset)
+    }
+
+    /// Replace the table of the entry with a newer version of the same table,
+    /// the caller must make sure the schema of the table is unchanged.
+    pub fn replace_table(&mut self, index: IndexType, table: Arc<dyn Table>) {
+        let entry = self
+            .tables
+            .get_mut(index)
+            .expect("metadata must contain table");
+        entry.table = table;
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
@@ -320,6 +355,29 @@ impl Metadata {
     }
 }
 
+/// A fresh materialized view built on a base table, which can be used to
+/// answer aggregation queries on the base table.
+#[derive(Clone)]
+pub struct MaterializedView {
+    pub catalog: String,
+    pub database: String,
+    pub table: Arc<dyn Table>,
+    /// The bound plan of the view query.
+    pub plan: SExpr,
+    /// The output column indexes of `plan`, in the order of the view table schema.
+    pub output_columns: Vec<IndexType>,
+}
+
+impl Debug for MaterializedView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaterializedView")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("name", &self.table.name())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Clone)]
 pub struct TableEntry {
     catalog: String,
@@ -22,7 +22,6 @@ use crate::plans::Aggregate;
 use crate::plans::DummyTableScan;
 use crate::plans::EvalScalar;
 use crate::plans::RelOperator;
-use crate::plans::WindowFuncType;
 use crate::ColumnEntry;
 use crate::MetadataRef;
 
@@ -194,11 +193,7 @@ impl UnusedColumnPruner {
             }
             RelOperator::Window(p) => {
                 if required.contains(&p.index) {
-                    if let WindowFuncType::Aggregate(agg) = &p.function {
-                        agg.args.iter().for_each(|item| {
-                            required.extend(item.used_columns());
-                        });
-                    }
+                    required.extend(p.function.used_columns());
                     p.partition_by.iter().for_each(|item| {
                         required.insert(item.index);
                     });
@@ -38,6 +38,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::Limit;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
@@ -131,12 +132,30 @@ impl SubqueryRewriter {
                     item.order_by_item.scalar = res.0;
                 }
 
-                if let WindowFuncType::Aggregate(agg) = &mut plan.function {
-                    for item in agg.args.iter_mut() {
-                        let res = self.try_rewrite_subquery(item, &input, false)?;
+                match &mut plan.function {
+                    WindowFuncType::Aggregate(agg) => {
+                        for item in agg.args.iter_mut() {
+                            let res = self.try_rewrite_subquery(item, &input, false)?;
+                            input = res.1;
+                            *item = res.0;
+                        }
+                    }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let res = self.try_rewrite_subquery(&lag_lead.arg, &input, false)?;
+                        input = res.1;
+                        lag_lead.arg = Box::new(res.0);
+                        if let Some(default) = &mut lag_lead.default {
+                            let res = self.try_rewrite_subquery(default, &input, false)?;
+                            input = res.1;
+                            *default = Box::new(res.0);
+                        }
+                    }
+                    WindowFuncType::NthValue(nth) => {
+                        let res = self.try_rewrite_subquery(&nth.arg, &input, false)?;
                         input = res.1;
-                        *item = res.0;
+                        nth.arg = Box::new(res.0);
                     }
+                    _ => {}
                 }
 
                 Ok(SExpr::create_unary(plan.into(), input))
@@ -170,6 +189,7 @@ impl SubqueryRewriter {
         match scalar {
             ScalarExpr::BoundColumnRef(_) => Ok((scalar.clone(), s_expr.clone())),
             ScalarExpr::ConstantExpr(_) => Ok((scalar.clone(), s_expr.clone())),
+            ScalarExpr::PlaceholderExpr(_) => Ok((scalar.clone(), s_expr.clone())),
             ScalarExpr::WindowFunction(_) => Ok((scalar.clone(), s_expr.clone())),
             ScalarExpr::AggregateFunction(_) => Ok((scalar.clone(), s_expr.clone())),
             ScalarExpr::FunctionCall(func) => {
@@ -191,6 +211,28 @@ impl SubqueryRewriter {
 
                 Ok((expr, s_expr))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let mut args = vec![];
+                let mut s_expr = s_expr.clone();
+                for arg in lambda.args.iter() {
+                    let res = self.try_rewrite_subquery(arg, &s_expr, false)?;
+                    s_expr = res.1;
+                    args.push(res.0);
+                }
+
+                let expr: ScalarExpr = LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into();
+
+                Ok((expr, s_expr))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let (scalar, s_expr) = self.try_rewrite_subquery(&cast.argument, s_expr, false)?;
                 Ok((
@@ -515,7 +557,9 @@ pub fn check_child_expr_in_subquery(
 ) -> Result<(ScalarExpr, bool)> {
     match child_expr {
         ScalarExpr::BoundColumnRef(_) => Ok((child_expr.clone(), op != &ComparisonOp::Equal)),
-        ScalarExpr::ConstantExpr(_) => Ok((child_expr.clone(), true)),
+        ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => {
+            Ok((child_expr.clone(), true))
+        }
         ScalarExpr::CastExpr(cast) => {
             let arg = &cast.argument;
             let (_, is_non_equi_condition) = check_child_expr_in_subquery(arg, op)?;
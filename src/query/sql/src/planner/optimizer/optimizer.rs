@@ -31,6 +31,7 @@ use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
 use crate::plans::CopyPlan;
+use crate::plans::MergeInto;
 use crate::plans::Plan;
 use crate::BindContext;
 use crate::IndexType;
@@ -134,6 +135,20 @@ pub fn optimize(
                 into_table => into_table,
             })))
         }
+        Plan::MergeInto(plan) => {
+            // The join of target table and source could be executed in cluster.
+            let input = optimize_query(
+                ctx,
+                opt_ctx,
+                plan.meta_data.clone(),
+                plan.bind_context.clone(),
+                *plan.input.clone(),
+            )?;
+            Ok(Plan::MergeInto(Box::new(MergeInto {
+                input: Box::new(input),
+                ..*plan
+            })))
+        }
         // Passthrough statements
         _ => Ok(plan),
     }
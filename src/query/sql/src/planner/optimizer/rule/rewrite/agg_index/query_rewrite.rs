@@ -28,6 +28,7 @@ use crate::plans::CastExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::ColumnBinding;
@@ -446,6 +447,7 @@ impl RewriteInfomartion<'_> {
                 s => self.format_scalar(s),
             },
             ScalarExpr::ConstantExpr(val) => format!("{}", val.value),
+            ScalarExpr::PlaceholderExpr(placeholder) => format!("?{}", placeholder.index + 1),
             ScalarExpr::FunctionCall(func) => format!(
                 "{}({})",
                 &func.func_name,
@@ -455,6 +457,17 @@ impl RewriteInfomartion<'_> {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            ScalarExpr::LambdaFunction(lambda) => format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                lambda
+                    .args
+                    .iter()
+                    .map(|arg| { self.format_scalar(arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda.lambda_display
+            ),
             ScalarExpr::CastExpr(cast) => format!(
                 "CAST({} AS {})",
                 self.format_scalar(&cast.argument),
@@ -737,7 +750,7 @@ fn rewrite_query_item(
             }
             s => rewrite_query_item(query_info, s, index_selection),
         },
-        ScalarExpr::ConstantExpr(_) => Some(query_item.clone()),
+        ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => Some(query_item.clone()),
         ScalarExpr::CastExpr(cast) => {
             let new_arg = rewrite_by_selection(query_info, &cast.argument, index_selection)?;
             Some(
@@ -766,6 +779,25 @@ fn rewrite_query_item(
                 .into(),
             )
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            let mut new_args = Vec::with_capacity(lambda.args.len());
+            for arg in lambda.args.iter() {
+                let new_arg = rewrite_by_selection(query_info, arg, index_selection)?;
+                new_args.push(new_arg);
+            }
+            Some(
+                LambdaFunc {
+                    span: None,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args: new_args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into(),
+            )
+        }
         ScalarExpr::AggregateFunction(agg) => {
             let mut new_args = Vec::with_capacity(agg.args.len());
             for arg in agg.args.iter() {
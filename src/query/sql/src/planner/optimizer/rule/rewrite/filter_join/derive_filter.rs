@@ -123,10 +123,22 @@ fn replace_column(scalar: &mut ScalarExpr, col_to_scalar: &HashMap<&IndexType, &
             *scalar = (*col_to_scalar.get(&column_index).unwrap()).clone();
         }
         ScalarExpr::WindowFunction(expr) => {
-            if let WindowFuncType::Aggregate(agg) = &mut expr.func {
-                for arg in agg.args.iter_mut() {
-                    replace_column(arg, col_to_scalar);
+            match &mut expr.func {
+                WindowFuncType::Aggregate(agg) => {
+                    for arg in agg.args.iter_mut() {
+                        replace_column(arg, col_to_scalar);
+                    }
+                }
+                WindowFuncType::LagLead(lag_lead) => {
+                    replace_column(&mut lag_lead.arg, col_to_scalar);
+                    if let Some(default) = &mut lag_lead.default {
+                        replace_column(default, col_to_scalar);
+                    }
+                }
+                WindowFuncType::NthValue(nth) => {
+                    replace_column(&mut nth.arg, col_to_scalar);
                 }
+                _ => {}
             }
             for arg in expr.partition_by.iter_mut() {
                 replace_column(arg, col_to_scalar)
@@ -145,9 +157,16 @@ fn replace_column(scalar: &mut ScalarExpr, col_to_scalar: &HashMap<&IndexType, &
                 replace_column(arg, col_to_scalar)
             }
         }
+        ScalarExpr::LambdaFunction(expr) => {
+            for arg in expr.args.iter_mut() {
+                replace_column(arg, col_to_scalar)
+            }
+        }
         ScalarExpr::CastExpr(expr) => {
             replace_column(&mut expr.argument, col_to_scalar);
         }
-        ScalarExpr::ConstantExpr(_) | ScalarExpr::SubqueryExpr(_) => {}
+        ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::PlaceholderExpr(_)
+        | ScalarExpr::SubqueryExpr(_) => {}
     }
 }
@@ -0,0 +1,552 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_functions::aggregates::AggregateFunctionFactory;
+
+use crate::binder::split_conjunctions;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MaterializedView;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Try to rewrite the aggregation `s_expr` to read the data of a materialized view.
+///
+/// The rows of a materialized view are partial aggregation results of the base table
+/// (incremental refreshing appends new partial results), so the aggregation is always
+/// kept and computed again over the view:
+///
+/// - `sum`, `min` and `max` are computed with the same function over the view column.
+/// - `count` is computed with `sum` over the view column.
+///
+/// A view can be used if:
+///
+/// 1. Each predicate of the view is in the query.
+/// 2. The other predicates of the query only reference group columns of the view.
+/// 3. Each group item of the query is a group column of the view.
+/// 4. Each aggregate function of the query is in the view.
+pub fn try_rewrite(
+    s_expr: &SExpr,
+    views: &[MaterializedView],
+    metadata: MetadataRef,
+) -> Result<Option<SExpr>> {
+    let query_info = match collect_query_information(s_expr)? {
+        Some(info) => info,
+        None => return Ok(None),
+    };
+    if query_info.aggregate.mode != AggregateMode::Initial
+        || !query_info.aggregate.grouping_sets.is_empty()
+    {
+        return Ok(None);
+    }
+    let query_predicates = match query_info.formatted_predicates() {
+        Some(predicates) => predicates,
+        None => return Ok(None),
+    };
+
+    for view in views.iter() {
+        let view_info = match collect_view_information(&view.plan)? {
+            Some(info) => info,
+            None => continue,
+        };
+        let view_outputs = match view_info.formatted_outputs(&view.output_columns) {
+            Some(outputs) => outputs,
+            None => continue,
+        };
+        let view_predicates = match view_info.formatted_predicates() {
+            Some(predicates) => predicates,
+            None => continue,
+        };
+        if let Some(result) = try_rewrite_with_view(
+            &query_info,
+            &query_predicates,
+            view,
+            &view_outputs,
+            &view_predicates,
+            metadata.clone(),
+        )? {
+            return Ok(Some(result));
+        }
+    }
+
+    Ok(None)
+}
+
+/// The output of a materialized view.
+#[derive(PartialEq)]
+enum ViewOutput {
+    /// A group column, with the formatted group item.
+    Group(String),
+    /// An aggregation column, with the function name and the formatted argument.
+    Agg(String, String),
+}
+
+fn try_rewrite_with_view(
+    query_info: &AggregationInformation<'_>,
+    query_predicates: &[(String, &ScalarExpr)],
+    view: &MaterializedView,
+    view_outputs: &[ViewOutput],
+    view_predicates: &[(String, &ScalarExpr)],
+    metadata: MetadataRef,
+) -> Result<Option<SExpr>> {
+    // 1. Each predicate of the view should be in the query.
+    if view_predicates
+        .iter()
+        .any(|(v, _)| !query_predicates.iter().any(|(q, _)| q == v))
+    {
+        return Ok(None);
+    }
+
+    let view_schema = view.table.schema();
+    let view_types = view_schema
+        .fields()
+        .iter()
+        .map(|f| DataType::from(f.data_type()))
+        .collect::<Vec<_>>();
+
+    let group_outputs = view_outputs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, output)| match output {
+            ViewOutput::Group(key) => Some((key.as_str(), i)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    // 2. Check group items of the query.
+    let mut group_mapping = Vec::with_capacity(query_info.aggregate.group_items.len());
+    for item in query_info.aggregate.group_items.iter() {
+        let key = match query_info.format_scalar(&item.scalar) {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+        match group_outputs.get(key.as_str()) {
+            Some(pos) if item.scalar.data_type()? == view_types[*pos] => {
+                group_mapping.push((item.index, *pos));
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    // 3. Check aggregate functions of the query.
+    let function_factory = AggregateFunctionFactory::instance();
+    let mut agg_mapping = Vec::with_capacity(query_info.aggregate.aggregate_functions.len());
+    for item in query_info.aggregate.aggregate_functions.iter() {
+        let agg = match &item.scalar {
+            ScalarExpr::AggregateFunction(agg) if !agg.distinct && agg.params.is_empty() => agg,
+            _ => return Ok(None),
+        };
+        let func_name = match agg.func_name.as_str() {
+            // The result of `count` on empty input is not the same as `sum`.
+            "count" if query_info.aggregate.group_items.is_empty() => return Ok(None),
+            "count" => "sum",
+            "sum" | "min" | "max" => agg.func_name.as_str(),
+            _ => return Ok(None),
+        };
+        let key = match query_info.format_aggregate(agg) {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+        let output = ViewOutput::Agg(agg.func_name.clone(), key);
+        let pos = match view_outputs.iter().position(|o| o == &output) {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+        let func = function_factory.get(func_name, vec![], vec![view_types[pos].clone()])?;
+        if func.return_type()? != *agg.return_type {
+            return Ok(None);
+        }
+        agg_mapping.push((item, func_name, pos));
+    }
+
+    // 4. Rewrite the other predicates of the query with group columns of the view.
+    let mut rewritten_predicates = Vec::new();
+    for (key, pred) in query_predicates.iter() {
+        if view_predicates.iter().any(|(v, _)| v == key) {
+            continue;
+        }
+        rewritten_predicates.push((*pred).clone());
+    }
+
+    // Add the view table to metadata, and build the new plan.
+    let mut metadata = metadata.write();
+    let table_index = metadata.add_table(
+        view.catalog.clone(),
+        view.database.clone(),
+        view.table.clone(),
+        None,
+        false,
+    );
+    let view_columns = metadata
+        .columns_by_table_index(table_index)
+        .into_iter()
+        .filter_map(|col| match col {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_index,
+                column_name,
+                path_indices: None,
+                ..
+            }) => Some((column_index, column_name)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    drop(metadata);
+    if view_columns.len() != view_types.len() {
+        return Ok(None);
+    }
+    let view_column_refs = view_columns
+        .iter()
+        .zip(view_types.iter())
+        .map(|((index, name), ty)| BoundColumnRef {
+            span: None,
+            column: ColumnBinding {
+                database_name: Some(view.database.clone()),
+                table_name: Some(view.table.name().to_string()),
+                table_index: Some(table_index),
+                column_name: name.clone(),
+                index: *index,
+                data_type: Box::new(ty.clone()),
+                visibility: Visibility::Visible,
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let group_columns = group_outputs
+        .iter()
+        .map(|(key, pos)| (key.to_string(), view_column_refs[*pos].clone()))
+        .collect::<HashMap<_, _>>();
+    let mut new_predicates = Vec::with_capacity(rewritten_predicates.len());
+    for pred in rewritten_predicates.iter() {
+        match query_info.rewrite_scalar(pred, &group_columns) {
+            Some(pred) => new_predicates.push(pred),
+            None => return Ok(None),
+        }
+    }
+
+    let mut new_expr = SExpr::create_leaf(
+        Scan {
+            table_index,
+            columns: view_columns.iter().map(|(index, _)| *index).collect(),
+            ..Default::default()
+        }
+        .into(),
+    );
+    if !new_predicates.is_empty() {
+        new_expr = SExpr::create_unary(
+            Filter {
+                predicates: new_predicates,
+                is_having: false,
+            }
+            .into(),
+            new_expr,
+        );
+    }
+
+    let mut group_items = Vec::with_capacity(group_mapping.len());
+    let mut eval_items = Vec::with_capacity(group_mapping.len());
+    for (index, pos) in group_mapping {
+        let view_column = &view_column_refs[pos];
+        eval_items.push(ScalarItem {
+            index,
+            scalar: view_column.clone().into(),
+        });
+        group_items.push(ScalarItem {
+            index,
+            scalar: BoundColumnRef {
+                span: None,
+                column: ColumnBinding {
+                    index,
+                    ..view_column.column.clone()
+                },
+            }
+            .into(),
+        });
+    }
+    if !eval_items.is_empty() {
+        new_expr = SExpr::create_unary(EvalScalar { items: eval_items }.into(), new_expr);
+    }
+
+    let aggregate_functions = agg_mapping
+        .into_iter()
+        .map(|(item, func_name, pos)| {
+            let agg = match &item.scalar {
+                ScalarExpr::AggregateFunction(agg) => agg,
+                _ => unreachable!(),
+            };
+            ScalarItem {
+                index: item.index,
+                scalar: AggregateFunction {
+                    func_name: func_name.to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![view_column_refs[pos].clone().into()],
+                    return_type: agg.return_type.clone(),
+                    display_name: agg.display_name.clone(),
+                }
+                .into(),
+            }
+        })
+        .collect();
+
+    let new_aggregate = Aggregate {
+        group_items,
+        aggregate_functions,
+        ..query_info.aggregate.clone()
+    };
+    Ok(Some(SExpr::create_unary(new_aggregate.into(), new_expr)))
+}
+
+/// Record information of an aggregation on a table scan.
+struct AggregationInformation<'a> {
+    table_index: IndexType,
+    aggregate: &'a Aggregate,
+    /// The scalars computed below the aggregation, such as arguments of aggregate functions.
+    args: HashMap<IndexType, &'a ScalarExpr>,
+    /// The projections above the aggregation, only exist in the view plan.
+    projections: HashMap<IndexType, &'a ScalarExpr>,
+    predicates: Vec<ScalarExpr>,
+}
+
+impl<'a> AggregationInformation<'a> {
+    fn formatted_predicates(&self) -> Option<Vec<(String, &ScalarExpr)>> {
+        let mut keys = HashSet::with_capacity(self.predicates.len());
+        let mut predicates = Vec::with_capacity(self.predicates.len());
+        for pred in self.predicates.iter() {
+            let key = self.format_scalar(pred)?;
+            if keys.insert(key.clone()) {
+                predicates.push((key, pred));
+            }
+        }
+        Some(predicates)
+    }
+
+    /// Format outputs of the view in the order of `output_columns`.
+    fn formatted_outputs(&self, output_columns: &[IndexType]) -> Option<Vec<ViewOutput>> {
+        let mut outputs = Vec::with_capacity(output_columns.len());
+        for index in output_columns {
+            let mut index = *index;
+            while let Some(ScalarExpr::BoundColumnRef(col)) = self.projections.get(&index) {
+                if col.column.index == index {
+                    break;
+                }
+                index = col.column.index;
+            }
+            if let Some(item) = self
+                .aggregate
+                .aggregate_functions
+                .iter()
+                .find(|item| item.index == index)
+            {
+                match &item.scalar {
+                    ScalarExpr::AggregateFunction(agg) => {
+                        outputs.push(ViewOutput::Agg(
+                            agg.func_name.clone(),
+                            self.format_aggregate(agg)?,
+                        ));
+                    }
+                    _ => return None,
+                }
+            } else if let Some(item) = self
+                .aggregate
+                .group_items
+                .iter()
+                .find(|item| item.index == index)
+            {
+                outputs.push(ViewOutput::Group(self.format_scalar(&item.scalar)?));
+            } else {
+                return None;
+            }
+        }
+        Some(outputs)
+    }
+
+    fn format_aggregate(&self, agg: &AggregateFunction) -> Option<String> {
+        let mut args = Vec::with_capacity(agg.args.len());
+        for arg in agg.args.iter() {
+            args.push(self.format_scalar(arg)?);
+        }
+        Some(format!("{}({})", agg.func_name, args.join(", ")))
+    }
+
+    // If the column ref is computed below the aggregation, recover it.
+    fn actual_scalar<'b>(&'b self, scalar: &'b ScalarExpr) -> &'b ScalarExpr {
+        if let ScalarExpr::BoundColumnRef(col) = scalar {
+            if let Some(arg) = self.args.get(&col.column.index) {
+                match arg {
+                    ScalarExpr::BoundColumnRef(arg_col)
+                        if arg_col.column.index == col.column.index => {}
+                    _ => return self.actual_scalar(arg),
+                }
+            }
+        }
+        scalar
+    }
+
+    /// Format the scalar with the names of base table columns, so that
+    /// the scalars of the query and the view can be compared.
+    fn format_scalar(&self, scalar: &ScalarExpr) -> Option<String> {
+        match self.actual_scalar(scalar) {
+            ScalarExpr::BoundColumnRef(col) => {
+                if col.column.table_index != Some(self.table_index) {
+                    return None;
+                }
+                Some(col.column.column_name.clone())
+            }
+            ScalarExpr::ConstantExpr(val) => Some(format!("{}", val.value)),
+            ScalarExpr::FunctionCall(func) if func.params.is_empty() => {
+                let mut args = Vec::with_capacity(func.arguments.len());
+                for arg in func.arguments.iter() {
+                    args.push(self.format_scalar(arg)?);
+                }
+                Some(format!("{}({})", func.func_name, args.join(", ")))
+            }
+            ScalarExpr::CastExpr(cast) => Some(format!(
+                "{}({} AS {})",
+                if cast.is_try { "TRY_CAST" } else { "CAST" },
+                self.format_scalar(&cast.argument)?,
+                cast.target_type
+            )),
+            _ => None,
+        }
+    }
+
+    /// Rewrite the scalar with the group columns of the view.
+    fn rewrite_scalar(
+        &self,
+        scalar: &ScalarExpr,
+        group_columns: &HashMap<String, BoundColumnRef>,
+    ) -> Option<ScalarExpr> {
+        if let Some(col) = group_columns.get(&self.format_scalar(scalar)?) {
+            return Some(col.clone().into());
+        }
+        match self.actual_scalar(scalar) {
+            ScalarExpr::ConstantExpr(_) => Some(scalar.clone()),
+            ScalarExpr::FunctionCall(func) => {
+                let mut arguments = Vec::with_capacity(func.arguments.len());
+                for arg in func.arguments.iter() {
+                    arguments.push(self.rewrite_scalar(arg, group_columns)?);
+                }
+                Some(
+                    FunctionCall {
+                        span: func.span,
+                        func_name: func.func_name.clone(),
+                        params: func.params.clone(),
+                        arguments,
+                    }
+                    .into(),
+                )
+            }
+            ScalarExpr::CastExpr(cast) => Some(
+                CastExpr {
+                    span: cast.span,
+                    is_try: cast.is_try,
+                    argument: Box::new(self.rewrite_scalar(&cast.argument, group_columns)?),
+                    target_type: cast.target_type.clone(),
+                }
+                .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Collect information from the plan of the query, which is started with [`Aggregate`].
+fn collect_query_information(s_expr: &SExpr) -> Result<Option<AggregationInformation<'_>>> {
+    let aggregate = match s_expr.plan() {
+        RelOperator::Aggregate(agg) => agg,
+        _ => return Ok(None),
+    };
+    collect_information_impl(aggregate, s_expr.child(0)?, HashMap::new())
+}
+
+/// Collect information from the bound plan of the view, which may be started with
+/// projections above [`Aggregate`].
+fn collect_view_information(s_expr: &SExpr) -> Result<Option<AggregationInformation<'_>>> {
+    let mut projections = HashMap::new();
+    let mut s_expr = s_expr;
+    loop {
+        match s_expr.plan() {
+            RelOperator::EvalScalar(eval) => {
+                projections.extend(eval.items.iter().map(|item| (item.index, &item.scalar)));
+                s_expr = s_expr.child(0)?;
+            }
+            RelOperator::Aggregate(agg) => {
+                return collect_information_impl(agg, s_expr.child(0)?, projections);
+            }
+            _ => return Ok(None),
+        }
+    }
+}
+
+fn collect_information_impl<'a>(
+    aggregate: &'a Aggregate,
+    s_expr: &'a SExpr,
+    projections: HashMap<IndexType, &'a ScalarExpr>,
+) -> Result<Option<AggregationInformation<'a>>> {
+    let mut s_expr = s_expr;
+    let mut args = HashMap::new();
+    if let RelOperator::EvalScalar(eval) = s_expr.plan() {
+        // This eval scalar hold aggregation's arguments.
+        args.extend(eval.items.iter().map(|item| (item.index, &item.scalar)));
+        s_expr = s_expr.child(0)?;
+    }
+    let mut filter = None;
+    if let RelOperator::Filter(f) = s_expr.plan() {
+        filter = Some(&f.predicates);
+        s_expr = s_expr.child(0)?;
+    }
+    let scan = match s_expr.plan() {
+        RelOperator::Scan(scan) => scan,
+        _ => return Ok(None),
+    };
+    if scan.limit.is_some() || scan.order_by.is_some() || scan.agg_index.is_some() {
+        return Ok(None);
+    }
+
+    // The filter may be already pushed down to the scan.
+    let predicates = match (filter, &scan.prewhere, &scan.push_down_predicates) {
+        (Some(predicates), _, _) => predicates.clone(),
+        (None, Some(prewhere), _) => prewhere.predicates.clone(),
+        (None, None, Some(predicates)) => predicates.clone(),
+        (None, None, None) => vec![],
+    };
+
+    Ok(Some(AggregationInformation {
+        table_index: scan.table_index,
+        aggregate,
+        args,
+        projections,
+        predicates: predicates.iter().flat_map(split_conjunctions).collect(),
+    }))
+}
@@ -14,6 +14,7 @@
 
 pub mod agg_index;
 mod filter_join;
+pub mod materialized_view;
 mod rule_eliminate_eval_scalar;
 mod rule_eliminate_filter;
 mod rule_fold_constant;
@@ -38,6 +39,7 @@ mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
+mod rule_try_apply_materialized_view;
 
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
 pub use rule_eliminate_filter::RuleEliminateFilter;
@@ -64,3 +66,4 @@ pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
+pub use rule_try_apply_materialized_view::RuleTryApplyMaterializedView;
@@ -17,6 +17,7 @@ use common_expression::ConstantFolder;
 use common_expression::FunctionContext;
 use common_functions::BUILTIN_FUNCTIONS;
 
+use crate::binder::contain_placeholder;
 use crate::optimizer::rule::Rule;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -118,6 +119,11 @@ impl RuleFoldConstant {
     }
 
     fn fold_constant(&self, scalar: &ScalarExpr) -> Result<ScalarExpr> {
+        // Placeholders are lowered to dummy columns, keep them untouched
+        // until the parameters of the prepared statement are bound.
+        if contain_placeholder(scalar) {
+            return Ok(scalar.clone());
+        }
         let expr = scalar.as_expr()?;
         let (new_expr, _) = ConstantFolder::fold_with_untrusted_column_type(
             &expr,
@@ -26,6 +26,9 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
+use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -104,6 +107,27 @@ impl RulePushDownFilterEvalScalar {
                             display_name: agg.display_name.clone(),
                         })
                     }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let default = match &lag_lead.default {
+                            Some(default) => {
+                                Some(Box::new(Self::replace_predicate(default, items)?))
+                            }
+                            None => None,
+                        };
+                        WindowFuncType::LagLead(LagLeadFunction {
+                            is_lag: lag_lead.is_lag,
+                            arg: Box::new(Self::replace_predicate(&lag_lead.arg, items)?),
+                            offset: lag_lead.offset,
+                            default,
+                            return_type: lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth) => WindowFuncType::NthValue(NthValueFunction {
+                        n: nth.n,
+                        arg: Box::new(Self::replace_predicate(&nth.arg, items)?),
+                        ignore_nulls: nth.ignore_nulls,
+                        return_type: nth.return_type.clone(),
+                    }),
                     func => func.clone(),
                 };
 
@@ -164,6 +188,23 @@ impl RulePushDownFilterEvalScalar {
                     func_name: func.func_name.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| Self::replace_predicate(arg, items))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let arg = Self::replace_predicate(&cast.argument, items)?;
                 Ok(ScalarExpr::CastExpr(CastExpr {
@@ -23,6 +23,9 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
+use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::Scan;
@@ -127,6 +130,37 @@ impl RulePushDownFilterScan {
                             display_name: agg.display_name.clone(),
                         })
                     }
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let default = match &lag_lead.default {
+                            Some(default) => Some(Box::new(Self::replace_view_column(
+                                default,
+                                table_entries,
+                                column_entries,
+                            )?)),
+                            None => None,
+                        };
+                        WindowFuncType::LagLead(LagLeadFunction {
+                            is_lag: lag_lead.is_lag,
+                            arg: Box::new(Self::replace_view_column(
+                                &lag_lead.arg,
+                                table_entries,
+                                column_entries,
+                            )?),
+                            offset: lag_lead.offset,
+                            default,
+                            return_type: lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth) => WindowFuncType::NthValue(NthValueFunction {
+                        n: nth.n,
+                        arg: Box::new(Self::replace_view_column(
+                            &nth.arg,
+                            table_entries,
+                            column_entries,
+                        )?),
+                        ignore_nulls: nth.ignore_nulls,
+                        return_type: nth.return_type.clone(),
+                    }),
                     func => func.clone(),
                 };
 
@@ -189,6 +223,23 @@ impl RulePushDownFilterScan {
                     func_name: func.func_name.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| Self::replace_view_column(arg, table_entries, column_entries))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let arg = Self::replace_view_column(&cast.argument, table_entries, column_entries)?;
                 Ok(ScalarExpr::CastExpr(CastExpr {
@@ -25,6 +25,9 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
+use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -150,6 +153,7 @@ fn replace_column_binding(
             Ok(ScalarExpr::BoundColumnRef(column))
         }
         constant_expr @ ScalarExpr::ConstantExpr(_) => Ok(constant_expr),
+        placeholder @ ScalarExpr::PlaceholderExpr(_) => Ok(placeholder),
         ScalarExpr::WindowFunction(expr) => Ok(ScalarExpr::WindowFunction(WindowFunc {
             span: expr.span,
             display_name: expr.display_name,
@@ -166,6 +170,24 @@ fn replace_column_binding(
                         .collect::<Result<Vec<_>>>()?,
                     return_type: arg.return_type,
                 }),
+                WindowFuncType::LagLead(lag_lead) => WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: lag_lead.is_lag,
+                    arg: Box::new(replace_column_binding(index_pairs, *lag_lead.arg)?),
+                    offset: lag_lead.offset,
+                    default: match lag_lead.default {
+                        Some(default) => {
+                            Some(Box::new(replace_column_binding(index_pairs, *default)?))
+                        }
+                        None => None,
+                    },
+                    return_type: lag_lead.return_type,
+                }),
+                WindowFuncType::NthValue(nth) => WindowFuncType::NthValue(NthValueFunction {
+                    n: nth.n,
+                    arg: Box::new(replace_column_binding(index_pairs, *nth.arg)?),
+                    ignore_nulls: nth.ignore_nulls,
+                    return_type: nth.return_type,
+                }),
                 t => t,
             },
             partition_by: expr
@@ -210,6 +232,19 @@ fn replace_column_binding(
                 .map(|arg| replace_column_binding(index_pairs, arg))
                 .collect::<Result<Vec<_>>>()?,
         })),
+        ScalarExpr::LambdaFunction(expr) => Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+            span: expr.span,
+            func_name: expr.func_name,
+            display_name: expr.display_name,
+            args: expr
+                .args
+                .into_iter()
+                .map(|arg| replace_column_binding(index_pairs, arg))
+                .collect::<Result<Vec<_>>>()?,
+            lambda_expr: expr.lambda_expr,
+            lambda_display: expr.lambda_display,
+            return_type: expr.return_type,
+        })),
         ScalarExpr::CastExpr(expr) => Ok(ScalarExpr::CastExpr(CastExpr {
             span: expr.span,
             is_try: expr.is_try,
@@ -81,10 +81,15 @@ impl RulePushDownPrewhere {
                     Self::collect_columns_impl(table_index, schema, arg, columns)?;
                 }
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                for arg in lambda.args.iter() {
+                    Self::collect_columns_impl(table_index, schema, arg, columns)?;
+                }
+            }
             ScalarExpr::CastExpr(cast) => {
                 Self::collect_columns_impl(table_index, schema, cast.argument.as_ref(), columns)?;
             }
-            ScalarExpr::ConstantExpr(_) => {}
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => {}
             _ => {
                 // SubqueryExpr and AggregateFunction will not appear in Filter-LogicalGet
                 return Err(ErrorCode::Unimplemented(format!(
@@ -0,0 +1,178 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use super::materialized_view;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// Try to answer an aggregation on a base table with the data of
+/// a fresh materialized view built on the table.
+pub struct RuleTryApplyMaterializedView {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+    metadata: MetadataRef,
+}
+
+impl RuleTryApplyMaterializedView {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyMaterializedView,
+            metadata,
+            patterns: vec![
+                // Aggregation
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    PatternPlan {
+                        plan_type: RelOp::Aggregate,
+                    }
+                    .into(),
+                    SExpr::create_leaf(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ),
+                ),
+                // Aggregation
+                //     |
+                //   Filter
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    PatternPlan {
+                        plan_type: RelOp::Aggregate,
+                    }
+                    .into(),
+                    SExpr::create_unary(
+                        PatternPlan {
+                            plan_type: RelOp::Filter,
+                        }
+                        .into(),
+                        SExpr::create_leaf(
+                            PatternPlan {
+                                plan_type: RelOp::Scan,
+                            }
+                            .into(),
+                        ),
+                    ),
+                ),
+                // Aggregation
+                //     |
+                // Expression
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    PatternPlan {
+                        plan_type: RelOp::Aggregate,
+                    }
+                    .into(),
+                    SExpr::create_unary(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                        SExpr::create_leaf(
+                            PatternPlan {
+                                plan_type: RelOp::Scan,
+                            }
+                            .into(),
+                        ),
+                    ),
+                ),
+                // Aggregation
+                //     |
+                // Expression
+                //     |
+                //   Filter
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    PatternPlan {
+                        plan_type: RelOp::Aggregate,
+                    }
+                    .into(),
+                    SExpr::create_unary(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                        SExpr::create_unary(
+                            PatternPlan {
+                                plan_type: RelOp::Filter,
+                            }
+                            .into(),
+                            SExpr::create_leaf(
+                                PatternPlan {
+                                    plan_type: RelOp::Scan,
+                                }
+                                .into(),
+                            ),
+                        ),
+                    ),
+                ),
+            ],
+        }
+    }
+}
+
+impl Rule for RuleTryApplyMaterializedView {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let table_index = Self::get_table_index(s_expr);
+        let views = {
+            let metadata = self.metadata.read();
+            let table_id = metadata.table(table_index).table().get_id();
+            match metadata.get_materialized_views(table_id) {
+                Some(views) if !views.is_empty() => views.to_vec(),
+                // No fresh materialized view.
+                _ => return Ok(()),
+            }
+        };
+
+        if let Some(mut result) =
+            materialized_view::try_rewrite(s_expr, &views, self.metadata.clone())?
+        {
+            result.set_applied_rule(&self.id);
+            state.add_result(result);
+        }
+
+        Ok(())
+    }
+}
+
+impl RuleTryApplyMaterializedView {
+    fn get_table_index(s_expr: &SExpr) -> IndexType {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.table_index,
+            _ => Self::get_table_index(s_expr.child(0).unwrap()),
+        }
+    }
+}
@@ -65,6 +65,7 @@ pub enum RuleID {
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    TryApplyMaterializedView,
 
     // Exploration rules
     CommuteJoin,
@@ -113,6 +114,7 @@ impl Display for RuleID {
             RuleID::RightExchangeJoin => write!(f, "RightExchangeJoin"),
             RuleID::ExchangeJoin => write!(f, "ExchangeJoin"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::TryApplyMaterializedView => write!(f, "TryApplyMaterializedView"),
         }
     }
 }
@@ -271,6 +271,14 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
                     .any(|expr| find_subquery_in_expr(&expr.scalar))
                 || match &op.function {
                     WindowFuncType::Aggregate(agg) => agg.args.iter().any(find_subquery_in_expr),
+                    WindowFuncType::LagLead(lag_lead) => {
+                        find_subquery_in_expr(&lag_lead.arg)
+                            || lag_lead
+                                .default
+                                .as_ref()
+                                .map_or(false, |default| find_subquery_in_expr(default))
+                    }
+                    WindowFuncType::NthValue(nth) => find_subquery_in_expr(&nth.arg),
                     _ => false,
                 }
         }
@@ -283,10 +291,20 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
 
 fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
     match expr {
-        ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => false,
+        ScalarExpr::BoundColumnRef(_)
+        | ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::PlaceholderExpr(_) => false,
         ScalarExpr::WindowFunction(expr) => {
             let flag = match &expr.func {
                 WindowFuncType::Aggregate(agg) => agg.args.iter().any(find_subquery_in_expr),
+                WindowFuncType::LagLead(lag_lead) => {
+                    find_subquery_in_expr(&lag_lead.arg)
+                        || lag_lead
+                            .default
+                            .as_ref()
+                            .map_or(false, |default| find_subquery_in_expr(default))
+                }
+                WindowFuncType::NthValue(nth) => find_subquery_in_expr(&nth.arg),
                 _ => false,
             };
             flag || expr.partition_by.iter().any(find_subquery_in_expr)
@@ -294,6 +312,7 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
         }
         ScalarExpr::AggregateFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
         ScalarExpr::FunctionCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
+        ScalarExpr::LambdaFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
         ScalarExpr::CastExpr(expr) => find_subquery_in_expr(&expr.argument),
         ScalarExpr::SubqueryExpr(_) => true,
     }
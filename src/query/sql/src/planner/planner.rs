@@ -25,7 +25,9 @@ use common_ast::walk_statement_mut;
 use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use parking_lot::RwLock;
 
 use super::semantic::AggregateRewriter;
@@ -62,9 +64,6 @@ impl Planner {
 
     #[async_backtrace::framed]
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
-        let settings = self.ctx.get_settings();
-        let sql_dialect = settings.get_sql_dialect()?;
-
         // Step 1: Tokenize the SQL.
         let mut tokenizer = Tokenizer::new(sql).peekable();
 
@@ -89,35 +88,8 @@ impl Planner {
         };
 
         loop {
-            let res = async {
-                // Step 2: Parse the SQL.
-                let (mut stmt, format) = parse_sql(&tokens, sql_dialect)?;
-                self.replace_stmt(&mut stmt, sql_dialect);
-
-                // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-                let plan = binder.bind(&stmt).await?;
-
-                // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
-                    enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
-                }));
-
-                let optimized_plan = optimize(self.ctx.clone(), opt_ctx, plan)?;
-                Ok((optimized_plan, PlanExtras {
-                    metadata,
-                    format,
-                    statement: stmt,
-                }))
-            }
-            .await;
+            let metadata = Arc::new(RwLock::new(Metadata::default()));
+            let res = self.plan_tokens(&tokens, metadata).await;
 
             let mut maybe_partial_insert = false;
             if is_insert_stmt && matches!(tokenizer.peek(), Some(Ok(_))) {
@@ -159,6 +131,91 @@ impl Planner {
         }
     }
 
+    /// Bind a prepared statement, the `?` placeholders in `sql` are bound
+    /// with the given data types in order and kept as `PlaceholderExpr`s
+    /// in the plan, so the plan can be reused with different parameters.
+    ///
+    /// The plan is not optimized, as the optimizations depend on the parameters
+    /// and the statistics of the tables, see `optimize_plan`.
+    #[async_backtrace::framed]
+    pub async fn bind_sql_with_placeholders(
+        &mut self,
+        sql: &str,
+        types: &[DataType],
+    ) -> Result<(Plan, PlanExtras)> {
+        let tokens: Vec<Token> = Tokenizer::new(sql).collect::<Result<_>>()?;
+
+        let offsets = tokens
+            .iter()
+            .filter(|token| token.kind == TokenKind::Placeholder)
+            .map(|token| token.span.start)
+            .collect::<Vec<_>>();
+        if offsets.len() != types.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "expected {} parameters, but got {}",
+                offsets.len(),
+                types.len()
+            )));
+        }
+
+        let mut metadata = Metadata::default();
+        for (offset, data_type) in offsets.into_iter().zip(types.iter()) {
+            metadata.add_placeholder(offset, data_type.clone());
+        }
+        self.bind_tokens(&tokens, Arc::new(RwLock::new(metadata)))
+            .await
+    }
+
+    /// Optimize a plan generated by `bind_sql_with_placeholders`.
+    pub fn optimize_plan(&self, plan: Plan) -> Result<Plan> {
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        optimize(self.ctx.clone(), opt_ctx, plan)
+    }
+
+    #[async_backtrace::framed]
+    async fn plan_tokens(
+        &self,
+        tokens: &[Token<'_>],
+        metadata: MetadataRef,
+    ) -> Result<(Plan, PlanExtras)> {
+        let (plan, extras) = self.bind_tokens(tokens, metadata).await?;
+
+        // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+        let optimized_plan = self.optimize_plan(plan)?;
+        Ok((optimized_plan, extras))
+    }
+
+    #[async_backtrace::framed]
+    async fn bind_tokens(
+        &self,
+        tokens: &[Token<'_>],
+        metadata: MetadataRef,
+    ) -> Result<(Plan, PlanExtras)> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+
+        // Step 2: Parse the SQL.
+        let (mut stmt, format) = parse_sql(tokens, sql_dialect)?;
+        self.replace_stmt(&mut stmt, sql_dialect);
+
+        // Step 3: Bind AST with catalog, and generate a pure logical SExpr
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+        let plan = binder.bind(&stmt).await?;
+        Ok((plan, PlanExtras {
+            metadata,
+            format,
+            statement: stmt,
+        }))
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -17,8 +17,12 @@ mod catalog;
 mod database;
 mod file_format;
 mod index;
+mod masking_policy;
+mod sequence;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod udf;
 mod view;
 
@@ -27,7 +31,11 @@ pub use catalog::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use masking_policy::*;
+pub use sequence::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use udf::*;
 pub use view::*;
@@ -23,6 +23,7 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
@@ -184,6 +185,40 @@ impl AddTableColumnPlan {
     }
 }
 
+// Table modify column
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModifyTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    // the new definition of the column, with the default expr
+    pub field: TableField,
+    pub field_comment: String,
+}
+
+impl ModifyTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table set or unset the masking policy of column
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetColumnMaskingPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+    // `None` to unset the masking policy
+    pub policy: Option<String>,
+}
+
+impl SetColumnMaskingPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // Table drop column
 #[derive(Clone, Debug, PartialEq)]
 pub struct DropTableColumnPlan {
@@ -0,0 +1,43 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::schema::TaskState;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTaskPlan {
+    pub if_not_exists: bool,
+    pub task: String,
+    pub schedule: Option<String>,
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+    /// The SQL the task runs.
+    pub query: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterTaskPlan {
+    pub task: String,
+    pub state: TaskState,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTaskPlan {
+    pub if_exists: bool,
+    pub task: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecuteTaskPlan {
+    pub task: String,
+}
@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::TableSchemaRef;
+use common_meta_types::MetaId;
+
+use crate::plans::TableOptions;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
@@ -41,3 +46,26 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: TableSchemaRef,
+    pub options: TableOptions,
+    pub subquery: String,
+    pub base_database: String,
+    pub base_table: String,
+    pub base_table_id: MetaId,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
@@ -25,6 +25,7 @@ pub mod insert;
 mod join;
 mod kill;
 mod limit;
+mod merge_into;
 mod operator;
 mod pattern;
 mod plan;
@@ -57,6 +58,7 @@ pub use insert::InsertInputSource;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use merge_into::*;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
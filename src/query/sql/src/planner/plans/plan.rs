@@ -22,10 +22,20 @@ use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 
+use super::AlterTaskPlan;
 use super::CreateIndexPlan;
+use super::CreateMaskingPolicyPlan;
+use super::CreateSequencePlan;
 use super::CreateShareEndpointPlan;
+use super::CreateStreamPlan;
+use super::CreateTaskPlan;
 use super::DropIndexPlan;
+use super::DropMaskingPolicyPlan;
+use super::DropSequencePlan;
 use super::DropShareEndpointPlan;
+use super::DropStreamPlan;
+use super::DropTaskPlan;
+use super::ExecuteTaskPlan;
 use super::VacuumTablePlan;
 use crate::optimizer::SExpr;
 use crate::plans::copy::CopyPlan;
@@ -51,6 +61,7 @@ use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateTablePlan;
@@ -74,7 +85,10 @@ use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeInto;
+use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTablePlan;
@@ -82,6 +96,7 @@ use crate::plans::Replace;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
+use crate::plans::SetColumnMaskingPolicyPlan;
 use crate::plans::SetRolePlan;
 use crate::plans::SettingPlan;
 use crate::plans::ShowCreateCatalogPlan;
@@ -154,6 +169,8 @@ pub enum Plan {
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
+    ModifyTableColumn(Box<ModifyTableColumnPlan>),
+    SetColumnMaskingPolicy(Box<SetColumnMaskingPolicyPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
@@ -168,18 +185,44 @@ pub enum Plan {
     // Insert
     Insert(Box<Insert>),
     Replace(Box<Replace>),
+    MergeInto(Box<MergeInto>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Views
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
 
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
 
+    // Sequences
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+
+    // Tasks
+    CreateTask(Box<CreateTaskPlan>),
+    AlterTask(Box<AlterTaskPlan>),
+    DropTask(Box<DropTaskPlan>),
+    ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
+
+    // Masking policies
+    CreateMaskingPolicy(Box<CreateMaskingPolicyPlan>),
+    DropMaskingPolicy(Box<DropMaskingPolicyPlan>),
+
     // Account
     AlterUser(Box<AlterUserPlan>),
     CreateUser(Box<CreateUserPlan>),
@@ -241,6 +284,9 @@ pub enum RewriteKind {
     ShowProcessList,
     ShowEngines,
     ShowIndexes,
+    ShowSequences,
+    ShowTasks,
+    ShowStreams,
 
     ShowCatalogs,
     ShowDatabases,
@@ -281,6 +327,8 @@ impl Display for Plan {
             Plan::UndropTable(_) => write!(f, "UndropTable"),
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
+            Plan::ModifyTableColumn(_) => write!(f, "ModifyTableColumn"),
+            Plan::SetColumnMaskingPolicy(_) => write!(f, "SetColumnMaskingPolicy"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
@@ -293,8 +341,20 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
+            Plan::CreateSequence(_) => write!(f, "CreateSequence"),
+            Plan::DropSequence(_) => write!(f, "DropSequence"),
+            Plan::CreateTask(_) => write!(f, "CreateTask"),
+            Plan::AlterTask(_) => write!(f, "AlterTask"),
+            Plan::DropTask(_) => write!(f, "DropTask"),
+            Plan::ExecuteTask(_) => write!(f, "ExecuteTask"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
+            Plan::CreateMaskingPolicy(_) => write!(f, "CreateMaskingPolicy"),
+            Plan::DropMaskingPolicy(_) => write!(f, "DropMaskingPolicy"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -317,8 +377,12 @@ impl Display for Plan {
             Plan::DropUDF(_) => write!(f, "DropUDF"),
             Plan::Insert(_) => write!(f, "Insert"),
             Plan::Replace(_) => write!(f, "Replace"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Abort => write!(f, "Abort"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
@@ -21,6 +21,7 @@ use common_exception::Range;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
+use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use educe::Educe;
 use itertools::Itertools;
@@ -37,9 +38,11 @@ use crate::MetadataRef;
 pub enum ScalarExpr {
     BoundColumnRef(BoundColumnRef),
     ConstantExpr(ConstantExpr),
+    PlaceholderExpr(PlaceholderExpr),
     WindowFunction(WindowFunc),
     AggregateFunction(AggregateFunction),
     FunctionCall(FunctionCall),
+    LambdaFunction(LambdaFunc),
     CastExpr(CastExpr),
     SubqueryExpr(SubqueryExpr),
 }
@@ -52,7 +55,7 @@ impl ScalarExpr {
     pub fn used_columns(&self) -> ColumnSet {
         match self {
             ScalarExpr::BoundColumnRef(scalar) => ColumnSet::from([scalar.column.index]),
-            ScalarExpr::ConstantExpr(_) => ColumnSet::new(),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => ColumnSet::new(),
             ScalarExpr::WindowFunction(scalar) => {
                 let mut result = scalar.func.used_columns();
                 for scalar in &scalar.partition_by {
@@ -77,6 +80,13 @@ impl ScalarExpr {
                 }
                 result
             }
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = ColumnSet::new();
+                for scalar in &scalar.args {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                result
+            }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
         }
@@ -92,7 +102,7 @@ impl ScalarExpr {
                 }
                 Ok(tables)
             }
-            ScalarExpr::ConstantExpr(_) => Ok(vec![]),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => Ok(vec![]),
             ScalarExpr::AggregateFunction(scalar) => {
                 let mut result = vec![];
                 for scalar in &scalar.args {
@@ -107,6 +117,13 @@ impl ScalarExpr {
                 }
                 Ok(result)
             }
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = vec![];
+                for scalar in &scalar.args {
+                    result.append(&mut scalar.used_tables(metadata.clone())?);
+                }
+                Ok(result)
+            }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_tables(metadata),
             ScalarExpr::WindowFunction(_) | ScalarExpr::SubqueryExpr(_) => {
                 Err(ErrorCode::Unimplemented(
@@ -120,6 +137,7 @@ impl ScalarExpr {
         match self {
             ScalarExpr::BoundColumnRef(expr) => expr.span,
             ScalarExpr::ConstantExpr(expr) => expr.span,
+            ScalarExpr::PlaceholderExpr(expr) => expr.span,
             ScalarExpr::FunctionCall(expr) => expr.span.or_else(|| {
                 let (start, end) = expr
                     .arguments
@@ -130,6 +148,7 @@ impl ScalarExpr {
                     .into_option()?;
                 Some(Range { start, end })
             }),
+            ScalarExpr::LambdaFunction(expr) => expr.span,
             ScalarExpr::CastExpr(expr) => expr.span.or(expr.argument.span()),
             ScalarExpr::SubqueryExpr(expr) => expr.span,
             _ => None,
@@ -175,6 +194,12 @@ impl TryFrom<ScalarExpr> for ConstantExpr {
     }
 }
 
+impl From<PlaceholderExpr> for ScalarExpr {
+    fn from(v: PlaceholderExpr) -> Self {
+        Self::PlaceholderExpr(v)
+    }
+}
+
 impl From<AggregateFunction> for ScalarExpr {
     fn from(v: AggregateFunction) -> Self {
         Self::AggregateFunction(v)
@@ -231,6 +256,23 @@ impl TryFrom<ScalarExpr> for FunctionCall {
     }
 }
 
+impl From<LambdaFunc> for ScalarExpr {
+    fn from(v: LambdaFunc) -> Self {
+        Self::LambdaFunction(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for LambdaFunc {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::LambdaFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast Scalar to LambdaFunc"))
+        }
+    }
+}
+
 impl From<CastExpr> for ScalarExpr {
     fn from(v: CastExpr) -> Self {
         Self::CastExpr(v)
@@ -283,6 +325,18 @@ pub struct ConstantExpr {
     pub value: Scalar,
 }
 
+/// A `?` parameter of a prepared statement, replaced by a `ConstantExpr`
+/// once the parameter value is bound.
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct PlaceholderExpr {
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub span: Span,
+    /// Zero-based position of the parameter in the statement.
+    pub index: usize,
+    pub data_type: Box<DataType>,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum ComparisonOp {
     Equal,
@@ -382,6 +436,23 @@ pub struct FunctionCall {
     pub arguments: Vec<ScalarExpr>,
 }
 
+/// A higher-order function call like `array_transform(arr, x -> x + 1)`. The lambda is
+/// type-checked against the element type of the array when binding, and its body only
+/// references the lambda parameters, by their positions.
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct LambdaFunc {
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub span: Span,
+    pub func_name: String,
+    pub display_name: String,
+    pub args: Vec<ScalarExpr>,
+    #[educe(Hash(ignore))]
+    pub lambda_expr: Box<RemoteExpr>,
+    pub lambda_display: String,
+    pub return_type: Box<DataType>,
+}
+
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct CastExpr {
@@ -40,6 +40,7 @@ use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics;
 use crate::plans::Operator;
 use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::IndexType;
 
@@ -69,14 +70,7 @@ impl Window {
 
         used_columns.insert(self.index);
 
-        if let WindowFuncType::Aggregate(agg) = &self.function {
-            for scalar in &agg.args {
-                used_columns = used_columns
-                    .union(&scalar.used_columns())
-                    .cloned()
-                    .collect();
-            }
-        }
+        used_columns.extend(self.function.used_columns());
 
         for part in self.partition_by.iter() {
             used_columns.insert(part.index);
@@ -221,6 +215,42 @@ pub enum WindowFuncType {
     Rank,
     DenseRank,
     PercentRank,
+    LagLead(LagLeadFunction),
+    NthValue(NthValueFunction),
+    Ntile(NtileFunction),
+    CumeDist,
+}
+
+/// `LAG(arg, offset, default)` or `LEAD(arg, offset, default)`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LagLeadFunction {
+    /// Is `lag` or `lead`.
+    pub is_lag: bool,
+    pub arg: Box<ScalarExpr>,
+    pub offset: u64,
+    /// Has been cast to `return_type` if exists.
+    pub default: Option<Box<ScalarExpr>>,
+    pub return_type: Box<DataType>,
+}
+
+/// `FIRST_VALUE(arg)`, `LAST_VALUE(arg)` or `NTH_VALUE(arg, n)`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NthValueFunction {
+    /// The nth row of the window frame (counting from 1).
+    /// - Some(1): `first_value`
+    /// - Some(n): `nth_value`
+    /// - None: `last_value`
+    pub n: Option<u64>,
+    pub arg: Box<ScalarExpr>,
+    pub ignore_nulls: bool,
+    pub return_type: Box<DataType>,
+}
+
+/// `NTILE(n)`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NtileFunction {
+    /// The number of the buckets, must be positive.
+    pub n: u64,
 }
 
 impl WindowFuncType {
@@ -230,6 +260,7 @@ impl WindowFuncType {
             "rank" => Ok(WindowFuncType::Rank),
             "dense_rank" => Ok(WindowFuncType::DenseRank),
             "percent_rank" => Ok(WindowFuncType::PercentRank),
+            "cume_dist" => Ok(WindowFuncType::CumeDist),
             _ => Err(ErrorCode::UnknownFunction(format!(
                 "Unknown window function: {}",
                 name
@@ -243,6 +274,15 @@ impl WindowFuncType {
             WindowFuncType::Rank => "rank".to_string(),
             WindowFuncType::DenseRank => "dense_rank".to_string(),
             WindowFuncType::PercentRank => "percent_rank".to_string(),
+            WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => "lag".to_string(),
+            WindowFuncType::LagLead(_) => "lead".to_string(),
+            WindowFuncType::NthValue(nth) => match nth.n {
+                Some(1) => "first_value".to_string(),
+                Some(_) => "nth_value".to_string(),
+                None => "last_value".to_string(),
+            },
+            WindowFuncType::Ntile(_) => "ntile".to_string(),
+            WindowFuncType::CumeDist => "cume_dist".to_string(),
         }
     }
 
@@ -251,6 +291,14 @@ impl WindowFuncType {
             WindowFuncType::Aggregate(agg) => {
                 agg.args.iter().flat_map(|arg| arg.used_columns()).collect()
             }
+            WindowFuncType::LagLead(lag_lead) => {
+                let mut used_columns = lag_lead.arg.used_columns();
+                if let Some(default) = &lag_lead.default {
+                    used_columns.extend(default.used_columns());
+                }
+                used_columns
+            }
+            WindowFuncType::NthValue(nth) => nth.arg.used_columns(),
             _ => ColumnSet::new(),
         }
     }
@@ -258,10 +306,15 @@ impl WindowFuncType {
     pub fn return_type(&self) -> DataType {
         match self {
             WindowFuncType::Aggregate(agg) => *agg.return_type.clone(),
-            WindowFuncType::RowNumber | WindowFuncType::Rank | WindowFuncType::DenseRank => {
-                DataType::Number(NumberDataType::UInt64)
+            WindowFuncType::RowNumber
+            | WindowFuncType::Rank
+            | WindowFuncType::DenseRank
+            | WindowFuncType::Ntile(_) => DataType::Number(NumberDataType::UInt64),
+            WindowFuncType::PercentRank | WindowFuncType::CumeDist => {
+                DataType::Number(NumberDataType::Float64)
             }
-            WindowFuncType::PercentRank => DataType::Number(NumberDataType::Float64),
+            WindowFuncType::LagLead(lag_lead) => *lag_lead.return_type.clone(),
+            WindowFuncType::NthValue(nth) => *nth.return_type.clone(),
         }
     }
 }
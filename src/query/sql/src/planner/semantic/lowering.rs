@@ -28,6 +28,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
@@ -126,6 +127,27 @@ fn resolve_column_type<C: LoweringContext>(
                 args,
             })
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+            return_type,
+        } => {
+            let args = args
+                .iter()
+                .map(|arg| resolve_column_type(arg, context))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(RawExpr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args,
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            })
+        }
         RawExpr::Constant { .. } => Ok(raw_expr.clone()),
     }
 }
@@ -228,12 +250,26 @@ impl ScalarExpr {
                 params: func.params.clone(),
                 args: func.arguments.iter().map(ScalarExpr::as_raw_expr).collect(),
             },
+            ScalarExpr::LambdaFunction(func) => RawExpr::LambdaFunctionCall {
+                span: func.span,
+                name: func.func_name.clone(),
+                args: func.args.iter().map(ScalarExpr::as_raw_expr).collect(),
+                lambda_expr: func.lambda_expr.clone(),
+                lambda_display: func.lambda_display.clone(),
+                return_type: (*func.return_type).clone(),
+            },
             ScalarExpr::CastExpr(cast) => RawExpr::Cast {
                 span: cast.span,
                 is_try: cast.is_try,
                 expr: Box::new(cast.argument.as_raw_expr()),
                 dest_type: (*cast.target_type).clone(),
             },
+            ScalarExpr::PlaceholderExpr(placeholder) => RawExpr::ColumnRef {
+                span: placeholder.span,
+                id: new_dummy_column((*placeholder.data_type).clone()),
+                data_type: (*placeholder.data_type).clone(),
+                display_name: format!("?{}", placeholder.index + 1),
+            },
             ScalarExpr::SubqueryExpr(subquery) => RawExpr::ColumnRef {
                 span: subquery.span,
                 id: new_dummy_column(subquery.data_type()),
@@ -268,6 +304,28 @@ impl ScalarExpr {
                         .collect::<Result<Vec<_>>>()?,
                 }))
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args = args
+                    .iter()
+                    .map(ScalarExpr::from_expr)
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: *span,
+                    func_name: name.clone(),
+                    display_name: expr.sql_display(),
+                    args,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: Box::new(return_type.clone()),
+                }))
+            }
             Expr::Cast {
                 span,
                 is_try,
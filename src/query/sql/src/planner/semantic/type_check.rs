@@ -21,6 +21,7 @@ use common_ast::ast::BinaryOperator;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
 use common_ast::ast::Query;
@@ -76,6 +77,11 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
+use crate::plans::NthValueFunction;
+use crate::plans::NtileFunction;
+use crate::plans::PlaceholderExpr;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
@@ -324,6 +330,7 @@ impl<'a> TypeChecker<'a> {
                             args: args.iter().copied().cloned().collect(),
                             params: vec![],
                             window: None,
+                            lambda: None,
                         })
                         .await?
                     } else {
@@ -533,6 +540,7 @@ impl<'a> TypeChecker<'a> {
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -583,9 +591,15 @@ impl<'a> TypeChecker<'a> {
                 args,
                 params,
                 window,
+                lambda,
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
+                if let Some(lambda) = lambda {
+                    return self
+                        .resolve_lambda_function(*span, func_name, args, lambda)
+                        .await;
+                }
                 if !is_builtin_function(func_name)
                     && !Self::all_rewritable_scalar_function().contains(&func_name)
                 {
@@ -664,16 +678,16 @@ impl<'a> TypeChecker<'a> {
                             "window function {name} can only be used in window clause"
                         )));
                     }
-                    if !args.is_empty() {
-                        return Err(ErrorCode::SemanticError(format!(
-                            "window function {name} does not have any argument"
-                        )));
-                    }
                     let window = window.as_ref().unwrap();
+                    let in_window = self.in_window_function;
+                    self.in_window_function = true;
+                    let func = self
+                        .resolve_general_window_function(*span, &name, &args, window.ignore_nulls)
+                        .await?;
+                    self.in_window_function = in_window;
                     // WindowReference already rewritten by `SelectRewriter` before.
-                    let window = window.as_window_spec().unwrap();
+                    let window = window.window.as_window_spec().unwrap();
                     let display_name = format!("{:#}", expr);
-                    let func = WindowFuncType::from_name(&name)?;
                     self.resolve_window(*span, display_name, window, func)
                         .await?
                 } else if AggregateFunctionFactory::instance().contains(&name) {
@@ -685,10 +699,16 @@ impl<'a> TypeChecker<'a> {
                     self.in_window_function = in_window;
                     if let Some(window) = window {
                         // aggregate window function
+                        if window.ignore_nulls.is_some() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "aggregate window function {name} does not support IGNORE NULLS or RESPECT NULLS"
+                            ))
+                            .set_span(*span));
+                        }
                         let display_name = format!("{:#}", expr);
                         let func = WindowFuncType::Aggregate(new_agg_func);
                         // WindowReference already rewritten by `SelectRewriter` before.
-                        let window = window.as_window_spec().unwrap();
+                        let window = window.window.as_window_spec().unwrap();
                         self.resolve_window(*span, display_name, window, func)
                             .await?
                     } else {
@@ -811,11 +831,8 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
+            Expr::Interval { span, expr, unit } => {
+                self.resolve_interval_expr(*span, unit, expr).await?
             }
             Expr::DateAdd {
                 span,
@@ -870,6 +887,28 @@ impl<'a> TypeChecker<'a> {
 
             Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs).await?,
 
+            Expr::Placeholder { span } => {
+                let placeholder =
+                    span.and_then(|span| self.metadata.read().placeholder(span.start).cloned());
+                match placeholder {
+                    Some((index, data_type)) => Box::new((
+                        PlaceholderExpr {
+                            span: *span,
+                            index,
+                            data_type: Box::new(data_type.clone()),
+                        }
+                        .into(),
+                        data_type,
+                    )),
+                    None => {
+                        return Err(ErrorCode::SemanticError(
+                            "placeholder `?` is only allowed in prepared statements",
+                        )
+                        .set_span(*span));
+                    }
+                }
+            }
+
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs).await?,
         };
 
@@ -935,6 +974,152 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((window_func.into(), data_type)))
     }
 
+    /// Resolve the general window functions, which are not aggregate functions.
+    #[async_backtrace::framed]
+    async fn resolve_general_window_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+        ignore_nulls: Option<bool>,
+    ) -> Result<WindowFuncType> {
+        if ignore_nulls.is_some()
+            && !matches!(func_name, "first_value" | "last_value" | "nth_value")
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "window function {func_name} does not support IGNORE NULLS or RESPECT NULLS"
+            ))
+            .set_span(span));
+        }
+
+        let check_args_len = |min: usize, max: usize| {
+            if args.len() < min || args.len() > max {
+                let expected = if min == max {
+                    format!("{min}")
+                } else {
+                    format!("{min} to {max}")
+                };
+                Err(ErrorCode::SemanticError(format!(
+                    "window function {func_name} expects {expected} arguments, but got {}",
+                    args.len()
+                ))
+                .set_span(span))
+            } else {
+                Ok(())
+            }
+        };
+
+        match func_name {
+            "lag" | "lead" => {
+                check_args_len(1, 3)?;
+                let box (arg, arg_type) = self.resolve(args[0]).await?;
+                let offset = if args.len() > 1 {
+                    self.resolve_window_function_constant_arg(func_name, args[1])
+                        .await?
+                } else {
+                    1
+                };
+                let return_type = arg_type.wrap_nullable();
+                let default = if args.len() > 2 {
+                    let box (default, default_type) = self.resolve(args[2]).await?;
+                    let default = if default_type == return_type {
+                        default
+                    } else {
+                        CastExpr {
+                            span: args[2].span(),
+                            is_try: false,
+                            argument: Box::new(default),
+                            target_type: Box::new(return_type.clone()),
+                        }
+                        .into()
+                    };
+                    Some(Box::new(default))
+                } else {
+                    None
+                };
+                Ok(WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: func_name == "lag",
+                    arg: Box::new(arg),
+                    offset,
+                    default,
+                    return_type: Box::new(return_type),
+                }))
+            }
+            "first_value" | "last_value" | "nth_value" => {
+                let n = if func_name == "nth_value" {
+                    check_args_len(2, 2)?;
+                    let n = self
+                        .resolve_window_function_constant_arg(func_name, args[1])
+                        .await?;
+                    if n == 0 {
+                        return Err(ErrorCode::SemanticError(
+                            "the n of window function nth_value must be greater than 0",
+                        )
+                        .set_span(args[1].span()));
+                    }
+                    Some(n)
+                } else {
+                    check_args_len(1, 1)?;
+                    (func_name == "first_value").then_some(1)
+                };
+                let box (arg, arg_type) = self.resolve(args[0]).await?;
+                Ok(WindowFuncType::NthValue(NthValueFunction {
+                    n,
+                    arg: Box::new(arg),
+                    ignore_nulls: ignore_nulls.unwrap_or(false),
+                    return_type: Box::new(arg_type.wrap_nullable()),
+                }))
+            }
+            "ntile" => {
+                check_args_len(1, 1)?;
+                let n = self
+                    .resolve_window_function_constant_arg(func_name, args[0])
+                    .await?;
+                if n == 0 {
+                    return Err(ErrorCode::SemanticError(
+                        "the number of buckets of window function ntile must be greater than 0",
+                    )
+                    .set_span(args[0].span()));
+                }
+                Ok(WindowFuncType::Ntile(NtileFunction { n }))
+            }
+            _ => {
+                if !args.is_empty() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} does not have any argument"
+                    ))
+                    .set_span(span));
+                }
+                WindowFuncType::from_name(func_name)
+            }
+        }
+    }
+
+    /// Resolve the constant unsigned integer argument of window function,
+    /// such as the offset of `lag` or the number of buckets of `ntile`.
+    #[async_backtrace::framed]
+    async fn resolve_window_function_constant_arg(
+        &mut self,
+        func_name: &str,
+        arg: &Expr,
+    ) -> Result<u64> {
+        let box (scalar, _) = self.resolve(arg).await?;
+        let expr = common_expression::Expr::Cast {
+            span: arg.span(),
+            is_try: false,
+            expr: Box::new(scalar.as_expr()?),
+            dest_type: DataType::Number(NumberDataType::UInt64),
+        };
+        check_number::<_, u64>(arg.span(), &self.func_ctx, &expr, &BUILTIN_FUNCTIONS).map_err(
+            |_| {
+                ErrorCode::SemanticError(format!(
+                    "the argument {arg} of window function {func_name} must be a constant unsigned integer"
+                ))
+                .set_span(arg.span())
+            },
+        )
+    }
+
     // just support integer
     #[inline]
     fn resolve_rows_offset(&self, expr: &Expr) -> Result<Scalar> {
@@ -1064,12 +1249,30 @@ impl<'a> TypeChecker<'a> {
         order_by: &mut [WindowOrderBy],
         window_frame: Option<WindowFrame>,
     ) -> Result<WindowFuncFrame> {
-        if matches!(func, WindowFuncType::PercentRank) {
-            return Ok(WindowFuncFrame {
-                units: WindowFuncFrameUnits::Rows,
-                start_bound: WindowFuncFrameBound::Preceding(None),
-                end_bound: WindowFuncFrameBound::Following(None),
-            });
+        match func {
+            WindowFuncType::PercentRank | WindowFuncType::Ntile(_) | WindowFuncType::CumeDist => {
+                // Need the size of the whole partition.
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Preceding(None),
+                    end_bound: WindowFuncFrameBound::Following(None),
+                });
+            }
+            WindowFuncType::LagLead(lag_lead) => {
+                // The frame only contains the row at the offset, it's empty if out of the partition.
+                let offset = Some(Scalar::Number(NumberScalar::UInt64(lag_lead.offset)));
+                let bound = if lag_lead.is_lag {
+                    WindowFuncFrameBound::Preceding(offset)
+                } else {
+                    WindowFuncFrameBound::Following(offset)
+                };
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: bound.clone(),
+                    end_bound: bound,
+                });
+            }
+            _ => {}
         }
         if let Some(frame) = window_frame {
             if frame.units.is_range() {
@@ -1418,6 +1621,32 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval_expr(
+        &mut self,
+        span: Span,
+        interval_kind: &ASTIntervalKind,
+        arg: &Expr,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = match interval_kind {
+            ASTIntervalKind::Year => "to_years",
+            ASTIntervalKind::Quarter => "to_quarters",
+            ASTIntervalKind::Month => "to_months",
+            ASTIntervalKind::Day => "to_days",
+            ASTIntervalKind::Hour => "to_hours",
+            ASTIntervalKind::Minute => "to_minutes",
+            ASTIntervalKind::Second => "to_seconds",
+            ASTIntervalKind::Doy | ASTIntervalKind::Dow => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Unsupported interval unit {interval_kind}"
+                ))
+                .set_span(span));
+            }
+        };
+        self.resolve_function(span, func_name, vec![], &[arg]).await
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_extract_expr(
@@ -1748,6 +1977,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![arg_x.clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     })
                     .await,
                 )
@@ -1784,6 +2014,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![(*arg).clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -2210,6 +2441,174 @@ impl<'a> TypeChecker<'a> {
         Ok(Some(self.resolve(&udf_expr).await?))
     }
 
+    /// Resolve a higher-order array function, whose lambda is bound against the
+    /// elements of the array and evaluated over the flattened array values.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[Expr],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = func_name.to_lowercase();
+        let params_len = match func_name.as_str() {
+            "array_transform" | "array_filter" => 1,
+            "array_reduce" | "array_sort" => 2,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} does not accept lambda arguments"
+                ))
+                .set_span(span));
+            }
+        };
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "function {func_name} expects 1 argument before the lambda, but got {}",
+                args.len()
+            ))
+            .set_span(span));
+        }
+        if lambda.params.len() != params_len {
+            return Err(ErrorCode::SemanticError(format!(
+                "the lambda of function {func_name} expects {params_len} parameter(s), but got {}",
+                lambda.params.len()
+            ))
+            .set_span(span));
+        }
+
+        let box (arg, arg_type) = self.resolve(&args[0]).await?;
+        let inner_type = match arg_type.remove_nullable() {
+            DataType::Null => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            DataType::EmptyArray if func_name == "array_reduce" => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            DataType::EmptyArray => return Ok(Box::new((arg, arg_type))),
+            DataType::Array(box inner_type) => inner_type,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} expects an array as the first argument, but got {arg_type}"
+                ))
+                .set_span(span));
+            }
+        };
+
+        // The lambda can only see its own parameters, which are bound to the array elements.
+        let mut lambda_context = BindContext::new();
+        let mut lambda_columns = Vec::with_capacity(params_len);
+        for param in lambda.params.iter() {
+            let column_name = normalize_identifier(param, self.name_resolution_ctx).name;
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), inner_type.clone());
+            lambda_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                table_index: None,
+                column_name,
+                index,
+                data_type: Box::new(inner_type.clone()),
+                visibility: Visibility::Visible,
+            });
+            lambda_columns.push(index);
+        }
+        let box (lambda_scalar, lambda_type) = {
+            let mut lambda_checker = TypeChecker::new(
+                &mut lambda_context,
+                self.ctx.clone(),
+                self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                false,
+            );
+            lambda_checker.resolve(&lambda.expr).await?
+        };
+        if lambda_scalar
+            .used_columns()
+            .iter()
+            .any(|index| !lambda_columns.contains(index))
+        {
+            return Err(ErrorCode::SemanticError(
+                "the lambda can only reference its own parameters".to_string(),
+            )
+            .set_span(lambda.expr.span()));
+        }
+
+        let (lambda_scalar, lambda_type) = match func_name.as_str() {
+            "array_filter" | "array_sort" => {
+                let target_type = if func_name == "array_filter" {
+                    DataType::Boolean
+                } else {
+                    DataType::Number(NumberDataType::Int64)
+                };
+                if lambda_type.remove_nullable() == target_type || lambda_type == DataType::Null {
+                    (lambda_scalar, lambda_type)
+                } else {
+                    let target_type = if lambda_type.is_nullable() {
+                        target_type.wrap_nullable()
+                    } else {
+                        target_type
+                    };
+                    (wrap_cast(&lambda_scalar, &target_type), target_type)
+                }
+            }
+            // The accumulator has the same type as the elements.
+            "array_reduce" if lambda_type != inner_type => {
+                (wrap_cast(&lambda_scalar, &inner_type), inner_type.clone())
+            }
+            _ => (lambda_scalar, lambda_type),
+        };
+        let lambda_expr = lambda_scalar
+            .as_expr()?
+            .project_column_ref(|col| {
+                lambda_columns
+                    .iter()
+                    .position(|index| *index == col.index)
+                    .unwrap()
+            })
+            .as_remote_expr();
+
+        let return_type = match func_name.as_str() {
+            "array_transform" => DataType::Array(Box::new(lambda_type)),
+            "array_reduce" => inner_type.wrap_nullable(),
+            _ => DataType::Array(Box::new(inner_type)),
+        };
+        let return_type = if arg_type.is_nullable() && func_name != "array_reduce" {
+            return_type.wrap_nullable()
+        } else {
+            return_type
+        };
+        let lambda_func = LambdaFunc {
+            span,
+            func_name: func_name.clone(),
+            display_name: format!("{func_name}({}, {lambda})", args[0]),
+            args: vec![arg],
+            lambda_expr: Box::new(lambda_expr),
+            lambda_display: lambda.to_string(),
+            return_type: Box::new(return_type.clone()),
+        };
+
+        Ok(Box::new((lambda_func.into(), return_type)))
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_map_access(
@@ -2222,32 +2621,34 @@ impl<'a> TypeChecker<'a> {
         // If it is a tuple column, convert it to the internal column specified by the paths.
         // If it is a variant column, try convert it to a virtual column.
         // For other types of columns, convert it to get functions.
+        // Only the columns read from a table have inner columns, the other columns, e.g. the
+        // masked columns, are accessed by their values.
         if let ScalarExpr::BoundColumnRef(BoundColumnRef { ref column, .. }) = scalar {
             let column_entry = self.metadata.read().column(column.index).clone();
             if let ColumnEntry::BaseTableColumn(BaseTableColumn { data_type, .. }) = column_entry {
                 table_data_type = data_type;
-            }
-            match table_data_type.remove_nullable() {
-                TableDataType::Tuple { .. } => {
-                    let box (inner_scalar, _inner_data_type) = self
-                        .resolve_tuple_map_access_pushdown(
-                            expr.span(),
-                            column.clone(),
-                            &mut table_data_type,
-                            &mut paths,
-                        )
-                        .await?;
-                    scalar = inner_scalar;
-                }
-                TableDataType::Variant => {
-                    if let Some(result) = self
-                        .resolve_variant_map_access_pushdown(column.clone(), &mut paths)
-                        .await
-                    {
-                        return result;
+                match table_data_type.remove_nullable() {
+                    TableDataType::Tuple { .. } => {
+                        let box (inner_scalar, _inner_data_type) = self
+                            .resolve_tuple_map_access_pushdown(
+                                expr.span(),
+                                column.clone(),
+                                &mut table_data_type,
+                                &mut paths,
+                            )
+                            .await?;
+                        scalar = inner_scalar;
                     }
+                    TableDataType::Variant => {
+                        if let Some(result) = self
+                            .resolve_variant_map_access_pushdown(column.clone(), &mut paths)
+                            .await
+                        {
+                            return result;
+                        }
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
         }
 
@@ -2693,6 +3094,7 @@ impl<'a> TypeChecker<'a> {
                     args,
                     params,
                     window,
+                    lambda,
                 } => Ok(Expr::FunctionCall {
                     span: *span,
                     distinct: *distinct,
@@ -2703,6 +3105,7 @@ impl<'a> TypeChecker<'a> {
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
                     window: window.clone(),
+                    lambda: lambda.clone(),
                 }),
                 Expr::Case {
                     span,
@@ -2829,6 +3232,8 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
         }
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::Date => TableDataType::Date,
         TypeName::Array(item_type) => TableDataType::Array(Box::new(resolve_type_name(item_type)?)),
         TypeName::Map { key_type, val_type } => {
@@ -2855,6 +3260,8 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
                 }
             }
         }
+        TypeName::Binary => TableDataType::Binary,
+        TypeName::Geometry => TableDataType::Geometry,
         TypeName::Bitmap => TableDataType::Bitmap,
         TypeName::Tuple {
             fields_type,
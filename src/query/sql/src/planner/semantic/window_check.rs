@@ -18,6 +18,7 @@ use common_exception::Result;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::BindContext;
 use crate::ColumnBinding;
 use crate::ScalarExpr;
@@ -34,7 +35,9 @@ impl<'a> WindowChecker<'a> {
 
     pub fn resolve(&self, scalar: &ScalarExpr) -> Result<ScalarExpr> {
         match scalar {
-            ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => Ok(scalar.clone()),
+            ScalarExpr::BoundColumnRef(_)
+            | ScalarExpr::ConstantExpr(_)
+            | ScalarExpr::PlaceholderExpr(_) => Ok(scalar.clone()),
             ScalarExpr::FunctionCall(func) => {
                 let args = func
                     .arguments
@@ -49,6 +52,23 @@ impl<'a> WindowChecker<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
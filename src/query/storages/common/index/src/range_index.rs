@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_expression::types::geometry::statistics_to_bounding_box;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::string::StringDomain;
@@ -178,6 +179,9 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                     min: DateType::try_downcast_scalar(&stat.min.as_ref()).unwrap(),
                     max: DateType::try_downcast_scalar(&stat.max.as_ref()).unwrap(),
                 }),
+                DataType::Geometry => statistics_to_bounding_box(&stat.min, &stat.max)
+                    .map(Domain::Geometry)
+                    .unwrap_or_else(|| Domain::full(data_type)),
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
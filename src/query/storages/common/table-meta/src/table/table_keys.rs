@@ -22,6 +22,24 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
+/// If set, only the segments appended after this snapshot are read from the table,
+/// used by the incremental refresh of materialized views.
+pub const OPT_KEY_BASE_SNAPSHOT_LOCATION: &str = "base_snapshot_location";
+pub const OPT_KEY_MATERIALIZED_VIEWS: &str = "materialized_views";
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+pub const OPT_KEY_MATERIALIZED_VIEW_BASE_DATABASE: &str = "materialized_view_base_database";
+pub const OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE: &str = "materialized_view_base_table";
+pub const OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID: &str = "materialized_view_base_table_id";
+/// The base table snapshot location that the materialized view is refreshed to.
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT: &str =
+    "materialized_view_refreshed_snapshot";
+pub const OPT_KEY_STREAM_BASE_DATABASE: &str = "stream_base_database";
+pub const OPT_KEY_STREAM_BASE_TABLE: &str = "stream_base_table";
+pub const OPT_KEY_STREAM_BASE_TABLE_ID: &str = "stream_base_table_id";
+/// The base table snapshot location that the changes of the stream are read since.
+pub const OPT_KEY_STREAM_OFFSET_SNAPSHOT: &str = "stream_offset_snapshot";
+/// The masking policies attached to the columns, a JSON map from column id to policy name.
+pub const OPT_KEY_COLUMN_MASKING_POLICIES: &str = "column_masking_policies";
 
 /// Legacy table snapshot location key
 ///
@@ -40,6 +58,18 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_BASE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_DATABASE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT);
+    r.insert(OPT_KEY_STREAM_BASE_DATABASE);
+    r.insert(OPT_KEY_STREAM_BASE_TABLE);
+    r.insert(OPT_KEY_STREAM_BASE_TABLE_ID);
+    r.insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT);
+    r.insert(OPT_KEY_COLUMN_MASKING_POLICIES);
     r
 });
 
@@ -48,6 +78,18 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_BASE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_DATABASE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT);
+    r.insert(OPT_KEY_STREAM_BASE_DATABASE);
+    r.insert(OPT_KEY_STREAM_BASE_TABLE);
+    r.insert(OPT_KEY_STREAM_BASE_TABLE_ID);
+    r.insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT);
+    r.insert(OPT_KEY_COLUMN_MASKING_POLICIES);
     r
 });
 
@@ -26,6 +26,7 @@ use common_storages_view::view_table::ViewTable;
 use dashmap::DashMap;
 
 use crate::fuse::FuseTable;
+use crate::fuse::StreamTable;
 use crate::Table;
 
 pub trait StorageCreator: Send + Sync {
@@ -90,6 +91,12 @@ impl StorageFactory {
             descriptor: Arc::new(FuseTable::description),
         });
 
+        // Register STREAM table engine.
+        creators.insert("STREAM".to_string(), Storage {
+            creator: Arc::new(StreamTable::try_create),
+            descriptor: Arc::new(StreamTable::description),
+        });
+
         // Register View table engine
         creators.insert("VIEW".to_string(), Storage {
             creator: Arc::new(ViewTable::try_create),
@@ -21,6 +21,7 @@ use std::sync::Arc;
 
 use common_catalog::catalog::StorageDescription;
 use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::MergeIntoInfo;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
@@ -39,6 +40,7 @@ use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
 use common_expression::TableField;
+use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
@@ -265,6 +267,22 @@ impl FuseTable {
         }
     }
 
+    #[async_backtrace::framed]
+    pub async fn read_table_snapshot_by_location(
+        &self,
+        location: String,
+    ) -> Result<Arc<TableSnapshot>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let ver = self.snapshot_format_version(Some(location.clone())).await?;
+        let params = LoadParams {
+            location,
+            len_hint: None,
+            ver,
+            put_cache: true,
+        };
+        reader.read(&params).await
+    }
+
     #[async_backtrace::framed]
     pub async fn snapshot_format_version(&self, location_opt: Option<String>) -> Result<u64> {
         let location_opt = if location_opt.is_some() {
@@ -333,6 +351,12 @@ impl FuseTable {
     pub fn cluster_key_str(&self) -> Option<&String> {
         self.cluster_key_meta.as_ref().map(|(_, key)| key)
     }
+
+    /// The schema of the columns stored in the blocks, the virtual computed columns
+    /// are evaluated when reading and never written.
+    pub fn storage_schema(&self) -> TableSchemaRef {
+        Arc::new(self.table_info.schema().remove_virtual_computed_fields())
+    }
 }
 
 #[async_trait::async_trait]
@@ -526,6 +550,17 @@ impl Table for FuseTable {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        merge_into_info: MergeIntoInfo,
+    ) -> Result<()> {
+        self.build_merge_into_pipeline(ctx, merge_into_info, pipeline)
+            .await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_commit_insertion", skip(self, ctx, operations), fields(ctx.id = ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn commit_insertion(
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Field;
@@ -34,6 +35,7 @@ use common_sql::field_default_value;
 use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
+use parking_lot::RwLock;
 
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
@@ -46,8 +48,17 @@ pub struct BlockReader {
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
     pub query_internal_columns: bool,
+    // the schema of the table, and the projected fields whose data type has been modified,
+    // which are used to read the blocks written before the modification.
+    pub(crate) schema: TableSchemaRef,
+    pub(crate) evolved_fields: Vec<FieldIndex>,
+    pub(crate) evolved_readers: Arc<RwLock<EvolvedReaders>>,
 }
 
+// The readers of the blocks written before the data type of columns was modified,
+// keyed by the previous column ids of the projected fields.
+pub(crate) type EvolvedReaders = HashMap<Vec<(FieldIndex, ColumnId)>, Arc<BlockReader>>;
+
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
     if paths.is_empty() {
         return Err(ErrorCode::BadArguments(
@@ -80,21 +91,19 @@ impl BlockReader {
         ctx: Arc<dyn TableContext>,
         query_internal_columns: bool,
     ) -> Result<Arc<BlockReader>> {
-        // init projected_schema and default_vals of schema.fields
-        let (projected_schema, default_vals) = match projection {
+        // init default_vals of schema.fields
+        let default_vals = match projection {
             Projection::Columns(ref indices) => {
-                let projected_schema = TableSchemaRef::new(schema.project(indices));
                 // If projection by Columns, just calc default values by projected fields.
-                let mut default_vals = Vec::with_capacity(projected_schema.fields().len());
-                for field in projected_schema.fields() {
-                    let default_val = field_default_value(ctx.clone(), field)?;
+                let mut default_vals = Vec::with_capacity(indices.len());
+                for index in indices {
+                    let default_val = field_default_value(ctx.clone(), schema.field(*index))?;
                     default_vals.push(default_val);
                 }
 
-                (projected_schema, default_vals)
+                default_vals
             }
             Projection::InnerColumns(ref path_indices) => {
-                let projected_schema = TableSchemaRef::new(schema.inner_project(path_indices));
                 let mut field_default_vals = Vec::with_capacity(schema.fields().len());
 
                 // If projection by InnerColumns, first calc default value of all schema fields.
@@ -110,9 +119,48 @@ impl BlockReader {
                     );
                 });
 
-                (projected_schema, default_vals)
+                default_vals
+            }
+        };
+
+        Ok(Arc::new(Self::create_with_default_values(
+            operator,
+            schema,
+            projection,
+            default_vals,
+            query_internal_columns,
+        )?))
+    }
+
+    pub(crate) fn create_with_default_values(
+        operator: Operator,
+        schema: TableSchemaRef,
+        projection: Projection,
+        default_vals: Vec<Scalar>,
+        query_internal_columns: bool,
+    ) -> Result<BlockReader> {
+        let (projected_schema, projected_fields) = match projection {
+            Projection::Columns(ref indices) => (
+                TableSchemaRef::new(schema.project(indices)),
+                indices.clone(),
+            ),
+            Projection::InnerColumns(ref path_indices) => {
+                let mut fields = path_indices
+                    .values()
+                    .map(|path| path[0])
+                    .collect::<Vec<_>>();
+                fields.sort();
+                fields.dedup();
+                (
+                    TableSchemaRef::new(schema.inner_project(path_indices)),
+                    fields,
+                )
             }
         };
+        let evolved_fields = projected_fields
+            .into_iter()
+            .filter(|i| !schema.field(*i).history().is_empty())
+            .collect();
 
         let arrow_schema = schema.to_arrow();
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
@@ -126,7 +174,7 @@ impl BlockReader {
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
 
-        Ok(Arc::new(BlockReader {
+        Ok(BlockReader {
             operator,
             projection,
             projected_schema,
@@ -135,7 +183,10 @@ impl BlockReader {
             parquet_schema_descriptor,
             default_vals,
             query_internal_columns,
-        }))
+            schema,
+            evolved_fields,
+            evolved_readers: Arc::new(RwLock::new(HashMap::new())),
+        })
     }
 
     pub fn support_blocking_api(&self) -> bool {
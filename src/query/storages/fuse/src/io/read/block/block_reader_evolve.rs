@@ -0,0 +1,132 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::meta::ColumnMeta;
+
+use crate::io::BlockReader;
+
+impl BlockReader {
+    /// Get the reader of the block if it was written before the data type of some projected
+    /// columns was modified, the old columns are read by their previous data types.
+    ///
+    /// Returns None if the block has the current columns (or none of the columns).
+    pub(crate) fn evolved_reader(
+        &self,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Result<Option<Arc<BlockReader>>> {
+        if self.evolved_fields.is_empty() {
+            return Ok(None);
+        }
+
+        let in_block = |column_ids: Vec<ColumnId>| {
+            column_ids
+                .iter()
+                .any(|column_id| columns_meta.contains_key(column_id))
+        };
+        let mut previous_fields = vec![];
+        for index in &self.evolved_fields {
+            let field = self.schema.field(*index);
+            if in_block(field.leaf_column_ids()) {
+                continue;
+            }
+            // the newest previous field which the block is written by
+            if let Some(previous) = field
+                .previous_fields()
+                .into_iter()
+                .find(|f| in_block(f.leaf_column_ids()))
+            {
+                previous_fields.push((*index, previous));
+            }
+        }
+        if previous_fields.is_empty() {
+            return Ok(None);
+        }
+
+        let key = previous_fields
+            .iter()
+            .map(|(index, field)| (*index, field.column_id()))
+            .collect::<Vec<_>>();
+        if let Some(reader) = self.evolved_readers.read().get(&key) {
+            return Ok(Some(reader.clone()));
+        }
+
+        // the previous fields have the same positions, so the projection and default values are not changed.
+        let schema = self.schema.with_previous_fields(&previous_fields);
+        let mut reader = Self::create_with_default_values(
+            self.operator.clone(),
+            Arc::new(schema),
+            self.projection.clone(),
+            self.default_vals.clone(),
+            self.query_internal_columns,
+        )?;
+        reader.evolved_fields = vec![];
+        let reader = Arc::new(reader);
+        self.evolved_readers.write().insert(key, reader.clone());
+        Ok(Some(reader))
+    }
+
+    /// Cast the block read by the evolved reader to the current data types of the columns.
+    pub(crate) fn cast_evolved_block(&self, data_block: DataBlock) -> Result<DataBlock> {
+        let data_types: Vec<DataType> = self
+            .projected_schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().into())
+            .collect();
+
+        let func_ctx = FunctionContext::default();
+        let evaluator = Evaluator::new(&data_block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let mut columns = Vec::with_capacity(data_block.num_columns());
+        for (index, (entry, data_type)) in data_block.columns().iter().zip(data_types).enumerate() {
+            if entry.data_type == data_type {
+                columns.push(entry.clone());
+                continue;
+            }
+            let expr = Expr::Cast {
+                span: None,
+                is_try: false,
+                expr: Box::new(Expr::ColumnRef {
+                    span: None,
+                    id: index,
+                    data_type: entry.data_type.clone(),
+                    display_name: self.projected_schema.field(index).name().clone(),
+                }),
+                dest_type: data_type.clone(),
+            };
+            let value = evaluator.run(&expr)?;
+            columns.push(BlockEntry { data_type, value });
+        }
+        // the internal columns appended after the projected columns are kept
+        columns.extend(
+            data_block
+                .columns()
+                .iter()
+                .skip(self.projected_schema.num_fields())
+                .cloned(),
+        );
+        Ok(DataBlock::new(columns, data_block.num_rows()))
+    }
+}
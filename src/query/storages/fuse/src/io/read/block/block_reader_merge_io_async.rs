@@ -132,6 +132,27 @@ impl BlockReader {
         settings: &ReadSettings,
         location: &str,
         columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Result<MergeIOReadResult> {
+        // the block written before the data type of columns was modified has the previous columns
+        match self.evolved_reader(columns_meta)? {
+            Some(reader) => {
+                reader
+                    .read_projected_columns_data(settings, location, columns_meta)
+                    .await
+            }
+            None => {
+                self.read_projected_columns_data(settings, location, columns_meta)
+                    .await
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn read_projected_columns_data(
+        &self,
+        settings: &ReadSettings,
+        location: &str,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
     ) -> Result<MergeIOReadResult> {
         // Perf
         {
@@ -101,7 +101,12 @@ impl BlockReader {
         settings: &ReadSettings,
         part: PartInfoPtr,
     ) -> Result<MergeIOReadResult> {
-        let part = FusePartInfo::from_part(&part)?;
+        let fuse_part = FusePartInfo::from_part(&part)?;
+        // the block written before the data type of columns was modified has the previous columns
+        if let Some(reader) = self.evolved_reader(&fuse_part.columns_meta)? {
+            return reader.sync_read_columns_data_by_merge_io(settings, part.clone());
+        }
+        let part = fuse_part;
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
 
         let mut ranges = vec![];
@@ -96,6 +96,18 @@ impl BlockReader {
             return self.build_default_values_block(num_rows);
         }
 
+        if let Some(reader) = self.evolved_reader(column_metas)? {
+            let data_block = reader.deserialize_parquet_chunks_with_buffer(
+                block_path,
+                num_rows,
+                compression,
+                column_metas,
+                column_chunks,
+                uncompressed_buffer,
+            )?;
+            return self.cast_evolved_block(data_block);
+        }
+
         let mut need_default_vals = Vec::with_capacity(self.project_column_nodes.len());
         let mut need_to_fill_default_val = false;
         let mut deserialized_column_arrays = Vec::with_capacity(self.projection.len());
@@ -25,6 +25,7 @@ pub mod io;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
+mod stream_table;
 pub mod table_functions;
 
 mod metrics;
@@ -39,6 +40,9 @@ pub use fuse_part::FusePartInfo;
 pub use fuse_table::FuseStorageFormat;
 pub use fuse_table::FuseTable;
 pub use io::MergeIOReadResult;
+pub use stream_table::StreamTable;
+pub use stream_table::STREAM_COLUMN_ACTION;
+pub use stream_table::STREAM_COLUMN_ROW_ID;
 
 pub use crate::metrics::metrics_reset;
 
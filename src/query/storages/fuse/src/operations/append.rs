@@ -84,7 +84,7 @@ impl FuseTable {
                     self.meta_location_generator().clone(),
                     cluster_stats_gen.clone(),
                     block_thresholds,
-                    self.table_info.schema(),
+                    self.storage_schema(),
                     Some(transform_output_port),
                 )
             })?;
@@ -98,7 +98,7 @@ impl FuseTable {
                     self.meta_location_generator().clone(),
                     cluster_stats_gen.clone(),
                     block_thresholds,
-                    self.table_info.schema(),
+                    self.storage_schema(),
                     None,
                 )
             })?;
@@ -116,7 +116,7 @@ impl FuseTable {
 
         let operators = cluster_stats_gen.operators.clone();
         if !operators.is_empty() {
-            let num_input_columns = self.table_info.schema().fields().len();
+            let num_input_columns = self.storage_schema().fields().len();
             let func_ctx2 = cluster_stats_gen.func_ctx.clone();
             pipeline.add_transform(move |input, output| {
                 Ok(ProcessorPtr::create(CompoundBlockOperator::create(
@@ -164,7 +164,7 @@ impl FuseTable {
             return Ok(ClusterStatsGenerator::default());
         }
 
-        let input_schema = self.table_info.schema();
+        let input_schema = self.storage_schema();
         let mut merged: Vec<DataField> =
             input_schema.fields().iter().map(DataField::from).collect();
 
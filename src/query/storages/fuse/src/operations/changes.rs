@@ -0,0 +1,165 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::plan::block_id_in_segment;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storage::ColumnNodes;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Read the partitions of the blocks changed since the snapshot at `offset`.
+    ///
+    /// The changes are found at block granularity: the blocks only in the current snapshot
+    /// are inserted and the blocks only in the offset snapshot are deleted. The rows kept in a
+    /// block rewritten by a mutation are in both, they are cancelled out by the stream when
+    /// the parts are read. The snapshot location in the `BlockMetaIndex` of a part tells which
+    /// of the two snapshots it is from.
+    #[async_backtrace::framed]
+    pub async fn read_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        offset: Option<String>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let current = self.snapshot_loc().await?;
+        if current == offset {
+            return Ok((PartStatistics::default_exact(), Partitions::default()));
+        }
+
+        let current_snapshot = match &current {
+            Some(location) => Some(
+                self.read_table_snapshot_by_location(location.clone())
+                    .await?,
+            ),
+            None => None,
+        };
+        let offset_snapshot = match &offset {
+            Some(location) => Some(
+                self.read_table_snapshot_by_location(location.clone())
+                    .await?,
+            ),
+            None => None,
+        };
+
+        // Segments are immutable, the ones in both snapshots are not changed.
+        let current_segments = segment_locations(&current_snapshot);
+        let offset_segments = segment_locations(&offset_snapshot);
+        let mut inserted = self
+            .blocks_not_in(ctx.clone(), &current, &current_snapshot, &offset_segments)
+            .await?;
+        let mut deleted = self
+            .blocks_not_in(ctx, &offset, &offset_snapshot, &current_segments)
+            .await?;
+
+        // The blocks only moved to other segments, e.g. by the compaction of segments, are
+        // not changed either.
+        let inserted_blocks = inserted
+            .iter()
+            .map(|(_, meta)| meta.location.0.clone())
+            .collect::<HashSet<_>>();
+        let deleted_blocks = deleted
+            .iter()
+            .map(|(_, meta)| meta.location.0.clone())
+            .collect::<HashSet<_>>();
+        inserted.retain(|(_, meta)| !deleted_blocks.contains(&meta.location.0));
+        deleted.retain(|(_, meta)| !inserted_blocks.contains(&meta.location.0));
+
+        let block_metas = deleted
+            .into_iter()
+            .chain(inserted)
+            .map(|(index, meta)| (Some(index), meta))
+            .collect::<Vec<_>>();
+
+        let schema = self.schema();
+        let arrow_schema = schema.to_arrow();
+        let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&schema));
+        let (mut statistics, parts) =
+            Self::to_partitions(Some(&schema), &block_metas, &column_nodes, None, None);
+        statistics.partitions_total = block_metas.len();
+        statistics.partitions_scanned = block_metas.len();
+        Ok((statistics, parts))
+    }
+
+    // The blocks of the snapshot in the segments that are not in `segments`.
+    #[async_backtrace::framed]
+    async fn blocks_not_in(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot_location: &Option<String>,
+        snapshot: &Option<Arc<TableSnapshot>>,
+        segments: &HashSet<Location>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let (snapshot_location, snapshot) = match (snapshot_location, snapshot) {
+            (Some(snapshot_location), Some(snapshot)) => (snapshot_location, snapshot),
+            _ => return Ok(vec![]),
+        };
+
+        let changed_segments = snapshot
+            .segments
+            .iter()
+            .enumerate()
+            .filter(|(_, location)| !segments.contains(*location))
+            .collect::<Vec<_>>();
+        let locations = changed_segments
+            .iter()
+            .map(|(_, location)| (*location).clone())
+            .collect::<Vec<_>>();
+
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let segment_infos = segments_io
+            .read_segments::<Arc<SegmentInfo>>(&locations, true)
+            .await?;
+
+        let mut blocks = vec![];
+        for ((segment_idx, segment_location), segment_info) in
+            changed_segments.into_iter().zip(segment_infos)
+        {
+            let segment_info = segment_info?;
+            let block_num = segment_info.blocks.len();
+            for (block_idx, block_meta) in segment_info.blocks.iter().enumerate() {
+                let index = BlockMetaIndex {
+                    segment_idx,
+                    block_idx,
+                    range: None,
+                    page_size: block_meta.page_size() as usize,
+                    block_id: block_id_in_segment(block_num, block_idx),
+                    block_location: block_meta.location.0.clone(),
+                    segment_location: segment_location.0.clone(),
+                    snapshot_location: Some(snapshot_location.clone()),
+                };
+                blocks.push((index, block_meta.clone()));
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+fn segment_locations(snapshot: &Option<Arc<TableSnapshot>>) -> HashSet<Location> {
+    snapshot
+        .iter()
+        .flat_map(|snapshot| snapshot.segments.iter().cloned())
+        .collect()
+}
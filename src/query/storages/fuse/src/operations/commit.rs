@@ -29,6 +29,7 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::MatchSeq;
@@ -412,8 +413,32 @@ impl FuseTable {
             copied_files: copied_files.clone(),
         };
 
+        // The offsets of the streams consumed by the query advance with the table.
+        let stream_offsets = ctx.get_stream_offsets();
+
+        // In a multi-statement transaction, the table meta is committed when the transaction commits.
+        let txn_mgr = ctx.txn_mgr();
+        if txn_mgr.lock().is_active() {
+            let mut txn_mgr = txn_mgr.lock();
+            txn_mgr.update_table_meta(table_info.catalog(), req);
+            for stream_req in stream_offsets {
+                txn_mgr.update_table_meta(table_info.catalog(), stream_req);
+            }
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
         // 3. let's roll
-        let reply = catalog.update_table_meta(table_info, req).await;
+        let reply = if stream_offsets.is_empty() {
+            catalog.update_table_meta(table_info, req).await.map(|_| ())
+        } else {
+            let mut update_table_metas = vec![req];
+            update_table_metas.extend(stream_offsets);
+            catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+                .map(|_| ())
+        };
         match reply {
             Ok(_) => {
                 // upsert snapshot statistics cache
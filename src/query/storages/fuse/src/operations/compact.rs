@@ -122,7 +122,7 @@ impl FuseTable {
         }
 
         let thresholds = self.get_block_thresholds();
-        let schema = self.schema();
+        let schema = self.storage_schema();
         let write_settings = self.get_write_settings();
 
         let mut mutator =
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::MergeIntoInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_transforms::processors::transforms::create_dummy_item;
+
+use crate::operations::merge_into::AppendTransform;
+use crate::operations::merge_into::BroadcastProcessor;
+use crate::operations::merge_into::MergeIntoSplitMutator;
+use crate::operations::merge_into::MergeIntoSplitProcessor;
+use crate::pipelines::Pipeline;
+use crate::FuseTable;
+
+impl FuseTable {
+    // The pipeline is the same as the one of replace into (see `build_replace_pipeline`),
+    // except that the ReplaceIntoProcessor is replaced by the MergeIntoSplitProcessor:
+    //
+    //                           ┌────────────────────────┐            ┌──────────────────┐
+    //                           │                        ├──┬────────►│ AppendTransform  ├───────► ...
+    // ┌──────────────────┐      │                        ├──┘         └──────────────────┘
+    // │ target RIGHT JOIN├─────►│ MergeIntoSplitProcessor│
+    // │      source      │      │                        ├──┐         ┌──────────────────┐
+    // └──────────────────┘      │                        ├──┴────────►│BroadcastProcessor├───────► MergeIntoOperationAggrs ...
+    //                           └────────────────────────┘            └──────────────────┘
+    //
+    // The MergeIntoSplitProcessor evaluates the WHEN [NOT] MATCHED clauses, the updated and inserted rows are
+    // sent to the AppendTransform, and the `_row_id` of the updated and deleted rows are sent to the
+    // MergeIntoOperationAggregators as MergeIntoOperation::DeleteByRowId.
+    #[async_backtrace::framed]
+    pub async fn build_merge_into_pipeline<'a>(
+        &'a self,
+        ctx: Arc<dyn TableContext>,
+        merge_into_info: MergeIntoInfo,
+        pipeline: &'a mut Pipeline,
+    ) -> Result<()> {
+        let block_thresholds = self.get_block_thresholds();
+        let cluster_stats_gen = self.get_cluster_stats_gen(ctx.clone(), 0, block_thresholds)?;
+
+        // 1. resize input to 1, since a row of target table can only be matched once "globally"
+        pipeline.resize(1)?;
+
+        // 2. connect with MergeIntoSplitProcessor
+        //    the pipe items of the pipe are arranged in the following order
+        //    (0) -> output_port_append_data
+        //    (1) -> output_port_merge_into_action
+        let base_snapshot = self
+            .read_table_snapshot()
+            .await?
+            .unwrap_or_else(|| Arc::new(self.new_empty_snapshot()));
+
+        let empty_table = base_snapshot.segments.is_empty();
+        let split_mutator = MergeIntoSplitMutator::create(
+            merge_into_info,
+            ctx.get_function_context()?,
+            cluster_stats_gen.operators.clone(),
+        );
+        let split_processor = MergeIntoSplitProcessor::create(split_mutator, empty_table);
+        pipeline.add_pipe(split_processor.into_pipe());
+
+        // 3. connect to broadcast processor and append transform
+        let max_threads = ctx.get_settings().get_max_threads()?;
+        let segment_partition_num =
+            std::cmp::min(base_snapshot.segments.len(), max_threads as usize);
+
+        let append_transform = AppendTransform::try_create(
+            ctx.clone(),
+            self.get_write_settings(),
+            self.operator.clone(),
+            self.meta_location_generator.clone(),
+            self.storage_schema(),
+            block_thresholds,
+            cluster_stats_gen,
+        );
+        let block_builder = append_transform.get_block_builder();
+
+        if segment_partition_num == 0 {
+            let dummy_item = create_dummy_item();
+            pipeline.add_pipe(Pipe::create(2, 2, vec![
+                append_transform.into_pipe_item(),
+                dummy_item,
+            ]));
+        } else {
+            let broadcast_processor = BroadcastProcessor::new(segment_partition_num);
+            pipeline.add_pipe(Pipe::create(2, segment_partition_num + 1, vec![
+                append_transform.into_pipe_item(),
+                broadcast_processor.into_pipe_item(),
+            ]));
+
+            // 4. connect with MergeIntoOperationAggregators, the rows are located by `_row_id`,
+            //    so no on conflict fields are needed.
+            let item_size = segment_partition_num + 1;
+            let mut pipe_items = Vec::with_capacity(item_size);
+            pipe_items.push(create_dummy_item());
+            let mut merge_into_operation_aggregators = self
+                .merge_into_mutators(
+                    ctx.clone(),
+                    segment_partition_num,
+                    block_builder,
+                    vec![],
+                    &base_snapshot,
+                )
+                .await?;
+            pipe_items.append(&mut merge_into_operation_aggregators);
+            pipeline.add_pipe(Pipe::create(item_size, item_size, pipe_items));
+        }
+
+        // 5. connect with mutation pipes, the TableMutationAggregator, then CommitSink
+        self.chain_mutation_pipes(&ctx, pipeline, base_snapshot)
+            .await
+    }
+}
@@ -18,9 +18,11 @@ pub mod mutation_meta;
 mod mutator;
 mod processors;
 
+pub use mutator::MergeIntoSplitMutator;
 pub use processors::AppendTransform;
 pub use processors::BroadcastProcessor;
 pub use processors::CommitSink;
 pub use processors::MergeIntoOperationAggregator;
+pub use processors::MergeIntoSplitProcessor;
 pub use processors::OnConflictField;
 pub use processors::TableMutationAggregator;
@@ -24,6 +24,7 @@ use common_expression::Scalar;
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub enum MergeIntoOperation {
     Delete(DeletionByColumn),
+    DeleteByRowId(DeletionByRowId),
     None,
 }
 
@@ -36,6 +37,12 @@ pub struct DeletionByColumn {
     pub key_hashes: HashSet<UniqueKeyDigest>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DeletionByRowId {
+    // the internal `_row_id` of the rows to be deleted
+    pub row_ids: Vec<u64>,
+}
+
 #[typetag::serde(name = "merge_into_operation_meta")]
 impl BlockMetaInfo for MergeIntoOperation {
     fn as_any(&self) -> &dyn Any {
@@ -21,9 +21,12 @@ use crate::operations::mutation::BlockIndex;
 use crate::operations::mutation::SegmentIndex;
 
 pub type BlockDeletionKeys = HashMap<BlockIndex, HashSet<UniqueKeyDigest>>;
+// offsets of the deleted rows in each block
+pub type BlockDeletionRows = HashMap<BlockIndex, HashSet<usize>>;
 #[derive(Default)]
 pub struct DeletionAccumulator {
     pub deletions: HashMap<SegmentIndex, BlockDeletionKeys>,
+    pub row_deletions: HashMap<SegmentIndex, BlockDeletionRows>,
 }
 
 impl DeletionAccumulator {
@@ -46,4 +49,18 @@ impl DeletionAccumulator {
             }
         }
     }
+
+    pub fn add_block_row_deletion(
+        &mut self,
+        segment_index: SegmentIndex,
+        block_index: BlockIndex,
+        row_offsets: impl IntoIterator<Item = usize>,
+    ) {
+        self.row_deletions
+            .entry(segment_index)
+            .or_default()
+            .entry(block_index)
+            .or_default()
+            .extend(row_offsets);
+    }
 }
@@ -20,11 +20,14 @@ use std::sync::Arc;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::ProgressValues;
 use common_base::runtime::GlobalIORuntime;
+use common_catalog::plan::split_prefix;
+use common_catalog::plan::split_row_id;
 use common_catalog::plan::Projection;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnId;
+use common_expression::DataBlock;
 use common_expression::Scalar;
 use common_expression::TableSchema;
 use opendal::Operator;
@@ -45,6 +48,7 @@ use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
 use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::DeletionByColumn;
+use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::DeletionByRowId;
 use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::MergeIntoOperation;
 use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::UniqueKeyDigest;
 use crate::operations::merge_into::mutation_meta::BlockMetaIndex;
@@ -57,6 +61,14 @@ use crate::operations::merge_into::OnConflictField;
 use crate::operations::mutation::BlockIndex;
 use crate::operations::mutation::SegmentIndex;
 
+// Rows of a block to be deleted
+enum BlockDeletion<'a> {
+    // by the hash of on conflict fields (REPLACE INTO)
+    Keys(&'a HashSet<UniqueKeyDigest>),
+    // by the row offsets (MERGE INTO)
+    Rows(&'a HashSet<usize>),
+}
+
 // Apply MergeIntoOperations to segments
 pub struct MergeIntoOperationAggregator {
     segment_locations: HashMap<SegmentIndex, Location>,
@@ -144,6 +156,45 @@ impl MergeIntoOperationAggregator {
                     }
                 }
             }
+            MergeIntoOperation::DeleteByRowId(DeletionByRowId { row_ids }) => {
+                // group the row offsets by (segment index, block id)
+                let mut grouped: HashMap<SegmentIndex, HashMap<u64, Vec<usize>>> = HashMap::new();
+                for row_id in row_ids {
+                    let (prefix, row_offset) = split_row_id(*row_id);
+                    let (segment_index, block_id) = split_prefix(prefix);
+                    let segment_index = segment_index as SegmentIndex;
+                    // segments of other partitions are handled by other aggregators
+                    if self.segment_locations.contains_key(&segment_index) {
+                        grouped
+                            .entry(segment_index)
+                            .or_default()
+                            .entry(block_id)
+                            .or_default()
+                            .push(row_offset as usize);
+                    }
+                }
+
+                for (segment_index, block_rows) in grouped {
+                    let segment_info = self.read_segment_info(segment_index).await?;
+                    let num_blocks = segment_info.blocks.len();
+                    for (block_id, row_offsets) in block_rows {
+                        // the block id in `_row_id` is reversed, see `block_id_in_segment`
+                        let block_id = block_id as usize;
+                        if block_id >= num_blocks {
+                            return Err(ErrorCode::Internal(format!(
+                                "unexpected, block (id {}) not found in segment (idx {}), during accumulating deletion by row id",
+                                block_id, segment_index
+                            )));
+                        }
+                        let block_index = num_blocks - block_id - 1;
+                        self.deletion_accumulator.add_block_row_deletion(
+                            segment_index,
+                            block_index,
+                            row_offsets,
+                        );
+                    }
+                }
+            }
             MergeIntoOperation::None => {}
         }
         Ok(())
@@ -156,28 +207,34 @@ impl MergeIntoOperationAggregator {
     pub async fn apply(&mut self) -> Result<Option<MutationLogs>> {
         let mut mutation_logs = Vec::new();
         for (segment_idx, block_deletion) in &self.deletion_accumulator.deletions {
-            // do we need a local cache?
-            let (path, ver) = self.segment_locations.get(segment_idx).ok_or_else(|| {
-                ErrorCode::Internal(format!(
-                    "unexpected, segment (idx {}) not found, during applying mutation log",
-                    segment_idx
-                ))
-            })?;
-
-            let load_param = LoadParams {
-                location: path.clone(),
-                len_hint: None,
-                ver: *ver,
-                put_cache: true,
-            };
-
-            let compact_segment_info = self.segment_reader.read(&load_param).await?;
-            let segment_info: SegmentInfo = compact_segment_info.as_ref().try_into()?;
-
+            let segment_info = self.read_segment_info(*segment_idx).await?;
             for (block_index, keys) in block_deletion {
                 let block_meta = &segment_info.blocks[*block_index];
                 if let Some(segment_mutation_log) = self
-                    .apply_deletion_to_data_block(*segment_idx, *block_index, block_meta, keys)
+                    .apply_deletion_to_data_block(
+                        *segment_idx,
+                        *block_index,
+                        block_meta,
+                        BlockDeletion::Keys(keys),
+                    )
+                    .await?
+                {
+                    mutation_logs.push(MutationLogEntry::Replacement(segment_mutation_log));
+                }
+            }
+        }
+
+        for (segment_idx, block_deletion) in &self.deletion_accumulator.row_deletions {
+            let segment_info = self.read_segment_info(*segment_idx).await?;
+            for (block_index, row_offsets) in block_deletion {
+                let block_meta = &segment_info.blocks[*block_index];
+                if let Some(segment_mutation_log) = self
+                    .apply_deletion_to_data_block(
+                        *segment_idx,
+                        *block_index,
+                        block_meta,
+                        BlockDeletion::Rows(row_offsets),
+                    )
                     .await?
                 {
                     mutation_logs.push(MutationLogEntry::Replacement(segment_mutation_log));
@@ -189,13 +246,35 @@ impl MergeIntoOperationAggregator {
         }))
     }
 
+    #[async_backtrace::framed]
+    async fn read_segment_info(&self, segment_idx: SegmentIndex) -> Result<SegmentInfo> {
+        // do we need a local cache?
+        let (path, ver) = self.segment_locations.get(&segment_idx).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "unexpected, segment (idx {}) not found, during applying mutation log",
+                segment_idx
+            ))
+        })?;
+
+        let load_param = LoadParams {
+            location: path.clone(),
+            len_hint: None,
+            ver: *ver,
+            put_cache: true,
+        };
+
+        let compact_segment_info = self.segment_reader.read(&load_param).await?;
+        let segment_info: SegmentInfo = compact_segment_info.as_ref().try_into()?;
+        Ok(segment_info)
+    }
+
     #[async_backtrace::framed]
     async fn apply_deletion_to_data_block(
         &self,
         segment_index: SegmentIndex,
         block_index: BlockIndex,
         block_meta: &BlockMeta,
-        deleted_key_hashes: &HashSet<UniqueKeyDigest>,
+        deletion: BlockDeletion<'_>,
     ) -> Result<Option<ReplacementLogEntry>> {
         info!(
             "apply delete to segment idx {}, block idx {}",
@@ -206,7 +285,6 @@ impl MergeIntoOperationAggregator {
         }
 
         let reader = &self.block_reader;
-        let on_conflict_fields = &self.on_conflict_fields;
         // TODO optimization "prewhere"?
         let data_block = reader
             .read_by_meta(
@@ -217,40 +295,21 @@ impl MergeIntoOperationAggregator {
             .await?;
         let num_rows = data_block.num_rows();
 
-        let mut columns = Vec::with_capacity(on_conflict_fields.len());
-        for field in on_conflict_fields {
-            let on_conflict_field_index = field.field_index;
-            let key_column = data_block
-                .columns()
-                .get(on_conflict_field_index)
-                .ok_or_else(|| {
-                    ErrorCode::Internal(format!(
-                        "unexpected, block entry (index {}) not found. segment index {}, block index {}",
-                        on_conflict_field_index, segment_index, block_index
-                    ))
-                })?
-                .value
-                .as_column()
-                .ok_or_else(|| {
-                    ErrorCode::Internal(format!(
-                        "unexpected, cast block entry (index {}) to column failed, got None. segment index {}, block index {}",
-                        on_conflict_field_index, segment_index, block_index
-                    ))
-                })?;
-            columns.push(key_column);
-        }
-
-        let mut bitmap = MutableBitmap::new();
-        for row in 0..num_rows {
-            let mut sip = sip128::SipHasher24::new();
-            for column in &columns {
-                let value = column.index(row).unwrap();
-                let string = value.to_string();
-                sip.write(string.as_bytes());
+        let bitmap = match deletion {
+            BlockDeletion::Keys(deleted_key_hashes) => self.build_bitmap_by_keys(
+                &data_block,
+                segment_index,
+                block_index,
+                deleted_key_hashes,
+            )?,
+            BlockDeletion::Rows(deleted_rows) => {
+                let mut bitmap = MutableBitmap::with_capacity(num_rows);
+                for row in 0..num_rows {
+                    bitmap.push(!deleted_rows.contains(&row));
+                }
+                bitmap
             }
-            let hash = sip.finish128().as_u128();
-            bitmap.push(!deleted_key_hashes.contains(&hash));
-        }
+        };
 
         let delete_nums = bitmap.unset_bits();
         // shortcuts
@@ -325,6 +384,52 @@ impl MergeIntoOperationAggregator {
         Ok(Some(mutation))
     }
 
+    fn build_bitmap_by_keys(
+        &self,
+        data_block: &DataBlock,
+        segment_index: SegmentIndex,
+        block_index: BlockIndex,
+        deleted_key_hashes: &HashSet<UniqueKeyDigest>,
+    ) -> Result<MutableBitmap> {
+        let num_rows = data_block.num_rows();
+        let on_conflict_fields = &self.on_conflict_fields;
+        let mut columns = Vec::with_capacity(on_conflict_fields.len());
+        for field in on_conflict_fields {
+            let on_conflict_field_index = field.field_index;
+            let key_column = data_block
+                .columns()
+                .get(on_conflict_field_index)
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "unexpected, block entry (index {}) not found. segment index {}, block index {}",
+                        on_conflict_field_index, segment_index, block_index
+                    ))
+                })?
+                .value
+                .as_column()
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "unexpected, cast block entry (index {}) to column failed, got None. segment index {}, block index {}",
+                        on_conflict_field_index, segment_index, block_index
+                    ))
+                })?;
+            columns.push(key_column);
+        }
+
+        let mut bitmap = MutableBitmap::new();
+        for row in 0..num_rows {
+            let mut sip = sip128::SipHasher24::new();
+            for column in &columns {
+                let value = column.index(row).unwrap();
+                let string = value.to_string();
+                sip.write(string.as_bytes());
+            }
+            let hash = sip.finish128().as_u128();
+            bitmap.push(!deleted_key_hashes.contains(&hash));
+        }
+        Ok(bitmap)
+    }
+
     fn overlapped(
         &self,
         column_stats: &HashMap<ColumnId, ColumnStatistics>,
@@ -15,5 +15,7 @@
 pub mod deletion_accumulator;
 pub mod merge_into_mutator;
 mod mutation_accumulator;
+mod split_mutator;
 
 pub use mutation_accumulator::MutationAccumulator;
+pub use split_mutator::MergeIntoSplitMutator;
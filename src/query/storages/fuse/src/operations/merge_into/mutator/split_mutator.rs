@@ -0,0 +1,292 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::plan::MergeIntoInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
+use common_expression::types::BooleanType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::evaluator::BlockOperator;
+
+use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::DeletionByRowId;
+use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::MergeIntoOperation;
+
+struct MatchedClause {
+    condition: Option<Expr>,
+    // None means DELETE
+    update: Option<Vec<Expr>>,
+}
+
+struct UnmatchedClause {
+    condition: Option<Expr>,
+    values: Vec<Expr>,
+}
+
+// Split the output of `target RIGHT JOIN source` into
+// - the rows to be appended, i.e. the updated rows and the inserted rows
+// - the rows to be deleted, i.e. the updated rows and the deleted rows, by sending
+//   MergeIntoOperation to downstream
+pub struct MergeIntoSplitMutator {
+    row_id_offset: usize,
+    matched_clauses: Vec<MatchedClause>,
+    unmatched_clauses: Vec<UnmatchedClause>,
+    func_ctx: FunctionContext,
+    // operators which generate the cluster key columns of the appended rows
+    cluster_key_operators: Vec<BlockOperator>,
+    row_id_saw: HashSet<u64>,
+}
+
+impl MergeIntoSplitMutator {
+    pub fn create(
+        merge_into_info: MergeIntoInfo,
+        func_ctx: FunctionContext,
+        cluster_key_operators: Vec<BlockOperator>,
+    ) -> Self {
+        let matched_clauses = merge_into_info
+            .matched_clauses
+            .iter()
+            .map(|clause| MatchedClause {
+                condition: clause
+                    .condition
+                    .as_ref()
+                    .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS)),
+                update: clause.update.as_ref().map(|exprs| {
+                    exprs
+                        .iter()
+                        .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+                        .collect()
+                }),
+            })
+            .collect();
+        let unmatched_clauses = merge_into_info
+            .unmatched_clauses
+            .iter()
+            .map(|clause| UnmatchedClause {
+                condition: clause
+                    .condition
+                    .as_ref()
+                    .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS)),
+                values: clause
+                    .values
+                    .iter()
+                    .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            row_id_offset: merge_into_info.row_id_offset,
+            matched_clauses,
+            unmatched_clauses,
+            func_ctx,
+            cluster_key_operators,
+            row_id_saw: Default::default(),
+        }
+    }
+
+    pub fn process_input_block(
+        &mut self,
+        data_block: &DataBlock,
+    ) -> Result<(Option<DataBlock>, MergeIntoOperation)> {
+        let num_rows = data_block.num_rows();
+        let row_id_entry = data_block.get_by_offset(self.row_id_offset);
+        let row_id_column = row_id_entry
+            .value
+            .convert_to_full_column(&row_id_entry.data_type, num_rows);
+
+        // rows with NULL `_row_id` are not matched
+        let (row_id_column, matched) = match row_id_column {
+            Column::Nullable(nullable) => {
+                let nullable = *nullable;
+                (nullable.column, nullable.validity)
+            }
+            column => (column, MutableBitmap::from_len_set(num_rows).into()),
+        };
+
+        let mut append_blocks = Vec::new();
+        let mut deleted_row_ids = Vec::new();
+
+        if !self.matched_clauses.is_empty() && matched.unset_bits() != num_rows {
+            let matched_block = data_block.clone().filter_with_bitmap(&matched)?;
+            let row_ids = Column::filter(&row_id_column, &matched);
+            let row_ids = NumberType::<u64>::try_downcast_column(&row_ids).ok_or_else(|| {
+                ErrorCode::Internal("unexpected, the type of _row_id column is not UInt64")
+            })?;
+            self.process_matched(
+                matched_block,
+                &row_ids,
+                &mut append_blocks,
+                &mut deleted_row_ids,
+            )?;
+        }
+
+        if !self.unmatched_clauses.is_empty() && matched.unset_bits() != 0 {
+            let unmatched: Bitmap = matched.iter().map(|v| !v).collect();
+            let unmatched_block = data_block.clone().filter_with_bitmap(&unmatched)?;
+            self.process_unmatched(unmatched_block, &mut append_blocks)?;
+        }
+
+        let append_block = if append_blocks.is_empty() {
+            None
+        } else {
+            let block = DataBlock::concat(&append_blocks)?;
+            let block = self
+                .cluster_key_operators
+                .iter()
+                .try_fold(block, |input, op| op.execute(&self.func_ctx, input))?;
+            Some(block)
+        };
+
+        let merge_into_operation = if deleted_row_ids.is_empty() {
+            MergeIntoOperation::None
+        } else {
+            MergeIntoOperation::DeleteByRowId(DeletionByRowId {
+                row_ids: deleted_row_ids,
+            })
+        };
+
+        Ok((append_block, merge_into_operation))
+    }
+
+    fn process_matched(
+        &mut self,
+        matched_block: DataBlock,
+        row_ids: &[u64],
+        append_blocks: &mut Vec<DataBlock>,
+        deleted_row_ids: &mut Vec<u64>,
+    ) -> Result<()> {
+        // a target row can only be modified by one source row
+        for row_id in row_ids {
+            if !self.row_id_saw.insert(*row_id) {
+                return Err(ErrorCode::StorageOther(
+                    "multiple source rows matched the same row of the target table in merge into",
+                ));
+            }
+        }
+
+        let num_rows = matched_block.num_rows();
+        // the rows not handled by the previous clauses
+        let mut remaining = MutableBitmap::from_len_set(num_rows);
+        for clause in &self.matched_clauses {
+            let selected = self.select(&matched_block, &clause.condition, &remaining)?;
+            if selected.unset_bits() == num_rows {
+                continue;
+            }
+
+            for (row, row_id) in row_ids.iter().enumerate() {
+                if selected.get_bit(row) {
+                    remaining.set(row, false);
+                    deleted_row_ids.push(*row_id);
+                }
+            }
+
+            if let Some(update) = &clause.update {
+                let block = matched_block.clone().filter_with_bitmap(&selected)?;
+                append_blocks.push(self.eval_row(&block, update)?);
+            }
+
+            if remaining.unset_bits() == num_rows {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn process_unmatched(
+        &self,
+        unmatched_block: DataBlock,
+        append_blocks: &mut Vec<DataBlock>,
+    ) -> Result<()> {
+        let num_rows = unmatched_block.num_rows();
+        // the rows not handled by the previous clauses
+        let mut remaining = MutableBitmap::from_len_set(num_rows);
+        for clause in &self.unmatched_clauses {
+            let selected = self.select(&unmatched_block, &clause.condition, &remaining)?;
+            if selected.unset_bits() == num_rows {
+                continue;
+            }
+
+            for row in 0..num_rows {
+                if selected.get_bit(row) {
+                    remaining.set(row, false);
+                }
+            }
+
+            let block = unmatched_block.clone().filter_with_bitmap(&selected)?;
+            append_blocks.push(self.eval_row(&block, &clause.values)?);
+
+            if remaining.unset_bits() == num_rows {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    // rows which are not handled yet and satisfy the condition
+    fn select(
+        &self,
+        data_block: &DataBlock,
+        condition: &Option<Expr>,
+        remaining: &MutableBitmap,
+    ) -> Result<Bitmap> {
+        let num_rows = data_block.num_rows();
+        match condition {
+            None => Ok(remaining.clone().into()),
+            Some(condition) => {
+                let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let predicates = evaluator
+                    .run(condition)
+                    .map_err(|e| e.add_message("eval merge into condition failed:"))?
+                    .try_downcast::<BooleanType>()
+                    .unwrap();
+                let predicates = FilterHelpers::filter_to_bitmap(predicates, num_rows);
+                let mut selected = MutableBitmap::with_capacity(num_rows);
+                for row in 0..num_rows {
+                    selected.push(remaining.get(row) && predicates.get(row));
+                }
+                Ok(selected.into())
+            }
+        }
+    }
+
+    // evaluate the row of target table
+    fn eval_row(&self, data_block: &DataBlock, exprs: &[Expr]) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut entries = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            let value = evaluator
+                .run(expr)
+                .map_err(|e| e.add_message("eval merge into values failed:"))?;
+            entries.push(BlockEntry {
+                data_type: expr.data_type().clone(),
+                value,
+            });
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+}
@@ -15,6 +15,7 @@
 // exports components as pipeline processors
 
 mod processor_broadcast;
+mod processor_merge_into_split;
 mod sink_commit;
 mod transform_append;
 mod transform_merge_into_mutation_aggregator;
@@ -23,6 +24,7 @@ mod transform_mutation_aggregator;
 use common_expression::FieldIndex;
 use common_expression::TableField;
 pub use processor_broadcast::*;
+pub use processor_merge_into_split::MergeIntoSplitProcessor;
 pub use sink_commit::CommitSink;
 pub use transform_append::AppendTransform;
 pub use transform_merge_into_mutation_aggregator::*;
@@ -14,11 +14,13 @@
 
 mod analyze;
 mod append;
+mod changes;
 mod commit;
 mod compact;
 mod delete;
 mod fuse_sink;
 mod gc;
+mod merge;
 mod merge_into;
 mod mutation;
 mod navigate;
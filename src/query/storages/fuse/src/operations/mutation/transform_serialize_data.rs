@@ -72,7 +72,7 @@ impl SerializeDataTransform {
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
-            source_schema: table.schema(),
+            source_schema: table.storage_schema(),
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
         };
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -39,6 +40,7 @@ use storages_common_index::Index;
 use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::table::OPT_KEY_BASE_SNAPSHOT_LOCATION;
 use tracing::debug;
 use tracing::info;
 
@@ -68,13 +70,36 @@ impl FuseTable {
                     .meta_location_generator
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version)?;
 
+                // Only the segments appended after the base snapshot are read, if it is set.
+                let base_segments = match self
+                    .table_info
+                    .options()
+                    .get(OPT_KEY_BASE_SNAPSHOT_LOCATION)
+                {
+                    Some(location) => self
+                        .read_table_snapshot_by_location(location.clone())
+                        .await?
+                        .segments
+                        .iter()
+                        .cloned()
+                        .collect(),
+                    None => HashSet::new(),
+                };
+                let segments = snapshot
+                    .segments
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, location)| !base_segments.contains(*location))
+                    .collect::<Vec<_>>();
+
                 let settings = ctx.get_settings();
                 if (settings.get_enable_distributed_eval_index()? && !ctx.get_cluster().is_empty())
                     || is_lazy
                 {
-                    let mut segments = Vec::with_capacity(snapshot.segments.len());
-                    for (idx, segment_location) in snapshot.segments.iter().enumerate() {
-                        segments.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
+                    let segments_len = segments.len();
+                    let mut lazy_parts = Vec::with_capacity(segments_len);
+                    for (idx, segment_location) in segments {
+                        lazy_parts.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
                     }
 
                     return Ok((
@@ -82,18 +107,18 @@ impl FuseTable {
                             Some(snapshot_loc),
                             snapshot.summary.row_count as usize,
                             snapshot.summary.compressed_byte_size as usize,
-                            snapshot.segments.len(),
-                            snapshot.segments.len(),
+                            segments_len,
+                            segments_len,
                         ),
-                        Partitions::create(PartitionsShuffleKind::Mod, segments, true),
+                        Partitions::create(PartitionsShuffleKind::Mod, lazy_parts, true),
                     ));
                 }
 
                 let snapshot_loc = Some(snapshot_loc);
                 let table_info = self.table_info.clone();
                 let summary = snapshot.summary.block_count as usize;
-                let mut segments_location = Vec::with_capacity(snapshot.segments.len());
-                for (idx, segment_location) in snapshot.segments.iter().enumerate() {
+                let mut segments_location = Vec::with_capacity(segments.len());
+                for (idx, segment_location) in segments {
                     segments_location.push(SegmentLocation {
                         segment_idx: idx,
                         location: segment_location.clone(),
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::Projection;
 use common_catalog::plan::PruningStatistics;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
@@ -117,9 +118,14 @@ impl FuseTable {
             .iter()
             .map(|meta| (None, meta.clone()))
             .collect();
+        // The virtual computed columns are not stored, only read the stored columns.
+        let read_push_downs = Some(PushDownInfo {
+            projection: Some(Projection::Columns(self.all_column_indices())),
+            ..PushDownInfo::default()
+        });
         let (statistics, parts) = self.read_partitions_with_metas(
             self.table_info.schema(),
-            None,
+            read_push_downs.clone(),
             &block_metas,
             partitions_total,
             PruningStatistics::default(),
@@ -129,12 +135,12 @@ impl FuseTable {
         let plan = DataSourcePlan {
             catalog: table_info.catalog().to_string(),
             source_info: DataSourceInfo::TableSource(table_info.clone()),
-            output_schema: table_info.schema(),
+            output_schema: self.storage_schema(),
             parts,
             statistics,
             description,
             tbl_args: self.table_args(),
-            push_downs: None,
+            push_downs: read_push_downs,
             query_internal_columns: false,
         };
 
@@ -147,7 +153,7 @@ impl FuseTable {
             self.get_cluster_stats_gen(ctx.clone(), mutator.level() + 1, block_thresholds)?;
         let operators = cluster_stats_gen.operators.clone();
         if !operators.is_empty() {
-            let num_input_columns = self.table_info.schema().fields().len();
+            let num_input_columns = self.storage_schema().fields().len();
             let func_ctx2 = cluster_stats_gen.func_ctx.clone();
             pipeline.add_transform(move |input, output| {
                 Ok(ProcessorPtr::create(CompoundBlockOperator::create(
@@ -216,7 +222,7 @@ impl FuseTable {
                 self.meta_location_generator().clone(),
                 cluster_stats_gen.clone(),
                 block_thresholds,
-                self.table_info.schema(),
+                self.storage_schema(),
                 None,
             )
         })?;
@@ -104,7 +104,7 @@ impl FuseTable {
         on_conflict_field_identifiers: Vec<TableField>,
         pipeline: &'a mut Pipeline,
     ) -> Result<()> {
-        let schema = self.table_info.schema();
+        let schema = self.storage_schema();
 
         let mut on_conflicts = Vec::with_capacity(on_conflict_field_identifiers.len());
         for f in on_conflict_field_identifiers {
@@ -170,7 +170,7 @@ impl FuseTable {
             self.get_write_settings(),
             self.operator.clone(),
             self.meta_location_generator.clone(),
-            self.table_info.schema(),
+            self.storage_schema(),
             self.get_block_thresholds(),
             cluster_stats_gen,
         );
@@ -266,7 +266,7 @@ impl FuseTable {
     }
 
     #[async_backtrace::framed]
-    async fn merge_into_mutators(
+    pub(crate) async fn merge_into_mutators(
         &self,
         ctx: Arc<dyn TableContext>,
         num_partition: usize,
@@ -283,7 +283,7 @@ impl FuseTable {
                 on_conflicts.clone(),
                 chunk_of_segment_locations,
                 self.operator.clone(),
-                self.table_info.schema(),
+                self.storage_schema(),
                 self.get_write_settings(),
                 read_settings.clone(),
                 block_builder.clone(),
@@ -316,7 +316,7 @@ impl FuseTable {
     }
 
     #[async_backtrace::framed]
-    async fn chain_mutation_pipes(
+    pub(crate) async fn chain_mutation_pipes(
         &self,
         ctx: &Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
@@ -357,7 +357,7 @@ impl FuseTable {
         Ok(())
     }
 
-    fn new_empty_snapshot(&self) -> TableSnapshot {
+    pub(crate) fn new_empty_snapshot(&self) -> TableSnapshot {
         TableSnapshot::new(
             Uuid::new_v4(),
             &None,
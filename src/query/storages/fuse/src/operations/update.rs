@@ -136,6 +136,7 @@ impl FuseTable {
     ) -> Result<()> {
         let all_column_indices = self.all_column_indices();
         let schema = self.schema();
+        let storage_schema = self.storage_schema();
 
         let mut offset_map = BTreeMap::new();
         let mut remain_reader = None;
@@ -146,7 +147,7 @@ impl FuseTable {
                 pos += 1;
             });
 
-            (Projection::Columns(all_column_indices), schema.clone())
+            (Projection::Columns(all_column_indices), storage_schema)
         } else {
             col_indices.iter().for_each(|&index| {
                 offset_map.insert(index, pos);
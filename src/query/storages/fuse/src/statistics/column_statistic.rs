@@ -15,10 +15,14 @@
 use std::collections::HashMap;
 
 use common_exception::Result;
+use common_expression::types::geometry::bounding_box_to_statistics;
+use common_expression::types::DataType;
 use common_expression::types::NumberType;
 use common_expression::types::ValueType;
 use common_expression::Column;
+use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
+use common_expression::Domain;
 use common_expression::FieldIndex;
 use common_expression::Scalar;
 use common_expression::TableSchemaRef;
@@ -50,8 +54,9 @@ pub fn gen_columns_statistics(
     let leaves = get_traverse_columns_dfs(&data_block)?;
     let leaf_column_ids = schema.to_leaf_column_ids();
     for ((col_idx, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
+        let is_geometry = data_type.remove_nullable() == DataType::Geometry;
         // Ignore the range index does not supported type.
-        if !RangeIndex::supported_type(data_type) {
+        if !RangeIndex::supported_type(data_type) && !is_geometry {
             continue;
         }
 
@@ -59,8 +64,27 @@ pub fn gen_columns_statistics(
         let mut min = Scalar::Null;
         let mut max = Scalar::Null;
 
-        let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
-        let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
+        let (is_all_null, bitmap) = col.validity();
+        let unset_bits = match (is_all_null, bitmap) {
+            (true, _) => rows,
+            (false, Some(bitmap)) => bitmap.unset_bits(),
+            (false, None) => 0,
+        };
+
+        let (mins, maxs) = if is_geometry {
+            match gen_geometry_statistics(col) {
+                Some(v) => v,
+                // A block without any geometry has no bounding box, the statistics
+                // keep the NULL min/max and the null count, e.g. for `IS NULL`.
+                None if unset_bits == rows => (Column::Null { len: 0 }, Column::Null { len: 0 }),
+                None => continue,
+            }
+        } else {
+            (
+                eval_aggr("min", vec![], &[col.clone()], rows)?.0,
+                eval_aggr("max", vec![], &[col.clone()], rows)?.0,
+            )
+        };
 
         if mins.len() > 0 {
             min = if let Some(v) = mins.index(0) {
@@ -86,13 +110,6 @@ pub fn gen_columns_statistics(
             }
         }
 
-        let (is_all_null, bitmap) = col.validity();
-        let unset_bits = match (is_all_null, bitmap) {
-            (true, _) => rows,
-            (false, Some(bitmap)) => bitmap.unset_bits(),
-            (false, None) => 0,
-        };
-
         // use distinct count calculated by the xor hash function to avoid repetitive operation.
         let distinct_of_values = match (col_idx, &column_distinct_count) {
             (Some(col_idx), Some(ref column_distinct_count)) => {
@@ -124,10 +141,26 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+// Geometries have no meaningful total order, so the min/max statistics of a
+// geometry column keep the corners of its bounding box instead.
+fn gen_geometry_statistics(col: &Column) -> Option<(Column, Column)> {
+    let domain = match col.domain() {
+        Domain::Geometry(domain) => domain,
+        Domain::Nullable(nullable) => *nullable.value?.as_geometry()?,
+        _ => return None,
+    };
+    if domain.is_full() {
+        return None;
+    }
+    let (min, max) = bounding_box_to_statistics(&domain)?;
+    let to_column =
+        |scalar: Scalar| ColumnBuilder::repeat(&scalar.as_ref(), 1, &DataType::Geometry).build();
+    Some((to_column(min), to_column(max)))
+}
+
 pub mod traverse {
     use common_expression::types::map::KvPair;
     use common_expression::types::AnyType;
-    use common_expression::types::DataType;
     use common_expression::BlockEntry;
     use common_expression::Column;
 
@@ -16,6 +16,8 @@ use std::borrow::Borrow;
 use std::collections::HashMap;
 
 use common_exception::Result;
+use common_expression::types::geometry::bounding_box_to_statistics;
+use common_expression::types::geometry::statistics_to_bounding_box;
 use common_expression::BlockThresholds;
 use common_expression::ColumnId;
 use common_expression::Scalar;
@@ -66,19 +68,24 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
             // like this:
             //   `let maxs = eval_aggr("max", vec![], &[column_field], rows)?`
             // we should unify these logics, or at least, ensure the ways they compares do NOT diverge
-            let min = min_stats
-                .iter()
-                .filter(|s| !s.is_null())
-                .min_by(|&x, &y| x.cmp(y))
-                .cloned()
-                .unwrap_or(Scalar::Null);
-
-            let max = max_stats
-                .iter()
-                .filter(|s| !s.is_null())
-                .max_by(|&x, &y| x.cmp(y))
-                .cloned()
-                .unwrap_or(Scalar::Null);
+            let (min, max) = if min_stats.iter().any(|s| matches!(s, Scalar::Geometry(_))) {
+                reduce_geometry_statistics(&min_stats, &max_stats)
+            } else {
+                let min = min_stats
+                    .iter()
+                    .filter(|s| !s.is_null())
+                    .min_by(|&x, &y| x.cmp(y))
+                    .cloned()
+                    .unwrap_or(Scalar::Null);
+
+                let max = max_stats
+                    .iter()
+                    .filter(|s| !s.is_null())
+                    .max_by(|&x, &y| x.cmp(y))
+                    .cloned()
+                    .unwrap_or(Scalar::Null);
+                (min, max)
+            };
 
             acc.insert(*id, ColumnStatistics {
                 min,
@@ -91,6 +98,18 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+// The min/max statistics of a geometry column are the corners of its bounding box,
+// merge them as boxes instead of comparing the scalars.
+fn reduce_geometry_statistics(min_stats: &[Scalar], max_stats: &[Scalar]) -> (Scalar, Scalar) {
+    min_stats
+        .iter()
+        .zip(max_stats)
+        .filter_map(|(min, max)| statistics_to_bounding_box(min, max))
+        .reduce(|l, r| l.merge(&r))
+        .and_then(|domain| bounding_box_to_statistics(&domain))
+        .unwrap_or((Scalar::Null, Scalar::Null))
+}
+
 pub fn merge_statistics(l: &Statistics, r: &Statistics) -> Result<Statistics> {
     let s = Statistics {
         row_count: l.row_count + r.row_count,
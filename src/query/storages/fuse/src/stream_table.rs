@@ -0,0 +1,394 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::InternalColumnMeta;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::BlockEntry;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::FieldIndex;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::Value;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+use sha2::Digest;
+use sha2::Sha256;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_BASE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET_SNAPSHOT;
+
+use crate::FuseTable;
+
+/// The column of a stream telling whether the row is inserted or deleted.
+pub const STREAM_COLUMN_ACTION: &str = "change$action";
+/// The column of a stream identifying the changed row.
+pub const STREAM_COLUMN_ROW_ID: &str = "change$row_id";
+
+const STREAM_ACTION_INSERT: &str = "INSERT";
+const STREAM_ACTION_DELETE: &str = "DELETE";
+
+/// A stream tracks the changes of a fuse table since its offset snapshot.
+///
+/// Reading a stream returns the rows of the base table changed since the offset, with two
+/// more columns telling how each row is changed. A row deleted and inserted again with the
+/// same values, e.g. a row kept in a block rewritten by a mutation, is not changed. The
+/// offset is advanced to the snapshot read when the query reading the stream commits a
+/// mutation.
+pub struct StreamTable {
+    table_info: TableInfo,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        Ok(Box::new(StreamTable { table_info }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "STREAM".to_string(),
+            comment: "STREAM Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    /// The extra columns appended to the schema of the base table.
+    pub fn stream_columns() -> Vec<TableField> {
+        vec![
+            TableField::new(STREAM_COLUMN_ACTION, TableDataType::String),
+            TableField::new(STREAM_COLUMN_ROW_ID, TableDataType::String),
+        ]
+    }
+
+    pub fn offset(&self) -> Option<String> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_STREAM_OFFSET_SNAPSHOT)
+            .cloned()
+    }
+
+    #[async_backtrace::framed]
+    async fn base_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let options = self.table_info.options();
+        let (database, table, table_id) = match (
+            options.get(OPT_KEY_STREAM_BASE_DATABASE),
+            options.get(OPT_KEY_STREAM_BASE_TABLE),
+            options.get(OPT_KEY_STREAM_BASE_TABLE_ID),
+        ) {
+            (Some(database), Some(table), Some(table_id)) => (database, table, table_id),
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "the base table of stream {} is missing",
+                    self.table_info.desc
+                )));
+            }
+        };
+
+        let base = ctx
+            .get_table(self.table_info.catalog(), database, table)
+            .await?;
+        if &base.get_id().to_string() != table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "The base table {}.{} of stream {} has been dropped",
+                database, table, self.table_info.desc
+            )));
+        }
+        Ok(base)
+    }
+
+    // The base table seen by the stream: the stream schema without the stream columns,
+    // sharing the storage of the base table.
+    fn base_table_info(&self) -> Result<TableInfo> {
+        let options = self.table_info.options();
+        let table_id = options
+            .get(OPT_KEY_STREAM_BASE_TABLE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "the base table of stream {} is missing",
+                    self.table_info.desc
+                ))
+            })?;
+
+        let mut table_info = self.table_info.clone();
+        table_info.ident.table_id = table_id;
+        table_info.meta.engine = "FUSE".to_string();
+        let mut schema = table_info.meta.schema.as_ref().clone();
+        schema.drop_column(STREAM_COLUMN_ACTION)?;
+        schema.drop_column(STREAM_COLUMN_ROW_ID)?;
+        table_info.meta.schema = Arc::new(schema);
+        Ok(table_info)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let base = self.base_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(base.as_ref())?;
+        let res = fuse_table.read_changes(ctx.clone(), self.offset()).await?;
+
+        // The offset is advanced along with the mutation committed by this query.
+        let mut new_table_meta = self.table_info.meta.clone();
+        match fuse_table.snapshot_loc().await? {
+            Some(location) => {
+                new_table_meta
+                    .options
+                    .insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT.to_string(), location);
+            }
+            None => {
+                new_table_meta
+                    .options
+                    .remove(OPT_KEY_STREAM_OFFSET_SNAPSHOT);
+            }
+        }
+        ctx.add_stream_offset(UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        });
+
+        Ok(res)
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let base_info = self.base_table_info()?;
+        let base_schema = base_info.schema();
+        let fuse_table = FuseTable::do_create(base_info.clone())?;
+
+        let mut base_plan = plan.clone();
+        base_plan.source_info = DataSourceInfo::TableSource(base_info);
+        base_plan.output_schema = base_schema;
+        base_plan.push_downs = None;
+        base_plan.query_internal_columns = true;
+        let num_base_columns = base_plan.output_schema.num_fields();
+        fuse_table.do_read_data(ctx, &base_plan, pipeline)?;
+
+        // The changes of all the parts are needed to cancel out the unchanged rows.
+        pipeline.resize(1)?;
+        let schema = self.table_info.schema();
+        let projection = plan
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| schema.index_of(field.name()))
+            .collect::<Result<Vec<_>>>()?;
+        let offset = self.offset();
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                TransformStreamChanges {
+                    offset: offset.clone(),
+                    num_base_columns,
+                    projection: projection.clone(),
+                    deleted: vec![],
+                    inserted: vec![],
+                },
+            )))
+        })
+    }
+}
+
+// Fills the stream columns from the `InternalColumnMeta` of the blocks read from the base table,
+// and cancels out the inserted rows against the deleted rows with the same values.
+struct TransformStreamChanges {
+    offset: Option<String>,
+    num_base_columns: usize,
+    projection: Vec<FieldIndex>,
+    deleted: Vec<DataBlock>,
+    inserted: Vec<DataBlock>,
+}
+
+impl TransformStreamChanges {
+    fn row_values<'a>(&self, block: &'a DataBlock, row: usize) -> Vec<ScalarRef<'a>> {
+        block.columns()[..self.num_base_columns]
+            .iter()
+            .map(|entry| match &entry.value {
+                Value::Scalar(scalar) => scalar.as_ref(),
+                Value::Column(column) => column.index(row).unwrap(),
+            })
+            .collect()
+    }
+
+    // Returns the filters of the deleted and inserted blocks, keeping the rows not cancelled out.
+    fn filters(&self) -> (Vec<Bitmap>, Vec<Bitmap>) {
+        // The number of the deleted rows of each values not cancelled out yet.
+        let mut deleted_rows: HashMap<Vec<ScalarRef>, usize> = HashMap::new();
+        for block in &self.deleted {
+            for row in 0..block.num_rows() {
+                *deleted_rows.entry(self.row_values(block, row)).or_default() += 1;
+            }
+        }
+
+        let mut cancelled_rows: HashMap<Vec<ScalarRef>, usize> = HashMap::new();
+        let inserted_filters = self
+            .inserted
+            .iter()
+            .map(|block| {
+                let mut filter = MutableBitmap::with_capacity(block.num_rows());
+                for row in 0..block.num_rows() {
+                    let values = self.row_values(block, row);
+                    match deleted_rows.get_mut(&values) {
+                        Some(count) if *count > 0 => {
+                            *count -= 1;
+                            *cancelled_rows.entry(values).or_default() += 1;
+                            filter.push(false);
+                        }
+                        _ => filter.push(true),
+                    }
+                }
+                filter.into()
+            })
+            .collect();
+
+        let deleted_filters = self
+            .deleted
+            .iter()
+            .map(|block| {
+                let mut filter = MutableBitmap::with_capacity(block.num_rows());
+                for row in 0..block.num_rows() {
+                    match cancelled_rows.get_mut(&self.row_values(block, row)) {
+                        Some(count) if *count > 0 => {
+                            *count -= 1;
+                            filter.push(false);
+                        }
+                        _ => filter.push(true),
+                    }
+                }
+                filter.into()
+            })
+            .collect();
+
+        (deleted_filters, inserted_filters)
+    }
+}
+
+impl AccumulatingTransform for TransformStreamChanges {
+    const NAME: &'static str = "TransformStreamChanges";
+
+    fn transform(&mut self, mut data: DataBlock) -> Result<Vec<DataBlock>> {
+        let meta = data
+            .take_meta()
+            .and_then(InternalColumnMeta::downcast_from)
+            .ok_or_else(|| ErrorCode::Internal("It's a bug: invalid stream block meta"))?;
+        let num_rows = data.num_rows();
+
+        let deleted = self.offset.as_ref() == Some(&meta.snapshot_location);
+        let action = if deleted {
+            STREAM_ACTION_DELETE
+        } else {
+            STREAM_ACTION_INSERT
+        };
+        let block_prefix = format!("{:x}", Sha256::digest(meta.block_location.as_bytes()));
+        let row_ids = match &meta.offsets {
+            Some(offsets) => offsets
+                .iter()
+                .map(|offset| format!("{}{:06x}", block_prefix, offset).into_bytes())
+                .collect::<Vec<_>>(),
+            None => (0..num_rows)
+                .map(|offset| format!("{}{:06x}", block_prefix, offset).into_bytes())
+                .collect::<Vec<_>>(),
+        };
+
+        data.add_column(BlockEntry {
+            data_type: DataType::String,
+            value: Value::Scalar(Scalar::String(action.as_bytes().to_vec())),
+        });
+        data.add_column(BlockEntry {
+            data_type: DataType::String,
+            value: Value::Column(StringType::from_data(row_ids)),
+        });
+
+        if deleted {
+            self.deleted.push(data);
+        } else {
+            self.inserted.push(data);
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+
+        let (deleted_filters, inserted_filters) = self.filters();
+        let deleted = std::mem::take(&mut self.deleted);
+        let inserted = std::mem::take(&mut self.inserted);
+
+        let mut blocks = vec![];
+        for (data, filter) in deleted
+            .into_iter()
+            .zip(deleted_filters)
+            .chain(inserted.into_iter().zip(inserted_filters))
+        {
+            let data = data.filter_with_bitmap(&filter)?;
+            if data.is_empty() {
+                continue;
+            }
+            let columns = self
+                .projection
+                .iter()
+                .map(|index| data.columns()[*index].clone())
+                .collect();
+            blocks.push(DataBlock::new(columns, data.num_rows()));
+        }
+        Ok(blocks)
+    }
+}
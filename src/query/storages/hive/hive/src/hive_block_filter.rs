@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_arrow::arrow::io::orc::format::proto::ColumnStatistics as OrcColumnStatistics;
 use common_arrow::parquet::metadata::RowGroupMetaData;
 use common_arrow::parquet::statistics::BinaryStatistics;
 use common_arrow::parquet::statistics::BooleanStatistics;
@@ -31,6 +32,10 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_storage::orc_column_ids;
+use common_storage::orc_stripe_column_statistics;
+use common_storage::orc_stripe_num_rows;
+use common_storage::OrcFileMeta;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
@@ -95,36 +100,158 @@ impl HiveBlockFilter {
                 }
             }
 
-            for (p_key, p_value) in part_columns {
-                if let Ok(idx) = self.data_schema.index_of(&p_key) {
-                    let mut null_count = 0;
-                    let v = if p_value == HIVE_DEFAULT_PARTITION {
-                        null_count = row_group.num_rows();
-                        Scalar::Null
-                    } else {
-                        Scalar::String(p_value.as_bytes().to_vec())
-                    };
+            return self.filter_by_statistics(
+                filter,
+                statistics,
+                part_columns,
+                row_group.num_rows(),
+            );
+        }
+        false
+    }
 
+    // true: stripe of orc file is filtered by predict
+    pub fn filter_stripe(
+        &self,
+        meta: &OrcFileMeta,
+        stripe: usize,
+        part_columns: HashMap<String, String>,
+    ) -> bool {
+        if let Some(filter) = &self.range_filter {
+            let num_rows = orc_stripe_num_rows(meta, stripe);
+            let mut statistics = StatisticsOfColumns::new();
+            for col in self.projections.iter() {
+                let column_id = match orc_column_ids(meta, &[col.name()]) {
+                    Ok(ids) => ids[0],
+                    Err(_) => continue,
+                };
+                // if stats is none, we couldn't make a decision whether the block should be filtered
+                let stats = match orc_stripe_column_statistics(meta, stripe, column_id) {
+                    None => return false,
+                    Some(stats) => stats,
+                };
+                if let Some((max, min)) = Self::get_orc_max_min_stats(col.data_type(), stats) {
+                    let null_count = stats
+                        .number_of_values
+                        .map(|n| (num_rows as u64).saturating_sub(n))
+                        .unwrap_or_default();
                     let col_stats = ColumnStatistics {
-                        min: v.clone(),
-                        max: v,
-                        null_count: null_count as u64,
-                        in_memory_size: 0,
+                        min,
+                        max,
+                        null_count,
+                        in_memory_size: stats.bytes_on_disk.unwrap_or_default(),
                         distinct_of_values: None,
                     };
-                    statistics.insert(idx as u32, col_stats);
+                    if let Ok(idx) = self.data_schema.index_of(col.name()) {
+                        statistics.insert(idx as u32, col_stats);
+                    }
                 }
             }
 
-            if let Ok(ret) = filter.apply(&statistics) {
-                if !ret {
-                    return true;
-                }
+            return self.filter_by_statistics(filter, statistics, part_columns, num_rows);
+        }
+        false
+    }
+
+    // the values of partition columns are the same in the block
+    fn filter_by_statistics(
+        &self,
+        filter: &RangeIndex,
+        mut statistics: StatisticsOfColumns,
+        part_columns: HashMap<String, String>,
+        num_rows: usize,
+    ) -> bool {
+        for (p_key, p_value) in part_columns {
+            if let Ok(idx) = self.data_schema.index_of(&p_key) {
+                let mut null_count = 0;
+                let v = if p_value == HIVE_DEFAULT_PARTITION {
+                    null_count = num_rows;
+                    Scalar::Null
+                } else {
+                    Scalar::String(p_value.as_bytes().to_vec())
+                };
+
+                let col_stats = ColumnStatistics {
+                    min: v.clone(),
+                    max: v,
+                    null_count: null_count as u64,
+                    in_memory_size: 0,
+                    distinct_of_values: None,
+                };
+                statistics.insert(idx as u32, col_stats);
+            }
+        }
+
+        if let Ok(ret) = filter.apply(&statistics) {
+            if !ret {
+                return true;
             }
         }
         false
     }
 
+    fn get_orc_max_min_stats(
+        column_type: &TableDataType,
+        stats: &OrcColumnStatistics,
+    ) -> Option<(Scalar, Scalar)> {
+        match column_type {
+            TableDataType::Number(NumberDataType::Int8) => {
+                let s = stats.int_statistics.as_ref()?;
+                let max = NumberType::<i8>::upcast_scalar(s.maximum? as i8);
+                let min = NumberType::<i8>::upcast_scalar(s.minimum? as i8);
+                Some((max, min))
+            }
+            TableDataType::Number(NumberDataType::Int16) => {
+                let s = stats.int_statistics.as_ref()?;
+                let max = NumberType::<i16>::upcast_scalar(s.maximum? as i16);
+                let min = NumberType::<i16>::upcast_scalar(s.minimum? as i16);
+                Some((max, min))
+            }
+            TableDataType::Number(NumberDataType::Int32) => {
+                let s = stats.int_statistics.as_ref()?;
+                let max = NumberType::<i32>::upcast_scalar(s.maximum? as i32);
+                let min = NumberType::<i32>::upcast_scalar(s.minimum? as i32);
+                Some((max, min))
+            }
+            TableDataType::Number(NumberDataType::Int64) => {
+                let s = stats.int_statistics.as_ref()?;
+                let max = NumberType::<i64>::upcast_scalar(s.maximum?);
+                let min = NumberType::<i64>::upcast_scalar(s.minimum?);
+                Some((max, min))
+            }
+            TableDataType::Number(NumberDataType::Float32) => {
+                let s = stats.double_statistics.as_ref()?;
+                let max = NumberType::<F32>::upcast_scalar((s.maximum? as f32).into());
+                let min = NumberType::<F32>::upcast_scalar((s.minimum? as f32).into());
+                Some((max, min))
+            }
+            TableDataType::Number(NumberDataType::Float64) => {
+                let s = stats.double_statistics.as_ref()?;
+                let max = NumberType::<F64>::upcast_scalar(s.maximum?.into());
+                let min = NumberType::<F64>::upcast_scalar(s.minimum?.into());
+                Some((max, min))
+            }
+            TableDataType::Boolean => {
+                // the count of true values
+                let true_count = *stats.bucket_statistics.as_ref()?.count.first()?;
+                let num_values = stats.number_of_values?;
+                let max = BooleanType::upcast_scalar(true_count > 0);
+                let min = BooleanType::upcast_scalar(true_count == num_values);
+                Some((max, min))
+            }
+            TableDataType::String => {
+                let s = stats.string_statistics.as_ref()?;
+                let max = StringType::upcast_scalar(s.maximum.clone()?.into_bytes());
+                let min = StringType::upcast_scalar(s.minimum.clone()?.into_bytes());
+                Some((max, min))
+            }
+            TableDataType::Nullable(inner_ty) => {
+                Self::get_orc_max_min_stats(inner_ty.as_ref(), stats)
+            }
+            _ => None,
+        }
+    }
+
     fn get_max_min_stats(
         column_type: &TableDataType,
         stats: &dyn Statistics,
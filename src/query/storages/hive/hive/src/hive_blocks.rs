@@ -15,14 +15,44 @@
 use std::sync::Arc;
 
 use common_arrow::parquet::metadata::FileMetaData;
-use common_arrow::parquet::metadata::RowGroupMetaData;
+use common_storage::orc_stripe_range;
+use common_storage::OrcFileMeta;
 
 use crate::HiveBlockFilter;
 use crate::HivePartInfo;
 
+// the meta data of a data file of hive table,
+// the blocks of parquet file are row groups, and those of orc file are stripes
+#[derive(Clone)]
+pub enum HiveFileMeta {
+    Parquet(Arc<FileMetaData>),
+    Orc(Arc<OrcFileMeta>),
+}
+
+impl HiveFileMeta {
+    fn num_blocks(&self) -> usize {
+        match self {
+            HiveFileMeta::Parquet(meta) => meta.row_groups.len(),
+            HiveFileMeta::Orc(meta) => meta.footer.stripes.len(),
+        }
+    }
+
+    // the byte range (offset, length) of the block in the file
+    fn block_range(&self, idx: usize) -> (u64, u64) {
+        match self {
+            HiveFileMeta::Parquet(meta) => {
+                let row_group = &meta.row_groups[idx];
+                let start = row_group.columns()[0].byte_range().0;
+                (start, row_group.compressed_size() as u64)
+            }
+            HiveFileMeta::Orc(meta) => orc_stripe_range(meta, idx),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct HiveBlocks {
-    pub file_meta: Arc<FileMetaData>,
+    pub file_meta: HiveFileMeta,
     pub part: HivePartInfo,
     pub valid_rowgroups: Vec<usize>,
     pub current_index: usize,
@@ -31,7 +61,7 @@ pub struct HiveBlocks {
 
 impl HiveBlocks {
     pub fn create(
-        file_meta: Arc<FileMetaData>,
+        file_meta: HiveFileMeta,
         part: HivePartInfo,
         hive_block_filter: Arc<HiveBlockFilter>,
     ) -> Self {
@@ -44,28 +74,34 @@ impl HiveBlocks {
         }
     }
 
-    // there are some conditions to filter invalid row_groups:
+    // there are some conditions to filter invalid row_groups (or stripes of orc):
     // 1. the rowgroup doesn't belong to the partition
     // 2. filtered by predict pushdown
     pub fn prune(&mut self) -> bool {
         let mut pruned_rg_cnt = 0;
-        for (idx, row_group) in self.file_meta.row_groups.iter().enumerate() {
-            let start = row_group.columns()[0].byte_range().0;
-            let mid = start + row_group.compressed_size() as u64 / 2;
+        for idx in 0..self.file_meta.num_blocks() {
+            let (start, len) = self.file_meta.block_range(idx);
+            let mid = start + len / 2;
             if !self.part.range.contains(&mid) {
                 continue;
             }
-            if self
-                .hive_block_filter
-                .filter(row_group, self.part.get_partition_map())
-            {
+            let filtered = match &self.file_meta {
+                HiveFileMeta::Parquet(meta) => self
+                    .hive_block_filter
+                    .filter(&meta.row_groups[idx], self.part.get_partition_map()),
+                HiveFileMeta::Orc(meta) => {
+                    self.hive_block_filter
+                        .filter_stripe(meta, idx, self.part.get_partition_map())
+                }
+            };
+            if filtered {
                 pruned_rg_cnt += 1;
             } else {
                 self.valid_rowgroups.push(idx);
             }
         }
         tracing::debug!(
-            "hive parquet predict pushdown have pruned {} rowgroups",
+            "hive predict pushdown have pruned {} rowgroups",
             pruned_rg_cnt
         );
         self.has_blocks()
@@ -75,10 +111,6 @@ impl HiveBlocks {
         self.part.clone()
     }
 
-    pub fn get_current_row_group_meta_data(&self) -> &RowGroupMetaData {
-        &self.file_meta.row_groups[self.get_current_rowgroup_index()]
-    }
-
     pub fn advance(&mut self) {
         self.current_index += 1;
     }
@@ -87,7 +119,7 @@ impl HiveBlocks {
         self.current_index < self.valid_rowgroups.len()
     }
 
-    fn get_current_rowgroup_index(&self) -> usize {
+    pub fn get_current_rowgroup_index(&self) -> usize {
         self.valid_rowgroups[self.current_index]
     }
 }
@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::datatypes::Schema;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
@@ -33,10 +35,20 @@ use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_storage::deserialize_orc_stripe;
+use common_storage::orc_column_ids;
+use common_storage::orc_stripe_num_rows;
+use common_storage::orc_stripe_range;
+use common_storage::read_orc_metadata_async;
+use common_storage::OrcFileMeta;
+use common_storage::OrcStripe;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
 
+use crate::hive_blocks::HiveFileMeta;
 use crate::hive_partition::HivePartInfo;
+use crate::hive_table_options::HiveFileFormat;
+use crate::HiveBlocks;
 use crate::HivePartitionFiller;
 use crate::MetaDataReader;
 
@@ -50,10 +62,18 @@ pub struct HiveBlockReader {
     output_schema: DataSchemaRef,
     hive_partition_filler: Option<HivePartitionFiller>,
     chunk_size: usize,
+    file_format: HiveFileFormat,
+}
+
+enum BlockDeserializer {
+    Parquet(RowGroupDeserializer),
+    // the projected columns of the stripe are deserialized at once
+    Orc(Option<ArrowChunk<Box<dyn Array>>>),
 }
 
 pub struct DataBlockDeserializer {
-    deserializer: RowGroupDeserializer,
+    deserializer: BlockDeserializer,
+    num_rows: usize,
     drained: bool,
 }
 
@@ -61,7 +81,16 @@ impl DataBlockDeserializer {
     fn new(deserializer: RowGroupDeserializer) -> Self {
         let num_rows = deserializer.num_rows();
         Self {
-            deserializer,
+            deserializer: BlockDeserializer::Parquet(deserializer),
+            num_rows,
+            drained: num_rows == 0,
+        }
+    }
+
+    fn new_orc(chunk: ArrowChunk<Box<dyn Array>>, num_rows: usize) -> Self {
+        Self {
+            deserializer: BlockDeserializer::Orc(Some(chunk)),
+            num_rows,
             drained: num_rows == 0,
         }
     }
@@ -76,7 +105,10 @@ impl DataBlockDeserializer {
             return Ok(None);
         };
 
-        let opt = self.deserializer.next().transpose()?;
+        let opt = match &mut self.deserializer {
+            BlockDeserializer::Parquet(deserializer) => deserializer.next().transpose()?,
+            BlockDeserializer::Orc(chunk) => chunk.take(),
+        };
         if let Some(chunk) = opt {
             // If the `Vec<ArrayIter<'static>>` we have passed into the `RowGroupDeserializer`
             // is empty, the deserializer will returns an empty chunk as well(since now rows are consumed).
@@ -88,8 +120,7 @@ impl DataBlockDeserializer {
             let block: DataBlock = DataBlock::from_arrow_chunk(&chunk, schema)?;
 
             return if let Some(filler) = filler {
-                let num_rows = self.deserializer.num_rows();
-                let filled = filler.fill_data(block, part_info, num_rows)?;
+                let filled = filler.fill_data(block, part_info, self.num_rows)?;
                 Ok(Some(filled))
             } else {
                 Ok(Some(block))
@@ -108,6 +139,7 @@ impl HiveBlockReader {
         projection: Projection,
         partition_keys: &Option<Vec<String>>,
         chunk_size: usize,
+        file_format: HiveFileFormat,
     ) -> Result<Arc<HiveBlockReader>> {
         let original_projection = match projection {
             Projection::Columns(projection) => projection,
@@ -146,6 +178,7 @@ impl HiveBlockReader {
             arrow_schema: Arc::new(arrow_schema),
             hive_partition_filler,
             chunk_size,
+            file_format,
         }))
     }
 
@@ -232,7 +265,12 @@ impl HiveBlockReader {
         dal: Operator,
         filename: &str,
         filesize: u64,
-    ) -> Result<Arc<FileMetaData>> {
+    ) -> Result<HiveFileMeta> {
+        if self.file_format == HiveFileFormat::Orc {
+            let meta = read_orc_metadata_async(&dal, filename, Some(filesize)).await?;
+            return Ok(HiveFileMeta::Orc(Arc::new(meta)));
+        }
+
         let reader = MetaDataReader::meta_data_reader(dal);
 
         let load_params = LoadParams {
@@ -242,15 +280,39 @@ impl HiveBlockReader {
             put_cache: true,
         };
 
-        reader.read(&load_params).await
+        let meta: Arc<FileMetaData> = reader.read(&load_params).await?;
+        Ok(HiveFileMeta::Parquet(meta))
     }
 
     #[async_backtrace::framed]
     pub async fn read_columns_data(
         &self,
-        row_group: &RowGroupMetaData,
+        hive_blocks: &HiveBlocks,
         part: &HivePartInfo,
     ) -> Result<Vec<Vec<u8>>> {
+        let row_group = match &hive_blocks.file_meta {
+            HiveFileMeta::Parquet(meta) => {
+                &meta.row_groups[hive_blocks.get_current_rowgroup_index()]
+            }
+            HiveFileMeta::Orc(meta) => {
+                // the columns are located by the stripe footer, so read the whole stripe
+                if self.projection.is_empty() {
+                    return Ok(vec![]);
+                }
+                let stripe = hive_blocks.get_current_rowgroup_index();
+                let (start, len) = orc_stripe_range(meta, stripe);
+                let data = Self::read_column(
+                    self.operator.clone(),
+                    part.filename.to_string(),
+                    start,
+                    len,
+                    Arc::new(Semaphore::new(1)),
+                )
+                .await?;
+                return Ok(vec![data]);
+            }
+        };
+
         let mut join_handlers = Vec::with_capacity(self.projection.len());
 
         let semaphore = Arc::new(Semaphore::new(10));
@@ -274,8 +336,21 @@ impl HiveBlockReader {
     pub fn create_rowgroup_deserializer(
         &self,
         chunks: Vec<Vec<u8>>,
-        row_group: &RowGroupMetaData,
+        hive_blocks: &HiveBlocks,
     ) -> Result<DataBlockDeserializer> {
+        let row_group = match &hive_blocks.file_meta {
+            HiveFileMeta::Parquet(meta) => {
+                &meta.row_groups[hive_blocks.get_current_rowgroup_index()]
+            }
+            HiveFileMeta::Orc(meta) => {
+                return self.create_stripe_deserializer(
+                    chunks,
+                    meta,
+                    hive_blocks.get_current_rowgroup_index(),
+                );
+            }
+        };
+
         if self.projection.len() != chunks.len() {
             return Err(ErrorCode::Internal(
                 "Columns chunk len must be equals projections len.",
@@ -303,6 +378,35 @@ impl HiveBlockReader {
         Ok(DataBlockDeserializer::new(deserializer))
     }
 
+    fn create_stripe_deserializer(
+        &self,
+        mut chunks: Vec<Vec<u8>>,
+        meta: &OrcFileMeta,
+        stripe: usize,
+    ) -> Result<DataBlockDeserializer> {
+        let num_rows = orc_stripe_num_rows(meta, stripe);
+        let chunk = match chunks.pop() {
+            None => ArrowChunk::new(vec![]),
+            Some(data) => {
+                let (offset, _) = orc_stripe_range(meta, stripe);
+                let stripe = OrcStripe {
+                    index: stripe,
+                    offset,
+                    data,
+                };
+                let fields = self
+                    .projection
+                    .iter()
+                    .map(|idx| self.arrow_schema.fields[*idx].clone())
+                    .collect::<Vec<_>>();
+                let names = fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+                let column_ids = orc_column_ids(meta, &names)?;
+                deserialize_orc_stripe(meta, &stripe, &fields, &column_ids)?
+            }
+        };
+        Ok(DataBlockDeserializer::new_orc(chunk, num_rows))
+    }
+
     pub fn create_data_block(
         &self,
         row_group_iterator: &mut DataBlockDeserializer,
@@ -308,6 +308,7 @@ impl HiveTable {
                     projection,
                     &self.table_options.partition_keys,
                     chunk_size,
+                    self.table_options.file_format,
                 )
             }
             (false, Some(v)) => HiveBlockReader::create(
@@ -316,6 +317,7 @@ impl HiveTable {
                 v.prewhere_columns,
                 &self.table_options.partition_keys,
                 chunk_size,
+                self.table_options.file_format,
             ),
         }
     }
@@ -361,6 +363,7 @@ impl HiveTable {
                             v.remain_columns,
                             &self.table_options.partition_keys,
                             chunk_size,
+                            self.table_options.file_format,
                         )?;
                         Arc::new(Some((*reader).clone()))
                     }
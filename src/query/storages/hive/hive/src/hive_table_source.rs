@@ -51,7 +51,7 @@ struct PreWhereData {
 }
 
 enum State {
-    /// Read parquet file meta data
+    /// Read parquet (or orc) file meta data
     /// IO bound
     ReadMeta(Option<PartInfoPtr>),
 
@@ -377,27 +377,25 @@ impl Processor for HiveTableSource {
                 Ok(())
             }
             State::ReadPrewhereData(hive_blocks) => {
-                let row_group = hive_blocks.get_current_row_group_meta_data();
                 let part = hive_blocks.get_part_info();
                 let chunks = self
                     .prewhere_block_reader
-                    .read_columns_data(row_group, &part)
+                    .read_columns_data(&hive_blocks, &part)
                     .await?;
                 let rowgroup_deserializer = self
                     .prewhere_block_reader
-                    .create_rowgroup_deserializer(chunks, row_group)?;
+                    .create_rowgroup_deserializer(chunks, &hive_blocks)?;
                 self.state = State::PrewhereFilter(hive_blocks, rowgroup_deserializer);
                 Ok(())
             }
 
             State::ReadRemainData(hive_blocks, prewhere_data) => {
-                let row_group = hive_blocks.get_current_row_group_meta_data();
                 let part = hive_blocks.get_part_info();
 
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
-                    let chunks = remain_reader.read_columns_data(row_group, &part).await?;
+                    let chunks = remain_reader.read_columns_data(&hive_blocks, &part).await?;
                     let rowgroup_deserializer =
-                        remain_reader.create_rowgroup_deserializer(chunks, row_group)?;
+                        remain_reader.create_rowgroup_deserializer(chunks, &hive_blocks)?;
                     self.state =
                         State::Deserialize(hive_blocks, rowgroup_deserializer, prewhere_data);
                     Ok(())
@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::FunctionContext;
+use common_expression::RawExpr;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_storage::read_orc_metadata;
+use common_storages_hive::HiveBlockFilter;
+use storages_common_index::RangeIndex;
+
+// t_orc.orc has the columns (id int, name string) and 3 stripes,
+// the ids of the stripes are 1..=3, 4..=6 and 7..=9, the names are 'a'..='i'.
+const ORC_FILE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../../../../tests/data/hive/t_orc/t_orc.orc"
+);
+
+fn filter_of(schema: Arc<TableSchema>, func: &str, column: &str, value: Scalar) -> HiveBlockFilter {
+    let field = schema.field_with_name(column).unwrap().clone();
+    let expr = RawExpr::FunctionCall {
+        span: None,
+        name: func.to_string(),
+        params: vec![],
+        args: vec![
+            RawExpr::ColumnRef {
+                span: None,
+                id: column.to_string(),
+                data_type: DataType::from(field.data_type()),
+                display_name: column.to_string(),
+            },
+            RawExpr::Constant {
+                span: None,
+                scalar: value,
+            },
+        ],
+    };
+    let expr = check(&expr, &BUILTIN_FUNCTIONS).unwrap();
+    let range_filter =
+        RangeIndex::try_create(FunctionContext::default(), &expr, schema.clone()).unwrap();
+    HiveBlockFilter::create(Some(range_filter), vec![field], schema)
+}
+
+#[test]
+fn test_filter_orc_stripes() -> Result<()> {
+    let data = std::fs::read(ORC_FILE)?;
+    let meta = read_orc_metadata(&data)?;
+    assert_eq!(meta.footer.stripes.len(), 3);
+
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new(
+            "id",
+            TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int32))),
+        ),
+        TableField::new(
+            "name",
+            TableDataType::Nullable(Box::new(TableDataType::String)),
+        ),
+    ]));
+    let pruned_stripes = |filter: &HiveBlockFilter| {
+        (0..3)
+            .map(|stripe| filter.filter_stripe(&meta, stripe, HashMap::new()))
+            .collect::<Vec<_>>()
+    };
+
+    let filter = filter_of(
+        schema.clone(),
+        "gt",
+        "id",
+        Scalar::Number(NumberScalar::Int32(6)),
+    );
+    assert_eq!(pruned_stripes(&filter), vec![true, true, false]);
+
+    let filter = filter_of(
+        schema.clone(),
+        "lte",
+        "id",
+        Scalar::Number(NumberScalar::Int32(4)),
+    );
+    assert_eq!(pruned_stripes(&filter), vec![false, false, true]);
+
+    let filter = filter_of(
+        schema.clone(),
+        "gt",
+        "id",
+        Scalar::Number(NumberScalar::Int32(100)),
+    );
+    assert_eq!(pruned_stripes(&filter), vec![true, true, true]);
+
+    let filter = filter_of(schema.clone(), "eq", "name", Scalar::String(b"e".to_vec()));
+    assert_eq!(pruned_stripes(&filter), vec![true, false, true]);
+
+    // without range filter, no stripe is pruned
+    let filter = HiveBlockFilter::create(None, vec![], schema);
+    assert_eq!(pruned_stripes(&filter), vec![false, false, false]);
+
+    Ok(())
+}
@@ -39,11 +39,14 @@ mod processes_table;
 mod query_cache_table;
 mod query_log_table;
 mod roles_table;
+mod sequences_table;
 mod settings_table;
 mod stages_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod task_history_table;
+mod tasks_table;
 mod tracing_table;
 mod users_table;
 mod util;
@@ -77,6 +80,7 @@ pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
+pub use sequences_table::SequencesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
 pub use table::SyncOneBlockSystemTable;
@@ -85,5 +89,7 @@ pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::TasksTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
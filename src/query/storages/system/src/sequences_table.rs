@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::ListSequencesReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_fuse::TableContext;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct SequencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for SequencesTable {
+    const NAME: &'static str = "system.sequences";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let sequences = catalog
+            .list_sequences(ListSequencesReq::new(tenant))
+            .await?;
+
+        let mut names = Vec::with_capacity(sequences.len());
+        let mut starts = Vec::with_capacity(sequences.len());
+        let mut increments = Vec::with_capacity(sequences.len());
+        let mut currents = Vec::with_capacity(sequences.len());
+        let mut comments = Vec::with_capacity(sequences.len());
+        let mut created_on = Vec::with_capacity(sequences.len());
+        let mut updated_on = Vec::with_capacity(sequences.len());
+
+        for (name, meta) in sequences {
+            names.push(name.as_bytes().to_vec());
+            starts.push(meta.start);
+            increments.push(meta.increment);
+            currents.push(meta.current);
+            comments.push(meta.comment.unwrap_or_default().as_bytes().to_vec());
+            created_on.push(meta.created_on.timestamp_micros());
+            updated_on.push(meta.updated_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(starts),
+            UInt64Type::from_data(increments),
+            UInt64Type::from_data(currents),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on),
+            TimestampType::from_data(updated_on),
+        ]))
+    }
+}
+
+impl SequencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("start", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("increment", TableDataType::Number(NumberDataType::UInt64)),
+            // The next value not reserved by any query node.
+            TableField::new("current", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'sequences'".to_string(),
+            name: "sequences".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSequences".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt32Type;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::ListTaskRunsReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_fuse::TableContext;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TaskHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TaskHistoryTable {
+    const NAME: &'static str = "system.task_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let runs = catalog.list_task_runs(ListTaskRunsReq::new(tenant)).await?;
+
+        let mut run_ids = Vec::with_capacity(runs.len());
+        let mut names = Vec::with_capacity(runs.len());
+        let mut states = Vec::with_capacity(runs.len());
+        let mut triggers = Vec::with_capacity(runs.len());
+        let mut queries = Vec::with_capacity(runs.len());
+        let mut query_ids = Vec::with_capacity(runs.len());
+        let mut node_ids = Vec::with_capacity(runs.len());
+        let mut scheduled_on = Vec::with_capacity(runs.len());
+        let mut started_on = Vec::with_capacity(runs.len());
+        let mut completed_on = Vec::with_capacity(runs.len());
+        let mut error_codes = Vec::with_capacity(runs.len());
+        let mut error_messages = Vec::with_capacity(runs.len());
+
+        for run in runs {
+            run_ids.push(run.run_id);
+            names.push(run.task_name.as_bytes().to_vec());
+            states.push(run.state.to_string().as_bytes().to_vec());
+            triggers.push(run.trigger.as_bytes().to_vec());
+            queries.push(run.query.as_bytes().to_vec());
+            query_ids.push(run.query_id.as_bytes().to_vec());
+            node_ids.push(run.node_id.as_bytes().to_vec());
+            scheduled_on.push(run.scheduled_on.timestamp_micros());
+            started_on.push(run.started_on.timestamp_micros());
+            completed_on.push(run.completed_on.map(|t| t.timestamp_micros()));
+            error_codes.push(run.error_code);
+            error_messages.push(run.error_message.map(|m| m.as_bytes().to_vec()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(run_ids),
+            StringType::from_data(names),
+            StringType::from_data(states),
+            StringType::from_data(triggers),
+            StringType::from_data(queries),
+            StringType::from_data(query_ids),
+            StringType::from_data(node_ids),
+            TimestampType::from_data(scheduled_on),
+            TimestampType::from_data(started_on),
+            TimestampType::from_opt_data(completed_on),
+            UInt32Type::from_data(error_codes),
+            StringType::from_opt_data(error_messages),
+        ]))
+    }
+}
+
+impl TaskHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("run_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("name", TableDataType::String),
+            // EXECUTING, SUCCEEDED or FAILED.
+            TableField::new("state", TableDataType::String),
+            // SCHEDULE, EXECUTE or AFTER <task>.
+            TableField::new("trigger", TableDataType::String),
+            TableField::new("query", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("scheduled_on", TableDataType::Timestamp),
+            TableField::new("started_on", TableDataType::Timestamp),
+            TableField::new(
+                "completed_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "error_message",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'task_history'".to_string(),
+            name: "task_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTaskHistory".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::ListTasksReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_fuse::TableContext;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TasksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TasksTable {
+    const NAME: &'static str = "system.tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let tasks = catalog.list_tasks(ListTasksReq::new(tenant)).await?;
+
+        let mut names = Vec::with_capacity(tasks.len());
+        let mut states = Vec::with_capacity(tasks.len());
+        let mut schedules = Vec::with_capacity(tasks.len());
+        let mut afters = Vec::with_capacity(tasks.len());
+        let mut queries = Vec::with_capacity(tasks.len());
+        let mut comments = Vec::with_capacity(tasks.len());
+        let mut owners = Vec::with_capacity(tasks.len());
+        let mut created_on = Vec::with_capacity(tasks.len());
+        let mut updated_on = Vec::with_capacity(tasks.len());
+
+        for (name, meta) in tasks {
+            names.push(name.as_bytes().to_vec());
+            states.push(meta.state.to_string().as_bytes().to_vec());
+            schedules.push(meta.schedule.map(|s| s.as_bytes().to_vec()));
+            afters.push(meta.after.join(", ").as_bytes().to_vec());
+            queries.push(meta.query.as_bytes().to_vec());
+            comments.push(meta.comment.unwrap_or_default().as_bytes().to_vec());
+            owners.push(meta.owner.to_string().as_bytes().to_vec());
+            created_on.push(meta.created_on.timestamp_micros());
+            updated_on.push(meta.updated_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(states),
+            StringType::from_opt_data(schedules),
+            StringType::from_data(afters),
+            StringType::from_data(queries),
+            StringType::from_data(comments),
+            StringType::from_data(owners),
+            TimestampType::from_data(created_on),
+            TimestampType::from_data(updated_on),
+        ]))
+    }
+}
+
+impl TasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            // STARTED or SUSPENDED.
+            TableField::new("state", TableDataType::String),
+            TableField::new(
+                "schedule",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            // The tasks after which this task runs.
+            TableField::new("after", TableDataType::String),
+            TableField::new("query", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tasks'".to_string(),
+            name: "tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTasks".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -34,6 +34,7 @@ mod jwt;
 mod role_mgr;
 mod user;
 mod user_api;
+mod user_masking_policy;
 mod user_mgr;
 mod user_setting;
 mod user_stage;
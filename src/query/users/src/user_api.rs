@@ -19,6 +19,8 @@ use common_exception::Result;
 use common_grpc::RpcClientConf;
 use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
+use common_management::MaskingPolicyApi;
+use common_management::MaskingPolicyMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
@@ -118,6 +120,13 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_masking_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn MaskingPolicyApi>> {
+        Ok(Arc::new(MaskingPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::MaskingPolicy;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Masking policy operations.
+impl UserApiProvider {
+    // Add a new masking policy.
+    #[async_backtrace::framed]
+    pub async fn add_masking_policy(
+        &self,
+        tenant: &str,
+        policy: MaskingPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_masking_policy_api_client(tenant)?;
+        match client.add_masking_policy(policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::MASKING_POLICY_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a masking policy by name.
+    #[async_backtrace::framed]
+    pub async fn get_masking_policy(&self, tenant: &str, name: &str) -> Result<MaskingPolicy> {
+        let client = self.get_masking_policy_api_client(tenant)?;
+        Ok(client.get_masking_policy(name, MatchSeq::GE(0)).await?.data)
+    }
+
+    // Get all masking policies for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_masking_policies(&self, tenant: &str) -> Result<Vec<MaskingPolicy>> {
+        let client = self.get_masking_policy_api_client(tenant)?;
+        match client.get_masking_policies().await {
+            Err(e) => Err(e.add_message_back("(while get masking policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a masking policy by name.
+    #[async_backtrace::framed]
+    pub async fn drop_masking_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_masking_policy_api_client(tenant)?;
+        match client.drop_masking_policy(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_MASKING_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop masking policy)"))
+                }
+            }
+        }
+    }
+}
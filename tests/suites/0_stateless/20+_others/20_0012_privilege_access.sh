@@ -106,6 +106,15 @@ echo "select count(*)>=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_
 echo "GRANT SELECT ON system.fuse_block TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
 echo "select count(*)>=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_CONNECT
 
+## stream
+echo "select 'test -- stream'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT CREATE ON default.* TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create stream default.s_t20_0012 on table default.t20_0012_b" | $TEST_USER_CONNECT
+echo "GRANT SELECT ON default.t20_0012_b TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create stream default.s_t20_0012 on table default.t20_0012_b" | $TEST_USER_CONNECT
+echo "select count(*) from default.s_t20_0012" | $MYSQL_CLIENT_CONNECT
+echo "drop stream default.s_t20_0012" | $MYSQL_CLIENT_CONNECT
+
 ## Drop table.
 echo "drop table default.t20_0012 all" | $MYSQL_CLIENT_CONNECT
 echo "drop table default.t20_0012_a all" | $MYSQL_CLIENT_CONNECT
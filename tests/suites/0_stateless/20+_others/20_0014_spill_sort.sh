@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+SETTINGS="set max_threads = 8; set sort_spilling_bytes_threshold_per_proc = 1024 * 16;"
+OUTPUT="/tmp/20_0014_spill_sort.out"
+
+echo "--- order by"
+echo "${SETTINGS} SELECT number FROM numbers_mt(100000) ORDER BY number DESC;" | $MYSQL_CLIENT_CONNECT > ${OUTPUT}
+wc -l < ${OUTPUT}
+sort -c -n -r ${OUTPUT} && echo "sorted"
+
+echo "--- order by multiple columns"
+echo "${SETTINGS} SELECT number % 100 AS a, number::string AS b FROM numbers_mt(100000) ORDER BY a, b DESC;" | $MYSQL_CLIENT_CONNECT > ${OUTPUT}
+wc -l < ${OUTPUT}
+LC_ALL=C sort -c -t "$(printf '\t')" -k1,1n -k2,2r ${OUTPUT} && echo "sorted"
+
+echo "--- spilled"
+echo "${SETTINGS} EXPLAIN ANALYZE SELECT number FROM numbers_mt(100000) ORDER BY number DESC;" | $MYSQL_CLIENT_CONNECT > ${OUTPUT}
+grep -c "spilled blocks" ${OUTPUT}
+grep -c "restored blocks" ${OUTPUT}
+
+echo "--- not spilled"
+echo "set sort_spilling_bytes_threshold_per_proc = 0; EXPLAIN ANALYZE SELECT number FROM numbers_mt(100000) ORDER BY number DESC;" | $MYSQL_CLIENT_CONNECT > ${OUTPUT}
+grep -c "spilled blocks" ${OUTPUT}
+
+# The client stops reading after the first row, so the spilled runs are not all merged.
+echo "--- spill files removed"
+echo "${SETTINGS} SELECT number, number::string FROM numbers_mt(100000) ORDER BY number DESC;" | $MYSQL_CLIENT_CONNECT | head -n 1 > /dev/null
+sleep 3
+SPILL_DIR="$CURDIR/../../../../.databend/stateless_test_data/_sort_spill"
+find "${SPILL_DIR}" -type f 2>/dev/null | wc -l
+
+rm -f ${OUTPUT}
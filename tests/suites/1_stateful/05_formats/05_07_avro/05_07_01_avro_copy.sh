@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_avro (
+  id          INT,
+  name        VARCHAR,
+  tags        ARRAY(VARCHAR),
+  attrs       MAP(VARCHAR, INT64),
+  point       TUPLE(x FLOAT64, y FLOAT64),
+  note        VARCHAR NULL
+) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+
+DATADIR_PATH="/tmp/data_05_07_01"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+cp "$CURDIR"/../../../../data/sample.avro ${DATADIR_PATH}/
+
+echo "---copy into table"
+echo "copy into test_avro from 'fs://${DATADIR_PATH}/' FILE_FORMAT = (type = AVRO) pattern = '.*avro'" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_avro order by id" | $MYSQL_CLIENT_CONNECT
+echo "truncate table test_avro" | $MYSQL_CLIENT_CONNECT
+
+echo "---streaming load"
+curl -sH "insert_sql:insert into test_avro file_format = (type = AVRO)" -F "upload=@${DATADIR_PATH}/sample.avro" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -c "SUCCESS"
+echo "select * from test_avro order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => 'fs://${DATADIR_PATH}/', FILE_FORMAT => 'avro')" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_avro" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_orc (
+  id          INT,
+  name        VARCHAR NULL,
+  score       DOUBLE NULL
+) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+
+DATADIR_PATH="/tmp/data_05_08_01"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+cp "$CURDIR"/../../../../data/sample.orc ${DATADIR_PATH}/
+
+echo "---copy into table"
+echo "copy into test_orc from 'fs://${DATADIR_PATH}/' FILE_FORMAT = (type = ORC) pattern = '.*orc'" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_orc order by id" | $MYSQL_CLIENT_CONNECT
+echo "truncate table test_orc" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy into table with projection"
+echo "drop table if exists test_orc_projection" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_orc_projection (score DOUBLE NULL, id INT) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_orc_projection from 'fs://${DATADIR_PATH}/' FILE_FORMAT = (type = ORC) pattern = '.*orc'" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_orc_projection order by id" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_orc_projection" | $MYSQL_CLIENT_CONNECT
+
+echo "---streaming load"
+curl -sH "insert_sql:insert into test_orc file_format = (type = ORC)" -F "upload=@${DATADIR_PATH}/sample.orc" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -c "SUCCESS"
+echo "select * from test_orc order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => 'fs://${DATADIR_PATH}/', FILE_FORMAT => 'orc')" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_orc" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/08_01_00"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+cp "$CURDIR"/../../../../data/sample.orc ${DATADIR_PATH}/
+DATADIR="fs://$DATADIR_PATH/"
+
+echo '--- external stage'
+echo "drop stage if exists s_orc;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s_orc url = '${DATADIR}' FILE_FORMAT = (type = ORC);"  | $MYSQL_CLIENT_CONNECT
+echo "select * from @s_orc order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo '--- projection and filter'
+echo "select name, id from @s_orc where id > 2 order by id;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), count(name), count(score) from @s_orc;" | $MYSQL_CLIENT_CONNECT
+
+echo '--- file_format'
+echo "drop stage if exists s_csv;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s_csv url = '${DATADIR}' FILE_FORMAT = (type = CSV);"  | $MYSQL_CLIENT_CONNECT
+echo "select id, name from @s_csv (FILE_FORMAT => 'ORC', PATTERN => '.*orc') order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop stage s_orc;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s_csv;" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
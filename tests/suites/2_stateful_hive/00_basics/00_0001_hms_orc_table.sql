@@ -0,0 +1,6 @@
+select * from hive.default.t_orc order by id;
+-- the stripes are pruned by the statistics of the stripes
+select * from hive.default.t_orc where id > 6 order by id;
+select * from hive.default.t_orc where id >= 4 and id <= 5 order by id;
+select id from hive.default.t_orc where name = 'b';
+select count(*) from hive.default.t_orc where id > 100;